@@ -0,0 +1,47 @@
+//! Compare the static sorted table lookup with the former `HashMap` lookup
+//!
+//! ```cmd
+//! $ cargo bench
+//! ```
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use to_uppercase_az::{Uppercase, UppercaseAZ};
+
+/// Sample text mixing ASCII, decorated letters, ligatures and unmapped chars
+const SAMPLE: &str = "À l'œil, Ærøskøbing et ǅemal ont 2 ℍ ℕ ⅆ ⅇ: Ça déménage à São Paulo !";
+
+/// Build the `HashMap` used before the static table, from the static table content
+fn hashmap_from_table() -> HashMap<u32, Uppercase> {
+    UppercaseAZ::default()
+        .iter()
+        .map(|(c, uppercase)| (c as u32, uppercase))
+        .collect()
+}
+
+fn bench_lookup(criterion: &mut Criterion) {
+    let uppercase_az = UppercaseAZ::default();
+    let hashmap = hashmap_from_table();
+
+    let mut group = criterion.benchmark_group("lookup");
+    group.bench_function("static_table", |b| {
+        b.iter(|| {
+            for c in black_box(SAMPLE).chars() {
+                black_box(uppercase_az.get(c));
+            }
+        });
+    });
+    group.bench_function("hashmap", |b| {
+        b.iter(|| {
+            for c in black_box(SAMPLE).chars() {
+                black_box(hashmap.get(&(c as u32)).copied());
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookup);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+//! Combining marks (general category `Mn`) absorbed by the preceding letter as decorations (e.g: `e` + U+0301)
+use crate::decorations::Decorations;
+use crate::table::lookup;
+
+// Static table (sorted by code point) for combining marks and their decorations
+include!("hash_combining_marks.rs");
+
+/// Returns the decorations of a combining mark (e.g: `ACUTE` for U+0301 COMBINING ACUTE ACCENT)
+///
+/// The decorations are empty for a combining mark without decoration keyword (e.g: U+0363 COMBINING LATIN
+/// SMALL LETTER A).
+pub fn combining_mark_of(c: char) -> Option<Decorations> {
+    lookup(COMBINING_MARKS, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_combining_marks_table_sorted() {
+        assert!(is_sorted_by_code_point(COMBINING_MARKS));
+    }
+
+    #[test]
+    fn test_combining_mark_of() {
+        assert_eq!(combining_mark_of('\u{301}'), Some(Decorations::ACUTE));
+        assert_eq!(combining_mark_of('\u{300}'), Some(Decorations::GRAVE));
+        assert_eq!(combining_mark_of('\u{327}'), Some(Decorations::CEDILLA));
+        assert_eq!(
+            combining_mark_of('\u{30A}'),
+            Some(Decorations::ABOVE | Decorations::RING)
+        );
+        assert_eq!(combining_mark_of('\u{342}'), Some(Decorations::PERISPOMENI));
+        assert_eq!(combining_mark_of('\u{363}'), Some(Decorations::empty()));
+        assert_eq!(combining_mark_of('é'), None);
+        assert_eq!(combining_mark_of('´'), None);
+    }
+}
@@ -0,0 +1,504 @@
+//! Conversion options: allowed properties and policy for chars without uppercase AZ equivalent
+use core::fmt::Display;
+
+use crate::cyrillic::Cyrillic;
+use crate::decorations::Decorations;
+use crate::locale::Locale;
+use crate::property::Property;
+use crate::symbol::Symbol;
+
+/// Policy for a char without uppercase AZ equivalent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unmapped {
+    /// The char is copied unchanged
+    #[default]
+    Keep,
+
+    /// The char is removed
+    Drop,
+
+    /// The char is replaced by the given char (e.g: `'?'` as in [`crate::NOT_AN_UPPERCASE`])
+    Replace(char),
+
+    /// The conversion fails with a [`ConversionError`]
+    Fail,
+}
+
+/// Conversion options: allowed properties and policy for chars without uppercase AZ equivalent by
+/// category of char
+///
+/// By default, all properties are allowed and all chars without uppercase AZ equivalent are kept unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conversion {
+    // Allowed properties (bit set indexed by `Property`)
+    properties: u16,
+
+    // Allowed decorations
+    decorations: Decorations,
+
+    // Converted symbols (bit set indexed by `Symbol`)
+    symbols: u8,
+
+    // true to produce lowercase letters for small letters (a-z and A-Z)
+    preserve_case: bool,
+
+    // true to convert Unicode digits to 0-9
+    fold_digits: bool,
+
+    // true to convert Unicode punctuation and spaces to ASCII
+    fold_punctuation: bool,
+
+    // Language-specific tailorings (None: default uppercase AZ equivalent)
+    locale: Option<Locale>,
+
+    // Transliteration standard for Cyrillic letters (None: not converted)
+    cyrillic: Option<Cyrillic>,
+
+    // true to transliterate Greek letters (ELOT 743)
+    greek: bool,
+
+    // true to romanize Hangul syllables (Revised Romanization)
+    hangul: bool,
+
+    // Separator between consecutive romanized Hangul syllables (e.g: `-`)
+    hangul_separator: Option<char>,
+
+    // true to romanize kana (modified Hepburn)
+    kana: bool,
+
+    // Policy for digits (e.g: 0, 1, ², ①, ...)
+    digit: Unmapped,
+
+    // Policy for whitespaces (e.g: space, tab, ...)
+    whitespace: Unmapped,
+
+    // Policy for ASCII punctuation (e.g: ', !, ?, ...)
+    punctuation: Unmapped,
+
+    // Policy for any other char (e.g: CJK, emoji, ...)
+    other: Unmapped,
+}
+
+impl Default for Conversion {
+    fn default() -> Self {
+        Self::new(Unmapped::Keep)
+    }
+}
+
+impl Conversion {
+    /// Conversion options with the same policy for all categories of chars
+    #[must_use]
+    pub const fn new(unmapped: Unmapped) -> Self {
+        Self {
+            properties: u16::MAX,
+            decorations: Decorations::all(),
+            symbols: u8::MAX,
+            preserve_case: false,
+            fold_digits: false,
+            fold_punctuation: false,
+            locale: None,
+            cyrillic: None,
+            greek: false,
+            hangul: false,
+            hangul_separator: None,
+            kana: false,
+            digit: unmapped,
+            whitespace: unmapped,
+            punctuation: unmapped,
+            other: unmapped,
+        }
+    }
+
+    /// Policy for digits
+    #[must_use]
+    pub const fn digit(mut self, unmapped: Unmapped) -> Self {
+        self.digit = unmapped;
+        self
+    }
+
+    /// Policy for whitespaces
+    #[must_use]
+    pub const fn whitespace(mut self, unmapped: Unmapped) -> Self {
+        self.whitespace = unmapped;
+        self
+    }
+
+    /// Policy for ASCII punctuation
+    #[must_use]
+    pub const fn punctuation(mut self, unmapped: Unmapped) -> Self {
+        self.punctuation = unmapped;
+        self
+    }
+
+    /// Policy for any other char
+    #[must_use]
+    pub const fn other(mut self, unmapped: Unmapped) -> Self {
+        self.other = unmapped;
+        self
+    }
+
+    /// Allowed properties: chars with an uppercase AZ equivalent having another property are handled as
+    /// chars without uppercase AZ equivalent
+    #[must_use]
+    pub const fn properties(mut self, properties: &[Property]) -> Self {
+        self.properties = 0;
+        let mut index = 0;
+        while index < properties.len() {
+            self.properties |= 1 << properties[index] as u16;
+            index += 1;
+        }
+        self
+    }
+
+    /// Returns true if the property is allowed
+    #[must_use]
+    pub const fn is_allowed(&self, property: Property) -> bool {
+        self.properties & (1 << property as u16) != 0
+    }
+
+    /// Allowed decorations: chars with an uppercase AZ equivalent having some other decorations are
+    /// handled as chars without uppercase AZ equivalent (e.g: `Decorations::ACUTE` to convert `é` but not
+    /// `è` nor `ø`)
+    #[must_use]
+    pub const fn decorations(mut self, decorations: Decorations) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Returns true if all the decorations are allowed
+    #[must_use]
+    pub const fn are_allowed(&self, decorations: Decorations) -> bool {
+        self.decorations.contains(decorations)
+    }
+
+    /// Converted symbols: symbols with an uppercase AZ equivalent of another category (e.g: `🇫` with
+    /// [`Symbol::RegionalIndicator`], `Ⓐ` with [`Symbol::EnclosedLetter`], `㎏` with [`Symbol::Abbreviation`])
+    /// are handled as chars without uppercase AZ equivalent
+    #[must_use]
+    pub const fn symbols(mut self, symbols: &[Symbol]) -> Self {
+        self.symbols = 0;
+        let mut index = 0;
+        while index < symbols.len() {
+            self.symbols |= 1 << symbols[index] as u8;
+            index += 1;
+        }
+        self
+    }
+
+    /// Returns true if the symbols of the category are converted
+    #[must_use]
+    pub const fn is_converting(&self, symbol: Symbol) -> bool {
+        self.symbols & (1 << symbol as u8) != 0
+    }
+
+    /// Case-preserving mode: small letters are converted to a-z instead of A-Z (e.g: `à` -> `a`, `æ` -> `ae`,
+    /// `ǅ` -> `Dz`)
+    #[must_use]
+    pub const fn preserve_case(mut self, preserve_case: bool) -> Self {
+        self.preserve_case = preserve_case;
+        self
+    }
+
+    /// Returns true if the case-preserving mode is selected
+    #[must_use]
+    pub const fn is_preserving_case(&self) -> bool {
+        self.preserve_case
+    }
+
+    /// Digits folding: Unicode digits are converted to ASCII 0-9 (e.g: `０`, `①`, `²`, `₃`, `٣` or `३`)
+    #[must_use]
+    pub const fn fold_digits(mut self, fold_digits: bool) -> Self {
+        self.fold_digits = fold_digits;
+        self
+    }
+
+    /// Returns true if the Unicode digits are converted to ASCII 0-9
+    #[must_use]
+    pub const fn is_folding_digits(&self) -> bool {
+        self.fold_digits
+    }
+
+    /// Punctuation folding: Unicode punctuation and spaces are converted to their closest ASCII equivalent
+    /// (e.g: `’` -> `'`, `«` -> `"`, `–` -> `-`, `…` -> `...` or no-break space -> space)
+    #[must_use]
+    pub const fn fold_punctuation(mut self, fold_punctuation: bool) -> Self {
+        self.fold_punctuation = fold_punctuation;
+        self
+    }
+
+    /// Returns true if the Unicode punctuation and spaces are converted to ASCII
+    #[must_use]
+    pub const fn is_folding_punctuation(&self) -> bool {
+        self.fold_punctuation
+    }
+
+    /// Language-specific tailorings: some chars are converted according to the conventions of the language
+    /// (e.g: `Ä` -> `AE` with [`Locale::German`]), the others keep their default uppercase AZ equivalent
+    #[must_use]
+    pub const fn locale(mut self, locale: Option<Locale>) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Language of the tailorings, if any
+    #[must_use]
+    pub const fn locale_tailoring(&self) -> Option<Locale> {
+        self.locale
+    }
+
+    /// Cyrillic transliteration: Cyrillic letters are converted according to the given standard (e.g:
+    /// `Щ` -> `SHCH` with [`Cyrillic::Icao9303`]) or not converted (`None`, default)
+    #[must_use]
+    pub const fn cyrillic(mut self, cyrillic: Option<Cyrillic>) -> Self {
+        self.cyrillic = cyrillic;
+        self
+    }
+
+    /// Transliteration standard for Cyrillic letters, if any
+    #[must_use]
+    pub const fn cyrillic_standard(&self) -> Option<Cyrillic> {
+        self.cyrillic
+    }
+
+    /// Greek transliteration: Greek letters are converted according to ELOT 743 / ISO 843, with its context
+    /// rules (e.g: `Θ` -> `TH`, `αυ` -> `AV` or `AF`, `ου` -> `OU`, `γγ` -> `NG`)
+    #[must_use]
+    pub const fn greek(mut self, greek: bool) -> Self {
+        self.greek = greek;
+        self
+    }
+
+    /// Returns true if the Greek letters are transliterated
+    #[must_use]
+    pub const fn is_transliterating_greek(&self) -> bool {
+        self.greek
+    }
+
+    /// Hangul romanization: Hangul syllables are converted according to the Revised Romanization of Korean
+    /// (e.g: `한국` -> `HANGUK`)
+    #[must_use]
+    pub const fn hangul(mut self, hangul: bool) -> Self {
+        self.hangul = hangul;
+        self
+    }
+
+    /// Returns true if the Hangul syllables are romanized
+    #[must_use]
+    pub const fn is_romanizing_hangul(&self) -> bool {
+        self.hangul
+    }
+
+    /// Separator inserted between consecutive romanized Hangul syllables (e.g: `Some('-')` for `한국` ->
+    /// `HAN-GUK`), none by default
+    #[must_use]
+    pub const fn hangul_separator(mut self, separator: Option<char>) -> Self {
+        self.hangul_separator = separator;
+        self
+    }
+
+    /// Separator between consecutive romanized Hangul syllables, if any
+    #[must_use]
+    pub const fn hangul_syllable_separator(&self) -> Option<char> {
+        self.hangul_separator
+    }
+
+    /// Kana romanization: hiragana, katakana and halfwidth katakana are converted according to the modified
+    /// Hepburn romanization (e.g: `きゃ` -> `KYA`, `がっこう` -> `GAKKOU`)
+    #[must_use]
+    pub const fn kana(mut self, kana: bool) -> Self {
+        self.kana = kana;
+        self
+    }
+
+    /// Returns true if the kana are romanized
+    #[must_use]
+    pub const fn is_romanizing_kana(&self) -> bool {
+        self.kana
+    }
+
+    /// Policy for a given char without uppercase AZ equivalent
+    #[must_use]
+    pub fn unmapped(&self, c: char) -> Unmapped {
+        if c.is_numeric() {
+            self.digit
+        } else if c.is_whitespace() {
+            self.whitespace
+        } else if c.is_ascii_punctuation() {
+            self.punctuation
+        } else {
+            self.other
+        }
+    }
+}
+
+/// Error for a char without uppercase AZ equivalent when the policy is [`Unmapped::Fail`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError {
+    /// Char without uppercase AZ equivalent
+    pub c: char,
+
+    /// Position (byte offset) of the char in the text
+    pub position: usize,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "no uppercase AZ equivalent for '{}' (U+{:04X}) at position {}",
+            self.c, self.c as u32, self.position
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion_default() {
+        let conversion = Conversion::default();
+        for c in ['1', ' ', '!', '中'] {
+            assert_eq!(conversion.unmapped(c), Unmapped::Keep);
+        }
+    }
+
+    #[test]
+    fn test_conversion_by_category() {
+        let conversion = Conversion::new(Unmapped::Fail)
+            .digit(Unmapped::Drop)
+            .whitespace(Unmapped::Keep)
+            .punctuation(Unmapped::Replace('?'));
+        assert_eq!(conversion.unmapped('1'), Unmapped::Drop);
+        assert_eq!(conversion.unmapped('②'), Unmapped::Drop);
+        assert_eq!(conversion.unmapped(' '), Unmapped::Keep);
+        assert_eq!(conversion.unmapped('\t'), Unmapped::Keep);
+        assert_eq!(conversion.unmapped('!'), Unmapped::Replace('?'));
+        assert_eq!(conversion.unmapped('中'), Unmapped::Fail);
+        assert_eq!(conversion.unmapped('«'), Unmapped::Fail);
+    }
+
+    #[test]
+    fn test_conversion_properties() {
+        let conversion = Conversion::default();
+        assert!(conversion.is_allowed(Property::Capital));
+        assert!(conversion.is_allowed(Property::SmallAndCapitalWithDecoration));
+
+        let conversion = conversion.properties(&[Property::Capital, Property::Small]);
+        assert!(conversion.is_allowed(Property::Capital));
+        assert!(conversion.is_allowed(Property::Small));
+        assert!(!conversion.is_allowed(Property::CapitalWithDecoration));
+        assert!(!conversion.is_allowed(Property::SmallAndCapital));
+    }
+
+    #[test]
+    fn test_conversion_decorations() {
+        let conversion = Conversion::default();
+        assert!(conversion.are_allowed(Decorations::empty()));
+        assert!(conversion.are_allowed(Decorations::STROKE | Decorations::TURNED));
+
+        let conversion = conversion.decorations(Decorations::ACUTE | Decorations::GRAVE);
+        assert!(conversion.are_allowed(Decorations::empty()));
+        assert!(conversion.are_allowed(Decorations::ACUTE));
+        assert!(!conversion.are_allowed(Decorations::STROKE));
+        assert!(!conversion.are_allowed(Decorations::ACUTE | Decorations::TURNED));
+    }
+
+    #[test]
+    fn test_conversion_symbols() {
+        let conversion = Conversion::default();
+        assert!(conversion.is_converting(Symbol::RegionalIndicator));
+        assert!(conversion.is_converting(Symbol::EnclosedLetter));
+        assert!(conversion.is_converting(Symbol::Abbreviation));
+
+        let conversion = conversion.symbols(&[Symbol::EnclosedLetter]);
+        assert!(!conversion.is_converting(Symbol::RegionalIndicator));
+        assert!(conversion.is_converting(Symbol::EnclosedLetter));
+        assert!(!conversion.is_converting(Symbol::Abbreviation));
+        assert!(!Conversion::default()
+            .symbols(&[])
+            .is_converting(Symbol::EnclosedLetter));
+    }
+
+    #[test]
+    fn test_conversion_preserve_case() {
+        assert!(!Conversion::default().is_preserving_case());
+        assert!(Conversion::default()
+            .preserve_case(true)
+            .is_preserving_case());
+    }
+
+    #[test]
+    fn test_conversion_fold_digits() {
+        assert!(!Conversion::default().is_folding_digits());
+        assert!(Conversion::default().fold_digits(true).is_folding_digits());
+    }
+
+    #[test]
+    fn test_conversion_fold_punctuation() {
+        assert!(!Conversion::default().is_folding_punctuation());
+        assert!(Conversion::default()
+            .fold_punctuation(true)
+            .is_folding_punctuation());
+    }
+
+    #[test]
+    fn test_conversion_locale() {
+        assert_eq!(Conversion::default().locale_tailoring(), None);
+        assert_eq!(
+            Conversion::default()
+                .locale(Some(Locale::German))
+                .locale_tailoring(),
+            Some(Locale::German)
+        );
+    }
+
+    #[test]
+    fn test_conversion_cyrillic() {
+        assert_eq!(Conversion::default().cyrillic_standard(), None);
+        assert_eq!(
+            Conversion::default()
+                .cyrillic(Some(Cyrillic::Gost779B))
+                .cyrillic_standard(),
+            Some(Cyrillic::Gost779B)
+        );
+    }
+
+    #[test]
+    fn test_conversion_greek() {
+        assert!(!Conversion::default().is_transliterating_greek());
+        assert!(Conversion::default().greek(true).is_transliterating_greek());
+    }
+
+    #[test]
+    fn test_conversion_hangul() {
+        let conversion = Conversion::default();
+        assert!(!conversion.is_romanizing_hangul());
+        assert_eq!(conversion.hangul_syllable_separator(), None);
+
+        let conversion = conversion.hangul(true).hangul_separator(Some('-'));
+        assert!(conversion.is_romanizing_hangul());
+        assert_eq!(conversion.hangul_syllable_separator(), Some('-'));
+    }
+
+    #[test]
+    fn test_conversion_kana() {
+        assert!(!Conversion::default().is_romanizing_kana());
+        assert!(Conversion::default().kana(true).is_romanizing_kana());
+    }
+
+    #[test]
+    fn test_conversion_error_display() {
+        let error = ConversionError {
+            c: '中',
+            position: 3,
+        };
+        assert_eq!(
+            error.to_string(),
+            "no uppercase AZ equivalent for '中' (U+4E2D) at position 3"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Uppercase AZ transliteration of Cyrillic letters: ICAO 9303, GOST 7.79 system B or BGN/PCGN
+use crate::decorations::Decorations;
+use crate::letter::Letter;
+use crate::property::Property;
+use crate::table::lookup;
+use crate::uppercase::Uppercase;
+
+// Property, decorations and uppercase AZ equivalent for each standard (in `Cyrillic` order) of a Cyrillic letter
+type CyrillicLetter = (Property, Decorations, [Letter; 3]);
+
+// Static table (sorted by code point) for Cyrillic letters
+include!("hash_cyrillic.rs");
+
+/// Transliteration standard for Cyrillic letters (e.g: `Щ` -> `SHCH`, `SHH`, `SHCH`)
+///
+/// Only A-Z letters are produced: signs and apostrophes of the standard are dropped (e.g: GOST 7.79
+/// `Ь` -> `'` is removed).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cyrillic {
+    /// ICAO 9303 (machine readable passports): `Ю` -> `IU`, `Я` -> `IA`, `Ъ` -> `IE`
+    Icao9303,
+
+    /// GOST 7.79 system B: `Ю` -> `YU`, `Х` -> `X`, `Ц` -> `CZ` (`C` before `Е`, `И`, `Ы`, `Й`), `Щ` -> `SHH`
+    Gost779B,
+
+    /// BGN/PCGN: `Ю` -> `YU`, `Й` -> `Y`, `Х` -> `KH`, `Е` -> `YE` (at the beginning of a word or after a vowel)
+    BgnPcgn,
+}
+
+/// Returns the uppercase AZ equivalent of a Cyrillic letter for the given standard
+///
+/// The letter is empty for signs without equivalent in the standard (e.g: `Ь`).
+pub fn cyrillic_of(c: char, standard: Cyrillic) -> Option<Uppercase> {
+    lookup(CYRILLIC, c).map(|(property, decorations, letters)| Uppercase {
+        letter: letters[standard as usize],
+        property,
+        decorations,
+    })
+}
+
+/// Returns the uppercase AZ equivalent of a Cyrillic letter for the given standard according to the previous
+/// and the next chars
+///
+/// Context rules:
+/// * BGN/PCGN: `Е` -> `YE` at the beginning of a word, after a vowel, `Й`, `Ъ` or `Ь` (e.g: `Елена` ->
+///   `YELENA`, `объект` -> `OBYEKT`)
+/// * GOST 7.79 system B: `Ц` -> `C` before `Е`, `И`, `Ы` or `Й` (e.g: `цирк` -> `CIRK`), `CZ` otherwise
+pub(crate) fn cyrillic_in_context(
+    previous: Option<char>,
+    c: char,
+    next: Option<char>,
+    standard: Cyrillic,
+) -> Option<Uppercase> {
+    let uppercase = cyrillic_of(c, standard)?;
+    let letter = match (standard, c) {
+        (Cyrillic::BgnPcgn, 'Е' | 'е' | 'Ѐ' | 'ѐ')
+            if previous.is_none_or(|previous| {
+                cyrillic_of(previous, standard).is_none()
+                    || "АаЕеЁёИиОоУуЫыЭэЮюЯяЙйЪъЬь".contains(previous)
+            }) =>
+        {
+            Letter::Letters('Y', 'E')
+        }
+        (Cyrillic::Gost779B, 'Ц' | 'ц') if next.is_some_and(|next| "ЕеИиЫыЙй".contains(next)) => {
+            Letter::Letter('C')
+        }
+        _ => uppercase.letter,
+    };
+    Some(Uppercase {
+        letter,
+        ..uppercase
+    })
+}
+
+/// Returns the static sequence of three or more letters used by one of the standards, if any
+#[cfg(feature = "serde")]
+pub(crate) fn cyrillic_sequence(letters: &str) -> Option<&'static str> {
+    CYRILLIC
+        .iter()
+        .flat_map(|(_, (_, _, letters))| letters)
+        .find_map(|letter| match letter {
+            Letter::Sequence(s) if *s == letters => Some(*s),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_cyrillic_table_sorted() {
+        assert!(is_sorted_by_code_point(CYRILLIC));
+    }
+
+    #[test]
+    fn test_cyrillic_of() {
+        let letter = |c, standard| cyrillic_of(c, standard).map(|uppercase| uppercase.letter);
+        assert_eq!(
+            letter('Щ', Cyrillic::Icao9303),
+            Some(Letter::Sequence("SHCH"))
+        );
+        assert_eq!(
+            letter('щ', Cyrillic::Gost779B),
+            Some(Letter::Sequence("SHH"))
+        );
+        assert_eq!(
+            letter('Щ', Cyrillic::BgnPcgn),
+            Some(Letter::Sequence("SHCH"))
+        );
+        assert_eq!(
+            letter('Ю', Cyrillic::Icao9303),
+            Some(Letter::Letters('I', 'U'))
+        );
+        assert_eq!(
+            letter('Ю', Cyrillic::Gost779B),
+            Some(Letter::Letters('Y', 'U'))
+        );
+        assert_eq!(letter('х', Cyrillic::Gost779B), Some(Letter::Letter('X')));
+        assert_eq!(letter('й', Cyrillic::BgnPcgn), Some(Letter::Letter('Y')));
+        assert_eq!(
+            letter('Ъ', Cyrillic::Icao9303),
+            Some(Letter::Letters('I', 'E'))
+        );
+        assert_eq!(letter('Ь', Cyrillic::Icao9303), Some(Letter::Sequence("")));
+        assert_eq!(letter('Ґ', Cyrillic::Icao9303), Some(Letter::Letter('G')));
+        assert_eq!(letter('A', Cyrillic::Icao9303), None);
+    }
+
+    #[test]
+    fn test_cyrillic_in_context() {
+        let transliterate = |txt: &str, standard| {
+            let chars: Vec<char> = txt.chars().collect();
+            (0..chars.len())
+                .map(|index| {
+                    let previous = index.checked_sub(1).map(|index| chars[index]);
+                    let next = chars.get(index + 1).copied();
+                    cyrillic_in_context(previous, chars[index], next, standard)
+                        .unwrap()
+                        .letter
+                        .to_string()
+                })
+                .collect::<String>()
+        };
+        assert_eq!(transliterate("объект", Cyrillic::BgnPcgn), "OBYEKT");
+        assert_eq!(transliterate("Елена", Cyrillic::BgnPcgn), "YELENA");
+        assert_eq!(transliterate("моё", Cyrillic::BgnPcgn), "MOYE");
+        assert_eq!(transliterate("поезд", Cyrillic::BgnPcgn), "POYEZD");
+        assert_eq!(transliterate("Елена", Cyrillic::Icao9303), "ELENA");
+        assert_eq!(transliterate("цирк", Cyrillic::Gost779B), "CIRK");
+        assert_eq!(transliterate("Цыганов", Cyrillic::Gost779B), "CYGANOV");
+        assert_eq!(transliterate("царь", Cyrillic::Gost779B), "CZAR");
+        assert_eq!(transliterate("отец", Cyrillic::Gost779B), "OTECZ");
+        assert_eq!(transliterate("цирк", Cyrillic::BgnPcgn), "TSIRK");
+        assert_eq!(
+            cyrillic_in_context(Some(' '), 'Е', None, Cyrillic::BgnPcgn)
+                .unwrap()
+                .property,
+            Property::Capital
+        );
+    }
+
+    #[test]
+    fn test_cyrillic_of_property() {
+        let uppercase = cyrillic_of('Щ', Cyrillic::Icao9303).unwrap();
+        assert_eq!(uppercase.property, Property::Capital);
+        let uppercase = cyrillic_of('я', Cyrillic::Icao9303).unwrap();
+        assert_eq!(uppercase.property, Property::Small);
+        let uppercase = cyrillic_of('ѐ', Cyrillic::Icao9303).unwrap();
+        assert_eq!(uppercase.letter, Letter::Letter('E'));
+        assert_eq!(uppercase.property, Property::SmallWithDecoration);
+        assert_eq!(uppercase.decorations, Decorations::GRAVE);
+    }
+}
@@ -5,13 +5,14 @@
 //!
 //! The top module words list permit to filter Unicode end point description (selection, exclusion and property
 //! extraction).
-//! Once done, remaining text in the description is the uppercase A-Z equivalent.
-//! Only one or two uppercase A-Z are identified (three letters are ignored)
+//! Once done, remaining text in the description is the uppercase A-Z equivalent (one or more letters,
+//! e.g: `FFI` for LATIN SMALL LIGATURE FFI)
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-use crate::hash_generation::EndPoint;
+use crate::hash_generation::{EndPoint, Transliteration};
 
 /// The description must contain the following keywords to be considered as a LATIN LETTER:
 const UNICODE_LATIN: [&str; 1] = ["LATIN"];
@@ -20,7 +21,7 @@ const UNICODE_LATIN: [&str; 1] = ["LATIN"];
 const UNICODE_LATIN_LETTERS: [&str; 2] = ["LETTER", "LIGATURE"];
 
 /// The description must not contain one of the following keywords be considered as a LATIN LETTER:
-const UNICODE_NOT_LATIN_LETTERS: [&str; 70] = [
+const UNICODE_NOT_LATIN_LETTERS: [&str; 66] = [
     "ALPHA",
     "ALVEOLAR",
     "BASELINE ESH",
@@ -54,8 +55,6 @@ const UNICODE_NOT_LATIN_LETTERS: [&str; 70] = [
     "LETTER ETH",
     "LETTER EZH",
     "LETTER FENG",
-    "LETTER FFI",
-    "LETTER FFL",
     "LETTER KRA",
     "LETTER LUM",
     "LETTER MUM",
@@ -66,8 +65,6 @@ const UNICODE_NOT_LATIN_LETTERS: [&str; 70] = [
     "LETTER TWO",
     "LETTER TUM",
     "LEZH",
-    "LIGATURE FFI",
-    "LIGATURE FFL",
     "OMEGA",
     "PHI",
     "RAMS HORN",
@@ -212,34 +209,283 @@ const DECORATION_KEYWORDS: [&str; 113] = [
     "WITH",
 ];
 
+/// Style keywords of the Mathematical Alphanumeric Symbols and Letterlike Symbols descriptions (e.g:
+/// `MATHEMATICAL BOLD CAPITAL A`, `DOUBLE-STRUCK CAPITAL H`, `SCRIPT SMALL L`)
+const LETTERLIKE_STYLE_KEYWORDS: [&str; 8] = [
+    "BLACK-LETTER",
+    "BOLD",
+    "DOUBLE-STRUCK",
+    "FRAKTUR",
+    "ITALIC",
+    "MONOSPACE",
+    "SANS-SERIF",
+    "SCRIPT",
+];
+
+/// Prefix of the roman numerals description (e.g: `ROMAN NUMERAL TWELVE`, `SMALL ROMAN NUMERAL EIGHT`)
+const ROMAN_NUMERAL_KEYWORD: &str = "ROMAN NUMERAL ";
+
+/// Prefix of the small roman numerals description (e.g: `SMALL ROMAN NUMERAL EIGHT`)
+const SMALL_ROMAN_NUMERAL_KEYWORD: &str = "SMALL ROMAN NUMERAL ";
+
+/// Roman numerals symbols and their value, in decreasing order (subtractive notation included)
+const ROMAN_NUMERAL_SYMBOLS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// Enclosing keywords of a latin letter description (before LATIN) for an enclosed letter (e.g:
+/// `CIRCLED LATIN CAPITAL LETTER A`, `NEGATIVE SQUARED LATIN CAPITAL LETTER A`)
+const ENCLOSED_LETTER_KEYWORDS: [&str; 4] = ["BRACKETED", "CIRCLED", "PARENTHESIZED", "SQUARED"];
+
+/// Prefix of the regional indicators description (e.g: `REGIONAL INDICATOR SYMBOL LETTER F`)
+const REGIONAL_INDICATOR_PREFIX: &str = "REGIONAL INDICATOR SYMBOL LETTER ";
+
+/// Decomposition mapping formatting tags of the abbreviations and their decoration keyword (e.g:
+/// `SQUARE KG` is `<square> 006B 0067`, `TRADE MARK SIGN` is `<super> 0054 004D`)
+const ABBREVIATION_TAGS: [(&str, &str); 3] = [
+    ("<circle>", "CIRCLED"),
+    ("<square>", "SQUARED"),
+    ("<super>", "SUPERSCRIPT"),
+];
+
+/// Cyrillic letters (description without CYRILLIC, CAPITAL/SMALL and LETTER) and their uppercase A-Z
+/// equivalent for ICAO 9303, GOST 7.79 system B and BGN/PCGN (Russian, Ukrainian, Belarusian, Serbian and
+/// Macedonian letters)
+///
+/// Signs and apostrophes that are not A-Z (e.g: GOST `SOFT SIGN` -> `'`, `E` -> `E'`) are dropped.
+const CYRILLIC_LETTERS: [(&str, [&str; 3]); 47] = [
+    ("A", ["A", "A", "A"]),
+    ("BE", ["B", "B", "B"]),
+    ("VE", ["V", "V", "V"]),
+    ("GHE", ["G", "G", "G"]),
+    ("GHE WITH UPTURN", ["G", "G", "G"]),
+    ("DE", ["D", "D", "D"]),
+    ("IE", ["E", "E", "E"]),
+    ("IO", ["E", "YO", "YE"]),
+    ("UKRAINIAN IE", ["IE", "YE", "YE"]),
+    ("ZHE", ["ZH", "ZH", "ZH"]),
+    ("ZE", ["Z", "Z", "Z"]),
+    ("I", ["I", "I", "I"]),
+    ("BYELORUSSIAN-UKRAINIAN I", ["I", "I", "I"]),
+    ("YI", ["I", "YI", "YI"]),
+    ("SHORT I", ["I", "J", "Y"]),
+    ("KA", ["K", "K", "K"]),
+    ("EL", ["L", "L", "L"]),
+    ("EM", ["M", "M", "M"]),
+    ("EN", ["N", "N", "N"]),
+    ("O", ["O", "O", "O"]),
+    ("PE", ["P", "P", "P"]),
+    ("ER", ["R", "R", "R"]),
+    ("ES", ["S", "S", "S"]),
+    ("TE", ["T", "T", "T"]),
+    ("U", ["U", "U", "U"]),
+    ("SHORT U", ["U", "U", "W"]),
+    ("EF", ["F", "F", "F"]),
+    ("HA", ["KH", "X", "KH"]),
+    ("TSE", ["TS", "CZ", "TS"]),
+    ("CHE", ["CH", "CH", "CH"]),
+    ("SHA", ["SH", "SH", "SH"]),
+    ("SHCHA", ["SHCH", "SHH", "SHCH"]),
+    ("HARD SIGN", ["IE", "", ""]),
+    ("YERU", ["Y", "Y", "Y"]),
+    ("SOFT SIGN", ["", "", ""]),
+    ("E", ["E", "E", "E"]),
+    ("YU", ["IU", "YU", "YU"]),
+    ("YA", ["IA", "YA", "YA"]),
+    ("DJE", ["D", "DJ", "DJ"]),
+    ("GJE", ["G", "G", "G"]),
+    ("DZE", ["DZ", "Z", "DZ"]),
+    ("JE", ["J", "J", "J"]),
+    ("LJE", ["LJ", "L", "LJ"]),
+    ("NJE", ["NJ", "N", "NJ"]),
+    ("TSHE", ["C", "C", "C"]),
+    ("KJE", ["K", "K", "K"]),
+    ("DZHE", ["DZ", "DH", "DZ"]),
+];
+
+/// Greek letters (description without GREEK, CAPITAL/SMALL and LETTER) and their uppercase A-Z
+/// equivalent for ELOT 743 / ISO 843
+///
+/// Context rules (e.g: `ΑΥ` -> `AV` or `AF`) are applied at conversion time.
+const GREEK_LETTERS: [(&str, [&str; 1]); 25] = [
+    ("ALPHA", ["A"]),
+    ("BETA", ["V"]),
+    ("GAMMA", ["G"]),
+    ("DELTA", ["D"]),
+    ("EPSILON", ["E"]),
+    ("ZETA", ["Z"]),
+    ("ETA", ["I"]),
+    ("THETA", ["TH"]),
+    ("IOTA", ["I"]),
+    ("KAPPA", ["K"]),
+    ("LAMDA", ["L"]),
+    ("MU", ["M"]),
+    ("NU", ["N"]),
+    ("XI", ["X"]),
+    ("OMICRON", ["O"]),
+    ("PI", ["P"]),
+    ("RHO", ["R"]),
+    ("SIGMA", ["S"]),
+    ("FINAL SIGMA", ["S"]),
+    ("TAU", ["T"]),
+    ("UPSILON", ["Y"]),
+    ("PHI", ["F"]),
+    ("CHI", ["CH"]),
+    ("PSI", ["PS"]),
+    ("OMEGA", ["O"]),
+];
+
+/// List of decoration keywords for Greek letters description: accents, breathings and iota subscript
+const GREEK_DECORATION_KEYWORDS: [&str; 12] = [
+    "AND",
+    "DASIA",
+    "DIALYTIKA",
+    "MACRON",
+    "OXIA",
+    "PERISPOMENI",
+    "PROSGEGRAMMENI",
+    "PSILI",
+    "TONOS",
+    "VARIA",
+    "VRACHY",
+    "YPOGEGRAMMENI",
+];
+
+/// Kana syllables of the Unicode descriptions (Kunrei-shiki) with a different modified Hepburn romanization
+const KANA_HEPBURN: [(&str, &str); 10] = [
+    ("SI", "SHI"),
+    ("ZI", "JI"),
+    ("TI", "CHI"),
+    ("DI", "JI"),
+    ("TU", "TSU"),
+    ("DU", "ZU"),
+    ("HU", "FU"),
+    ("WI", "I"),
+    ("WE", "E"),
+    ("WO", "O"),
+];
+
+/// Mapping source used when the description and the decomposition mapping of a latin letter disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MappingSource {
+    /// Uppercase A-Z equivalent guessed from the description (keywords removed)
+    #[default]
+    Name,
+
+    /// Uppercase A-Z equivalent from the decomposition mapping (6th field of the Unicode database file,
+    /// resolved recursively, combining marks removed)
+    Decomposition,
+}
+
 /// Parse the Unicode database file and identify endpoints that can be converted to uppercase A-Z
-pub fn parse_unicode_database_file(filename: &str) -> Result<Vec<EndPoint>, std::io::Error> {
+///
+/// The uppercase A-Z equivalent guessed from the description is checked against the decomposition mapping
+/// (e.g: `1E09` is `0063 0327 0301`, `C` with combining marks): disagreements are reported and the
+/// `preferred` mapping source is used.
+pub fn parse_unicode_database_file(
+    filename: &str,
+    preferred: MappingSource,
+) -> Result<Vec<EndPoint>, std::io::Error> {
     let file = File::open(filename)?;
     let reader = BufReader::new(file);
 
     let mut nb_lines = 0;
     let mut used_decoration_keywords = vec![];
+    let mut nb_confirmed = 0;
+    let mut nb_disagreements = 0;
+
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let mut decompositions = HashMap::new();
+    let mut combining_marks = HashMap::new();
+    for line in &lines {
+        let elements: Vec<&str> = line.split(';').map(str::trim).collect();
+        if elements.len() < 6 {
+            continue;
+        }
+        if let Ok(code_point) = u32::from_str_radix(elements[0], 16) {
+            if !elements[5].is_empty() {
+                decompositions.insert(code_point, elements[5]);
+            }
+            if elements[2] == "Mn" {
+                combining_marks.insert(code_point, elements[1]);
+            }
+        }
+    }
 
     let mut all_uppercase_az = vec![];
+    // Latin letters already parsed (uppercase A-Z and decoration keywords), for the letterlike symbols
+    let mut latin_letters = HashMap::new();
 
-    for line in reader.lines() {
+    for line in &lines {
         nb_lines += 1;
-        let line = line?;
-        if let Some((code_point, description)) = is_latin_letter(nb_lines, &line) {
-            let (uppercase_az, is_capital, is_small, is_decoration, local_used_keyword) =
-                parse_latin_letter(&description);
-            used_decoration_keywords.extend(local_used_keyword);
+        let letter = match is_latin_letter(nb_lines, line) {
+            Some((code_point, description)) => {
+                let parsed = parse_latin_letter(&description);
+                used_decoration_keywords.extend(parsed.4.iter().cloned());
+                latin_letters.insert(code_point, (parsed.0.clone(), parsed.4.clone()));
+                Some((code_point, description, parsed))
+            }
+            None => is_letterlike_symbol(line, &latin_letters),
+        };
+        if let Some((code_point, description, parsed)) = letter {
+            let (mut uppercase_az, is_capital, is_small, is_decoration, local_used_keyword) =
+                parsed;
 
             // println!("{code_point:04X} '{uppercase_az}' (capital={is_capital}, small={is_small}, decoration={is_decoration}) from '{description}'");
 
-            all_uppercase_az.push(EndPoint::new(
+            if let Some(decomposition) =
+                uppercase_az_decomposition(code_point, &decompositions, &combining_marks)
+            {
+                if let Some(disagreement) =
+                    decomposition_disagreement(&uppercase_az, &decomposition)
+                {
+                    nb_disagreements += 1;
+                    println!("!!! Disagreement for {code_point:04X}: '{uppercase_az}' from description, {disagreement} ('{description}')");
+                    if let (MappingSource::Decomposition, Ok(decomposition)) =
+                        (preferred, decomposition)
+                    {
+                        uppercase_az = decomposition;
+                    }
+                } else {
+                    nb_confirmed += 1;
+                }
+            }
+
+            let is_enclosed_letter = is_enclosed_letter(&description);
+            let end_point = EndPoint::new(
                 code_point,
                 description,
                 uppercase_az,
                 is_capital,
                 is_small,
                 is_decoration,
-            ));
+                local_used_keyword,
+            );
+            all_uppercase_az.push(if is_enclosed_letter {
+                end_point.with_symbol("EnclosedLetter")
+            } else {
+                end_point
+            });
+        } else if let Some(end_point) = parse_symbol(line, &decompositions) {
+            all_uppercase_az.push(end_point);
+        } else if let Some(end_point) = parse_roman_numeral(line) {
+            all_uppercase_az.push(end_point);
+        } else if let Some(end_point) =
+            parse_canonical_letter(line, &decompositions, &combining_marks)
+        {
+            all_uppercase_az.push(end_point);
         }
     }
 
@@ -249,10 +495,448 @@ pub fn parse_unicode_database_file(filename: &str) -> Result<Vec<EndPoint>, std:
             eprintln!("!!! Unused decoration keyword: '{keyword}'");
         }
     }
+    println!("Decomposition mapping confirms {nb_confirmed} and disagrees with {nb_disagreements} descriptions ({preferred:?} used)");
 
     Ok(all_uppercase_az)
 }
 
+/// Parse the Unicode database file and identify the Cyrillic letters that can be transliterated to uppercase A-Z
+pub fn parse_unicode_database_cyrillic(
+    filename: &str,
+) -> Result<Vec<Transliteration>, std::io::Error> {
+    parse_unicode_database_script(
+        filename,
+        "CYRILLIC",
+        &CYRILLIC_LETTERS,
+        &DECORATION_KEYWORDS,
+    )
+}
+
+/// Parse the Unicode database file and identify the Greek letters that can be transliterated to uppercase A-Z
+pub fn parse_unicode_database_greek(
+    filename: &str,
+) -> Result<Vec<Transliteration>, std::io::Error> {
+    parse_unicode_database_script(
+        filename,
+        "GREEK",
+        &GREEK_LETTERS,
+        &GREEK_DECORATION_KEYWORDS,
+    )
+}
+
+/// Parse the Unicode database file and identify the letters of a script (e.g: `CYRILLIC`) that can be
+/// transliterated to uppercase A-Z
+///
+/// The letter is searched in `letters`, either with its full name (e.g: `GHE WITH UPTURN`) or with the name
+/// before `WITH` if the following keywords are all in `decoration_keywords` (e.g: `IE WITH GRAVE`).
+fn parse_unicode_database_script<const N: usize>(
+    filename: &str,
+    script: &str,
+    letters: &[(&str, [&str; N])],
+    decoration_keywords: &[&str],
+) -> Result<Vec<Transliteration>, std::io::Error> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+
+    let capital_prefix = format!("{script} CAPITAL LETTER ");
+    let small_prefix = format!("{script} SMALL LETTER ");
+    let small_capital_prefix = format!("{script} LETTER SMALL CAPITAL ");
+
+    let mut all_letters = vec![];
+
+    for line in reader.lines() {
+        let line = line?;
+        let elements: Vec<&str> = line.split(';').map(str::trim).take(2).collect();
+        if elements.len() < 2 {
+            continue;
+        }
+        let Ok(code_point) = u32::from_str_radix(elements[0], 16) else {
+            continue;
+        };
+        let description = elements[1];
+        let (name, is_capital, is_small) =
+            if let Some(name) = description.strip_prefix(&capital_prefix) {
+                (name, true, false)
+            } else if let Some(name) = description.strip_prefix(&small_prefix) {
+                (name, false, true)
+            } else if let Some(name) = description.strip_prefix(&small_capital_prefix) {
+                (name, true, true)
+            } else {
+                continue;
+            };
+        if let Some((letters, decorations)) =
+            parse_script_letter(name, letters, decoration_keywords)
+        {
+            all_letters.push(Transliteration::new(
+                code_point,
+                letters,
+                is_capital,
+                is_small,
+                decorations,
+            ));
+        }
+    }
+
+    Ok(all_letters)
+}
+
+/// Returns the uppercase A-Z equivalent for each standard and the decoration keywords of a letter name
+/// (e.g: `IE WITH GRAVE`)
+fn parse_script_letter<const N: usize>(
+    name: &str,
+    letters: &[(&str, [&str; N])],
+    decoration_keywords: &[&str],
+) -> Option<(Vec<String>, Vec<String>)> {
+    // Local function to get the uppercase A-Z equivalent of a letter name
+    let letters_of = |name: &str| {
+        letters
+            .iter()
+            .find(|(letter_name, _)| *letter_name == name)
+            .map(|(_, letters)| letters.iter().map(ToString::to_string).collect())
+    };
+
+    if let Some(letters) = letters_of(name) {
+        return Some((letters, vec![]));
+    }
+    let (name, decorations) = name.split_once(" WITH ")?;
+    let decorations: Vec<String> = decorations
+        .split_whitespace()
+        .map(ToString::to_string)
+        .collect();
+    if !decorations
+        .iter()
+        .all(|decoration| decoration_keywords.contains(&decoration.as_str()))
+    {
+        return None;
+    }
+    Some((letters_of(name)?, decorations))
+}
+
+/// Parse the Unicode database file and identify the kana (hiragana, katakana and halfwidth katakana) with their
+/// modified Hepburn romanization and true for small kana (e.g: `ゃ` -> `YA`)
+///
+/// The romanization is the syllable of the description (e.g: `HIRAGANA LETTER SMALL YA`), converted from
+/// Kunrei-shiki to modified Hepburn (e.g: `SI` -> `SHI`, `TU` -> `TSU`).
+pub fn parse_unicode_database_kana(
+    filename: &str,
+) -> Result<Vec<(u32, String, bool)>, std::io::Error> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+
+    let mut all_kana = vec![];
+
+    for line in reader.lines() {
+        let line = line?;
+        let elements: Vec<&str> = line.split(';').map(str::trim).take(2).collect();
+        if elements.len() < 2 {
+            continue;
+        }
+        let Ok(code_point) = u32::from_str_radix(elements[0], 16) else {
+            continue;
+        };
+        let description = elements[1]
+            .strip_prefix("HALFWIDTH ")
+            .unwrap_or(elements[1]);
+        let Some(syllable) = description
+            .strip_prefix("HIRAGANA LETTER ")
+            .or_else(|| description.strip_prefix("KATAKANA LETTER "))
+        else {
+            continue;
+        };
+        let (syllable, is_small) = match syllable.strip_prefix("SMALL ") {
+            Some(syllable) => (syllable, true),
+            None => (syllable, false),
+        };
+        // Archaic kana and other letters (e.g: `KATAKANA LETTER MINNAN TONE-2`) are not syllables
+        if syllable.is_empty()
+            || syllable.len() > 3
+            || !syllable.chars().all(|c| c.is_ascii_uppercase())
+        {
+            continue;
+        }
+        let syllable = KANA_HEPBURN
+            .iter()
+            .find(|(kunrei, _)| *kunrei == syllable)
+            .map_or(syllable, |(_, hepburn)| hepburn);
+        all_kana.push((code_point, syllable.to_string(), is_small));
+    }
+
+    Ok(all_kana)
+}
+
+/// Parse the Unicode database file and identify the combining marks (general category `Mn`) with their
+/// decoration keywords (e.g: `COMBINING ACUTE ACCENT` -> `ACUTE`, `COMBINING RING ABOVE` -> `ABOVE`, `RING`)
+///
+/// The decoration keywords may be empty (e.g: `COMBINING LATIN SMALL LETTER A`): the mark is still a decoration.
+pub fn parse_unicode_database_combining_marks(
+    filename: &str,
+) -> Result<Vec<(u32, Vec<String>)>, std::io::Error> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+
+    let mut all_combining_marks = vec![];
+
+    for line in reader.lines() {
+        let line = line?;
+        let elements: Vec<&str> = line.split(';').map(str::trim).take(3).collect();
+        if elements.len() < 3 || elements[2] != "Mn" {
+            continue;
+        }
+        let Ok(code_point) = u32::from_str_radix(elements[0], 16) else {
+            continue;
+        };
+        all_combining_marks.push((code_point, combining_mark_keywords(elements[1])));
+    }
+
+    Ok(all_combining_marks)
+}
+
+/// Returns the decoration keywords of a combining mark description (e.g: `COMBINING RING ABOVE` -> `ABOVE`,
+/// `RING`)
+fn combining_mark_keywords(description: &str) -> Vec<String> {
+    let description = format!(" {description} ");
+    let mut decorations: Vec<String> = vec![];
+    for keyword in DECORATION_KEYWORDS
+        .iter()
+        .chain(GREEK_DECORATION_KEYWORDS.iter())
+        .map(|keyword| keyword.trim())
+    {
+        if keyword != "COMBINING"
+            && description.contains(&format!(" {keyword} "))
+            && !decorations.iter().any(|decoration| decoration == keyword)
+        {
+            decorations.push(keyword.to_string());
+        }
+    }
+    decorations
+}
+
+/// Parse the Unicode database file and identify code points that are digits (digit value 0 to 9)
+///
+/// The digit value is the 8th field of the Unicode database file: it covers decimal digits of all
+/// scripts (e.g: `٣`, `३`, `０`) and other digits (e.g: `²`, `₃`, `①`).
+pub fn parse_unicode_database_digits(filename: &str) -> Result<Vec<(u32, char)>, std::io::Error> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+
+    let mut all_digits = vec![];
+
+    for line in reader.lines() {
+        let line = line?;
+        let elements: Vec<&str> = line.split(';').map(str::trim).collect();
+        if elements.len() < 8 || elements[7].is_empty() {
+            continue;
+        }
+        let (Ok(code_point), Ok(digit)) = (
+            u32::from_str_radix(elements[0], 16),
+            elements[7].parse::<u32>(),
+        ) else {
+            println!("Invalid digit line: {line}");
+            continue;
+        };
+        match char::from_digit(digit, 10) {
+            Some(digit) => all_digits.push((code_point, digit)),
+            None => println!("Invalid digit value {digit} for code point {code_point:04X}"),
+        }
+    }
+
+    Ok(all_digits)
+}
+
+/// Parse the Unicode database file and identify punctuation and space code points with an ASCII equivalent
+///
+/// The ASCII equivalent is the decomposition mapping (6th field of the Unicode database file, resolved
+/// recursively) if it only contains ASCII punctuation or spaces (e.g: `…` -> `...`, `０` -> `0` is not
+/// punctuation).
+/// Otherwise, the general category and the description are used (e.g: `–` -> `-`, `’` -> `'`, `«` -> `"`).
+pub fn parse_unicode_database_punctuation(
+    filename: &str,
+) -> Result<Vec<(u32, String)>, std::io::Error> {
+    let file = File::open(filename)?;
+    let reader = BufReader::new(file);
+
+    // (code point, description, general category, decomposition mapping) of all code points
+    let mut all_code_points = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        let elements: Vec<&str> = line.split(';').map(str::trim).collect();
+        if elements.len() < 6 {
+            continue;
+        }
+        if let Ok(code_point) = u32::from_str_radix(elements[0], 16) {
+            all_code_points.push((
+                code_point,
+                elements[1].to_string(),
+                elements[2].to_string(),
+                elements[5].to_string(),
+            ));
+        }
+    }
+    let decompositions: HashMap<u32, &str> = all_code_points
+        .iter()
+        .filter(|(_, _, _, decomposition)| !decomposition.is_empty())
+        .map(|(code_point, _, _, decomposition)| (*code_point, decomposition.as_str()))
+        .collect();
+
+    let mut all_punctuation = vec![];
+    for (code_point, description, category, _) in &all_code_points {
+        let is_candidate = category.starts_with('P')
+            || category.starts_with('S')
+            || category == "Zs"
+            || (category == "Lm" && description.ends_with("APOSTROPHE"));
+        if *code_point < 0x80 || !is_candidate {
+            continue;
+        }
+        let ascii = ascii_decomposition(*code_point, &decompositions)
+            .or_else(|| ascii_punctuation_by_name(category, description));
+        if let Some(ascii) = ascii {
+            all_punctuation.push((*code_point, ascii));
+        }
+    }
+
+    Ok(all_punctuation)
+}
+
+/// Returns the decomposition mapping of a code point resolved recursively (the code point itself if it has no
+/// decomposition mapping)
+fn resolve_decomposition(code_point: u32, decompositions: &HashMap<u32, &str>) -> Vec<u32> {
+    // Local function to resolve recursively the decomposition of a code point
+    fn resolve(code_point: u32, decompositions: &HashMap<u32, &str>, result: &mut Vec<u32>) {
+        match decompositions.get(&code_point) {
+            Some(decomposition) => {
+                for element in decomposition.split_whitespace() {
+                    // Skip the compatibility formatting tag (e.g: `<wide>`)
+                    if let Ok(code_point) = u32::from_str_radix(element, 16) {
+                        resolve(code_point, decompositions, result);
+                    }
+                }
+            }
+            None => result.push(code_point),
+        }
+    }
+
+    let mut result = vec![];
+    resolve(code_point, decompositions, &mut result);
+    result
+}
+
+/// Returns the decomposition mapping (resolved recursively) of a code point if it only contains ASCII
+/// punctuation or spaces
+fn ascii_decomposition(code_point: u32, decompositions: &HashMap<u32, &str>) -> Option<String> {
+    decompositions.get(&code_point)?;
+    resolve_decomposition(code_point, decompositions)
+        .into_iter()
+        .map(|code_point| {
+            char::from_u32(code_point).filter(|c| c.is_ascii_punctuation() || *c == ' ')
+        })
+        .collect()
+}
+
+/// Returns the uppercase A-Z equivalent from the decomposition mapping (resolved recursively) of a code point
+/// once the combining marks removed (e.g: `1E09` -> `0063 0327 0301` -> `C`), `None` without decomposition
+/// mapping
+///
+/// The resolved decomposition mapping is returned as an error if it contains other code points than ASCII
+/// letters and combining marks (e.g: `0140` -> `006C 00B7`).
+fn uppercase_az_decomposition(
+    code_point: u32,
+    decompositions: &HashMap<u32, &str>,
+    combining_marks: &HashMap<u32, &str>,
+) -> Option<Result<String, String>> {
+    decompositions.get(&code_point)?;
+    let decomposition = resolve_decomposition(code_point, decompositions);
+    let uppercase_az: Option<String> = decomposition
+        .iter()
+        .filter(|code_point| !combining_marks.contains_key(code_point))
+        .map(|&code_point| {
+            char::from_u32(code_point)
+                .filter(char::is_ascii_alphabetic)
+                .map(|c| c.to_ascii_uppercase())
+        })
+        .collect();
+    Some(
+        uppercase_az
+            .filter(|uppercase_az| !uppercase_az.is_empty())
+            .ok_or_else(|| {
+                decomposition
+                    .iter()
+                    .map(|code_point| format!("{code_point:04X}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }),
+    )
+}
+
+/// Returns the disagreement between the uppercase A-Z equivalent from the description and the one from the
+/// decomposition mapping (see [`uppercase_az_decomposition`]), `None` if they agree
+fn decomposition_disagreement(
+    uppercase_az: &str,
+    decomposition: &Result<String, String>,
+) -> Option<String> {
+    match decomposition {
+        Ok(decomposition) if decomposition == uppercase_az => None,
+        Ok(decomposition) => Some(format!("'{decomposition}' from decomposition")),
+        Err(decomposition) => Some(format!("unresolvable decomposition '{decomposition}'")),
+    }
+}
+
+/// Returns the ASCII equivalent of a punctuation or space code point from its general category and
+/// description
+fn ascii_punctuation_by_name(category: &str, description: &str) -> Option<String> {
+    let ascii = if category == "Zs" {
+        " "
+    } else if category == "Pd" {
+        "-"
+    } else if category.starts_with('P') && description.contains("QUOTATION MARK") {
+        if description.contains("DOUBLE")
+            || (description.contains("ANGLE") && !description.contains("SINGLE"))
+        {
+            "\""
+        } else {
+            "'"
+        }
+    } else if description.ends_with("APOSTROPHE") {
+        if description.contains("DOUBLE") {
+            "\""
+        } else {
+            "'"
+        }
+    } else if category == "Ps" || category == "Pe" {
+        // Some brackets have an unexpected general category (e.g: ORNATE LEFT PARENTHESIS is Pe)
+        let is_open = if description.contains("LEFT") {
+            true
+        } else if description.contains("RIGHT") {
+            false
+        } else {
+            category == "Ps"
+        };
+        if description.contains("PARENTHESIS") {
+            if is_open {
+                "("
+            } else {
+                ")"
+            }
+        } else if description.contains("SQUARE BRACKET") {
+            if is_open {
+                "["
+            } else {
+                "]"
+            }
+        } else if description.contains("CURLY BRACKET") {
+            if is_open {
+                "{"
+            } else {
+                "}"
+            }
+        } else {
+            return None;
+        }
+    } else {
+        return None;
+    };
+    Some(ascii.to_string())
+}
+
 /// Returns the code point and the description if the current Unicode line in database is a latin letter
 fn is_latin_letter(line_nb: usize, line_content: &str) -> Option<(u32, String)> {
     // Line is .csv file
@@ -322,6 +1006,203 @@ fn is_valid_latin_letter(description: &str) -> bool {
     true
 }
 
+/// Returns true if the latin letter description is an enclosed letter (e.g: `CIRCLED LATIN CAPITAL LETTER A`)
+fn is_enclosed_letter(description: &str) -> bool {
+    description
+        .split_once("LATIN")
+        .is_some_and(|(enclosing, _)| {
+            enclosing
+                .split_whitespace()
+                .any(|word| ENCLOSED_LETTER_KEYWORDS.contains(&word))
+        })
+}
+
+/// Returns the uppercase AZ endpoint of a symbol that is not a letter: a regional indicator (e.g:
+/// `REGIONAL INDICATOR SYMBOL LETTER F`) or an abbreviation of two letters or more (symbol whose
+/// decomposition mapping is enclosed or superscript letters, e.g: `㎏` -> `KG`, `㏂` -> `AM`, `™` -> `TM`)
+fn parse_symbol(line_content: &str, decompositions: &HashMap<u32, &str>) -> Option<EndPoint> {
+    let elements: Vec<&str> = line_content.split(';').map(str::trim).collect();
+    if elements.len() < 6 {
+        return None;
+    }
+    let code_point = u32::from_str_radix(elements[0], 16).ok()?;
+    let description = elements[1];
+
+    if let Some(letter) = description.strip_prefix(REGIONAL_INDICATOR_PREFIX) {
+        return Some(
+            EndPoint::new(
+                code_point,
+                description.to_string(),
+                letter.to_string(),
+                true,
+                false,
+                false,
+                vec![],
+            )
+            .with_symbol("RegionalIndicator"),
+        );
+    }
+
+    if elements[2] != "So" {
+        return None;
+    }
+    let (_, keyword) = ABBREVIATION_TAGS
+        .iter()
+        .find(|(tag, _)| elements[5].starts_with(tag))?;
+    // Periods are dropped (e.g: `a.m.` for `㏂`)
+    let letters: String = resolve_decomposition(code_point, decompositions)
+        .into_iter()
+        .filter_map(char::from_u32)
+        .filter(|&c| c != '.')
+        .map(|c| c.is_ascii_alphabetic().then_some(c))
+        .collect::<Option<_>>()?;
+    if letters.len() < 2 {
+        return None;
+    }
+    let is_capital = letters.starts_with(|c: char| c.is_ascii_uppercase());
+    Some(
+        EndPoint::new(
+            code_point,
+            description.to_string(),
+            letters.to_ascii_uppercase(),
+            is_capital,
+            !is_capital,
+            true,
+            vec![keyword.to_string()],
+        )
+        .with_symbol("Abbreviation"),
+    )
+}
+
+/// Returns the uppercase AZ endpoint of a letter that is not named as a latin letter but whose canonical
+/// decomposition mapping (resolved recursively) is an ASCII letter followed by combining marks (e.g: `KELVIN SIGN`
+/// -> `004B`, `ANGSTROM SIGN` -> `00C5` -> `0041 030A`)
+fn parse_canonical_letter(
+    line_content: &str,
+    decompositions: &HashMap<u32, &str>,
+    combining_marks: &HashMap<u32, &str>,
+) -> Option<EndPoint> {
+    let elements: Vec<&str> = line_content.split(';').map(str::trim).collect();
+    if elements.len() < 6 || !matches!(elements[2], "Lu" | "Ll") || elements[5].starts_with('<') {
+        return None;
+    }
+    let code_point = u32::from_str_radix(elements[0], 16).ok()?;
+    decompositions.get(&code_point)?;
+    let decomposition = resolve_decomposition(code_point, decompositions);
+    let (&letter, marks) = decomposition.split_first()?;
+    let letter = char::from_u32(letter).filter(char::is_ascii_alphabetic)?;
+    let mut decorations: Vec<String> = vec![];
+    for mark in marks {
+        for keyword in combining_mark_keywords(combining_marks.get(mark)?) {
+            if !decorations.contains(&keyword) {
+                decorations.push(keyword);
+            }
+        }
+    }
+    Some(EndPoint::new(
+        code_point,
+        elements[1].to_string(),
+        letter.to_ascii_uppercase().to_string(),
+        letter.is_ascii_uppercase(),
+        letter.is_ascii_lowercase(),
+        !marks.is_empty(),
+        decorations,
+    ))
+}
+
+/// Returns the uppercase AZ endpoint of a roman numeral (e.g: `Ⅻ` -> `XII`, `ⅷ` -> `VIII`)
+///
+/// The uppercase AZ equivalent is the spelling of the numeric value (9th field of the Unicode database file),
+/// roman numerals without a spelling in A-Z (e.g: `ↂ` for 10000) are skipped.
+fn parse_roman_numeral(line_content: &str) -> Option<EndPoint> {
+    let elements: Vec<&str> = line_content.split(';').map(str::trim).collect();
+    if elements.len() < 9 || elements[2] != "Nl" || !elements[1].contains(ROMAN_NUMERAL_KEYWORD) {
+        return None;
+    }
+    let code_point = u32::from_str_radix(elements[0], 16).ok()?;
+    let mut value = elements[8]
+        .parse::<u32>()
+        .ok()
+        .filter(|value| (1..4000).contains(value))?;
+
+    let mut uppercase_az = String::new();
+    for (symbol, symbol_value) in ROMAN_NUMERAL_SYMBOLS {
+        while value >= symbol_value {
+            uppercase_az.push_str(symbol);
+            value -= symbol_value;
+        }
+    }
+    Some(
+        EndPoint::new(
+            code_point,
+            elements[1].to_string(),
+            uppercase_az,
+            false,
+            false,
+            false,
+            vec![],
+        )
+        .with_property(if elements[1].contains(SMALL_ROMAN_NUMERAL_KEYWORD) {
+            "SmallRomanNumeral"
+        } else {
+            "RomanNumeral"
+        }),
+    )
+}
+
+/// Returns the code point, the description and the parsed letter (see [`parse_latin_letter`]) of a
+/// Mathematical Alphanumeric Symbol or a Letterlike Symbol (e.g: `MATHEMATICAL BOLD CAPITAL A`,
+/// `PLANCK CONSTANT`), these descriptions do not contain LATIN
+///
+/// The code point must be a letter (`Lu`, `Ll` or `Lt` general category) with a `<font>` compatibility
+/// decomposition to an ASCII letter or to an already parsed latin letter (e.g: `1D6A4` -> `0131` for
+/// `MATHEMATICAL ITALIC SMALL DOTLESS I`): `2118` (`SCRIPT CAPITAL P`, `Sm`) or `1D6A8` (`MATHEMATICAL BOLD
+/// CAPITAL ALPHA`) are not letterlike symbols.
+fn is_letterlike_symbol(
+    line_content: &str,
+    latin_letters: &HashMap<u32, (String, Vec<String>)>,
+) -> Option<(u32, String, ParsedLetter)> {
+    let elements: Vec<&str> = line_content.split(';').map(str::trim).collect();
+    if elements.len() < 6 || !matches!(elements[2], "Lu" | "Ll" | "Lt") {
+        return None;
+    }
+    let code_point = u32::from_str_radix(elements[0], 16).ok()?;
+    let decomposition = elements[5]
+        .strip_prefix("<font> ")
+        .and_then(|decomposition| u32::from_str_radix(decomposition, 16).ok())?;
+    let (uppercase_az, mut decorations) = match char::from_u32(decomposition) {
+        Some(c) if c.is_ascii_alphabetic() => (c.to_ascii_uppercase().to_string(), vec![]),
+        _ => latin_letters.get(&decomposition).cloned()?,
+    };
+    decorations.splice(0..0, letterlike_style_keywords(elements[1]));
+
+    Some((
+        code_point,
+        elements[1].to_string(),
+        (
+            uppercase_az,
+            elements[2] != "Ll",
+            elements[2] == "Ll",
+            true,
+            decorations,
+        ),
+    ))
+}
+
+/// Returns the style keywords of a Mathematical Alphanumeric Symbol or a Letterlike Symbol description (e.g:
+/// `BOLD` and `ITALIC` for `MATHEMATICAL BOLD ITALIC SMALL A`)
+fn letterlike_style_keywords(description: &str) -> Vec<String> {
+    description
+        .split(' ')
+        .filter(|word| LETTERLIKE_STYLE_KEYWORDS.contains(word))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Parsed letter: uppercase A-Z equivalent, `is_capital`, `is_small`, `is_decoration` and decoration keywords
+/// (see [`parse_latin_letter`])
+type ParsedLetter = (String, bool, bool, bool, Vec<String>);
+
 /// Parse the LATIN LETTER description to get matching uppercase letter A-Z and some properties.
 ///
 /// 3 properties are extracted:
@@ -331,8 +1212,8 @@ fn is_valid_latin_letter(description: &str) -> bool {
 ///
 /// Note that a letter can be both capital and small: A small capital letter is also a letter.
 ///
-/// For debugging purpose, the list of used decoration keywords is returned
-fn parse_latin_letter(description: &str) -> (String, bool, bool, bool, Vec<String>) {
+/// The list of used decoration keywords is also returned
+fn parse_latin_letter(description: &str) -> ParsedLetter {
     // Local function to check if the name contains a keyword and remove this keyword
     fn contains_keyword(description: &mut String, keyword: &str) -> bool {
         let keyword_with_space_before = format!(" {keyword}");
@@ -391,3 +1272,94 @@ fn parse_latin_letter(description: &str) -> (String, bool, bool, bool, Vec<Strin
         used_decoration_keywords,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uppercase_az_decomposition() {
+        let decompositions = HashMap::from([
+            (0x1E09, "00E7 0301"),
+            (0xE7, "0063 0327"),
+            (0x13F, "<compat> 004C 00B7"),
+            (0x149, "<compat> 02BC 006E"),
+        ]);
+        let combining_marks = HashMap::from([
+            (0x301, "COMBINING ACUTE ACCENT"),
+            (0x327, "COMBINING CEDILLA"),
+        ]);
+        let resolve =
+            |code_point| uppercase_az_decomposition(code_point, &decompositions, &combining_marks);
+        assert_eq!(resolve(0x1E09), Some(Ok("C".to_string())));
+        assert_eq!(resolve(0x13F), Some(Err("004C 00B7".to_string())));
+        assert_eq!(resolve(0x149), Some(Err("02BC 006E".to_string())));
+        assert_eq!(resolve(0x63), None);
+    }
+
+    #[test]
+    fn test_decomposition_disagreement() {
+        assert_eq!(decomposition_disagreement("C", &Ok("C".to_string())), None);
+        assert_eq!(
+            decomposition_disagreement("IJ", &Ok("I".to_string())),
+            Some("'I' from decomposition".to_string())
+        );
+        assert_eq!(
+            decomposition_disagreement("L", &Err("004C 00B7".to_string())),
+            Some("unresolvable decomposition '004C 00B7'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_letterlike_symbol_rejected() {
+        let latin_letters = HashMap::new();
+        for line in [
+            "2118;SCRIPT CAPITAL P;Sm;0;ON;;;;;N;SCRIPT P;;;;",
+            "2141;TURNED SANS-SERIF CAPITAL G;Sm;0;ON;;;;;N;;;;;",
+            "2144;TURNED SANS-SERIF CAPITAL Y;Sm;0;ON;;;;;N;;;;;",
+            "1D6A8;MATHEMATICAL BOLD CAPITAL ALPHA;Lu;0;L;<font> 0391;;;;N;;;;;",
+            "1D7CE;MATHEMATICAL BOLD DIGIT ZERO;Nd;0;EN;<font> 0030;0;0;0;N;;;;;",
+            "1D6A4;MATHEMATICAL ITALIC SMALL DOTLESS I;Ll;0;L;<font> 0131;;;;N;;;;;",
+        ] {
+            assert_eq!(is_letterlike_symbol(line, &latin_letters), None, "{line}");
+        }
+    }
+
+    #[test]
+    fn test_is_letterlike_symbol_accepted() {
+        let latin_letters =
+            HashMap::from([(0x131, ("I".to_string(), vec!["DOTLESS".to_string()]))]);
+        let parse = |line| {
+            is_letterlike_symbol(line, &latin_letters)
+                .map(|(code_point, _, parsed)| (code_point, parsed))
+        };
+        assert_eq!(
+            parse("1D400;MATHEMATICAL BOLD CAPITAL A;Lu;0;L;<font> 0041;;;;N;;;;;"),
+            Some((
+                0x1D400,
+                ("A".to_string(), true, false, true, vec!["BOLD".to_string()])
+            ))
+        );
+        assert_eq!(
+            parse("210E;PLANCK CONSTANT;Ll;0;L;<font> 0068;;;;N;;;;;"),
+            Some((0x210E, ("H".to_string(), false, true, true, vec![])))
+        );
+        assert_eq!(
+            parse("2139;INFORMATION SOURCE;Ll;0;L;<font> 0069;;;;N;;;;;"),
+            Some((0x2139, ("I".to_string(), false, true, true, vec![])))
+        );
+        assert_eq!(
+            parse("1D6A4;MATHEMATICAL ITALIC SMALL DOTLESS I;Ll;0;L;<font> 0131;;;;N;;;;;"),
+            Some((
+                0x1D6A4,
+                (
+                    "I".to_string(),
+                    false,
+                    true,
+                    true,
+                    vec!["ITALIC".to_string(), "DOTLESS".to_string()]
+                )
+            ))
+        );
+    }
+}
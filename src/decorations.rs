@@ -0,0 +1,795 @@
+//! Structure for the set of decorations of an uppercase AZ code point: ACUTE, GRAVE, CEDILLA, ...
+use core::fmt::Debug;
+use core::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Set of decorations (bit flags) of an uppercase AZ code point.
+///
+/// Each decoration matches a keyword of the Unicode description of the code point.
+/// e.g: `LATIN SMALL LETTER E WITH ACUTE` has the [`Decorations::ACUTE`] decoration.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Decorations([u128; 2]);
+
+// Each decoration of `NAMES` is the bit of its index: no decoration beyond the capacity, no duplicate
+const _: () = {
+    assert!(Decorations::NAMES.len() <= Decorations::CAPACITY);
+    let mut index = 0;
+    while index < Decorations::NAMES.len() {
+        let decoration = Decorations::NAMES[index].1 .0;
+        let bit = Decorations::bit(index).0;
+        assert!(decoration[0] == bit[0] && decoration[1] == bit[1]);
+        index += 1;
+    }
+};
+
+impl Decorations {
+    /// ABOVE
+    pub const ABOVE: Self = Self::bit(0);
+
+    /// ACUTE
+    pub const ACUTE: Self = Self::bit(1);
+
+    /// AFRICAN
+    pub const AFRICAN: Self = Self::bit(2);
+
+    /// ANGLICANA
+    pub const ANGLICANA: Self = Self::bit(3);
+
+    /// ARCHAIC
+    pub const ARCHAIC: Self = Self::bit(4);
+
+    /// BARRED
+    pub const BARRED: Self = Self::bit(5);
+
+    /// BELOW
+    pub const BELOW: Self = Self::bit(6);
+
+    /// BELT
+    pub const BELT: Self = Self::bit(7);
+
+    /// BLACK
+    pub const BLACK: Self = Self::bit(8);
+
+    /// BOTTOM
+    pub const BOTTOM: Self = Self::bit(9);
+
+    /// BRACKETED
+    pub const BRACKETED: Self = Self::bit(10);
+
+    /// BREVE
+    pub const BREVE: Self = Self::bit(11);
+
+    /// BROKEN
+    pub const BROKEN: Self = Self::bit(12);
+
+    /// COMMA
+    pub const COMMA: Self = Self::bit(13);
+
+    /// CARON
+    pub const CARON: Self = Self::bit(14);
+
+    /// CEDILLA
+    pub const CEDILLA: Self = Self::bit(15);
+
+    /// CIRCLED
+    pub const CIRCLED: Self = Self::bit(16);
+
+    /// CIRCUMFLEX
+    pub const CIRCUMFLEX: Self = Self::bit(17);
+
+    /// CLOSED
+    pub const CLOSED: Self = Self::bit(18);
+
+    /// COMBINING
+    pub const COMBINING: Self = Self::bit(19);
+
+    /// CROSSED-TAIL
+    pub const CROSSED_TAIL: Self = Self::bit(20);
+
+    /// CURL
+    pub const CURL: Self = Self::bit(21);
+
+    /// DESCENDER
+    pub const DESCENDER: Self = Self::bit(22);
+
+    /// DIAGONAL
+    pub const DIAGONAL: Self = Self::bit(23);
+
+    /// DIAERESIZED
+    pub const DIAERESIZED: Self = Self::bit(24);
+
+    /// DIAERESIS
+    pub const DIAERESIS: Self = Self::bit(25);
+
+    /// DIGRAPH
+    pub const DIGRAPH: Self = Self::bit(26);
+
+    /// DOUBLE
+    pub const DOUBLE: Self = Self::bit(27);
+
+    /// DOTLESS
+    pub const DOTLESS: Self = Self::bit(28);
+
+    /// EPIGRAPHIC
+    pub const EPIGRAPHIC: Self = Self::bit(29);
+
+    /// FISH
+    pub const FISH: Self = Self::bit(30);
+
+    /// FLATTENED
+    pub const FLATTENED: Self = Self::bit(31);
+
+    /// FLOURISH
+    pub const FLOURISH: Self = Self::bit(32);
+
+    /// FULLWIDTH
+    pub const FULLWIDTH: Self = Self::bit(33);
+
+    /// GLOTTAL
+    pub const GLOTTAL: Self = Self::bit(34);
+
+    /// GRAVE
+    pub const GRAVE: Self = Self::bit(35);
+
+    /// HALF
+    pub const HALF: Self = Self::bit(36);
+
+    /// HANDLE
+    pub const HANDLE: Self = Self::bit(37);
+
+    /// HIGH STROKE
+    pub const HIGH_STROKE: Self = Self::bit(38);
+
+    /// HOOK
+    pub const HOOK: Self = Self::bit(39);
+
+    /// HORIZONTAL
+    pub const HORIZONTAL: Self = Self::bit(40);
+
+    /// HORN
+    pub const HORN: Self = Self::bit(41);
+
+    /// INSULAR
+    pub const INSULAR: Self = Self::bit(42);
+
+    /// INSIDE
+    pub const INSIDE: Self = Self::bit(43);
+
+    /// IOTIFIED
+    pub const IOTIFIED: Self = Self::bit(44);
+
+    /// ITALIC
+    pub const ITALIC: Self = Self::bit(45);
+
+    /// INVERTED
+    pub const INVERTED: Self = Self::bit(46);
+
+    /// LIGHT CENTRALIZATION
+    pub const LIGHT_CENTRALIZATION: Self = Self::bit(47);
+
+    /// LAZY S
+    pub const LAZY_S: Self = Self::bit(48);
+
+    /// LEFT
+    pub const LEFT: Self = Self::bit(49);
+
+    /// LEG
+    pub const LEG: Self = Self::bit(50);
+
+    /// LENIS
+    pub const LENIS: Self = Self::bit(51);
+
+    /// LONGA
+    pub const LONGA: Self = Self::bit(52);
+
+    /// LONG
+    pub const LONG: Self = Self::bit(53);
+
+    /// LOOP
+    pub const LOOP: Self = Self::bit(54);
+
+    /// LOW
+    pub const LOW: Self = Self::bit(55);
+
+    /// MACRON
+    pub const MACRON: Self = Self::bit(56);
+
+    /// MID-HEIGHT
+    pub const MID_HEIGHT: Self = Self::bit(57);
+
+    /// MIDDLE-WELSH
+    pub const MIDDLE_WELSH: Self = Self::bit(58);
+
+    /// MIDDLE
+    pub const MIDDLE: Self = Self::bit(59);
+
+    /// NEGATIVE
+    pub const NEGATIVE: Self = Self::bit(60);
+
+    /// NOTCH
+    pub const NOTCH: Self = Self::bit(61);
+
+    /// OBLIQUE
+    pub const OBLIQUE: Self = Self::bit(62);
+
+    /// OGONEK
+    pub const OGONEK: Self = Self::bit(63);
+
+    /// OPEN-O
+    pub const OPEN_O: Self = Self::bit(64);
+
+    /// OPEN
+    pub const OPEN: Self = Self::bit(65);
+
+    /// OUTLINED
+    pub const OUTLINED: Self = Self::bit(66);
+
+    /// OVERLAY
+    pub const OVERLAY: Self = Self::bit(67);
+
+    /// PARENTHESIZED
+    pub const PARENTHESIZED: Self = Self::bit(68);
+
+    /// PALATAL
+    pub const PALATAL: Self = Self::bit(69);
+
+    /// PRECEDED BY APOSTROPHE
+    pub const PRECEDED_BY_APOSTROPHE: Self = Self::bit(70);
+
+    /// POLISH
+    pub const POLISH: Self = Self::bit(71);
+
+    /// RETROFLEX
+    pub const RETROFLEX: Self = Self::bit(72);
+
+    /// REVERSED-SCHWA
+    pub const REVERSED_SCHWA: Self = Self::bit(73);
+
+    /// REVERSED
+    pub const REVERSED: Self = Self::bit(74);
+
+    /// RIGHT
+    pub const RIGHT: Self = Self::bit(75);
+
+    /// RING
+    pub const RING: Self = Self::bit(76);
+
+    /// ROTUNDA
+    pub const ROTUNDA: Self = Self::bit(77);
+
+    /// SHARP
+    pub const SHARP: Self = Self::bit(78);
+
+    /// SCOTS
+    pub const SCOTS: Self = Self::bit(79);
+
+    /// SERIF
+    pub const SERIF: Self = Self::bit(80);
+
+    /// SHELL
+    pub const SHELL: Self = Self::bit(81);
+
+    /// SHORT
+    pub const SHORT: Self = Self::bit(82);
+
+    /// SIDEWAYS
+    pub const SIDEWAYS: Self = Self::bit(83);
+
+    /// SIGMOID
+    pub const SIGMOID: Self = Self::bit(84);
+
+    /// SQUARED
+    pub const SQUARED: Self = Self::bit(85);
+
+    /// SQUIRREL TAIL
+    pub const SQUIRREL_TAIL: Self = Self::bit(86);
+
+    /// STIRRUP
+    pub const STIRRUP: Self = Self::bit(87);
+
+    /// STRETCHED
+    pub const STRETCHED: Self = Self::bit(88);
+
+    /// STRIKETHROUGH
+    pub const STRIKETHROUGH: Self = Self::bit(89);
+
+    /// STROKE
+    pub const STROKE: Self = Self::bit(90);
+
+    /// SUBSCRIPT
+    pub const SUBSCRIPT: Self = Self::bit(91);
+
+    /// SUPERSCRIPT
+    pub const SUPERSCRIPT: Self = Self::bit(92);
+
+    /// SWASH TAIL
+    pub const SWASH_TAIL: Self = Self::bit(93);
+
+    /// THROUGH
+    pub const THROUGH: Self = Self::bit(94);
+
+    /// TILDE
+    pub const TILDE: Self = Self::bit(95);
+
+    /// TOPBAR
+    pub const TOPBAR: Self = Self::bit(96);
+
+    /// TORTOISE
+    pub const TORTOISE: Self = Self::bit(97);
+
+    /// TURNED
+    pub const TURNED: Self = Self::bit(98);
+
+    /// VISIGOTHIC
+    pub const VISIGOTHIC: Self = Self::bit(99);
+
+    /// VOLAPUK
+    pub const VOLAPUK: Self = Self::bit(100);
+
+    /// BAR
+    pub const BAR: Self = Self::bit(101);
+
+    /// CROSSED
+    pub const CROSSED: Self = Self::bit(102);
+
+    /// DOT
+    pub const DOT: Self = Self::bit(103);
+
+    /// LINE
+    pub const LINE: Self = Self::bit(104);
+
+    /// OLD
+    pub const OLD: Self = Self::bit(105);
+
+    /// SCRIPT
+    pub const SCRIPT: Self = Self::bit(106);
+
+    /// TAG
+    pub const TAG: Self = Self::bit(107);
+
+    /// TAIL
+    pub const TAIL: Self = Self::bit(108);
+
+    /// TOP
+    pub const TOP: Self = Self::bit(109);
+
+    /// DASIA
+    pub const DASIA: Self = Self::bit(110);
+
+    /// DIALYTIKA
+    pub const DIALYTIKA: Self = Self::bit(111);
+
+    /// OXIA
+    pub const OXIA: Self = Self::bit(112);
+
+    /// PERISPOMENI
+    pub const PERISPOMENI: Self = Self::bit(113);
+
+    /// PROSGEGRAMMENI
+    pub const PROSGEGRAMMENI: Self = Self::bit(114);
+
+    /// PSILI
+    pub const PSILI: Self = Self::bit(115);
+
+    /// TONOS
+    pub const TONOS: Self = Self::bit(116);
+
+    /// VARIA
+    pub const VARIA: Self = Self::bit(117);
+
+    /// VRACHY
+    pub const VRACHY: Self = Self::bit(118);
+
+    /// YPOGEGRAMMENI
+    pub const YPOGEGRAMMENI: Self = Self::bit(119);
+
+    /// BLACK-LETTER
+    pub const BLACK_LETTER: Self = Self::bit(120);
+
+    /// BOLD
+    pub const BOLD: Self = Self::bit(121);
+
+    /// DOUBLE-STRUCK
+    pub const DOUBLE_STRUCK: Self = Self::bit(122);
+
+    /// FRAKTUR
+    pub const FRAKTUR: Self = Self::bit(123);
+
+    /// MONOSPACE
+    pub const MONOSPACE: Self = Self::bit(124);
+
+    /// ROTATED
+    pub const ROTATED: Self = Self::bit(125);
+
+    /// SANS-SERIF
+    pub const SANS_SERIF: Self = Self::bit(126);
+}
+
+impl Decorations {
+    /// All decorations with their Unicode description keyword
+    pub const NAMES: [(&'static str, Self); 127] = [
+        ("ABOVE", Self::ABOVE),
+        ("ACUTE", Self::ACUTE),
+        ("AFRICAN", Self::AFRICAN),
+        ("ANGLICANA", Self::ANGLICANA),
+        ("ARCHAIC", Self::ARCHAIC),
+        ("BARRED", Self::BARRED),
+        ("BELOW", Self::BELOW),
+        ("BELT", Self::BELT),
+        ("BLACK", Self::BLACK),
+        ("BOTTOM", Self::BOTTOM),
+        ("BRACKETED", Self::BRACKETED),
+        ("BREVE", Self::BREVE),
+        ("BROKEN", Self::BROKEN),
+        ("COMMA", Self::COMMA),
+        ("CARON", Self::CARON),
+        ("CEDILLA", Self::CEDILLA),
+        ("CIRCLED", Self::CIRCLED),
+        ("CIRCUMFLEX", Self::CIRCUMFLEX),
+        ("CLOSED", Self::CLOSED),
+        ("COMBINING", Self::COMBINING),
+        ("CROSSED-TAIL", Self::CROSSED_TAIL),
+        ("CURL", Self::CURL),
+        ("DESCENDER", Self::DESCENDER),
+        ("DIAGONAL", Self::DIAGONAL),
+        ("DIAERESIZED", Self::DIAERESIZED),
+        ("DIAERESIS", Self::DIAERESIS),
+        ("DIGRAPH", Self::DIGRAPH),
+        ("DOUBLE", Self::DOUBLE),
+        ("DOTLESS", Self::DOTLESS),
+        ("EPIGRAPHIC", Self::EPIGRAPHIC),
+        ("FISH", Self::FISH),
+        ("FLATTENED", Self::FLATTENED),
+        ("FLOURISH", Self::FLOURISH),
+        ("FULLWIDTH", Self::FULLWIDTH),
+        ("GLOTTAL", Self::GLOTTAL),
+        ("GRAVE", Self::GRAVE),
+        ("HALF", Self::HALF),
+        ("HANDLE", Self::HANDLE),
+        ("HIGH STROKE", Self::HIGH_STROKE),
+        ("HOOK", Self::HOOK),
+        ("HORIZONTAL", Self::HORIZONTAL),
+        ("HORN", Self::HORN),
+        ("INSULAR", Self::INSULAR),
+        ("INSIDE", Self::INSIDE),
+        ("IOTIFIED", Self::IOTIFIED),
+        ("ITALIC", Self::ITALIC),
+        ("INVERTED", Self::INVERTED),
+        ("LIGHT CENTRALIZATION", Self::LIGHT_CENTRALIZATION),
+        ("LAZY S", Self::LAZY_S),
+        ("LEFT", Self::LEFT),
+        ("LEG", Self::LEG),
+        ("LENIS", Self::LENIS),
+        ("LONGA", Self::LONGA),
+        ("LONG", Self::LONG),
+        ("LOOP", Self::LOOP),
+        ("LOW", Self::LOW),
+        ("MACRON", Self::MACRON),
+        ("MID-HEIGHT", Self::MID_HEIGHT),
+        ("MIDDLE-WELSH", Self::MIDDLE_WELSH),
+        ("MIDDLE", Self::MIDDLE),
+        ("NEGATIVE", Self::NEGATIVE),
+        ("NOTCH", Self::NOTCH),
+        ("OBLIQUE", Self::OBLIQUE),
+        ("OGONEK", Self::OGONEK),
+        ("OPEN-O", Self::OPEN_O),
+        ("OPEN", Self::OPEN),
+        ("OUTLINED", Self::OUTLINED),
+        ("OVERLAY", Self::OVERLAY),
+        ("PARENTHESIZED", Self::PARENTHESIZED),
+        ("PALATAL", Self::PALATAL),
+        ("PRECEDED BY APOSTROPHE", Self::PRECEDED_BY_APOSTROPHE),
+        ("POLISH", Self::POLISH),
+        ("RETROFLEX", Self::RETROFLEX),
+        ("REVERSED-SCHWA", Self::REVERSED_SCHWA),
+        ("REVERSED", Self::REVERSED),
+        ("RIGHT", Self::RIGHT),
+        ("RING", Self::RING),
+        ("ROTUNDA", Self::ROTUNDA),
+        ("SHARP", Self::SHARP),
+        ("SCOTS", Self::SCOTS),
+        ("SERIF", Self::SERIF),
+        ("SHELL", Self::SHELL),
+        ("SHORT", Self::SHORT),
+        ("SIDEWAYS", Self::SIDEWAYS),
+        ("SIGMOID", Self::SIGMOID),
+        ("SQUARED", Self::SQUARED),
+        ("SQUIRREL TAIL", Self::SQUIRREL_TAIL),
+        ("STIRRUP", Self::STIRRUP),
+        ("STRETCHED", Self::STRETCHED),
+        ("STRIKETHROUGH", Self::STRIKETHROUGH),
+        ("STROKE", Self::STROKE),
+        ("SUBSCRIPT", Self::SUBSCRIPT),
+        ("SUPERSCRIPT", Self::SUPERSCRIPT),
+        ("SWASH TAIL", Self::SWASH_TAIL),
+        ("THROUGH", Self::THROUGH),
+        ("TILDE", Self::TILDE),
+        ("TOPBAR", Self::TOPBAR),
+        ("TORTOISE", Self::TORTOISE),
+        ("TURNED", Self::TURNED),
+        ("VISIGOTHIC", Self::VISIGOTHIC),
+        ("VOLAPUK", Self::VOLAPUK),
+        ("BAR", Self::BAR),
+        ("CROSSED", Self::CROSSED),
+        ("DOT", Self::DOT),
+        ("LINE", Self::LINE),
+        ("OLD", Self::OLD),
+        ("SCRIPT", Self::SCRIPT),
+        ("TAG", Self::TAG),
+        ("TAIL", Self::TAIL),
+        ("TOP", Self::TOP),
+        ("DASIA", Self::DASIA),
+        ("DIALYTIKA", Self::DIALYTIKA),
+        ("OXIA", Self::OXIA),
+        ("PERISPOMENI", Self::PERISPOMENI),
+        ("PROSGEGRAMMENI", Self::PROSGEGRAMMENI),
+        ("PSILI", Self::PSILI),
+        ("TONOS", Self::TONOS),
+        ("VARIA", Self::VARIA),
+        ("VRACHY", Self::VRACHY),
+        ("YPOGEGRAMMENI", Self::YPOGEGRAMMENI),
+        ("BLACK-LETTER", Self::BLACK_LETTER),
+        ("BOLD", Self::BOLD),
+        ("DOUBLE-STRUCK", Self::DOUBLE_STRUCK),
+        ("FRAKTUR", Self::FRAKTUR),
+        ("MONOSPACE", Self::MONOSPACE),
+        ("ROTATED", Self::ROTATED),
+        ("SANS-SERIF", Self::SANS_SERIF),
+    ];
+
+    /// Maximum number of decorations
+    pub const CAPACITY: usize = 2 * u128::BITS as usize;
+
+    /// Set of the decoration at `index`
+    const fn bit(index: usize) -> Self {
+        let mut words = [0; 2];
+        words[index / u128::BITS as usize] = 1 << (index % u128::BITS as usize);
+        Self(words)
+    }
+
+    /// Empty set of decorations
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self([0; 2])
+    }
+
+    /// Set of all decorations
+    #[must_use]
+    pub const fn all() -> Self {
+        // Mask of the `nb_bits` lowest bits of a word
+        const fn mask(nb_bits: usize) -> u128 {
+            if nb_bits >= u128::BITS as usize {
+                u128::MAX
+            } else {
+                (1 << nb_bits) - 1
+            }
+        }
+        let nb_bits = Self::NAMES.len();
+        Self([
+            mask(nb_bits),
+            mask(nb_bits.saturating_sub(u128::BITS as usize)),
+        ])
+    }
+
+    /// Raw bits of the set (least significant word first)
+    #[must_use]
+    pub const fn bits(self) -> [u128; 2] {
+        self.0
+    }
+
+    /// Set from raw bits, least significant word first (unknown bits are ignored)
+    #[must_use]
+    pub const fn from_bits_truncate(bits: [u128; 2]) -> Self {
+        Self(bits).intersection(Self::all())
+    }
+
+    /// Decoration matching a Unicode description keyword (e.g: `ACUTE`, `HIGH STROKE`)
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(decoration_name, _)| *decoration_name == name)
+            .map(|&(_, decoration)| decoration)
+    }
+
+    /// Returns true if the set is empty
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0[0] == 0 && self.0[1] == 0
+    }
+
+    /// Returns true if all decorations of `other` are in the set
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Returns true if some decorations of `other` are in the set
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Union of the two sets
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self([self.0[0] | other.0[0], self.0[1] | other.0[1]])
+    }
+
+    /// Intersection of the two sets
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self([self.0[0] & other.0[0], self.0[1] & other.0[1]])
+    }
+
+    /// Decorations of the set that are not in `other`
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self([self.0[0] & !other.0[0], self.0[1] & !other.0[1]])
+    }
+
+    /// Iterator over the Unicode description keywords of the decorations of the set
+    pub fn iter_names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMES
+            .iter()
+            .filter(move |&&(_, decoration)| self.contains(decoration))
+            .map(|&(name, _)| name)
+    }
+}
+
+impl BitOr for Decorations {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Decorations {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for Decorations {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Debug for Decorations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Decorations(")?;
+        for (index, name) in self.iter_names().enumerate() {
+            if index > 0 {
+                f.write_str(" | ")?;
+            }
+            f.write_str(name)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Serialized as the list of the Unicode description keywords of the decorations: `["ABOVE", "RING"]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Decorations {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_names())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Decorations {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // One decoration deserialized from its Unicode description keyword
+        struct Decoration(Decorations);
+
+        impl<'de> serde::Deserialize<'de> for Decoration {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct DecorationVisitor;
+
+                impl serde::de::Visitor<'_> for DecorationVisitor {
+                    type Value = Decoration;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.write_str("a decoration keyword")
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                        Decorations::from_name(v)
+                            .map(Decoration)
+                            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                    }
+                }
+
+                deserializer.deserialize_str(DecorationVisitor)
+            }
+        }
+
+        struct DecorationsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DecorationsVisitor {
+            type Value = Decorations;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a list of decoration keywords")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut decorations = Decorations::empty();
+                while let Some(Decoration(decoration)) = seq.next_element()? {
+                    decorations |= decoration;
+                }
+                Ok(decorations)
+            }
+        }
+
+        deserializer.deserialize_seq(DecorationsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decorations_set() {
+        let decorations = Decorations::ACUTE | Decorations::DOT;
+        assert!(decorations.contains(Decorations::ACUTE));
+        assert!(!decorations.contains(Decorations::ACUTE | Decorations::GRAVE));
+        assert!(decorations.intersects(Decorations::ACUTE | Decorations::GRAVE));
+        assert_eq!(decorations.difference(Decorations::DOT), Decorations::ACUTE);
+        assert!(Decorations::empty().is_empty());
+        assert!(Decorations::all().contains(decorations));
+        assert!(Decorations::SANS_SERIF.intersects(Decorations::all()));
+        assert_eq!(
+            Decorations::from_bits_truncate([u128::MAX; 2]),
+            Decorations::all()
+        );
+        assert_eq!(
+            Decorations::from_bits_truncate(Decorations::ACUTE.bits()),
+            Decorations::ACUTE
+        );
+    }
+
+    #[test]
+    fn test_decorations_names() {
+        assert_eq!(
+            Decorations::from_name("HIGH STROKE"),
+            Some(Decorations::HIGH_STROKE)
+        );
+        assert_eq!(
+            Decorations::from_name("DOUBLE-STRUCK"),
+            Some(Decorations::DOUBLE_STRUCK)
+        );
+        assert_eq!(Decorations::from_name("WITH"), None);
+        let decorations = Decorations::ACUTE | Decorations::DOT;
+        assert_eq!(
+            decorations.iter_names().collect::<Vec<_>>(),
+            vec!["ACUTE", "DOT"]
+        );
+        assert_eq!(format!("{decorations:?}"), "Decorations(ACUTE | DOT)");
+        assert_eq!(
+            Decorations::all().iter_names().count(),
+            Decorations::NAMES.len()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let decorations = Decorations::ABOVE | Decorations::RING;
+        let json = r#"["ABOVE","RING"]"#;
+        assert_eq!(serde_json::to_string(&decorations).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Decorations>(json).unwrap(),
+            decorations
+        );
+        assert_eq!(serde_json::to_string(&Decorations::empty()).unwrap(), "[]");
+        assert!(serde_json::from_str::<Decorations>(r#"["WITH"]"#).is_err());
+    }
+}
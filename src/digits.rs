@@ -0,0 +1,34 @@
+//! Digit 0-9 equivalent for Unicode digits: fullwidth, circled, superscript, Arabic-Indic, ...
+use crate::table::lookup;
+
+// Static table (sorted by code point) for digit 0-9 equivalent
+include!("hash_digits.rs");
+
+/// Returns the ASCII digit 0-9 equivalent of a Unicode digit
+pub fn digit_of(c: char) -> Option<char> {
+    lookup(DIGITS, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_digits_table_sorted() {
+        assert!(is_sorted_by_code_point(DIGITS));
+    }
+
+    #[test]
+    fn test_digit_of() {
+        assert_eq!(digit_of('7'), Some('7'));
+        assert_eq!(digit_of('０'), Some('0'));
+        assert_eq!(digit_of('①'), Some('1'));
+        assert_eq!(digit_of('²'), Some('2'));
+        assert_eq!(digit_of('₃'), Some('3'));
+        assert_eq!(digit_of('٣'), Some('3'));
+        assert_eq!(digit_of('३'), Some('3'));
+        assert_eq!(digit_of('⑩'), None);
+        assert_eq!(digit_of('A'), None);
+    }
+}
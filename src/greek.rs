@@ -0,0 +1,175 @@
+//! Uppercase AZ transliteration of Greek letters: ELOT 743 / ISO 843
+use crate::decorations::Decorations;
+use crate::letter::Letter;
+use crate::property::Property;
+use crate::table::lookup;
+use crate::uppercase::Uppercase;
+
+// Static table (sorted by code point) for Greek letters: property, decorations and uppercase AZ equivalent
+include!("hash_greek.rs");
+
+/// Returns the uppercase AZ equivalent of a Greek letter, without context rules (e.g: `Θ` -> `TH`, `ύ` -> `Y`)
+pub fn greek_of(c: char) -> Option<Uppercase> {
+    lookup(GREEK, c).map(|(property, decorations, letter)| Uppercase {
+        letter,
+        property,
+        decorations,
+    })
+}
+
+/// Returns the uppercase AZ equivalent of a Greek letter according to the two following chars, and the number of
+/// following chars that are part of the equivalent
+///
+/// Context rules of ELOT 743:
+/// * `ΑΥ`, `ΕΥ`, `ΗΥ` -> `AV`, `EV`, `IV` before a vowel or a voiced consonant, `AF`, `EF`, `IF` otherwise
+/// * `ΟΥ` -> `OU`
+/// * `ΓΓ`, `ΓΞ`, `ΓΧ` -> `NG`, `NX`, `NCH`
+///
+/// The `Υ` of a diphthong may have a tonos (e.g: `αύ`) but not a dialytika (e.g: `αϋ` -> `AY`).
+pub(crate) fn greek_in_context(
+    c: char,
+    next: Option<char>,
+    after_next: Option<char>,
+) -> Option<(Uppercase, usize)> {
+    let uppercase = greek_of(c)?;
+    let next = next.and_then(greek_of);
+
+    // Diphthongs ending with Υ
+    let upsilon = next.filter(|next| {
+        next.letter == Letter::Letter('Y') && !next.decorations.contains(Decorations::DIALYTIKA)
+    });
+    let first = match c {
+        'Α' | 'α' => Some('A'),
+        'Ε' | 'ε' => Some('E'),
+        'Η' | 'η' => Some('I'),
+        'Ο' | 'ο' => Some('O'),
+        _ => None,
+    };
+    if let (Some(first), Some(upsilon)) = (first, upsilon) {
+        let second = if first == 'O' {
+            'U'
+        } else if after_next.and_then(greek_of).is_some_and(|after_next| {
+            !matches!(
+                after_next.letter,
+                Letter::Letter('K' | 'X' | 'P' | 'S' | 'T' | 'F')
+                    | Letter::Letters('T', 'H')
+                    | Letter::Letters('C', 'H')
+                    | Letter::Letters('P', 'S')
+            )
+        }) {
+            'V'
+        } else {
+            'F'
+        };
+        let decorations = uppercase.decorations | upsilon.decorations;
+        // Title case diphthong (e.g: `Αυ` -> `Av`)
+        let property = if uppercase.property.is_capital() && upsilon.property.is_small() {
+            Property::SmallAndCapital
+        } else {
+            uppercase.property
+        };
+        let property = if decorations.is_empty() {
+            property
+        } else {
+            property.with_decoration()
+        };
+        let uppercase = Uppercase {
+            letter: Letter::Letters(first, second),
+            property,
+            decorations,
+        };
+        return Some((uppercase, 1));
+    }
+
+    // Γ before Γ, Ξ or Χ
+    let is_gamma = uppercase.letter == Letter::Letter('G');
+    if is_gamma
+        && next.is_some_and(|next| {
+            matches!(
+                next.letter,
+                Letter::Letter('G' | 'X') | Letter::Letters('C', 'H')
+            )
+        })
+    {
+        let uppercase = Uppercase {
+            letter: Letter::Letter('N'),
+            ..uppercase
+        };
+        return Some((uppercase, 0));
+    }
+
+    Some((uppercase, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_greek_table_sorted() {
+        assert!(is_sorted_by_code_point(GREEK));
+    }
+
+    #[test]
+    fn test_greek_of() {
+        let letter = |c| greek_of(c).map(|uppercase| uppercase.letter);
+        assert_eq!(letter('Θ'), Some(Letter::Letters('T', 'H')));
+        assert_eq!(letter('ψ'), Some(Letter::Letters('P', 'S')));
+        assert_eq!(letter('Χ'), Some(Letter::Letters('C', 'H')));
+        assert_eq!(letter('ς'), Some(Letter::Letter('S')));
+        assert_eq!(letter('ώ'), Some(Letter::Letter('O')));
+        assert_eq!(letter('A'), None);
+
+        let uppercase = greek_of('ΐ').unwrap();
+        assert_eq!(uppercase.letter, Letter::Letter('I'));
+        assert_eq!(uppercase.property, Property::SmallWithDecoration);
+        assert_eq!(
+            uppercase.decorations,
+            Decorations::DIALYTIKA | Decorations::TONOS
+        );
+        assert_eq!(greek_of('Ω').unwrap().property, Property::Capital);
+    }
+
+    #[test]
+    fn test_greek_in_context() {
+        let letter = |c, next, after_next| {
+            greek_in_context(c, next, after_next).map(|(uppercase, len)| (uppercase.letter, len))
+        };
+        assert_eq!(
+            letter('α', Some('υ'), Some('γ')),
+            Some((Letter::Letters('A', 'V'), 1))
+        );
+        assert_eq!(
+            letter('Ε', Some('Υ'), Some('Τ')),
+            Some((Letter::Letters('E', 'F'), 1))
+        );
+        assert_eq!(
+            letter('ε', Some('υ'), None),
+            Some((Letter::Letters('E', 'F'), 1))
+        );
+        assert_eq!(
+            letter('ο', Some('ύ'), Some('ς')),
+            Some((Letter::Letters('O', 'U'), 1))
+        );
+        assert_eq!(
+            letter('α', Some('ϋ'), Some('λ')),
+            Some((Letter::Letter('A'), 0))
+        );
+        assert_eq!(
+            letter('γ', Some('γ'), Some('ε')),
+            Some((Letter::Letter('N'), 0))
+        );
+
+        let (uppercase, _) = greek_in_context('α', Some('ύ'), Some('ρ')).unwrap();
+        assert_eq!(uppercase.property, Property::SmallWithDecoration);
+        assert_eq!(uppercase.decorations, Decorations::TONOS);
+
+        let (uppercase, _) = greek_in_context('Α', Some('υ'), Some('γ')).unwrap();
+        assert_eq!(uppercase.property, Property::SmallAndCapital);
+        let (uppercase, _) = greek_in_context('Ε', Some('ύ'), Some('ρ')).unwrap();
+        assert_eq!(uppercase.property, Property::SmallAndCapitalWithDecoration);
+        let (uppercase, _) = greek_in_context('Ο', Some('Υ'), None).unwrap();
+        assert_eq!(uppercase.property, Property::Capital);
+    }
+}
@@ -0,0 +1,152 @@
+//! Revised Romanization of Korean for Hangul syllables (U+AC00 to U+D7A3)
+//!
+//! Each syllable is decomposed algorithmically into its initial consonant, vowel and optional final
+//! consonant (jamo): `syllable = 0xAC00 + (initial * 21 + vowel) * 28 + final`.
+//!
+//! Context rules across syllables:
+//! * liaison of a final `ㄱ`, `ㄷ`, `ㅂ`, `ㄹ` before an initial `ㅇ` (e.g: `한국어` -> `HANGUGEO`)
+//! * `ㄹㄹ` and `ㄴㄹ` -> `LL` (e.g: `울릉` -> `ULLEUNG`, `신라` -> `SILLA`)
+
+/// First Hangul syllable (`가`)
+const FIRST_SYLLABLE: u32 = 0xAC00;
+
+/// Number of vowels
+const NB_VOWELS: u32 = 21;
+
+/// Number of final consonants (including none)
+const NB_FINALS: u32 = 28;
+
+/// Index of the initial consonants `ㄹ` and `ㅇ`
+const INITIAL_RIEUL: usize = 5;
+const INITIAL_IEUNG: usize = 11;
+
+/// Index of the final consonants `ㄱ`, `ㄴ`, `ㄷ`, `ㄹ` and `ㅂ`
+const FINAL_KIYEOK: usize = 1;
+const FINAL_NIEUN: usize = 4;
+const FINAL_TIKEUT: usize = 7;
+const FINAL_RIEUL: usize = 8;
+const FINAL_PIEUP: usize = 17;
+
+/// Romanization of the 19 initial consonants: ㄱ ㄲ ㄴ ㄷ ㄸ ㄹ ㅁ ㅂ ㅃ ㅅ ㅆ ㅇ ㅈ ㅉ ㅊ ㅋ ㅌ ㅍ ㅎ
+const INITIALS: [&str; 19] = [
+    "G", "KK", "N", "D", "TT", "R", "M", "B", "PP", "S", "SS", "", "J", "JJ", "CH", "K", "T", "P",
+    "H",
+];
+
+/// Romanization of the 21 vowels: ㅏ ㅐ ㅑ ㅒ ㅓ ㅔ ㅕ ㅖ ㅗ ㅘ ㅙ ㅚ ㅛ ㅜ ㅝ ㅞ ㅟ ㅠ ㅡ ㅢ ㅣ
+const VOWELS: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WO", "WE",
+    "WI", "YU", "EU", "UI", "I",
+];
+
+/// Romanization of the 28 final consonants (first one is none): ㄱ ㄲ ㄳ ㄴ ㄵ ㄶ ㄷ ㄹ ㄺ ㄻ ㄼ ㄽ ㄾ ㄿ ㅀ ㅁ
+/// ㅂ ㅄ ㅅ ㅆ ㅇ ㅈ ㅊ ㅋ ㅌ ㅍ ㅎ
+const FINALS: [&str; 28] = [
+    "", "K", "K", "K", "N", "N", "N", "T", "L", "K", "M", "L", "L", "L", "P", "L", "M", "P", "P",
+    "T", "T", "NG", "T", "T", "K", "T", "P", "T",
+];
+
+/// Maximum length of the romanization of a Hangul syllable (e.g: `GWAENG` for `괭`)
+pub(crate) const MAX_HANGUL_LEN: usize = max_len(&INITIALS) + max_len(&VOWELS) + max_len(&FINALS);
+
+/// Maximum length of the romanizations of a jamo table
+const fn max_len(jamos: &[&str]) -> usize {
+    let mut max = 0;
+    let mut index = 0;
+    while index < jamos.len() {
+        if jamos[index].len() > max {
+            max = jamos[index].len();
+        }
+        index += 1;
+    }
+    max
+}
+
+/// Returns the romanization of the initial consonant, the vowel and the final consonant of a Hangul
+/// syllable (e.g: `한` -> `["H", "A", "N"]`)
+pub fn hangul_of(c: char) -> Option<[&'static str; 3]> {
+    let (initial, vowel, last) = jamos(c)?;
+    Some([INITIALS[initial], VOWELS[vowel], FINALS[last]])
+}
+
+/// Returns the romanization of a Hangul syllable according to the previous and the next chars
+pub(crate) fn hangul_in_context(
+    previous: Option<char>,
+    c: char,
+    next: Option<char>,
+) -> Option<[&'static str; 3]> {
+    let (initial, vowel, last) = jamos(c)?;
+    let previous_last = previous.and_then(jamos).map(|(_, _, last)| last);
+    let next_initial = next.and_then(jamos).map(|(initial, _, _)| initial);
+
+    let initial = match (previous_last, initial) {
+        (Some(FINAL_NIEUN | FINAL_RIEUL), INITIAL_RIEUL) => "L",
+        _ => INITIALS[initial],
+    };
+    let last = match (last, next_initial) {
+        (FINAL_KIYEOK, Some(INITIAL_IEUNG)) => "G",
+        (FINAL_TIKEUT, Some(INITIAL_IEUNG)) => "D",
+        (FINAL_PIEUP, Some(INITIAL_IEUNG)) => "B",
+        (FINAL_RIEUL, Some(INITIAL_IEUNG)) => "R",
+        (FINAL_NIEUN | FINAL_RIEUL, Some(INITIAL_RIEUL)) => "L",
+        _ => FINALS[last],
+    };
+    Some([initial, VOWELS[vowel], last])
+}
+
+/// Index of the initial consonant, the vowel and the final consonant of a Hangul syllable
+fn jamos(c: char) -> Option<(usize, usize, usize)> {
+    let index = (c as u32).checked_sub(FIRST_SYLLABLE)?;
+    let initial = index / (NB_VOWELS * NB_FINALS);
+    (initial < INITIALS.len() as u32).then_some((
+        initial as usize,
+        (index / NB_FINALS % NB_VOWELS) as usize,
+        (index % NB_FINALS) as usize,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hangul_of() {
+        assert_eq!(hangul_of('가'), Some(["G", "A", ""]));
+        assert_eq!(hangul_of('한'), Some(["H", "A", "N"]));
+        assert_eq!(hangul_of('국'), Some(["G", "U", "K"]));
+        assert_eq!(hangul_of('서'), Some(["S", "EO", ""]));
+        assert_eq!(hangul_of('울'), Some(["", "U", "L"]));
+        assert_eq!(hangul_of('경'), Some(["G", "YEO", "NG"]));
+        assert_eq!(hangul_of('힣'), Some(["H", "I", "T"]));
+        assert_eq!(hangul_of('\u{D7A4}'), None);
+        assert_eq!(hangul_of('ㄱ'), None);
+        assert_eq!(hangul_of('A'), None);
+        assert_eq!(MAX_HANGUL_LEN, 7);
+    }
+
+    #[test]
+    fn test_hangul_in_context() {
+        let romanize = |txt: &str| {
+            let chars: Vec<char> = txt.chars().collect();
+            (0..chars.len())
+                .flat_map(|index| {
+                    let previous = index.checked_sub(1).map(|index| chars[index]);
+                    hangul_in_context(previous, chars[index], chars.get(index + 1).copied())
+                        .unwrap()
+                })
+                .collect::<String>()
+        };
+        assert_eq!(romanize("한국어"), "HANGUGEO");
+        assert_eq!(romanize("울릉"), "ULLEUNG");
+        assert_eq!(romanize("신라"), "SILLA");
+        assert_eq!(romanize("받아"), "BADA");
+        assert_eq!(romanize("밥을"), "BABEUL");
+        assert_eq!(romanize("물이"), "MURI");
+        assert_eq!(romanize("한국"), "HANGUK");
+        assert_eq!(
+            hangul_in_context(None, '울', Some('A')),
+            Some(["", "U", "L"])
+        );
+        assert_eq!(hangul_in_context(None, 'A', None), None);
+    }
+}
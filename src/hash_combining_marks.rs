@@ -0,0 +1,2026 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static COMBINING_MARKS: &[(u32, Decorations)] = &[
+    (0x300, Decorations::GRAVE),
+    (0x301, Decorations::ACUTE),
+    (0x302, Decorations::CIRCUMFLEX),
+    (0x303, Decorations::TILDE),
+    (0x304, Decorations::MACRON),
+    (0x305, Decorations::empty()),
+    (0x306, Decorations::BREVE),
+    (0x307, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x308, Decorations::DIAERESIS),
+    (0x309, Decorations::ABOVE.union(Decorations::HOOK)),
+    (0x30A, Decorations::ABOVE.union(Decorations::RING)),
+    (0x30B, Decorations::ACUTE.union(Decorations::DOUBLE)),
+    (0x30C, Decorations::CARON),
+    (0x30D, Decorations::ABOVE.union(Decorations::LINE)),
+    (0x30E, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::LINE)),
+    (0x30F, Decorations::DOUBLE.union(Decorations::GRAVE)),
+    (0x310, Decorations::empty()),
+    (0x311, Decorations::BREVE.union(Decorations::INVERTED)),
+    (0x312, Decorations::ABOVE.union(Decorations::COMMA).union(Decorations::TURNED)),
+    (0x313, Decorations::ABOVE.union(Decorations::COMMA)),
+    (0x314, Decorations::ABOVE.union(Decorations::COMMA).union(Decorations::REVERSED)),
+    (0x315, Decorations::ABOVE.union(Decorations::COMMA).union(Decorations::RIGHT)),
+    (0x316, Decorations::BELOW.union(Decorations::GRAVE)),
+    (0x317, Decorations::ACUTE.union(Decorations::BELOW)),
+    (0x318, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x319, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x31A, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x31B, Decorations::HORN),
+    (0x31C, Decorations::BELOW.union(Decorations::HALF).union(Decorations::LEFT).union(Decorations::RING)),
+    (0x31D, Decorations::BELOW),
+    (0x31E, Decorations::BELOW),
+    (0x31F, Decorations::BELOW),
+    (0x320, Decorations::BELOW),
+    (0x321, Decorations::BELOW.union(Decorations::HOOK)),
+    (0x322, Decorations::BELOW.union(Decorations::HOOK).union(Decorations::RETROFLEX)),
+    (0x323, Decorations::BELOW.union(Decorations::DOT)),
+    (0x324, Decorations::BELOW.union(Decorations::DIAERESIS)),
+    (0x325, Decorations::BELOW.union(Decorations::RING)),
+    (0x326, Decorations::BELOW.union(Decorations::COMMA)),
+    (0x327, Decorations::CEDILLA),
+    (0x328, Decorations::OGONEK),
+    (0x329, Decorations::BELOW.union(Decorations::LINE)),
+    (0x32A, Decorations::BELOW),
+    (0x32B, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::INVERTED)),
+    (0x32C, Decorations::BELOW.union(Decorations::CARON)),
+    (0x32D, Decorations::BELOW.union(Decorations::CIRCUMFLEX)),
+    (0x32E, Decorations::BELOW.union(Decorations::BREVE)),
+    (0x32F, Decorations::BELOW.union(Decorations::BREVE).union(Decorations::INVERTED)),
+    (0x330, Decorations::BELOW.union(Decorations::TILDE)),
+    (0x331, Decorations::BELOW.union(Decorations::MACRON)),
+    (0x332, Decorations::LOW.union(Decorations::LINE)),
+    (0x333, Decorations::DOUBLE.union(Decorations::LOW).union(Decorations::LINE)),
+    (0x334, Decorations::OVERLAY.union(Decorations::TILDE)),
+    (0x335, Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::STROKE)),
+    (0x336, Decorations::LONG.union(Decorations::OVERLAY).union(Decorations::STROKE)),
+    (0x337, Decorations::OVERLAY.union(Decorations::SHORT)),
+    (0x338, Decorations::LONG.union(Decorations::OVERLAY)),
+    (0x339, Decorations::BELOW.union(Decorations::HALF).union(Decorations::RIGHT).union(Decorations::RING)),
+    (0x33A, Decorations::BELOW.union(Decorations::INVERTED)),
+    (0x33B, Decorations::BELOW),
+    (0x33C, Decorations::BELOW),
+    (0x33D, Decorations::ABOVE),
+    (0x33E, Decorations::TILDE),
+    (0x33F, Decorations::DOUBLE),
+    (0x340, Decorations::GRAVE),
+    (0x341, Decorations::ACUTE),
+    (0x342, Decorations::PERISPOMENI),
+    (0x343, Decorations::empty()),
+    (0x344, Decorations::DIALYTIKA.union(Decorations::TONOS)),
+    (0x345, Decorations::YPOGEGRAMMENI),
+    (0x346, Decorations::ABOVE),
+    (0x347, Decorations::BELOW),
+    (0x348, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::LINE)),
+    (0x349, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x34A, Decorations::ABOVE.union(Decorations::TILDE)),
+    (0x34B, Decorations::ABOVE),
+    (0x34C, Decorations::ABOVE),
+    (0x34D, Decorations::BELOW.union(Decorations::LEFT).union(Decorations::RIGHT)),
+    (0x34E, Decorations::BELOW),
+    (0x34F, Decorations::empty()),
+    (0x350, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x351, Decorations::ABOVE.union(Decorations::HALF).union(Decorations::LEFT).union(Decorations::RING)),
+    (0x352, Decorations::empty()),
+    (0x353, Decorations::BELOW),
+    (0x354, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x355, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x356, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x357, Decorations::ABOVE.union(Decorations::HALF).union(Decorations::RIGHT).union(Decorations::RING)),
+    (0x358, Decorations::ABOVE.union(Decorations::RIGHT).union(Decorations::DOT)),
+    (0x359, Decorations::BELOW),
+    (0x35A, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::RING)),
+    (0x35B, Decorations::ABOVE),
+    (0x35C, Decorations::BELOW.union(Decorations::BREVE).union(Decorations::DOUBLE)),
+    (0x35D, Decorations::BREVE.union(Decorations::DOUBLE)),
+    (0x35E, Decorations::DOUBLE.union(Decorations::MACRON)),
+    (0x35F, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::MACRON)),
+    (0x360, Decorations::DOUBLE.union(Decorations::TILDE)),
+    (0x361, Decorations::BREVE.union(Decorations::DOUBLE).union(Decorations::INVERTED)),
+    (0x362, Decorations::BELOW.union(Decorations::DOUBLE)),
+    (0x363, Decorations::empty()),
+    (0x364, Decorations::empty()),
+    (0x365, Decorations::empty()),
+    (0x366, Decorations::empty()),
+    (0x367, Decorations::empty()),
+    (0x368, Decorations::empty()),
+    (0x369, Decorations::empty()),
+    (0x36A, Decorations::empty()),
+    (0x36B, Decorations::empty()),
+    (0x36C, Decorations::empty()),
+    (0x36D, Decorations::empty()),
+    (0x36E, Decorations::empty()),
+    (0x36F, Decorations::empty()),
+    (0x483, Decorations::empty()),
+    (0x484, Decorations::empty()),
+    (0x485, Decorations::DASIA),
+    (0x486, Decorations::PSILI),
+    (0x487, Decorations::empty()),
+    (0x591, Decorations::empty()),
+    (0x592, Decorations::empty()),
+    (0x593, Decorations::empty()),
+    (0x594, Decorations::empty()),
+    (0x595, Decorations::empty()),
+    (0x596, Decorations::empty()),
+    (0x597, Decorations::empty()),
+    (0x598, Decorations::empty()),
+    (0x599, Decorations::empty()),
+    (0x59A, Decorations::empty()),
+    (0x59B, Decorations::empty()),
+    (0x59C, Decorations::empty()),
+    (0x59D, Decorations::empty()),
+    (0x59E, Decorations::empty()),
+    (0x59F, Decorations::empty()),
+    (0x5A0, Decorations::empty()),
+    (0x5A1, Decorations::empty()),
+    (0x5A2, Decorations::empty()),
+    (0x5A3, Decorations::empty()),
+    (0x5A4, Decorations::empty()),
+    (0x5A5, Decorations::empty()),
+    (0x5A6, Decorations::empty()),
+    (0x5A7, Decorations::empty()),
+    (0x5A8, Decorations::empty()),
+    (0x5A9, Decorations::empty()),
+    (0x5AA, Decorations::empty()),
+    (0x5AB, Decorations::empty()),
+    (0x5AC, Decorations::empty()),
+    (0x5AD, Decorations::empty()),
+    (0x5AE, Decorations::empty()),
+    (0x5AF, Decorations::empty()),
+    (0x5B0, Decorations::empty()),
+    (0x5B1, Decorations::empty()),
+    (0x5B2, Decorations::empty()),
+    (0x5B3, Decorations::empty()),
+    (0x5B4, Decorations::empty()),
+    (0x5B5, Decorations::empty()),
+    (0x5B6, Decorations::empty()),
+    (0x5B7, Decorations::empty()),
+    (0x5B8, Decorations::empty()),
+    (0x5B9, Decorations::empty()),
+    (0x5BA, Decorations::empty()),
+    (0x5BB, Decorations::empty()),
+    (0x5BC, Decorations::empty()),
+    (0x5BD, Decorations::empty()),
+    (0x5BF, Decorations::empty()),
+    (0x5C1, Decorations::DOT),
+    (0x5C2, Decorations::DOT),
+    (0x5C4, Decorations::DOT),
+    (0x5C5, Decorations::DOT),
+    (0x5C7, Decorations::empty()),
+    (0x610, Decorations::empty()),
+    (0x611, Decorations::empty()),
+    (0x612, Decorations::empty()),
+    (0x613, Decorations::empty()),
+    (0x614, Decorations::empty()),
+    (0x615, Decorations::empty()),
+    (0x616, Decorations::empty()),
+    (0x617, Decorations::empty()),
+    (0x618, Decorations::empty()),
+    (0x619, Decorations::empty()),
+    (0x61A, Decorations::empty()),
+    (0x64B, Decorations::empty()),
+    (0x64C, Decorations::empty()),
+    (0x64D, Decorations::empty()),
+    (0x64E, Decorations::empty()),
+    (0x64F, Decorations::empty()),
+    (0x650, Decorations::empty()),
+    (0x651, Decorations::empty()),
+    (0x652, Decorations::empty()),
+    (0x653, Decorations::ABOVE),
+    (0x654, Decorations::ABOVE),
+    (0x655, Decorations::BELOW),
+    (0x656, Decorations::SUBSCRIPT),
+    (0x657, Decorations::INVERTED),
+    (0x658, Decorations::empty()),
+    (0x659, Decorations::empty()),
+    (0x65A, Decorations::ABOVE),
+    (0x65B, Decorations::ABOVE.union(Decorations::INVERTED)),
+    (0x65C, Decorations::BELOW.union(Decorations::DOT)),
+    (0x65D, Decorations::REVERSED),
+    (0x65E, Decorations::empty()),
+    (0x65F, Decorations::BELOW),
+    (0x670, Decorations::SUPERSCRIPT),
+    (0x6D6, Decorations::empty()),
+    (0x6D7, Decorations::empty()),
+    (0x6D8, Decorations::empty()),
+    (0x6D9, Decorations::empty()),
+    (0x6DA, Decorations::empty()),
+    (0x6DB, Decorations::empty()),
+    (0x6DC, Decorations::empty()),
+    (0x6DF, Decorations::empty()),
+    (0x6E0, Decorations::empty()),
+    (0x6E1, Decorations::DOTLESS),
+    (0x6E2, Decorations::empty()),
+    (0x6E3, Decorations::LOW),
+    (0x6E4, Decorations::empty()),
+    (0x6E7, Decorations::empty()),
+    (0x6E8, Decorations::empty()),
+    (0x6EA, Decorations::LOW),
+    (0x6EB, Decorations::empty()),
+    (0x6EC, Decorations::empty()),
+    (0x6ED, Decorations::LOW),
+    (0x711, Decorations::SUPERSCRIPT),
+    (0x730, Decorations::ABOVE),
+    (0x731, Decorations::BELOW),
+    (0x732, Decorations::empty()),
+    (0x733, Decorations::ABOVE),
+    (0x734, Decorations::BELOW),
+    (0x735, Decorations::empty()),
+    (0x736, Decorations::ABOVE),
+    (0x737, Decorations::BELOW),
+    (0x738, Decorations::HORIZONTAL),
+    (0x739, Decorations::empty()),
+    (0x73A, Decorations::ABOVE),
+    (0x73B, Decorations::BELOW),
+    (0x73C, Decorations::empty()),
+    (0x73D, Decorations::ABOVE),
+    (0x73E, Decorations::BELOW),
+    (0x73F, Decorations::empty()),
+    (0x740, Decorations::DOT),
+    (0x741, Decorations::empty()),
+    (0x742, Decorations::empty()),
+    (0x743, Decorations::ABOVE),
+    (0x744, Decorations::BELOW),
+    (0x745, Decorations::ABOVE),
+    (0x746, Decorations::BELOW),
+    (0x747, Decorations::ABOVE.union(Decorations::OBLIQUE).union(Decorations::LINE)),
+    (0x748, Decorations::BELOW.union(Decorations::OBLIQUE).union(Decorations::LINE)),
+    (0x749, Decorations::empty()),
+    (0x74A, Decorations::empty()),
+    (0x7A6, Decorations::empty()),
+    (0x7A7, Decorations::empty()),
+    (0x7A8, Decorations::empty()),
+    (0x7A9, Decorations::empty()),
+    (0x7AA, Decorations::empty()),
+    (0x7AB, Decorations::empty()),
+    (0x7AC, Decorations::empty()),
+    (0x7AD, Decorations::empty()),
+    (0x7AE, Decorations::empty()),
+    (0x7AF, Decorations::empty()),
+    (0x7B0, Decorations::empty()),
+    (0x7EB, Decorations::SHORT),
+    (0x7EC, Decorations::LOW.union(Decorations::SHORT)),
+    (0x7ED, Decorations::SHORT),
+    (0x7EE, Decorations::LONG),
+    (0x7EF, Decorations::LONG),
+    (0x7F0, Decorations::LONG.union(Decorations::LOW)),
+    (0x7F1, Decorations::LONG),
+    (0x7F2, Decorations::empty()),
+    (0x7F3, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::DOT)),
+    (0x7FD, Decorations::empty()),
+    (0x816, Decorations::empty()),
+    (0x817, Decorations::empty()),
+    (0x818, Decorations::empty()),
+    (0x819, Decorations::empty()),
+    (0x81B, Decorations::empty()),
+    (0x81C, Decorations::LONG),
+    (0x81D, Decorations::empty()),
+    (0x81E, Decorations::empty()),
+    (0x81F, Decorations::LONG),
+    (0x820, Decorations::empty()),
+    (0x821, Decorations::empty()),
+    (0x822, Decorations::LONG),
+    (0x823, Decorations::empty()),
+    (0x825, Decorations::SHORT),
+    (0x826, Decorations::LONG),
+    (0x827, Decorations::empty()),
+    (0x829, Decorations::LONG),
+    (0x82A, Decorations::empty()),
+    (0x82B, Decorations::empty()),
+    (0x82C, Decorations::empty()),
+    (0x82D, Decorations::empty()),
+    (0x859, Decorations::empty()),
+    (0x85A, Decorations::empty()),
+    (0x85B, Decorations::empty()),
+    (0x897, Decorations::empty()),
+    (0x898, Decorations::empty()),
+    (0x899, Decorations::LOW),
+    (0x89A, Decorations::LOW),
+    (0x89B, Decorations::LOW),
+    (0x89C, Decorations::empty()),
+    (0x89D, Decorations::SUPERSCRIPT),
+    (0x89E, Decorations::empty()),
+    (0x89F, Decorations::HALF),
+    (0x8CA, Decorations::empty()),
+    (0x8CB, Decorations::BELOW),
+    (0x8CC, Decorations::empty()),
+    (0x8CD, Decorations::empty()),
+    (0x8CE, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x8CF, Decorations::BELOW.union(Decorations::DOT)),
+    (0x8D0, Decorations::BELOW),
+    (0x8D1, Decorations::BELOW),
+    (0x8D2, Decorations::BELOW.union(Decorations::INSIDE).union(Decorations::DOT)),
+    (0x8D3, Decorations::LOW),
+    (0x8D4, Decorations::empty()),
+    (0x8D5, Decorations::empty()),
+    (0x8D6, Decorations::empty()),
+    (0x8D7, Decorations::empty()),
+    (0x8D8, Decorations::empty()),
+    (0x8D9, Decorations::LOW),
+    (0x8DA, Decorations::empty()),
+    (0x8DB, Decorations::empty()),
+    (0x8DC, Decorations::empty()),
+    (0x8DD, Decorations::empty()),
+    (0x8DE, Decorations::empty()),
+    (0x8DF, Decorations::empty()),
+    (0x8E0, Decorations::empty()),
+    (0x8E1, Decorations::empty()),
+    (0x8E3, Decorations::BELOW.union(Decorations::TURNED)),
+    (0x8E4, Decorations::empty()),
+    (0x8E5, Decorations::empty()),
+    (0x8E6, Decorations::empty()),
+    (0x8E7, Decorations::empty()),
+    (0x8E8, Decorations::empty()),
+    (0x8E9, Decorations::empty()),
+    (0x8EA, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x8EB, Decorations::ABOVE),
+    (0x8EC, Decorations::ABOVE.union(Decorations::LOOP)),
+    (0x8ED, Decorations::BELOW.union(Decorations::DOT)),
+    (0x8EE, Decorations::BELOW),
+    (0x8EF, Decorations::BELOW.union(Decorations::LOOP)),
+    (0x8F0, Decorations::OPEN),
+    (0x8F1, Decorations::OPEN),
+    (0x8F2, Decorations::OPEN),
+    (0x8F3, Decorations::empty()),
+    (0x8F4, Decorations::RING),
+    (0x8F5, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x8F6, Decorations::BELOW.union(Decorations::DOT)),
+    (0x8F7, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x8F8, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x8F9, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x8FA, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x8FB, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::RIGHT)),
+    (0x8FC, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::RIGHT).union(Decorations::DOT)),
+    (0x8FD, Decorations::ABOVE.union(Decorations::RIGHT).union(Decorations::DOT)),
+    (0x8FE, Decorations::DOT),
+    (0x8FF, Decorations::SIDEWAYS),
+    (0x900, Decorations::INVERTED),
+    (0x901, Decorations::empty()),
+    (0x902, Decorations::empty()),
+    (0x93A, Decorations::empty()),
+    (0x93C, Decorations::empty()),
+    (0x941, Decorations::empty()),
+    (0x942, Decorations::empty()),
+    (0x943, Decorations::empty()),
+    (0x944, Decorations::empty()),
+    (0x945, Decorations::empty()),
+    (0x946, Decorations::SHORT),
+    (0x947, Decorations::empty()),
+    (0x948, Decorations::empty()),
+    (0x94D, Decorations::empty()),
+    (0x951, Decorations::empty()),
+    (0x952, Decorations::empty()),
+    (0x953, Decorations::GRAVE),
+    (0x954, Decorations::ACUTE),
+    (0x955, Decorations::LONG),
+    (0x956, Decorations::empty()),
+    (0x957, Decorations::empty()),
+    (0x962, Decorations::empty()),
+    (0x963, Decorations::empty()),
+    (0x981, Decorations::empty()),
+    (0x9BC, Decorations::empty()),
+    (0x9C1, Decorations::empty()),
+    (0x9C2, Decorations::empty()),
+    (0x9C3, Decorations::empty()),
+    (0x9C4, Decorations::empty()),
+    (0x9CD, Decorations::empty()),
+    (0x9E2, Decorations::empty()),
+    (0x9E3, Decorations::empty()),
+    (0x9FE, Decorations::empty()),
+    (0xA01, Decorations::empty()),
+    (0xA02, Decorations::empty()),
+    (0xA3C, Decorations::empty()),
+    (0xA41, Decorations::empty()),
+    (0xA42, Decorations::empty()),
+    (0xA47, Decorations::empty()),
+    (0xA48, Decorations::empty()),
+    (0xA4B, Decorations::empty()),
+    (0xA4C, Decorations::empty()),
+    (0xA4D, Decorations::empty()),
+    (0xA51, Decorations::empty()),
+    (0xA70, Decorations::empty()),
+    (0xA71, Decorations::empty()),
+    (0xA75, Decorations::empty()),
+    (0xA81, Decorations::empty()),
+    (0xA82, Decorations::empty()),
+    (0xABC, Decorations::empty()),
+    (0xAC1, Decorations::empty()),
+    (0xAC2, Decorations::empty()),
+    (0xAC3, Decorations::empty()),
+    (0xAC4, Decorations::empty()),
+    (0xAC5, Decorations::empty()),
+    (0xAC7, Decorations::empty()),
+    (0xAC8, Decorations::empty()),
+    (0xACD, Decorations::empty()),
+    (0xAE2, Decorations::empty()),
+    (0xAE3, Decorations::empty()),
+    (0xAFA, Decorations::empty()),
+    (0xAFB, Decorations::empty()),
+    (0xAFC, Decorations::empty()),
+    (0xAFD, Decorations::ABOVE),
+    (0xAFE, Decorations::ABOVE),
+    (0xAFF, Decorations::ABOVE),
+    (0xB01, Decorations::empty()),
+    (0xB3C, Decorations::empty()),
+    (0xB3F, Decorations::empty()),
+    (0xB41, Decorations::empty()),
+    (0xB42, Decorations::empty()),
+    (0xB43, Decorations::empty()),
+    (0xB44, Decorations::empty()),
+    (0xB4D, Decorations::empty()),
+    (0xB55, Decorations::empty()),
+    (0xB56, Decorations::empty()),
+    (0xB62, Decorations::empty()),
+    (0xB63, Decorations::empty()),
+    (0xB82, Decorations::empty()),
+    (0xBC0, Decorations::empty()),
+    (0xBCD, Decorations::empty()),
+    (0xC00, Decorations::ABOVE),
+    (0xC04, Decorations::ABOVE),
+    (0xC3C, Decorations::empty()),
+    (0xC3E, Decorations::empty()),
+    (0xC3F, Decorations::empty()),
+    (0xC40, Decorations::empty()),
+    (0xC46, Decorations::empty()),
+    (0xC47, Decorations::empty()),
+    (0xC48, Decorations::empty()),
+    (0xC4A, Decorations::empty()),
+    (0xC4B, Decorations::empty()),
+    (0xC4C, Decorations::empty()),
+    (0xC4D, Decorations::empty()),
+    (0xC55, Decorations::empty()),
+    (0xC56, Decorations::empty()),
+    (0xC62, Decorations::empty()),
+    (0xC63, Decorations::empty()),
+    (0xC81, Decorations::empty()),
+    (0xCBC, Decorations::empty()),
+    (0xCBF, Decorations::empty()),
+    (0xCC6, Decorations::empty()),
+    (0xCCC, Decorations::empty()),
+    (0xCCD, Decorations::empty()),
+    (0xCE2, Decorations::empty()),
+    (0xCE3, Decorations::empty()),
+    (0xD00, Decorations::ABOVE),
+    (0xD01, Decorations::empty()),
+    (0xD3B, Decorations::BAR),
+    (0xD3C, Decorations::empty()),
+    (0xD41, Decorations::empty()),
+    (0xD42, Decorations::empty()),
+    (0xD43, Decorations::empty()),
+    (0xD44, Decorations::empty()),
+    (0xD4D, Decorations::empty()),
+    (0xD62, Decorations::empty()),
+    (0xD63, Decorations::empty()),
+    (0xD81, Decorations::empty()),
+    (0xDCA, Decorations::empty()),
+    (0xDD2, Decorations::empty()),
+    (0xDD3, Decorations::empty()),
+    (0xDD4, Decorations::empty()),
+    (0xDD6, Decorations::empty()),
+    (0xE31, Decorations::empty()),
+    (0xE34, Decorations::empty()),
+    (0xE35, Decorations::empty()),
+    (0xE36, Decorations::empty()),
+    (0xE37, Decorations::empty()),
+    (0xE38, Decorations::empty()),
+    (0xE39, Decorations::empty()),
+    (0xE3A, Decorations::empty()),
+    (0xE47, Decorations::empty()),
+    (0xE48, Decorations::empty()),
+    (0xE49, Decorations::empty()),
+    (0xE4A, Decorations::empty()),
+    (0xE4B, Decorations::empty()),
+    (0xE4C, Decorations::empty()),
+    (0xE4D, Decorations::empty()),
+    (0xE4E, Decorations::empty()),
+    (0xEB1, Decorations::empty()),
+    (0xEB4, Decorations::empty()),
+    (0xEB5, Decorations::empty()),
+    (0xEB6, Decorations::empty()),
+    (0xEB7, Decorations::empty()),
+    (0xEB8, Decorations::empty()),
+    (0xEB9, Decorations::empty()),
+    (0xEBA, Decorations::empty()),
+    (0xEBB, Decorations::empty()),
+    (0xEBC, Decorations::empty()),
+    (0xEC8, Decorations::empty()),
+    (0xEC9, Decorations::empty()),
+    (0xECA, Decorations::empty()),
+    (0xECB, Decorations::empty()),
+    (0xECC, Decorations::empty()),
+    (0xECD, Decorations::empty()),
+    (0xECE, Decorations::empty()),
+    (0xF18, Decorations::empty()),
+    (0xF19, Decorations::empty()),
+    (0xF35, Decorations::empty()),
+    (0xF37, Decorations::empty()),
+    (0xF39, Decorations::empty()),
+    (0xF71, Decorations::empty()),
+    (0xF72, Decorations::empty()),
+    (0xF73, Decorations::empty()),
+    (0xF74, Decorations::empty()),
+    (0xF75, Decorations::empty()),
+    (0xF76, Decorations::empty()),
+    (0xF77, Decorations::empty()),
+    (0xF78, Decorations::empty()),
+    (0xF79, Decorations::empty()),
+    (0xF7A, Decorations::empty()),
+    (0xF7B, Decorations::empty()),
+    (0xF7C, Decorations::empty()),
+    (0xF7D, Decorations::empty()),
+    (0xF7E, Decorations::empty()),
+    (0xF80, Decorations::REVERSED),
+    (0xF81, Decorations::REVERSED),
+    (0xF82, Decorations::empty()),
+    (0xF83, Decorations::empty()),
+    (0xF84, Decorations::empty()),
+    (0xF86, Decorations::empty()),
+    (0xF87, Decorations::empty()),
+    (0xF8D, Decorations::empty()),
+    (0xF8E, Decorations::empty()),
+    (0xF8F, Decorations::INVERTED),
+    (0xF90, Decorations::empty()),
+    (0xF91, Decorations::empty()),
+    (0xF92, Decorations::empty()),
+    (0xF93, Decorations::empty()),
+    (0xF94, Decorations::empty()),
+    (0xF95, Decorations::empty()),
+    (0xF96, Decorations::empty()),
+    (0xF97, Decorations::empty()),
+    (0xF99, Decorations::empty()),
+    (0xF9A, Decorations::empty()),
+    (0xF9B, Decorations::empty()),
+    (0xF9C, Decorations::empty()),
+    (0xF9D, Decorations::empty()),
+    (0xF9E, Decorations::empty()),
+    (0xF9F, Decorations::empty()),
+    (0xFA0, Decorations::empty()),
+    (0xFA1, Decorations::empty()),
+    (0xFA2, Decorations::empty()),
+    (0xFA3, Decorations::empty()),
+    (0xFA4, Decorations::empty()),
+    (0xFA5, Decorations::empty()),
+    (0xFA6, Decorations::empty()),
+    (0xFA7, Decorations::empty()),
+    (0xFA8, Decorations::empty()),
+    (0xFA9, Decorations::empty()),
+    (0xFAA, Decorations::empty()),
+    (0xFAB, Decorations::empty()),
+    (0xFAC, Decorations::empty()),
+    (0xFAD, Decorations::empty()),
+    (0xFAE, Decorations::empty()),
+    (0xFAF, Decorations::empty()),
+    (0xFB0, Decorations::empty()),
+    (0xFB1, Decorations::empty()),
+    (0xFB2, Decorations::empty()),
+    (0xFB3, Decorations::empty()),
+    (0xFB4, Decorations::empty()),
+    (0xFB5, Decorations::empty()),
+    (0xFB6, Decorations::empty()),
+    (0xFB7, Decorations::empty()),
+    (0xFB8, Decorations::empty()),
+    (0xFB9, Decorations::empty()),
+    (0xFBA, Decorations::empty()),
+    (0xFBB, Decorations::empty()),
+    (0xFBC, Decorations::empty()),
+    (0xFC6, Decorations::empty()),
+    (0x102D, Decorations::empty()),
+    (0x102E, Decorations::empty()),
+    (0x102F, Decorations::empty()),
+    (0x1030, Decorations::empty()),
+    (0x1032, Decorations::empty()),
+    (0x1033, Decorations::empty()),
+    (0x1034, Decorations::empty()),
+    (0x1035, Decorations::ABOVE),
+    (0x1036, Decorations::empty()),
+    (0x1037, Decorations::BELOW.union(Decorations::DOT)),
+    (0x1039, Decorations::empty()),
+    (0x103A, Decorations::empty()),
+    (0x103D, Decorations::empty()),
+    (0x103E, Decorations::empty()),
+    (0x1058, Decorations::empty()),
+    (0x1059, Decorations::empty()),
+    (0x105E, Decorations::empty()),
+    (0x105F, Decorations::empty()),
+    (0x1060, Decorations::empty()),
+    (0x1071, Decorations::empty()),
+    (0x1072, Decorations::empty()),
+    (0x1073, Decorations::empty()),
+    (0x1074, Decorations::empty()),
+    (0x1082, Decorations::empty()),
+    (0x1085, Decorations::ABOVE),
+    (0x1086, Decorations::empty()),
+    (0x108D, Decorations::empty()),
+    (0x109D, Decorations::empty()),
+    (0x135D, Decorations::empty()),
+    (0x135E, Decorations::empty()),
+    (0x135F, Decorations::empty()),
+    (0x1712, Decorations::empty()),
+    (0x1713, Decorations::empty()),
+    (0x1714, Decorations::empty()),
+    (0x1732, Decorations::empty()),
+    (0x1733, Decorations::empty()),
+    (0x1752, Decorations::empty()),
+    (0x1753, Decorations::empty()),
+    (0x1772, Decorations::empty()),
+    (0x1773, Decorations::empty()),
+    (0x17B4, Decorations::empty()),
+    (0x17B5, Decorations::empty()),
+    (0x17B7, Decorations::empty()),
+    (0x17B8, Decorations::empty()),
+    (0x17B9, Decorations::empty()),
+    (0x17BA, Decorations::empty()),
+    (0x17BB, Decorations::empty()),
+    (0x17BC, Decorations::empty()),
+    (0x17BD, Decorations::empty()),
+    (0x17C6, Decorations::empty()),
+    (0x17C9, Decorations::empty()),
+    (0x17CA, Decorations::empty()),
+    (0x17CB, Decorations::empty()),
+    (0x17CC, Decorations::empty()),
+    (0x17CD, Decorations::empty()),
+    (0x17CE, Decorations::empty()),
+    (0x17CF, Decorations::empty()),
+    (0x17D0, Decorations::empty()),
+    (0x17D1, Decorations::empty()),
+    (0x17D2, Decorations::empty()),
+    (0x17D3, Decorations::empty()),
+    (0x17DD, Decorations::empty()),
+    (0x180B, Decorations::empty()),
+    (0x180C, Decorations::empty()),
+    (0x180D, Decorations::empty()),
+    (0x180F, Decorations::empty()),
+    (0x1885, Decorations::empty()),
+    (0x1886, Decorations::empty()),
+    (0x18A9, Decorations::empty()),
+    (0x1920, Decorations::empty()),
+    (0x1921, Decorations::empty()),
+    (0x1922, Decorations::empty()),
+    (0x1927, Decorations::empty()),
+    (0x1928, Decorations::empty()),
+    (0x1932, Decorations::empty()),
+    (0x1939, Decorations::empty()),
+    (0x193A, Decorations::empty()),
+    (0x193B, Decorations::empty()),
+    (0x1A17, Decorations::empty()),
+    (0x1A18, Decorations::empty()),
+    (0x1A1B, Decorations::empty()),
+    (0x1A56, Decorations::empty()),
+    (0x1A58, Decorations::empty()),
+    (0x1A59, Decorations::empty()),
+    (0x1A5A, Decorations::LOW),
+    (0x1A5B, Decorations::LOW),
+    (0x1A5C, Decorations::empty()),
+    (0x1A5D, Decorations::empty()),
+    (0x1A5E, Decorations::empty()),
+    (0x1A60, Decorations::empty()),
+    (0x1A62, Decorations::empty()),
+    (0x1A65, Decorations::empty()),
+    (0x1A66, Decorations::empty()),
+    (0x1A67, Decorations::empty()),
+    (0x1A68, Decorations::empty()),
+    (0x1A69, Decorations::empty()),
+    (0x1A6A, Decorations::empty()),
+    (0x1A6B, Decorations::empty()),
+    (0x1A6C, Decorations::BELOW),
+    (0x1A73, Decorations::ABOVE),
+    (0x1A74, Decorations::empty()),
+    (0x1A75, Decorations::empty()),
+    (0x1A76, Decorations::empty()),
+    (0x1A77, Decorations::empty()),
+    (0x1A78, Decorations::empty()),
+    (0x1A79, Decorations::empty()),
+    (0x1A7A, Decorations::empty()),
+    (0x1A7B, Decorations::empty()),
+    (0x1A7C, Decorations::empty()),
+    (0x1A7F, Decorations::DOT),
+    (0x1AB0, Decorations::CIRCUMFLEX),
+    (0x1AB1, Decorations::empty()),
+    (0x1AB2, Decorations::empty()),
+    (0x1AB3, Decorations::empty()),
+    (0x1AB4, Decorations::DOT),
+    (0x1AB5, Decorations::BELOW),
+    (0x1AB6, Decorations::BELOW.union(Decorations::LINE)),
+    (0x1AB7, Decorations::BELOW.union(Decorations::OPEN)),
+    (0x1AB8, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::OPEN)),
+    (0x1AB9, Decorations::BELOW.union(Decorations::LIGHT_CENTRALIZATION).union(Decorations::STROKE)),
+    (0x1ABA, Decorations::BELOW.union(Decorations::STROKE)),
+    (0x1ABB, Decorations::ABOVE),
+    (0x1ABC, Decorations::ABOVE.union(Decorations::DOUBLE)),
+    (0x1ABD, Decorations::BELOW),
+    (0x1ABF, Decorations::BELOW),
+    (0x1AC0, Decorations::BELOW.union(Decorations::TURNED)),
+    (0x1AC1, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x1AC2, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x1AC3, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x1AC4, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x1AC5, Decorations::ABOVE),
+    (0x1AC6, Decorations::ABOVE),
+    (0x1AC7, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::INVERTED)),
+    (0x1AC8, Decorations::ABOVE),
+    (0x1AC9, Decorations::ABOVE.union(Decorations::DOUBLE)),
+    (0x1ACA, Decorations::BELOW.union(Decorations::DOUBLE)),
+    (0x1ACB, Decorations::ACUTE),
+    (0x1ACC, Decorations::INSULAR),
+    (0x1ACD, Decorations::INSULAR),
+    (0x1ACE, Decorations::INSULAR),
+    (0x1B00, Decorations::empty()),
+    (0x1B01, Decorations::empty()),
+    (0x1B02, Decorations::empty()),
+    (0x1B03, Decorations::empty()),
+    (0x1B34, Decorations::empty()),
+    (0x1B36, Decorations::empty()),
+    (0x1B37, Decorations::empty()),
+    (0x1B38, Decorations::empty()),
+    (0x1B39, Decorations::empty()),
+    (0x1B3A, Decorations::empty()),
+    (0x1B3C, Decorations::empty()),
+    (0x1B42, Decorations::empty()),
+    (0x1B6B, Decorations::empty()),
+    (0x1B6C, Decorations::empty()),
+    (0x1B6D, Decorations::empty()),
+    (0x1B6E, Decorations::empty()),
+    (0x1B6F, Decorations::empty()),
+    (0x1B70, Decorations::empty()),
+    (0x1B71, Decorations::empty()),
+    (0x1B72, Decorations::empty()),
+    (0x1B73, Decorations::empty()),
+    (0x1B80, Decorations::empty()),
+    (0x1B81, Decorations::empty()),
+    (0x1BA2, Decorations::empty()),
+    (0x1BA3, Decorations::empty()),
+    (0x1BA4, Decorations::empty()),
+    (0x1BA5, Decorations::empty()),
+    (0x1BA8, Decorations::empty()),
+    (0x1BA9, Decorations::empty()),
+    (0x1BAB, Decorations::empty()),
+    (0x1BAC, Decorations::empty()),
+    (0x1BAD, Decorations::empty()),
+    (0x1BE6, Decorations::empty()),
+    (0x1BE8, Decorations::empty()),
+    (0x1BE9, Decorations::empty()),
+    (0x1BED, Decorations::empty()),
+    (0x1BEF, Decorations::empty()),
+    (0x1BF0, Decorations::empty()),
+    (0x1BF1, Decorations::empty()),
+    (0x1C2C, Decorations::empty()),
+    (0x1C2D, Decorations::empty()),
+    (0x1C2E, Decorations::empty()),
+    (0x1C2F, Decorations::empty()),
+    (0x1C30, Decorations::empty()),
+    (0x1C31, Decorations::empty()),
+    (0x1C32, Decorations::empty()),
+    (0x1C33, Decorations::empty()),
+    (0x1C36, Decorations::empty()),
+    (0x1C37, Decorations::empty()),
+    (0x1CD0, Decorations::empty()),
+    (0x1CD1, Decorations::empty()),
+    (0x1CD2, Decorations::empty()),
+    (0x1CD4, Decorations::empty()),
+    (0x1CD5, Decorations::empty()),
+    (0x1CD6, Decorations::empty()),
+    (0x1CD7, Decorations::empty()),
+    (0x1CD8, Decorations::BELOW),
+    (0x1CD9, Decorations::empty()),
+    (0x1CDA, Decorations::DOUBLE),
+    (0x1CDB, Decorations::empty()),
+    (0x1CDC, Decorations::empty()),
+    (0x1CDD, Decorations::BELOW.union(Decorations::DOT)),
+    (0x1CDE, Decorations::BELOW),
+    (0x1CDF, Decorations::BELOW),
+    (0x1CE0, Decorations::empty()),
+    (0x1CE2, Decorations::empty()),
+    (0x1CE3, Decorations::empty()),
+    (0x1CE4, Decorations::REVERSED),
+    (0x1CE5, Decorations::empty()),
+    (0x1CE6, Decorations::REVERSED),
+    (0x1CE7, Decorations::TAIL),
+    (0x1CE8, Decorations::TAIL),
+    (0x1CED, Decorations::empty()),
+    (0x1CF4, Decorations::ABOVE),
+    (0x1CF8, Decorations::ABOVE.union(Decorations::RING)),
+    (0x1CF9, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::RING)),
+    (0x1DC0, Decorations::GRAVE),
+    (0x1DC1, Decorations::ACUTE),
+    (0x1DC2, Decorations::BELOW),
+    (0x1DC3, Decorations::empty()),
+    (0x1DC4, Decorations::empty()),
+    (0x1DC5, Decorations::empty()),
+    (0x1DC6, Decorations::empty()),
+    (0x1DC7, Decorations::empty()),
+    (0x1DC8, Decorations::empty()),
+    (0x1DC9, Decorations::empty()),
+    (0x1DCA, Decorations::BELOW),
+    (0x1DCB, Decorations::empty()),
+    (0x1DCC, Decorations::empty()),
+    (0x1DCD, Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::DOUBLE)),
+    (0x1DCE, Decorations::ABOVE.union(Decorations::OGONEK)),
+    (0x1DCF, Decorations::BELOW),
+    (0x1DD0, Decorations::BELOW),
+    (0x1DD1, Decorations::ABOVE),
+    (0x1DD2, Decorations::ABOVE),
+    (0x1DD3, Decorations::ABOVE.union(Decorations::FLATTENED).union(Decorations::OPEN)),
+    (0x1DD4, Decorations::empty()),
+    (0x1DD5, Decorations::empty()),
+    (0x1DD6, Decorations::empty()),
+    (0x1DD7, Decorations::CEDILLA),
+    (0x1DD8, Decorations::INSULAR),
+    (0x1DD9, Decorations::empty()),
+    (0x1DDA, Decorations::empty()),
+    (0x1DDB, Decorations::empty()),
+    (0x1DDC, Decorations::empty()),
+    (0x1DDD, Decorations::empty()),
+    (0x1DDE, Decorations::empty()),
+    (0x1DDF, Decorations::empty()),
+    (0x1DE0, Decorations::empty()),
+    (0x1DE1, Decorations::empty()),
+    (0x1DE2, Decorations::empty()),
+    (0x1DE3, Decorations::ROTUNDA),
+    (0x1DE4, Decorations::empty()),
+    (0x1DE5, Decorations::LONG),
+    (0x1DE6, Decorations::empty()),
+    (0x1DE7, Decorations::empty()),
+    (0x1DE8, Decorations::empty()),
+    (0x1DE9, Decorations::empty()),
+    (0x1DEA, Decorations::empty()),
+    (0x1DEB, Decorations::empty()),
+    (0x1DEC, Decorations::DOUBLE.union(Decorations::MIDDLE).union(Decorations::TILDE)),
+    (0x1DED, Decorations::LIGHT_CENTRALIZATION.union(Decorations::STROKE)),
+    (0x1DEE, Decorations::empty()),
+    (0x1DEF, Decorations::empty()),
+    (0x1DF0, Decorations::LIGHT_CENTRALIZATION.union(Decorations::STROKE)),
+    (0x1DF1, Decorations::empty()),
+    (0x1DF2, Decorations::DIAERESIS),
+    (0x1DF3, Decorations::DIAERESIS),
+    (0x1DF4, Decorations::DIAERESIS),
+    (0x1DF5, Decorations::ABOVE),
+    (0x1DF6, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x1DF7, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x1DF8, Decorations::ABOVE.union(Decorations::LEFT).union(Decorations::DOT)),
+    (0x1DF9, Decorations::BELOW.union(Decorations::INVERTED)),
+    (0x1DFA, Decorations::BELOW.union(Decorations::LEFT).union(Decorations::DOT)),
+    (0x1DFB, Decorations::empty()),
+    (0x1DFC, Decorations::BELOW.union(Decorations::BREVE).union(Decorations::DOUBLE).union(Decorations::INVERTED)),
+    (0x1DFD, Decorations::BELOW),
+    (0x1DFE, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x1DFF, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x20D0, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x20D1, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x20D2, Decorations::LONG.union(Decorations::OVERLAY).union(Decorations::LINE)),
+    (0x20D3, Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::LINE)),
+    (0x20D4, Decorations::ABOVE),
+    (0x20D5, Decorations::ABOVE),
+    (0x20D6, Decorations::ABOVE.union(Decorations::LEFT)),
+    (0x20D7, Decorations::ABOVE.union(Decorations::RIGHT)),
+    (0x20D8, Decorations::OVERLAY.union(Decorations::RING)),
+    (0x20D9, Decorations::OVERLAY.union(Decorations::RING)),
+    (0x20DA, Decorations::OVERLAY.union(Decorations::RING)),
+    (0x20DB, Decorations::ABOVE),
+    (0x20DC, Decorations::ABOVE),
+    (0x20E1, Decorations::ABOVE.union(Decorations::LEFT).union(Decorations::RIGHT)),
+    (0x20E5, Decorations::OVERLAY),
+    (0x20E6, Decorations::DOUBLE.union(Decorations::OVERLAY).union(Decorations::STROKE)),
+    (0x20E7, Decorations::empty()),
+    (0x20E8, Decorations::empty()),
+    (0x20E9, Decorations::ABOVE),
+    (0x20EA, Decorations::OVERLAY),
+    (0x20EB, Decorations::DOUBLE.union(Decorations::LONG).union(Decorations::OVERLAY)),
+    (0x20EC, Decorations::empty()),
+    (0x20ED, Decorations::empty()),
+    (0x20EE, Decorations::BELOW.union(Decorations::LEFT)),
+    (0x20EF, Decorations::BELOW.union(Decorations::RIGHT)),
+    (0x20F0, Decorations::ABOVE),
+    (0x2CEF, Decorations::ABOVE),
+    (0x2CF0, Decorations::empty()),
+    (0x2CF1, Decorations::LENIS),
+    (0x2D7F, Decorations::empty()),
+    (0x2DE0, Decorations::empty()),
+    (0x2DE1, Decorations::empty()),
+    (0x2DE2, Decorations::empty()),
+    (0x2DE3, Decorations::empty()),
+    (0x2DE4, Decorations::empty()),
+    (0x2DE5, Decorations::empty()),
+    (0x2DE6, Decorations::empty()),
+    (0x2DE7, Decorations::empty()),
+    (0x2DE8, Decorations::empty()),
+    (0x2DE9, Decorations::empty()),
+    (0x2DEA, Decorations::empty()),
+    (0x2DEB, Decorations::empty()),
+    (0x2DEC, Decorations::empty()),
+    (0x2DED, Decorations::empty()),
+    (0x2DEE, Decorations::empty()),
+    (0x2DEF, Decorations::empty()),
+    (0x2DF0, Decorations::empty()),
+    (0x2DF1, Decorations::empty()),
+    (0x2DF2, Decorations::empty()),
+    (0x2DF3, Decorations::empty()),
+    (0x2DF4, Decorations::empty()),
+    (0x2DF5, Decorations::empty()),
+    (0x2DF6, Decorations::empty()),
+    (0x2DF7, Decorations::empty()),
+    (0x2DF8, Decorations::empty()),
+    (0x2DF9, Decorations::empty()),
+    (0x2DFA, Decorations::empty()),
+    (0x2DFB, Decorations::empty()),
+    (0x2DFC, Decorations::IOTIFIED),
+    (0x2DFD, Decorations::empty()),
+    (0x2DFE, Decorations::empty()),
+    (0x2DFF, Decorations::IOTIFIED),
+    (0x302A, Decorations::empty()),
+    (0x302B, Decorations::empty()),
+    (0x302C, Decorations::empty()),
+    (0x302D, Decorations::empty()),
+    (0x3099, Decorations::empty()),
+    (0x309A, Decorations::empty()),
+    (0xA66F, Decorations::empty()),
+    (0xA674, Decorations::empty()),
+    (0xA675, Decorations::empty()),
+    (0xA676, Decorations::empty()),
+    (0xA677, Decorations::empty()),
+    (0xA678, Decorations::empty()),
+    (0xA679, Decorations::empty()),
+    (0xA67A, Decorations::empty()),
+    (0xA67B, Decorations::empty()),
+    (0xA67C, Decorations::empty()),
+    (0xA67D, Decorations::empty()),
+    (0xA69E, Decorations::empty()),
+    (0xA69F, Decorations::IOTIFIED),
+    (0xA6F0, Decorations::empty()),
+    (0xA6F1, Decorations::empty()),
+    (0xA802, Decorations::empty()),
+    (0xA806, Decorations::empty()),
+    (0xA80B, Decorations::empty()),
+    (0xA825, Decorations::empty()),
+    (0xA826, Decorations::empty()),
+    (0xA82C, Decorations::empty()),
+    (0xA8C4, Decorations::empty()),
+    (0xA8C5, Decorations::empty()),
+    (0xA8E0, Decorations::empty()),
+    (0xA8E1, Decorations::empty()),
+    (0xA8E2, Decorations::empty()),
+    (0xA8E3, Decorations::empty()),
+    (0xA8E4, Decorations::empty()),
+    (0xA8E5, Decorations::empty()),
+    (0xA8E6, Decorations::empty()),
+    (0xA8E7, Decorations::empty()),
+    (0xA8E8, Decorations::empty()),
+    (0xA8E9, Decorations::empty()),
+    (0xA8EA, Decorations::empty()),
+    (0xA8EB, Decorations::empty()),
+    (0xA8EC, Decorations::empty()),
+    (0xA8ED, Decorations::empty()),
+    (0xA8EE, Decorations::empty()),
+    (0xA8EF, Decorations::empty()),
+    (0xA8F0, Decorations::empty()),
+    (0xA8F1, Decorations::empty()),
+    (0xA8FF, Decorations::empty()),
+    (0xA926, Decorations::empty()),
+    (0xA927, Decorations::empty()),
+    (0xA928, Decorations::empty()),
+    (0xA929, Decorations::empty()),
+    (0xA92A, Decorations::empty()),
+    (0xA92B, Decorations::empty()),
+    (0xA92C, Decorations::empty()),
+    (0xA92D, Decorations::empty()),
+    (0xA947, Decorations::empty()),
+    (0xA948, Decorations::empty()),
+    (0xA949, Decorations::empty()),
+    (0xA94A, Decorations::empty()),
+    (0xA94B, Decorations::empty()),
+    (0xA94C, Decorations::empty()),
+    (0xA94D, Decorations::empty()),
+    (0xA94E, Decorations::empty()),
+    (0xA94F, Decorations::empty()),
+    (0xA950, Decorations::empty()),
+    (0xA951, Decorations::empty()),
+    (0xA980, Decorations::empty()),
+    (0xA981, Decorations::empty()),
+    (0xA982, Decorations::empty()),
+    (0xA9B3, Decorations::empty()),
+    (0xA9B6, Decorations::empty()),
+    (0xA9B7, Decorations::empty()),
+    (0xA9B8, Decorations::empty()),
+    (0xA9B9, Decorations::empty()),
+    (0xA9BC, Decorations::empty()),
+    (0xA9BD, Decorations::empty()),
+    (0xA9E5, Decorations::empty()),
+    (0xAA29, Decorations::empty()),
+    (0xAA2A, Decorations::empty()),
+    (0xAA2B, Decorations::empty()),
+    (0xAA2C, Decorations::empty()),
+    (0xAA2D, Decorations::empty()),
+    (0xAA2E, Decorations::empty()),
+    (0xAA31, Decorations::empty()),
+    (0xAA32, Decorations::empty()),
+    (0xAA35, Decorations::empty()),
+    (0xAA36, Decorations::empty()),
+    (0xAA43, Decorations::empty()),
+    (0xAA4C, Decorations::empty()),
+    (0xAA7C, Decorations::empty()),
+    (0xAAB0, Decorations::empty()),
+    (0xAAB2, Decorations::empty()),
+    (0xAAB3, Decorations::empty()),
+    (0xAAB4, Decorations::empty()),
+    (0xAAB7, Decorations::empty()),
+    (0xAAB8, Decorations::empty()),
+    (0xAABE, Decorations::empty()),
+    (0xAABF, Decorations::empty()),
+    (0xAAC1, Decorations::empty()),
+    (0xAAEC, Decorations::empty()),
+    (0xAAED, Decorations::empty()),
+    (0xAAF6, Decorations::empty()),
+    (0xABE5, Decorations::empty()),
+    (0xABE8, Decorations::empty()),
+    (0xABED, Decorations::empty()),
+    (0xFB1E, Decorations::empty()),
+    (0xFE00, Decorations::empty()),
+    (0xFE01, Decorations::empty()),
+    (0xFE02, Decorations::empty()),
+    (0xFE03, Decorations::empty()),
+    (0xFE04, Decorations::empty()),
+    (0xFE05, Decorations::empty()),
+    (0xFE06, Decorations::empty()),
+    (0xFE07, Decorations::empty()),
+    (0xFE08, Decorations::empty()),
+    (0xFE09, Decorations::empty()),
+    (0xFE0A, Decorations::empty()),
+    (0xFE0B, Decorations::empty()),
+    (0xFE0C, Decorations::empty()),
+    (0xFE0D, Decorations::empty()),
+    (0xFE0E, Decorations::empty()),
+    (0xFE0F, Decorations::empty()),
+    (0xFE20, Decorations::HALF.union(Decorations::LEFT)),
+    (0xFE21, Decorations::HALF.union(Decorations::RIGHT)),
+    (0xFE22, Decorations::DOUBLE.union(Decorations::HALF).union(Decorations::LEFT).union(Decorations::TILDE)),
+    (0xFE23, Decorations::DOUBLE.union(Decorations::HALF).union(Decorations::RIGHT).union(Decorations::TILDE)),
+    (0xFE24, Decorations::HALF.union(Decorations::LEFT).union(Decorations::MACRON)),
+    (0xFE25, Decorations::HALF.union(Decorations::MACRON).union(Decorations::RIGHT)),
+    (0xFE26, Decorations::MACRON),
+    (0xFE27, Decorations::BELOW.union(Decorations::HALF).union(Decorations::LEFT)),
+    (0xFE28, Decorations::BELOW.union(Decorations::HALF).union(Decorations::RIGHT)),
+    (0xFE29, Decorations::BELOW.union(Decorations::HALF).union(Decorations::LEFT).union(Decorations::TILDE)),
+    (0xFE2A, Decorations::BELOW.union(Decorations::HALF).union(Decorations::RIGHT).union(Decorations::TILDE)),
+    (0xFE2B, Decorations::BELOW.union(Decorations::HALF).union(Decorations::LEFT).union(Decorations::MACRON)),
+    (0xFE2C, Decorations::BELOW.union(Decorations::HALF).union(Decorations::MACRON).union(Decorations::RIGHT)),
+    (0xFE2D, Decorations::BELOW.union(Decorations::MACRON)),
+    (0xFE2E, Decorations::HALF.union(Decorations::LEFT)),
+    (0xFE2F, Decorations::HALF.union(Decorations::RIGHT)),
+    (0x101FD, Decorations::OBLIQUE.union(Decorations::STROKE)),
+    (0x102E0, Decorations::empty()),
+    (0x10376, Decorations::OLD),
+    (0x10377, Decorations::OLD),
+    (0x10378, Decorations::OLD),
+    (0x10379, Decorations::OLD),
+    (0x1037A, Decorations::OLD),
+    (0x10A01, Decorations::empty()),
+    (0x10A02, Decorations::empty()),
+    (0x10A03, Decorations::empty()),
+    (0x10A05, Decorations::empty()),
+    (0x10A06, Decorations::empty()),
+    (0x10A0C, Decorations::empty()),
+    (0x10A0D, Decorations::BELOW.union(Decorations::DOUBLE).union(Decorations::RING)),
+    (0x10A0E, Decorations::empty()),
+    (0x10A0F, Decorations::empty()),
+    (0x10A38, Decorations::ABOVE.union(Decorations::BAR)),
+    (0x10A39, Decorations::empty()),
+    (0x10A3A, Decorations::BELOW.union(Decorations::DOT)),
+    (0x10A3F, Decorations::empty()),
+    (0x10AE5, Decorations::ABOVE),
+    (0x10AE6, Decorations::BELOW),
+    (0x10D24, Decorations::empty()),
+    (0x10D25, Decorations::empty()),
+    (0x10D26, Decorations::empty()),
+    (0x10D27, Decorations::empty()),
+    (0x10D69, Decorations::empty()),
+    (0x10D6A, Decorations::empty()),
+    (0x10D6B, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x10D6C, Decorations::ABOVE.union(Decorations::DOUBLE).union(Decorations::DOT)),
+    (0x10D6D, Decorations::empty()),
+    (0x10EAB, Decorations::empty()),
+    (0x10EAC, Decorations::empty()),
+    (0x10EFC, Decorations::OVERLAY),
+    (0x10EFD, Decorations::LOW),
+    (0x10EFE, Decorations::LOW),
+    (0x10EFF, Decorations::LOW),
+    (0x10F46, Decorations::BELOW.union(Decorations::DOT)),
+    (0x10F47, Decorations::BELOW),
+    (0x10F48, Decorations::ABOVE.union(Decorations::DOT)),
+    (0x10F49, Decorations::ABOVE),
+    (0x10F4A, Decorations::ABOVE),
+    (0x10F4B, Decorations::BELOW),
+    (0x10F4C, Decorations::ABOVE.union(Decorations::HOOK)),
+    (0x10F4D, Decorations::BELOW.union(Decorations::HOOK)),
+    (0x10F4E, Decorations::BELOW.union(Decorations::HOOK).union(Decorations::LONG)),
+    (0x10F4F, Decorations::BELOW),
+    (0x10F50, Decorations::BELOW.union(Decorations::STROKE)),
+    (0x10F82, Decorations::ABOVE.union(Decorations::DOT).union(Decorations::OLD)),
+    (0x10F83, Decorations::BELOW.union(Decorations::DOT).union(Decorations::OLD)),
+    (0x10F84, Decorations::ABOVE.union(Decorations::OLD)),
+    (0x10F85, Decorations::BELOW.union(Decorations::OLD)),
+    (0x11001, Decorations::empty()),
+    (0x11038, Decorations::empty()),
+    (0x11039, Decorations::empty()),
+    (0x1103A, Decorations::empty()),
+    (0x1103B, Decorations::empty()),
+    (0x1103C, Decorations::empty()),
+    (0x1103D, Decorations::empty()),
+    (0x1103E, Decorations::empty()),
+    (0x1103F, Decorations::empty()),
+    (0x11040, Decorations::empty()),
+    (0x11041, Decorations::empty()),
+    (0x11042, Decorations::empty()),
+    (0x11043, Decorations::empty()),
+    (0x11044, Decorations::empty()),
+    (0x11045, Decorations::empty()),
+    (0x11046, Decorations::empty()),
+    (0x11070, Decorations::OLD),
+    (0x11073, Decorations::SHORT.union(Decorations::OLD)),
+    (0x11074, Decorations::SHORT.union(Decorations::OLD)),
+    (0x1107F, Decorations::empty()),
+    (0x11080, Decorations::empty()),
+    (0x11081, Decorations::empty()),
+    (0x110B3, Decorations::empty()),
+    (0x110B4, Decorations::empty()),
+    (0x110B5, Decorations::empty()),
+    (0x110B6, Decorations::empty()),
+    (0x110B9, Decorations::empty()),
+    (0x110BA, Decorations::empty()),
+    (0x110C2, Decorations::empty()),
+    (0x11100, Decorations::empty()),
+    (0x11101, Decorations::empty()),
+    (0x11102, Decorations::empty()),
+    (0x11127, Decorations::empty()),
+    (0x11128, Decorations::empty()),
+    (0x11129, Decorations::empty()),
+    (0x1112A, Decorations::empty()),
+    (0x1112B, Decorations::empty()),
+    (0x1112D, Decorations::empty()),
+    (0x1112E, Decorations::empty()),
+    (0x1112F, Decorations::empty()),
+    (0x11130, Decorations::empty()),
+    (0x11131, Decorations::empty()),
+    (0x11132, Decorations::empty()),
+    (0x11133, Decorations::empty()),
+    (0x11134, Decorations::empty()),
+    (0x11173, Decorations::empty()),
+    (0x11180, Decorations::empty()),
+    (0x11181, Decorations::empty()),
+    (0x111B6, Decorations::empty()),
+    (0x111B7, Decorations::empty()),
+    (0x111B8, Decorations::empty()),
+    (0x111B9, Decorations::empty()),
+    (0x111BA, Decorations::empty()),
+    (0x111BB, Decorations::empty()),
+    (0x111BC, Decorations::empty()),
+    (0x111BD, Decorations::empty()),
+    (0x111BE, Decorations::empty()),
+    (0x111C9, Decorations::empty()),
+    (0x111CA, Decorations::empty()),
+    (0x111CB, Decorations::empty()),
+    (0x111CC, Decorations::SHORT),
+    (0x111CF, Decorations::INVERTED),
+    (0x1122F, Decorations::empty()),
+    (0x11230, Decorations::empty()),
+    (0x11231, Decorations::empty()),
+    (0x11234, Decorations::empty()),
+    (0x11236, Decorations::empty()),
+    (0x11237, Decorations::empty()),
+    (0x1123E, Decorations::empty()),
+    (0x11241, Decorations::empty()),
+    (0x112DF, Decorations::empty()),
+    (0x112E3, Decorations::empty()),
+    (0x112E4, Decorations::empty()),
+    (0x112E5, Decorations::empty()),
+    (0x112E6, Decorations::empty()),
+    (0x112E7, Decorations::empty()),
+    (0x112E8, Decorations::empty()),
+    (0x112E9, Decorations::empty()),
+    (0x112EA, Decorations::empty()),
+    (0x11300, Decorations::ABOVE),
+    (0x11301, Decorations::empty()),
+    (0x1133B, Decorations::BELOW),
+    (0x1133C, Decorations::empty()),
+    (0x11340, Decorations::empty()),
+    (0x11366, Decorations::empty()),
+    (0x11367, Decorations::empty()),
+    (0x11368, Decorations::empty()),
+    (0x11369, Decorations::empty()),
+    (0x1136A, Decorations::empty()),
+    (0x1136B, Decorations::empty()),
+    (0x1136C, Decorations::empty()),
+    (0x11370, Decorations::empty()),
+    (0x11371, Decorations::empty()),
+    (0x11372, Decorations::empty()),
+    (0x11373, Decorations::empty()),
+    (0x11374, Decorations::empty()),
+    (0x113BB, Decorations::empty()),
+    (0x113BC, Decorations::empty()),
+    (0x113BD, Decorations::empty()),
+    (0x113BE, Decorations::empty()),
+    (0x113BF, Decorations::empty()),
+    (0x113C0, Decorations::empty()),
+    (0x113CE, Decorations::empty()),
+    (0x113D0, Decorations::empty()),
+    (0x113D2, Decorations::empty()),
+    (0x113E1, Decorations::empty()),
+    (0x113E2, Decorations::empty()),
+    (0x11438, Decorations::empty()),
+    (0x11439, Decorations::empty()),
+    (0x1143A, Decorations::empty()),
+    (0x1143B, Decorations::empty()),
+    (0x1143C, Decorations::empty()),
+    (0x1143D, Decorations::empty()),
+    (0x1143E, Decorations::empty()),
+    (0x1143F, Decorations::empty()),
+    (0x11442, Decorations::empty()),
+    (0x11443, Decorations::empty()),
+    (0x11444, Decorations::empty()),
+    (0x11446, Decorations::empty()),
+    (0x1145E, Decorations::empty()),
+    (0x114B3, Decorations::empty()),
+    (0x114B4, Decorations::empty()),
+    (0x114B5, Decorations::empty()),
+    (0x114B6, Decorations::empty()),
+    (0x114B7, Decorations::empty()),
+    (0x114B8, Decorations::empty()),
+    (0x114BA, Decorations::SHORT),
+    (0x114BF, Decorations::empty()),
+    (0x114C0, Decorations::empty()),
+    (0x114C2, Decorations::empty()),
+    (0x114C3, Decorations::empty()),
+    (0x115B2, Decorations::empty()),
+    (0x115B3, Decorations::empty()),
+    (0x115B4, Decorations::empty()),
+    (0x115B5, Decorations::empty()),
+    (0x115BC, Decorations::empty()),
+    (0x115BD, Decorations::empty()),
+    (0x115BF, Decorations::empty()),
+    (0x115C0, Decorations::empty()),
+    (0x115DC, Decorations::empty()),
+    (0x115DD, Decorations::empty()),
+    (0x11633, Decorations::empty()),
+    (0x11634, Decorations::empty()),
+    (0x11635, Decorations::empty()),
+    (0x11636, Decorations::empty()),
+    (0x11637, Decorations::empty()),
+    (0x11638, Decorations::empty()),
+    (0x11639, Decorations::empty()),
+    (0x1163A, Decorations::empty()),
+    (0x1163D, Decorations::empty()),
+    (0x1163F, Decorations::empty()),
+    (0x11640, Decorations::empty()),
+    (0x116AB, Decorations::empty()),
+    (0x116AD, Decorations::empty()),
+    (0x116B0, Decorations::empty()),
+    (0x116B1, Decorations::empty()),
+    (0x116B2, Decorations::empty()),
+    (0x116B3, Decorations::empty()),
+    (0x116B4, Decorations::empty()),
+    (0x116B5, Decorations::empty()),
+    (0x116B7, Decorations::empty()),
+    (0x1171D, Decorations::empty()),
+    (0x1171F, Decorations::empty()),
+    (0x11722, Decorations::empty()),
+    (0x11723, Decorations::empty()),
+    (0x11724, Decorations::empty()),
+    (0x11725, Decorations::empty()),
+    (0x11727, Decorations::empty()),
+    (0x11728, Decorations::empty()),
+    (0x11729, Decorations::empty()),
+    (0x1172A, Decorations::empty()),
+    (0x1172B, Decorations::empty()),
+    (0x1182F, Decorations::empty()),
+    (0x11830, Decorations::empty()),
+    (0x11831, Decorations::empty()),
+    (0x11832, Decorations::empty()),
+    (0x11833, Decorations::empty()),
+    (0x11834, Decorations::empty()),
+    (0x11835, Decorations::empty()),
+    (0x11836, Decorations::empty()),
+    (0x11837, Decorations::empty()),
+    (0x11839, Decorations::empty()),
+    (0x1183A, Decorations::empty()),
+    (0x1193B, Decorations::empty()),
+    (0x1193C, Decorations::empty()),
+    (0x1193E, Decorations::empty()),
+    (0x11943, Decorations::empty()),
+    (0x119D4, Decorations::empty()),
+    (0x119D5, Decorations::empty()),
+    (0x119D6, Decorations::empty()),
+    (0x119D7, Decorations::empty()),
+    (0x119DA, Decorations::empty()),
+    (0x119DB, Decorations::empty()),
+    (0x119E0, Decorations::empty()),
+    (0x11A01, Decorations::empty()),
+    (0x11A02, Decorations::empty()),
+    (0x11A03, Decorations::empty()),
+    (0x11A04, Decorations::empty()),
+    (0x11A05, Decorations::empty()),
+    (0x11A06, Decorations::empty()),
+    (0x11A07, Decorations::empty()),
+    (0x11A08, Decorations::empty()),
+    (0x11A09, Decorations::REVERSED),
+    (0x11A0A, Decorations::empty()),
+    (0x11A33, Decorations::empty()),
+    (0x11A34, Decorations::empty()),
+    (0x11A35, Decorations::empty()),
+    (0x11A36, Decorations::empty()),
+    (0x11A37, Decorations::empty()),
+    (0x11A38, Decorations::empty()),
+    (0x11A3B, Decorations::empty()),
+    (0x11A3C, Decorations::empty()),
+    (0x11A3D, Decorations::empty()),
+    (0x11A3E, Decorations::empty()),
+    (0x11A47, Decorations::empty()),
+    (0x11A51, Decorations::empty()),
+    (0x11A52, Decorations::empty()),
+    (0x11A53, Decorations::empty()),
+    (0x11A54, Decorations::empty()),
+    (0x11A55, Decorations::empty()),
+    (0x11A56, Decorations::empty()),
+    (0x11A59, Decorations::empty()),
+    (0x11A5A, Decorations::empty()),
+    (0x11A5B, Decorations::empty()),
+    (0x11A8A, Decorations::empty()),
+    (0x11A8B, Decorations::empty()),
+    (0x11A8C, Decorations::empty()),
+    (0x11A8D, Decorations::empty()),
+    (0x11A8E, Decorations::empty()),
+    (0x11A8F, Decorations::empty()),
+    (0x11A90, Decorations::empty()),
+    (0x11A91, Decorations::empty()),
+    (0x11A92, Decorations::empty()),
+    (0x11A93, Decorations::empty()),
+    (0x11A94, Decorations::empty()),
+    (0x11A95, Decorations::empty()),
+    (0x11A96, Decorations::empty()),
+    (0x11A98, Decorations::empty()),
+    (0x11A99, Decorations::empty()),
+    (0x11C30, Decorations::empty()),
+    (0x11C31, Decorations::empty()),
+    (0x11C32, Decorations::empty()),
+    (0x11C33, Decorations::empty()),
+    (0x11C34, Decorations::empty()),
+    (0x11C35, Decorations::empty()),
+    (0x11C36, Decorations::empty()),
+    (0x11C38, Decorations::empty()),
+    (0x11C39, Decorations::empty()),
+    (0x11C3A, Decorations::empty()),
+    (0x11C3B, Decorations::empty()),
+    (0x11C3C, Decorations::empty()),
+    (0x11C3D, Decorations::empty()),
+    (0x11C3F, Decorations::empty()),
+    (0x11C92, Decorations::empty()),
+    (0x11C93, Decorations::empty()),
+    (0x11C94, Decorations::empty()),
+    (0x11C95, Decorations::empty()),
+    (0x11C96, Decorations::empty()),
+    (0x11C97, Decorations::empty()),
+    (0x11C98, Decorations::empty()),
+    (0x11C99, Decorations::empty()),
+    (0x11C9A, Decorations::empty()),
+    (0x11C9B, Decorations::empty()),
+    (0x11C9C, Decorations::empty()),
+    (0x11C9D, Decorations::empty()),
+    (0x11C9E, Decorations::empty()),
+    (0x11C9F, Decorations::empty()),
+    (0x11CA0, Decorations::empty()),
+    (0x11CA1, Decorations::empty()),
+    (0x11CA2, Decorations::empty()),
+    (0x11CA3, Decorations::empty()),
+    (0x11CA4, Decorations::empty()),
+    (0x11CA5, Decorations::empty()),
+    (0x11CA6, Decorations::empty()),
+    (0x11CA7, Decorations::empty()),
+    (0x11CAA, Decorations::empty()),
+    (0x11CAB, Decorations::empty()),
+    (0x11CAC, Decorations::empty()),
+    (0x11CAD, Decorations::empty()),
+    (0x11CAE, Decorations::empty()),
+    (0x11CAF, Decorations::empty()),
+    (0x11CB0, Decorations::empty()),
+    (0x11CB2, Decorations::empty()),
+    (0x11CB3, Decorations::empty()),
+    (0x11CB5, Decorations::empty()),
+    (0x11CB6, Decorations::empty()),
+    (0x11D31, Decorations::empty()),
+    (0x11D32, Decorations::empty()),
+    (0x11D33, Decorations::empty()),
+    (0x11D34, Decorations::empty()),
+    (0x11D35, Decorations::empty()),
+    (0x11D36, Decorations::empty()),
+    (0x11D3A, Decorations::empty()),
+    (0x11D3C, Decorations::empty()),
+    (0x11D3D, Decorations::empty()),
+    (0x11D3F, Decorations::empty()),
+    (0x11D40, Decorations::empty()),
+    (0x11D41, Decorations::empty()),
+    (0x11D42, Decorations::empty()),
+    (0x11D43, Decorations::empty()),
+    (0x11D44, Decorations::empty()),
+    (0x11D45, Decorations::empty()),
+    (0x11D47, Decorations::empty()),
+    (0x11D90, Decorations::empty()),
+    (0x11D91, Decorations::empty()),
+    (0x11D95, Decorations::empty()),
+    (0x11D97, Decorations::empty()),
+    (0x11EF3, Decorations::empty()),
+    (0x11EF4, Decorations::empty()),
+    (0x11F00, Decorations::empty()),
+    (0x11F01, Decorations::empty()),
+    (0x11F36, Decorations::empty()),
+    (0x11F37, Decorations::empty()),
+    (0x11F38, Decorations::empty()),
+    (0x11F39, Decorations::empty()),
+    (0x11F3A, Decorations::empty()),
+    (0x11F40, Decorations::empty()),
+    (0x11F42, Decorations::empty()),
+    (0x11F5A, Decorations::empty()),
+    (0x13440, Decorations::empty()),
+    (0x13447, Decorations::TOP),
+    (0x13448, Decorations::BOTTOM),
+    (0x13449, Decorations::empty()),
+    (0x1344A, Decorations::TOP),
+    (0x1344B, Decorations::TOP),
+    (0x1344C, Decorations::BOTTOM.union(Decorations::TOP)),
+    (0x1344D, Decorations::TOP),
+    (0x1344E, Decorations::BOTTOM),
+    (0x1344F, Decorations::BOTTOM.union(Decorations::TOP)),
+    (0x13450, Decorations::BOTTOM),
+    (0x13451, Decorations::BOTTOM),
+    (0x13452, Decorations::empty()),
+    (0x13453, Decorations::TOP),
+    (0x13454, Decorations::BOTTOM),
+    (0x13455, Decorations::empty()),
+    (0x1611E, Decorations::empty()),
+    (0x1611F, Decorations::empty()),
+    (0x16120, Decorations::empty()),
+    (0x16121, Decorations::empty()),
+    (0x16122, Decorations::empty()),
+    (0x16123, Decorations::empty()),
+    (0x16124, Decorations::empty()),
+    (0x16125, Decorations::empty()),
+    (0x16126, Decorations::empty()),
+    (0x16127, Decorations::empty()),
+    (0x16128, Decorations::empty()),
+    (0x16129, Decorations::empty()),
+    (0x1612D, Decorations::empty()),
+    (0x1612E, Decorations::empty()),
+    (0x1612F, Decorations::empty()),
+    (0x16AF0, Decorations::empty()),
+    (0x16AF1, Decorations::LOW),
+    (0x16AF2, Decorations::empty()),
+    (0x16AF3, Decorations::empty()),
+    (0x16AF4, Decorations::empty()),
+    (0x16B30, Decorations::empty()),
+    (0x16B31, Decorations::empty()),
+    (0x16B32, Decorations::empty()),
+    (0x16B33, Decorations::empty()),
+    (0x16B34, Decorations::empty()),
+    (0x16B35, Decorations::empty()),
+    (0x16B36, Decorations::empty()),
+    (0x16F4F, Decorations::BAR),
+    (0x16F8F, Decorations::RIGHT),
+    (0x16F90, Decorations::RIGHT.union(Decorations::TOP)),
+    (0x16F91, Decorations::ABOVE),
+    (0x16F92, Decorations::BELOW),
+    (0x16FE4, Decorations::SCRIPT),
+    (0x1BC9D, Decorations::empty()),
+    (0x1BC9E, Decorations::DOUBLE),
+    (0x1CF00, Decorations::LEFT),
+    (0x1CF01, Decorations::LEFT),
+    (0x1CF02, Decorations::LEFT),
+    (0x1CF03, Decorations::LEFT),
+    (0x1CF04, Decorations::LEFT),
+    (0x1CF05, Decorations::LEFT),
+    (0x1CF06, Decorations::LEFT),
+    (0x1CF07, Decorations::LEFT),
+    (0x1CF08, Decorations::LEFT),
+    (0x1CF09, Decorations::LEFT),
+    (0x1CF0A, Decorations::LEFT),
+    (0x1CF0B, Decorations::LEFT),
+    (0x1CF0C, Decorations::RIGHT),
+    (0x1CF0D, Decorations::RIGHT),
+    (0x1CF0E, Decorations::RIGHT),
+    (0x1CF0F, Decorations::RIGHT),
+    (0x1CF10, Decorations::RIGHT),
+    (0x1CF11, Decorations::RIGHT),
+    (0x1CF12, Decorations::RIGHT),
+    (0x1CF13, Decorations::RIGHT),
+    (0x1CF14, Decorations::RIGHT),
+    (0x1CF15, Decorations::RIGHT),
+    (0x1CF16, Decorations::RIGHT),
+    (0x1CF17, Decorations::RIGHT),
+    (0x1CF18, Decorations::empty()),
+    (0x1CF19, Decorations::empty()),
+    (0x1CF1A, Decorations::empty()),
+    (0x1CF1B, Decorations::empty()),
+    (0x1CF1C, Decorations::empty()),
+    (0x1CF1D, Decorations::empty()),
+    (0x1CF1E, Decorations::empty()),
+    (0x1CF1F, Decorations::empty()),
+    (0x1CF20, Decorations::empty()),
+    (0x1CF21, Decorations::empty()),
+    (0x1CF22, Decorations::empty()),
+    (0x1CF23, Decorations::empty()),
+    (0x1CF24, Decorations::empty()),
+    (0x1CF25, Decorations::empty()),
+    (0x1CF26, Decorations::empty()),
+    (0x1CF27, Decorations::empty()),
+    (0x1CF28, Decorations::empty()),
+    (0x1CF29, Decorations::empty()),
+    (0x1CF2A, Decorations::empty()),
+    (0x1CF2B, Decorations::empty()),
+    (0x1CF2C, Decorations::empty()),
+    (0x1CF2D, Decorations::LEFT),
+    (0x1CF30, Decorations::empty()),
+    (0x1CF31, Decorations::empty()),
+    (0x1CF32, Decorations::empty()),
+    (0x1CF33, Decorations::empty()),
+    (0x1CF34, Decorations::empty()),
+    (0x1CF35, Decorations::empty()),
+    (0x1CF36, Decorations::empty()),
+    (0x1CF37, Decorations::STROKE),
+    (0x1CF38, Decorations::empty()),
+    (0x1CF39, Decorations::empty()),
+    (0x1CF3A, Decorations::empty()),
+    (0x1CF3B, Decorations::empty()),
+    (0x1CF3C, Decorations::empty()),
+    (0x1CF3D, Decorations::empty()),
+    (0x1CF3E, Decorations::empty()),
+    (0x1CF3F, Decorations::empty()),
+    (0x1CF40, Decorations::empty()),
+    (0x1CF41, Decorations::empty()),
+    (0x1CF42, Decorations::empty()),
+    (0x1CF43, Decorations::empty()),
+    (0x1CF44, Decorations::empty()),
+    (0x1CF45, Decorations::empty()),
+    (0x1CF46, Decorations::empty()),
+    (0x1D167, Decorations::empty()),
+    (0x1D168, Decorations::empty()),
+    (0x1D169, Decorations::empty()),
+    (0x1D17B, Decorations::empty()),
+    (0x1D17C, Decorations::empty()),
+    (0x1D17D, Decorations::empty()),
+    (0x1D17E, Decorations::empty()),
+    (0x1D17F, Decorations::empty()),
+    (0x1D180, Decorations::empty()),
+    (0x1D181, Decorations::empty()),
+    (0x1D182, Decorations::empty()),
+    (0x1D185, Decorations::empty()),
+    (0x1D186, Decorations::empty()),
+    (0x1D187, Decorations::empty()),
+    (0x1D188, Decorations::empty()),
+    (0x1D189, Decorations::empty()),
+    (0x1D18A, Decorations::DOUBLE),
+    (0x1D18B, Decorations::empty()),
+    (0x1D1AA, Decorations::empty()),
+    (0x1D1AB, Decorations::empty()),
+    (0x1D1AC, Decorations::empty()),
+    (0x1D1AD, Decorations::empty()),
+    (0x1D242, Decorations::empty()),
+    (0x1D243, Decorations::empty()),
+    (0x1D244, Decorations::empty()),
+    (0x1DA00, Decorations::empty()),
+    (0x1DA01, Decorations::empty()),
+    (0x1DA02, Decorations::empty()),
+    (0x1DA03, Decorations::empty()),
+    (0x1DA04, Decorations::empty()),
+    (0x1DA05, Decorations::empty()),
+    (0x1DA06, Decorations::empty()),
+    (0x1DA07, Decorations::empty()),
+    (0x1DA08, Decorations::empty()),
+    (0x1DA09, Decorations::empty()),
+    (0x1DA0A, Decorations::empty()),
+    (0x1DA0B, Decorations::empty()),
+    (0x1DA0C, Decorations::empty()),
+    (0x1DA0D, Decorations::empty()),
+    (0x1DA0E, Decorations::empty()),
+    (0x1DA0F, Decorations::empty()),
+    (0x1DA10, Decorations::empty()),
+    (0x1DA11, Decorations::empty()),
+    (0x1DA12, Decorations::empty()),
+    (0x1DA13, Decorations::empty()),
+    (0x1DA14, Decorations::OPEN),
+    (0x1DA15, Decorations::empty()),
+    (0x1DA16, Decorations::CLOSED),
+    (0x1DA17, Decorations::empty()),
+    (0x1DA18, Decorations::empty()),
+    (0x1DA19, Decorations::HALF.union(Decorations::OPEN)),
+    (0x1DA1A, Decorations::OPEN),
+    (0x1DA1B, Decorations::CLOSED.union(Decorations::HALF)),
+    (0x1DA1C, Decorations::empty()),
+    (0x1DA1D, Decorations::empty()),
+    (0x1DA1E, Decorations::empty()),
+    (0x1DA1F, Decorations::empty()),
+    (0x1DA20, Decorations::empty()),
+    (0x1DA21, Decorations::empty()),
+    (0x1DA22, Decorations::DOUBLE),
+    (0x1DA23, Decorations::empty()),
+    (0x1DA24, Decorations::empty()),
+    (0x1DA25, Decorations::DOUBLE),
+    (0x1DA26, Decorations::empty()),
+    (0x1DA27, Decorations::empty()),
+    (0x1DA28, Decorations::empty()),
+    (0x1DA29, Decorations::empty()),
+    (0x1DA2A, Decorations::empty()),
+    (0x1DA2B, Decorations::empty()),
+    (0x1DA2C, Decorations::empty()),
+    (0x1DA2D, Decorations::empty()),
+    (0x1DA2E, Decorations::MIDDLE),
+    (0x1DA2F, Decorations::LOW),
+    (0x1DA30, Decorations::empty()),
+    (0x1DA31, Decorations::empty()),
+    (0x1DA32, Decorations::empty()),
+    (0x1DA33, Decorations::empty()),
+    (0x1DA34, Decorations::empty()),
+    (0x1DA35, Decorations::empty()),
+    (0x1DA36, Decorations::empty()),
+    (0x1DA3B, Decorations::CLOSED),
+    (0x1DA3C, Decorations::CLOSED),
+    (0x1DA3D, Decorations::CLOSED),
+    (0x1DA3E, Decorations::empty()),
+    (0x1DA3F, Decorations::empty()),
+    (0x1DA40, Decorations::OPEN),
+    (0x1DA41, Decorations::empty()),
+    (0x1DA42, Decorations::empty()),
+    (0x1DA43, Decorations::OPEN),
+    (0x1DA44, Decorations::OPEN),
+    (0x1DA45, Decorations::OPEN),
+    (0x1DA46, Decorations::OPEN),
+    (0x1DA47, Decorations::OPEN),
+    (0x1DA48, Decorations::OPEN),
+    (0x1DA49, Decorations::OPEN),
+    (0x1DA4A, Decorations::OPEN),
+    (0x1DA4B, Decorations::OPEN),
+    (0x1DA4C, Decorations::OPEN),
+    (0x1DA4D, Decorations::empty()),
+    (0x1DA4E, Decorations::empty()),
+    (0x1DA4F, Decorations::empty()),
+    (0x1DA50, Decorations::empty()),
+    (0x1DA51, Decorations::empty()),
+    (0x1DA52, Decorations::empty()),
+    (0x1DA53, Decorations::empty()),
+    (0x1DA54, Decorations::empty()),
+    (0x1DA55, Decorations::empty()),
+    (0x1DA56, Decorations::empty()),
+    (0x1DA57, Decorations::empty()),
+    (0x1DA58, Decorations::DOUBLE),
+    (0x1DA59, Decorations::empty()),
+    (0x1DA5A, Decorations::empty()),
+    (0x1DA5B, Decorations::empty()),
+    (0x1DA5C, Decorations::INSIDE),
+    (0x1DA5D, Decorations::INSIDE),
+    (0x1DA5E, Decorations::empty()),
+    (0x1DA5F, Decorations::empty()),
+    (0x1DA60, Decorations::INSIDE),
+    (0x1DA61, Decorations::empty()),
+    (0x1DA62, Decorations::empty()),
+    (0x1DA63, Decorations::empty()),
+    (0x1DA64, Decorations::empty()),
+    (0x1DA65, Decorations::empty()),
+    (0x1DA66, Decorations::empty()),
+    (0x1DA67, Decorations::empty()),
+    (0x1DA68, Decorations::empty()),
+    (0x1DA69, Decorations::empty()),
+    (0x1DA6A, Decorations::empty()),
+    (0x1DA6B, Decorations::empty()),
+    (0x1DA6C, Decorations::empty()),
+    (0x1DA75, Decorations::empty()),
+    (0x1DA84, Decorations::empty()),
+    (0x1DA9B, Decorations::empty()),
+    (0x1DA9C, Decorations::empty()),
+    (0x1DA9D, Decorations::empty()),
+    (0x1DA9E, Decorations::empty()),
+    (0x1DA9F, Decorations::empty()),
+    (0x1DAA1, Decorations::empty()),
+    (0x1DAA2, Decorations::empty()),
+    (0x1DAA3, Decorations::empty()),
+    (0x1DAA4, Decorations::empty()),
+    (0x1DAA5, Decorations::empty()),
+    (0x1DAA6, Decorations::empty()),
+    (0x1DAA7, Decorations::empty()),
+    (0x1DAA8, Decorations::empty()),
+    (0x1DAA9, Decorations::empty()),
+    (0x1DAAA, Decorations::empty()),
+    (0x1DAAB, Decorations::empty()),
+    (0x1DAAC, Decorations::empty()),
+    (0x1DAAD, Decorations::empty()),
+    (0x1DAAE, Decorations::empty()),
+    (0x1DAAF, Decorations::empty()),
+    (0x1E000, Decorations::empty()),
+    (0x1E001, Decorations::empty()),
+    (0x1E002, Decorations::empty()),
+    (0x1E003, Decorations::empty()),
+    (0x1E004, Decorations::empty()),
+    (0x1E005, Decorations::empty()),
+    (0x1E006, Decorations::empty()),
+    (0x1E008, Decorations::empty()),
+    (0x1E009, Decorations::empty()),
+    (0x1E00A, Decorations::empty()),
+    (0x1E00B, Decorations::empty()),
+    (0x1E00C, Decorations::empty()),
+    (0x1E00D, Decorations::empty()),
+    (0x1E00E, Decorations::empty()),
+    (0x1E00F, Decorations::empty()),
+    (0x1E010, Decorations::empty()),
+    (0x1E011, Decorations::empty()),
+    (0x1E012, Decorations::empty()),
+    (0x1E013, Decorations::empty()),
+    (0x1E014, Decorations::empty()),
+    (0x1E015, Decorations::empty()),
+    (0x1E016, Decorations::empty()),
+    (0x1E017, Decorations::empty()),
+    (0x1E018, Decorations::empty()),
+    (0x1E01B, Decorations::empty()),
+    (0x1E01C, Decorations::empty()),
+    (0x1E01D, Decorations::empty()),
+    (0x1E01E, Decorations::empty()),
+    (0x1E01F, Decorations::empty()),
+    (0x1E020, Decorations::empty()),
+    (0x1E021, Decorations::empty()),
+    (0x1E023, Decorations::empty()),
+    (0x1E024, Decorations::empty()),
+    (0x1E026, Decorations::empty()),
+    (0x1E027, Decorations::empty()),
+    (0x1E028, Decorations::empty()),
+    (0x1E029, Decorations::empty()),
+    (0x1E02A, Decorations::empty()),
+    (0x1E08F, Decorations::empty()),
+    (0x1E130, Decorations::empty()),
+    (0x1E131, Decorations::empty()),
+    (0x1E132, Decorations::empty()),
+    (0x1E133, Decorations::empty()),
+    (0x1E134, Decorations::empty()),
+    (0x1E135, Decorations::empty()),
+    (0x1E136, Decorations::empty()),
+    (0x1E2AE, Decorations::empty()),
+    (0x1E2EC, Decorations::empty()),
+    (0x1E2ED, Decorations::empty()),
+    (0x1E2EE, Decorations::empty()),
+    (0x1E2EF, Decorations::empty()),
+    (0x1E4EC, Decorations::empty()),
+    (0x1E4ED, Decorations::empty()),
+    (0x1E4EE, Decorations::empty()),
+    (0x1E4EF, Decorations::empty()),
+    (0x1E5EE, Decorations::empty()),
+    (0x1E5EF, Decorations::empty()),
+    (0x1E8D0, Decorations::empty()),
+    (0x1E8D1, Decorations::empty()),
+    (0x1E8D2, Decorations::empty()),
+    (0x1E8D3, Decorations::empty()),
+    (0x1E8D4, Decorations::empty()),
+    (0x1E8D5, Decorations::empty()),
+    (0x1E8D6, Decorations::empty()),
+    (0x1E944, Decorations::empty()),
+    (0x1E945, Decorations::empty()),
+    (0x1E946, Decorations::empty()),
+    (0x1E947, Decorations::empty()),
+    (0x1E948, Decorations::empty()),
+    (0x1E949, Decorations::empty()),
+    (0x1E94A, Decorations::empty()),
+    (0xE0100, Decorations::empty()),
+    (0xE0101, Decorations::empty()),
+    (0xE0102, Decorations::empty()),
+    (0xE0103, Decorations::empty()),
+    (0xE0104, Decorations::empty()),
+    (0xE0105, Decorations::empty()),
+    (0xE0106, Decorations::empty()),
+    (0xE0107, Decorations::empty()),
+    (0xE0108, Decorations::empty()),
+    (0xE0109, Decorations::empty()),
+    (0xE010A, Decorations::empty()),
+    (0xE010B, Decorations::empty()),
+    (0xE010C, Decorations::empty()),
+    (0xE010D, Decorations::empty()),
+    (0xE010E, Decorations::empty()),
+    (0xE010F, Decorations::empty()),
+    (0xE0110, Decorations::empty()),
+    (0xE0111, Decorations::empty()),
+    (0xE0112, Decorations::empty()),
+    (0xE0113, Decorations::empty()),
+    (0xE0114, Decorations::empty()),
+    (0xE0115, Decorations::empty()),
+    (0xE0116, Decorations::empty()),
+    (0xE0117, Decorations::empty()),
+    (0xE0118, Decorations::empty()),
+    (0xE0119, Decorations::empty()),
+    (0xE011A, Decorations::empty()),
+    (0xE011B, Decorations::empty()),
+    (0xE011C, Decorations::empty()),
+    (0xE011D, Decorations::empty()),
+    (0xE011E, Decorations::empty()),
+    (0xE011F, Decorations::empty()),
+    (0xE0120, Decorations::empty()),
+    (0xE0121, Decorations::empty()),
+    (0xE0122, Decorations::empty()),
+    (0xE0123, Decorations::empty()),
+    (0xE0124, Decorations::empty()),
+    (0xE0125, Decorations::empty()),
+    (0xE0126, Decorations::empty()),
+    (0xE0127, Decorations::empty()),
+    (0xE0128, Decorations::empty()),
+    (0xE0129, Decorations::empty()),
+    (0xE012A, Decorations::empty()),
+    (0xE012B, Decorations::empty()),
+    (0xE012C, Decorations::empty()),
+    (0xE012D, Decorations::empty()),
+    (0xE012E, Decorations::empty()),
+    (0xE012F, Decorations::empty()),
+    (0xE0130, Decorations::empty()),
+    (0xE0131, Decorations::empty()),
+    (0xE0132, Decorations::empty()),
+    (0xE0133, Decorations::empty()),
+    (0xE0134, Decorations::empty()),
+    (0xE0135, Decorations::empty()),
+    (0xE0136, Decorations::empty()),
+    (0xE0137, Decorations::empty()),
+    (0xE0138, Decorations::empty()),
+    (0xE0139, Decorations::empty()),
+    (0xE013A, Decorations::empty()),
+    (0xE013B, Decorations::empty()),
+    (0xE013C, Decorations::empty()),
+    (0xE013D, Decorations::empty()),
+    (0xE013E, Decorations::empty()),
+    (0xE013F, Decorations::empty()),
+    (0xE0140, Decorations::empty()),
+    (0xE0141, Decorations::empty()),
+    (0xE0142, Decorations::empty()),
+    (0xE0143, Decorations::empty()),
+    (0xE0144, Decorations::empty()),
+    (0xE0145, Decorations::empty()),
+    (0xE0146, Decorations::empty()),
+    (0xE0147, Decorations::empty()),
+    (0xE0148, Decorations::empty()),
+    (0xE0149, Decorations::empty()),
+    (0xE014A, Decorations::empty()),
+    (0xE014B, Decorations::empty()),
+    (0xE014C, Decorations::empty()),
+    (0xE014D, Decorations::empty()),
+    (0xE014E, Decorations::empty()),
+    (0xE014F, Decorations::empty()),
+    (0xE0150, Decorations::empty()),
+    (0xE0151, Decorations::empty()),
+    (0xE0152, Decorations::empty()),
+    (0xE0153, Decorations::empty()),
+    (0xE0154, Decorations::empty()),
+    (0xE0155, Decorations::empty()),
+    (0xE0156, Decorations::empty()),
+    (0xE0157, Decorations::empty()),
+    (0xE0158, Decorations::empty()),
+    (0xE0159, Decorations::empty()),
+    (0xE015A, Decorations::empty()),
+    (0xE015B, Decorations::empty()),
+    (0xE015C, Decorations::empty()),
+    (0xE015D, Decorations::empty()),
+    (0xE015E, Decorations::empty()),
+    (0xE015F, Decorations::empty()),
+    (0xE0160, Decorations::empty()),
+    (0xE0161, Decorations::empty()),
+    (0xE0162, Decorations::empty()),
+    (0xE0163, Decorations::empty()),
+    (0xE0164, Decorations::empty()),
+    (0xE0165, Decorations::empty()),
+    (0xE0166, Decorations::empty()),
+    (0xE0167, Decorations::empty()),
+    (0xE0168, Decorations::empty()),
+    (0xE0169, Decorations::empty()),
+    (0xE016A, Decorations::empty()),
+    (0xE016B, Decorations::empty()),
+    (0xE016C, Decorations::empty()),
+    (0xE016D, Decorations::empty()),
+    (0xE016E, Decorations::empty()),
+    (0xE016F, Decorations::empty()),
+    (0xE0170, Decorations::empty()),
+    (0xE0171, Decorations::empty()),
+    (0xE0172, Decorations::empty()),
+    (0xE0173, Decorations::empty()),
+    (0xE0174, Decorations::empty()),
+    (0xE0175, Decorations::empty()),
+    (0xE0176, Decorations::empty()),
+    (0xE0177, Decorations::empty()),
+    (0xE0178, Decorations::empty()),
+    (0xE0179, Decorations::empty()),
+    (0xE017A, Decorations::empty()),
+    (0xE017B, Decorations::empty()),
+    (0xE017C, Decorations::empty()),
+    (0xE017D, Decorations::empty()),
+    (0xE017E, Decorations::empty()),
+    (0xE017F, Decorations::empty()),
+    (0xE0180, Decorations::empty()),
+    (0xE0181, Decorations::empty()),
+    (0xE0182, Decorations::empty()),
+    (0xE0183, Decorations::empty()),
+    (0xE0184, Decorations::empty()),
+    (0xE0185, Decorations::empty()),
+    (0xE0186, Decorations::empty()),
+    (0xE0187, Decorations::empty()),
+    (0xE0188, Decorations::empty()),
+    (0xE0189, Decorations::empty()),
+    (0xE018A, Decorations::empty()),
+    (0xE018B, Decorations::empty()),
+    (0xE018C, Decorations::empty()),
+    (0xE018D, Decorations::empty()),
+    (0xE018E, Decorations::empty()),
+    (0xE018F, Decorations::empty()),
+    (0xE0190, Decorations::empty()),
+    (0xE0191, Decorations::empty()),
+    (0xE0192, Decorations::empty()),
+    (0xE0193, Decorations::empty()),
+    (0xE0194, Decorations::empty()),
+    (0xE0195, Decorations::empty()),
+    (0xE0196, Decorations::empty()),
+    (0xE0197, Decorations::empty()),
+    (0xE0198, Decorations::empty()),
+    (0xE0199, Decorations::empty()),
+    (0xE019A, Decorations::empty()),
+    (0xE019B, Decorations::empty()),
+    (0xE019C, Decorations::empty()),
+    (0xE019D, Decorations::empty()),
+    (0xE019E, Decorations::empty()),
+    (0xE019F, Decorations::empty()),
+    (0xE01A0, Decorations::empty()),
+    (0xE01A1, Decorations::empty()),
+    (0xE01A2, Decorations::empty()),
+    (0xE01A3, Decorations::empty()),
+    (0xE01A4, Decorations::empty()),
+    (0xE01A5, Decorations::empty()),
+    (0xE01A6, Decorations::empty()),
+    (0xE01A7, Decorations::empty()),
+    (0xE01A8, Decorations::empty()),
+    (0xE01A9, Decorations::empty()),
+    (0xE01AA, Decorations::empty()),
+    (0xE01AB, Decorations::empty()),
+    (0xE01AC, Decorations::empty()),
+    (0xE01AD, Decorations::empty()),
+    (0xE01AE, Decorations::empty()),
+    (0xE01AF, Decorations::empty()),
+    (0xE01B0, Decorations::empty()),
+    (0xE01B1, Decorations::empty()),
+    (0xE01B2, Decorations::empty()),
+    (0xE01B3, Decorations::empty()),
+    (0xE01B4, Decorations::empty()),
+    (0xE01B5, Decorations::empty()),
+    (0xE01B6, Decorations::empty()),
+    (0xE01B7, Decorations::empty()),
+    (0xE01B8, Decorations::empty()),
+    (0xE01B9, Decorations::empty()),
+    (0xE01BA, Decorations::empty()),
+    (0xE01BB, Decorations::empty()),
+    (0xE01BC, Decorations::empty()),
+    (0xE01BD, Decorations::empty()),
+    (0xE01BE, Decorations::empty()),
+    (0xE01BF, Decorations::empty()),
+    (0xE01C0, Decorations::empty()),
+    (0xE01C1, Decorations::empty()),
+    (0xE01C2, Decorations::empty()),
+    (0xE01C3, Decorations::empty()),
+    (0xE01C4, Decorations::empty()),
+    (0xE01C5, Decorations::empty()),
+    (0xE01C6, Decorations::empty()),
+    (0xE01C7, Decorations::empty()),
+    (0xE01C8, Decorations::empty()),
+    (0xE01C9, Decorations::empty()),
+    (0xE01CA, Decorations::empty()),
+    (0xE01CB, Decorations::empty()),
+    (0xE01CC, Decorations::empty()),
+    (0xE01CD, Decorations::empty()),
+    (0xE01CE, Decorations::empty()),
+    (0xE01CF, Decorations::empty()),
+    (0xE01D0, Decorations::empty()),
+    (0xE01D1, Decorations::empty()),
+    (0xE01D2, Decorations::empty()),
+    (0xE01D3, Decorations::empty()),
+    (0xE01D4, Decorations::empty()),
+    (0xE01D5, Decorations::empty()),
+    (0xE01D6, Decorations::empty()),
+    (0xE01D7, Decorations::empty()),
+    (0xE01D8, Decorations::empty()),
+    (0xE01D9, Decorations::empty()),
+    (0xE01DA, Decorations::empty()),
+    (0xE01DB, Decorations::empty()),
+    (0xE01DC, Decorations::empty()),
+    (0xE01DD, Decorations::empty()),
+    (0xE01DE, Decorations::empty()),
+    (0xE01DF, Decorations::empty()),
+    (0xE01E0, Decorations::empty()),
+    (0xE01E1, Decorations::empty()),
+    (0xE01E2, Decorations::empty()),
+    (0xE01E3, Decorations::empty()),
+    (0xE01E4, Decorations::empty()),
+    (0xE01E5, Decorations::empty()),
+    (0xE01E6, Decorations::empty()),
+    (0xE01E7, Decorations::empty()),
+    (0xE01E8, Decorations::empty()),
+    (0xE01E9, Decorations::empty()),
+    (0xE01EA, Decorations::empty()),
+    (0xE01EB, Decorations::empty()),
+    (0xE01EC, Decorations::empty()),
+    (0xE01ED, Decorations::empty()),
+    (0xE01EE, Decorations::empty()),
+    (0xE01EF, Decorations::empty()),
+];
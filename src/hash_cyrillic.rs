@@ -0,0 +1,193 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static CYRILLIC: &[(u32, CyrillicLetter)] = &[
+    (0x400, (Property::CapitalWithDecoration, Decorations::GRAVE, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x401, (Property::Capital, Decorations::empty(), [Letter::Letter('E'), Letter::Letters('Y', 'O'), Letter::Letters('Y', 'E')])),
+    (0x402, (Property::Capital, Decorations::empty(), [Letter::Letter('D'), Letter::Letters('D', 'J'), Letter::Letters('D', 'J')])),
+    (0x403, (Property::Capital, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x404, (Property::Capital, Decorations::empty(), [Letter::Letters('I', 'E'), Letter::Letters('Y', 'E'), Letter::Letters('Y', 'E')])),
+    (0x405, (Property::Capital, Decorations::empty(), [Letter::Letters('D', 'Z'), Letter::Letter('Z'), Letter::Letters('D', 'Z')])),
+    (0x406, (Property::Capital, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x407, (Property::Capital, Decorations::empty(), [Letter::Letter('I'), Letter::Letters('Y', 'I'), Letter::Letters('Y', 'I')])),
+    (0x408, (Property::Capital, Decorations::empty(), [Letter::Letter('J'), Letter::Letter('J'), Letter::Letter('J')])),
+    (0x409, (Property::Capital, Decorations::empty(), [Letter::Letters('L', 'J'), Letter::Letter('L'), Letter::Letters('L', 'J')])),
+    (0x40A, (Property::Capital, Decorations::empty(), [Letter::Letters('N', 'J'), Letter::Letter('N'), Letter::Letters('N', 'J')])),
+    (0x40B, (Property::Capital, Decorations::empty(), [Letter::Letter('C'), Letter::Letter('C'), Letter::Letter('C')])),
+    (0x40C, (Property::Capital, Decorations::empty(), [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x40D, (Property::CapitalWithDecoration, Decorations::GRAVE, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x40E, (Property::Capital, Decorations::empty(), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('W')])),
+    (0x40F, (Property::Capital, Decorations::empty(), [Letter::Letters('D', 'Z'), Letter::Letters('D', 'H'), Letter::Letters('D', 'Z')])),
+    (0x410, (Property::Capital, Decorations::empty(), [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x411, (Property::Capital, Decorations::empty(), [Letter::Letter('B'), Letter::Letter('B'), Letter::Letter('B')])),
+    (0x412, (Property::Capital, Decorations::empty(), [Letter::Letter('V'), Letter::Letter('V'), Letter::Letter('V')])),
+    (0x413, (Property::Capital, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x414, (Property::Capital, Decorations::empty(), [Letter::Letter('D'), Letter::Letter('D'), Letter::Letter('D')])),
+    (0x415, (Property::Capital, Decorations::empty(), [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x416, (Property::Capital, Decorations::empty(), [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x417, (Property::Capital, Decorations::empty(), [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x418, (Property::Capital, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x419, (Property::Capital, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('J'), Letter::Letter('Y')])),
+    (0x41A, (Property::Capital, Decorations::empty(), [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x41B, (Property::Capital, Decorations::empty(), [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x41C, (Property::Capital, Decorations::empty(), [Letter::Letter('M'), Letter::Letter('M'), Letter::Letter('M')])),
+    (0x41D, (Property::Capital, Decorations::empty(), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x41E, (Property::Capital, Decorations::empty(), [Letter::Letter('O'), Letter::Letter('O'), Letter::Letter('O')])),
+    (0x41F, (Property::Capital, Decorations::empty(), [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x420, (Property::Capital, Decorations::empty(), [Letter::Letter('R'), Letter::Letter('R'), Letter::Letter('R')])),
+    (0x421, (Property::Capital, Decorations::empty(), [Letter::Letter('S'), Letter::Letter('S'), Letter::Letter('S')])),
+    (0x422, (Property::Capital, Decorations::empty(), [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+    (0x423, (Property::Capital, Decorations::empty(), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x424, (Property::Capital, Decorations::empty(), [Letter::Letter('F'), Letter::Letter('F'), Letter::Letter('F')])),
+    (0x425, (Property::Capital, Decorations::empty(), [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x426, (Property::Capital, Decorations::empty(), [Letter::Letters('T', 'S'), Letter::Letters('C', 'Z'), Letter::Letters('T', 'S')])),
+    (0x427, (Property::Capital, Decorations::empty(), [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x428, (Property::Capital, Decorations::empty(), [Letter::Letters('S', 'H'), Letter::Letters('S', 'H'), Letter::Letters('S', 'H')])),
+    (0x429, (Property::Capital, Decorations::empty(), [Letter::Sequence("SHCH"), Letter::Sequence("SHH"), Letter::Sequence("SHCH")])),
+    (0x42A, (Property::Capital, Decorations::empty(), [Letter::Letters('I', 'E'), Letter::Sequence(""), Letter::Sequence("")])),
+    (0x42B, (Property::Capital, Decorations::empty(), [Letter::Letter('Y'), Letter::Letter('Y'), Letter::Letter('Y')])),
+    (0x42C, (Property::Capital, Decorations::empty(), [Letter::Sequence(""), Letter::Sequence(""), Letter::Sequence("")])),
+    (0x42D, (Property::Capital, Decorations::empty(), [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x42E, (Property::Capital, Decorations::empty(), [Letter::Letters('I', 'U'), Letter::Letters('Y', 'U'), Letter::Letters('Y', 'U')])),
+    (0x42F, (Property::Capital, Decorations::empty(), [Letter::Letters('I', 'A'), Letter::Letters('Y', 'A'), Letter::Letters('Y', 'A')])),
+    (0x430, (Property::Small, Decorations::empty(), [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x431, (Property::Small, Decorations::empty(), [Letter::Letter('B'), Letter::Letter('B'), Letter::Letter('B')])),
+    (0x432, (Property::Small, Decorations::empty(), [Letter::Letter('V'), Letter::Letter('V'), Letter::Letter('V')])),
+    (0x433, (Property::Small, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x434, (Property::Small, Decorations::empty(), [Letter::Letter('D'), Letter::Letter('D'), Letter::Letter('D')])),
+    (0x435, (Property::Small, Decorations::empty(), [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x436, (Property::Small, Decorations::empty(), [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x437, (Property::Small, Decorations::empty(), [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x438, (Property::Small, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x439, (Property::Small, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('J'), Letter::Letter('Y')])),
+    (0x43A, (Property::Small, Decorations::empty(), [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x43B, (Property::Small, Decorations::empty(), [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x43C, (Property::Small, Decorations::empty(), [Letter::Letter('M'), Letter::Letter('M'), Letter::Letter('M')])),
+    (0x43D, (Property::Small, Decorations::empty(), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x43E, (Property::Small, Decorations::empty(), [Letter::Letter('O'), Letter::Letter('O'), Letter::Letter('O')])),
+    (0x43F, (Property::Small, Decorations::empty(), [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x440, (Property::Small, Decorations::empty(), [Letter::Letter('R'), Letter::Letter('R'), Letter::Letter('R')])),
+    (0x441, (Property::Small, Decorations::empty(), [Letter::Letter('S'), Letter::Letter('S'), Letter::Letter('S')])),
+    (0x442, (Property::Small, Decorations::empty(), [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+    (0x443, (Property::Small, Decorations::empty(), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x444, (Property::Small, Decorations::empty(), [Letter::Letter('F'), Letter::Letter('F'), Letter::Letter('F')])),
+    (0x445, (Property::Small, Decorations::empty(), [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x446, (Property::Small, Decorations::empty(), [Letter::Letters('T', 'S'), Letter::Letters('C', 'Z'), Letter::Letters('T', 'S')])),
+    (0x447, (Property::Small, Decorations::empty(), [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x448, (Property::Small, Decorations::empty(), [Letter::Letters('S', 'H'), Letter::Letters('S', 'H'), Letter::Letters('S', 'H')])),
+    (0x449, (Property::Small, Decorations::empty(), [Letter::Sequence("SHCH"), Letter::Sequence("SHH"), Letter::Sequence("SHCH")])),
+    (0x44A, (Property::Small, Decorations::empty(), [Letter::Letters('I', 'E'), Letter::Sequence(""), Letter::Sequence("")])),
+    (0x44B, (Property::Small, Decorations::empty(), [Letter::Letter('Y'), Letter::Letter('Y'), Letter::Letter('Y')])),
+    (0x44C, (Property::Small, Decorations::empty(), [Letter::Sequence(""), Letter::Sequence(""), Letter::Sequence("")])),
+    (0x44D, (Property::Small, Decorations::empty(), [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x44E, (Property::Small, Decorations::empty(), [Letter::Letters('I', 'U'), Letter::Letters('Y', 'U'), Letter::Letters('Y', 'U')])),
+    (0x44F, (Property::Small, Decorations::empty(), [Letter::Letters('I', 'A'), Letter::Letters('Y', 'A'), Letter::Letters('Y', 'A')])),
+    (0x450, (Property::SmallWithDecoration, Decorations::GRAVE, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x451, (Property::Small, Decorations::empty(), [Letter::Letter('E'), Letter::Letters('Y', 'O'), Letter::Letters('Y', 'E')])),
+    (0x452, (Property::Small, Decorations::empty(), [Letter::Letter('D'), Letter::Letters('D', 'J'), Letter::Letters('D', 'J')])),
+    (0x453, (Property::Small, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x454, (Property::Small, Decorations::empty(), [Letter::Letters('I', 'E'), Letter::Letters('Y', 'E'), Letter::Letters('Y', 'E')])),
+    (0x455, (Property::Small, Decorations::empty(), [Letter::Letters('D', 'Z'), Letter::Letter('Z'), Letter::Letters('D', 'Z')])),
+    (0x456, (Property::Small, Decorations::empty(), [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x457, (Property::Small, Decorations::empty(), [Letter::Letter('I'), Letter::Letters('Y', 'I'), Letter::Letters('Y', 'I')])),
+    (0x458, (Property::Small, Decorations::empty(), [Letter::Letter('J'), Letter::Letter('J'), Letter::Letter('J')])),
+    (0x459, (Property::Small, Decorations::empty(), [Letter::Letters('L', 'J'), Letter::Letter('L'), Letter::Letters('L', 'J')])),
+    (0x45A, (Property::Small, Decorations::empty(), [Letter::Letters('N', 'J'), Letter::Letter('N'), Letter::Letters('N', 'J')])),
+    (0x45B, (Property::Small, Decorations::empty(), [Letter::Letter('C'), Letter::Letter('C'), Letter::Letter('C')])),
+    (0x45C, (Property::Small, Decorations::empty(), [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x45D, (Property::SmallWithDecoration, Decorations::GRAVE, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x45E, (Property::Small, Decorations::empty(), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('W')])),
+    (0x45F, (Property::Small, Decorations::empty(), [Letter::Letters('D', 'Z'), Letter::Letters('D', 'H'), Letter::Letters('D', 'Z')])),
+    (0x48A, (Property::CapitalWithDecoration, Decorations::TAIL, [Letter::Letter('I'), Letter::Letter('J'), Letter::Letter('Y')])),
+    (0x48B, (Property::SmallWithDecoration, Decorations::TAIL, [Letter::Letter('I'), Letter::Letter('J'), Letter::Letter('Y')])),
+    (0x490, (Property::Capital, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x491, (Property::Small, Decorations::empty(), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x492, (Property::CapitalWithDecoration, Decorations::STROKE, [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x493, (Property::SmallWithDecoration, Decorations::STROKE, [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x494, (Property::CapitalWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x495, (Property::SmallWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x496, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x497, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x498, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x499, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x49A, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x49B, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x49E, (Property::CapitalWithDecoration, Decorations::STROKE, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x49F, (Property::SmallWithDecoration, Decorations::STROKE, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x4A2, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4A3, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4A6, (Property::CapitalWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x4A7, (Property::SmallWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x4AA, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('S'), Letter::Letter('S'), Letter::Letter('S')])),
+    (0x4AB, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('S'), Letter::Letter('S'), Letter::Letter('S')])),
+    (0x4AC, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+    (0x4AD, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+    (0x4B2, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x4B3, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x4B6, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x4B7, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x4C1, (Property::CapitalWithDecoration, Decorations::BREVE, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x4C2, (Property::SmallWithDecoration, Decorations::BREVE, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x4C3, (Property::CapitalWithDecoration, Decorations::HOOK, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x4C4, (Property::SmallWithDecoration, Decorations::HOOK, [Letter::Letter('K'), Letter::Letter('K'), Letter::Letter('K')])),
+    (0x4C5, (Property::CapitalWithDecoration, Decorations::TAIL, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x4C6, (Property::SmallWithDecoration, Decorations::TAIL, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x4C7, (Property::CapitalWithDecoration, Decorations::HOOK, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4C8, (Property::SmallWithDecoration, Decorations::HOOK, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4C9, (Property::CapitalWithDecoration, Decorations::TAIL, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4CA, (Property::SmallWithDecoration, Decorations::TAIL, [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x4CD, (Property::CapitalWithDecoration, Decorations::TAIL, [Letter::Letter('M'), Letter::Letter('M'), Letter::Letter('M')])),
+    (0x4CE, (Property::SmallWithDecoration, Decorations::TAIL, [Letter::Letter('M'), Letter::Letter('M'), Letter::Letter('M')])),
+    (0x4D0, (Property::CapitalWithDecoration, Decorations::BREVE, [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x4D1, (Property::SmallWithDecoration, Decorations::BREVE, [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x4D2, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x4D3, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('A'), Letter::Letter('A'), Letter::Letter('A')])),
+    (0x4D6, (Property::CapitalWithDecoration, Decorations::BREVE, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x4D7, (Property::SmallWithDecoration, Decorations::BREVE, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x4DC, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x4DD, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H'), Letter::Letters('Z', 'H')])),
+    (0x4DE, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x4DF, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('Z'), Letter::Letter('Z'), Letter::Letter('Z')])),
+    (0x4E2, (Property::CapitalWithDecoration, Decorations::MACRON, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x4E3, (Property::SmallWithDecoration, Decorations::MACRON, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x4E4, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x4E5, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('I'), Letter::Letter('I'), Letter::Letter('I')])),
+    (0x4E6, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('O'), Letter::Letter('O'), Letter::Letter('O')])),
+    (0x4E7, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('O'), Letter::Letter('O'), Letter::Letter('O')])),
+    (0x4EC, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x4ED, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('E'), Letter::Letter('E'), Letter::Letter('E')])),
+    (0x4EE, (Property::CapitalWithDecoration, Decorations::MACRON, [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4EF, (Property::SmallWithDecoration, Decorations::MACRON, [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4F0, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4F1, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4F2, (Property::CapitalWithDecoration, Decorations::DOUBLE.union(Decorations::ACUTE), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4F3, (Property::SmallWithDecoration, Decorations::DOUBLE.union(Decorations::ACUTE), [Letter::Letter('U'), Letter::Letter('U'), Letter::Letter('U')])),
+    (0x4F4, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x4F5, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letters('C', 'H'), Letter::Letters('C', 'H'), Letter::Letters('C', 'H')])),
+    (0x4F6, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x4F7, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x4F8, (Property::CapitalWithDecoration, Decorations::DIAERESIS, [Letter::Letter('Y'), Letter::Letter('Y'), Letter::Letter('Y')])),
+    (0x4F9, (Property::SmallWithDecoration, Decorations::DIAERESIS, [Letter::Letter('Y'), Letter::Letter('Y'), Letter::Letter('Y')])),
+    (0x4FA, (Property::CapitalWithDecoration, Decorations::STROKE.union(Decorations::HOOK), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x4FB, (Property::SmallWithDecoration, Decorations::STROKE.union(Decorations::HOOK), [Letter::Letter('G'), Letter::Letter('G'), Letter::Letter('G')])),
+    (0x4FC, (Property::CapitalWithDecoration, Decorations::HOOK, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x4FD, (Property::SmallWithDecoration, Decorations::HOOK, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x4FE, (Property::CapitalWithDecoration, Decorations::STROKE, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x4FF, (Property::SmallWithDecoration, Decorations::STROKE, [Letter::Letters('K', 'H'), Letter::Letter('X'), Letter::Letters('K', 'H')])),
+    (0x512, (Property::CapitalWithDecoration, Decorations::HOOK, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x513, (Property::SmallWithDecoration, Decorations::HOOK, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x520, (Property::CapitalWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x521, (Property::SmallWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x522, (Property::CapitalWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x523, (Property::SmallWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x524, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x525, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('P'), Letter::Letter('P'), Letter::Letter('P')])),
+    (0x528, (Property::CapitalWithDecoration, Decorations::LEFT.union(Decorations::HOOK), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x529, (Property::SmallWithDecoration, Decorations::LEFT.union(Decorations::HOOK), [Letter::Letter('N'), Letter::Letter('N'), Letter::Letter('N')])),
+    (0x52E, (Property::CapitalWithDecoration, Decorations::DESCENDER, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x52F, (Property::SmallWithDecoration, Decorations::DESCENDER, [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0x1D2B, (Property::SmallAndCapital, Decorations::empty(), [Letter::Letter('L'), Letter::Letter('L'), Letter::Letter('L')])),
+    (0xA68A, (Property::CapitalWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+    (0xA68B, (Property::SmallWithDecoration, Decorations::MIDDLE.union(Decorations::HOOK), [Letter::Letter('T'), Letter::Letter('T'), Letter::Letter('T')])),
+];
@@ -0,0 +1,894 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static DIGITS: &[(u32, char)] = &[
+    (0x30, '0'),
+    (0x31, '1'),
+    (0x32, '2'),
+    (0x33, '3'),
+    (0x34, '4'),
+    (0x35, '5'),
+    (0x36, '6'),
+    (0x37, '7'),
+    (0x38, '8'),
+    (0x39, '9'),
+    (0xB2, '2'),
+    (0xB3, '3'),
+    (0xB9, '1'),
+    (0x660, '0'),
+    (0x661, '1'),
+    (0x662, '2'),
+    (0x663, '3'),
+    (0x664, '4'),
+    (0x665, '5'),
+    (0x666, '6'),
+    (0x667, '7'),
+    (0x668, '8'),
+    (0x669, '9'),
+    (0x6F0, '0'),
+    (0x6F1, '1'),
+    (0x6F2, '2'),
+    (0x6F3, '3'),
+    (0x6F4, '4'),
+    (0x6F5, '5'),
+    (0x6F6, '6'),
+    (0x6F7, '7'),
+    (0x6F8, '8'),
+    (0x6F9, '9'),
+    (0x7C0, '0'),
+    (0x7C1, '1'),
+    (0x7C2, '2'),
+    (0x7C3, '3'),
+    (0x7C4, '4'),
+    (0x7C5, '5'),
+    (0x7C6, '6'),
+    (0x7C7, '7'),
+    (0x7C8, '8'),
+    (0x7C9, '9'),
+    (0x966, '0'),
+    (0x967, '1'),
+    (0x968, '2'),
+    (0x969, '3'),
+    (0x96A, '4'),
+    (0x96B, '5'),
+    (0x96C, '6'),
+    (0x96D, '7'),
+    (0x96E, '8'),
+    (0x96F, '9'),
+    (0x9E6, '0'),
+    (0x9E7, '1'),
+    (0x9E8, '2'),
+    (0x9E9, '3'),
+    (0x9EA, '4'),
+    (0x9EB, '5'),
+    (0x9EC, '6'),
+    (0x9ED, '7'),
+    (0x9EE, '8'),
+    (0x9EF, '9'),
+    (0xA66, '0'),
+    (0xA67, '1'),
+    (0xA68, '2'),
+    (0xA69, '3'),
+    (0xA6A, '4'),
+    (0xA6B, '5'),
+    (0xA6C, '6'),
+    (0xA6D, '7'),
+    (0xA6E, '8'),
+    (0xA6F, '9'),
+    (0xAE6, '0'),
+    (0xAE7, '1'),
+    (0xAE8, '2'),
+    (0xAE9, '3'),
+    (0xAEA, '4'),
+    (0xAEB, '5'),
+    (0xAEC, '6'),
+    (0xAED, '7'),
+    (0xAEE, '8'),
+    (0xAEF, '9'),
+    (0xB66, '0'),
+    (0xB67, '1'),
+    (0xB68, '2'),
+    (0xB69, '3'),
+    (0xB6A, '4'),
+    (0xB6B, '5'),
+    (0xB6C, '6'),
+    (0xB6D, '7'),
+    (0xB6E, '8'),
+    (0xB6F, '9'),
+    (0xBE6, '0'),
+    (0xBE7, '1'),
+    (0xBE8, '2'),
+    (0xBE9, '3'),
+    (0xBEA, '4'),
+    (0xBEB, '5'),
+    (0xBEC, '6'),
+    (0xBED, '7'),
+    (0xBEE, '8'),
+    (0xBEF, '9'),
+    (0xC66, '0'),
+    (0xC67, '1'),
+    (0xC68, '2'),
+    (0xC69, '3'),
+    (0xC6A, '4'),
+    (0xC6B, '5'),
+    (0xC6C, '6'),
+    (0xC6D, '7'),
+    (0xC6E, '8'),
+    (0xC6F, '9'),
+    (0xCE6, '0'),
+    (0xCE7, '1'),
+    (0xCE8, '2'),
+    (0xCE9, '3'),
+    (0xCEA, '4'),
+    (0xCEB, '5'),
+    (0xCEC, '6'),
+    (0xCED, '7'),
+    (0xCEE, '8'),
+    (0xCEF, '9'),
+    (0xD66, '0'),
+    (0xD67, '1'),
+    (0xD68, '2'),
+    (0xD69, '3'),
+    (0xD6A, '4'),
+    (0xD6B, '5'),
+    (0xD6C, '6'),
+    (0xD6D, '7'),
+    (0xD6E, '8'),
+    (0xD6F, '9'),
+    (0xDE6, '0'),
+    (0xDE7, '1'),
+    (0xDE8, '2'),
+    (0xDE9, '3'),
+    (0xDEA, '4'),
+    (0xDEB, '5'),
+    (0xDEC, '6'),
+    (0xDED, '7'),
+    (0xDEE, '8'),
+    (0xDEF, '9'),
+    (0xE50, '0'),
+    (0xE51, '1'),
+    (0xE52, '2'),
+    (0xE53, '3'),
+    (0xE54, '4'),
+    (0xE55, '5'),
+    (0xE56, '6'),
+    (0xE57, '7'),
+    (0xE58, '8'),
+    (0xE59, '9'),
+    (0xED0, '0'),
+    (0xED1, '1'),
+    (0xED2, '2'),
+    (0xED3, '3'),
+    (0xED4, '4'),
+    (0xED5, '5'),
+    (0xED6, '6'),
+    (0xED7, '7'),
+    (0xED8, '8'),
+    (0xED9, '9'),
+    (0xF20, '0'),
+    (0xF21, '1'),
+    (0xF22, '2'),
+    (0xF23, '3'),
+    (0xF24, '4'),
+    (0xF25, '5'),
+    (0xF26, '6'),
+    (0xF27, '7'),
+    (0xF28, '8'),
+    (0xF29, '9'),
+    (0x1040, '0'),
+    (0x1041, '1'),
+    (0x1042, '2'),
+    (0x1043, '3'),
+    (0x1044, '4'),
+    (0x1045, '5'),
+    (0x1046, '6'),
+    (0x1047, '7'),
+    (0x1048, '8'),
+    (0x1049, '9'),
+    (0x1090, '0'),
+    (0x1091, '1'),
+    (0x1092, '2'),
+    (0x1093, '3'),
+    (0x1094, '4'),
+    (0x1095, '5'),
+    (0x1096, '6'),
+    (0x1097, '7'),
+    (0x1098, '8'),
+    (0x1099, '9'),
+    (0x1369, '1'),
+    (0x136A, '2'),
+    (0x136B, '3'),
+    (0x136C, '4'),
+    (0x136D, '5'),
+    (0x136E, '6'),
+    (0x136F, '7'),
+    (0x1370, '8'),
+    (0x1371, '9'),
+    (0x17E0, '0'),
+    (0x17E1, '1'),
+    (0x17E2, '2'),
+    (0x17E3, '3'),
+    (0x17E4, '4'),
+    (0x17E5, '5'),
+    (0x17E6, '6'),
+    (0x17E7, '7'),
+    (0x17E8, '8'),
+    (0x17E9, '9'),
+    (0x1810, '0'),
+    (0x1811, '1'),
+    (0x1812, '2'),
+    (0x1813, '3'),
+    (0x1814, '4'),
+    (0x1815, '5'),
+    (0x1816, '6'),
+    (0x1817, '7'),
+    (0x1818, '8'),
+    (0x1819, '9'),
+    (0x1946, '0'),
+    (0x1947, '1'),
+    (0x1948, '2'),
+    (0x1949, '3'),
+    (0x194A, '4'),
+    (0x194B, '5'),
+    (0x194C, '6'),
+    (0x194D, '7'),
+    (0x194E, '8'),
+    (0x194F, '9'),
+    (0x19D0, '0'),
+    (0x19D1, '1'),
+    (0x19D2, '2'),
+    (0x19D3, '3'),
+    (0x19D4, '4'),
+    (0x19D5, '5'),
+    (0x19D6, '6'),
+    (0x19D7, '7'),
+    (0x19D8, '8'),
+    (0x19D9, '9'),
+    (0x19DA, '1'),
+    (0x1A80, '0'),
+    (0x1A81, '1'),
+    (0x1A82, '2'),
+    (0x1A83, '3'),
+    (0x1A84, '4'),
+    (0x1A85, '5'),
+    (0x1A86, '6'),
+    (0x1A87, '7'),
+    (0x1A88, '8'),
+    (0x1A89, '9'),
+    (0x1A90, '0'),
+    (0x1A91, '1'),
+    (0x1A92, '2'),
+    (0x1A93, '3'),
+    (0x1A94, '4'),
+    (0x1A95, '5'),
+    (0x1A96, '6'),
+    (0x1A97, '7'),
+    (0x1A98, '8'),
+    (0x1A99, '9'),
+    (0x1B50, '0'),
+    (0x1B51, '1'),
+    (0x1B52, '2'),
+    (0x1B53, '3'),
+    (0x1B54, '4'),
+    (0x1B55, '5'),
+    (0x1B56, '6'),
+    (0x1B57, '7'),
+    (0x1B58, '8'),
+    (0x1B59, '9'),
+    (0x1BB0, '0'),
+    (0x1BB1, '1'),
+    (0x1BB2, '2'),
+    (0x1BB3, '3'),
+    (0x1BB4, '4'),
+    (0x1BB5, '5'),
+    (0x1BB6, '6'),
+    (0x1BB7, '7'),
+    (0x1BB8, '8'),
+    (0x1BB9, '9'),
+    (0x1C40, '0'),
+    (0x1C41, '1'),
+    (0x1C42, '2'),
+    (0x1C43, '3'),
+    (0x1C44, '4'),
+    (0x1C45, '5'),
+    (0x1C46, '6'),
+    (0x1C47, '7'),
+    (0x1C48, '8'),
+    (0x1C49, '9'),
+    (0x1C50, '0'),
+    (0x1C51, '1'),
+    (0x1C52, '2'),
+    (0x1C53, '3'),
+    (0x1C54, '4'),
+    (0x1C55, '5'),
+    (0x1C56, '6'),
+    (0x1C57, '7'),
+    (0x1C58, '8'),
+    (0x1C59, '9'),
+    (0x2070, '0'),
+    (0x2074, '4'),
+    (0x2075, '5'),
+    (0x2076, '6'),
+    (0x2077, '7'),
+    (0x2078, '8'),
+    (0x2079, '9'),
+    (0x2080, '0'),
+    (0x2081, '1'),
+    (0x2082, '2'),
+    (0x2083, '3'),
+    (0x2084, '4'),
+    (0x2085, '5'),
+    (0x2086, '6'),
+    (0x2087, '7'),
+    (0x2088, '8'),
+    (0x2089, '9'),
+    (0x2460, '1'),
+    (0x2461, '2'),
+    (0x2462, '3'),
+    (0x2463, '4'),
+    (0x2464, '5'),
+    (0x2465, '6'),
+    (0x2466, '7'),
+    (0x2467, '8'),
+    (0x2468, '9'),
+    (0x2474, '1'),
+    (0x2475, '2'),
+    (0x2476, '3'),
+    (0x2477, '4'),
+    (0x2478, '5'),
+    (0x2479, '6'),
+    (0x247A, '7'),
+    (0x247B, '8'),
+    (0x247C, '9'),
+    (0x2488, '1'),
+    (0x2489, '2'),
+    (0x248A, '3'),
+    (0x248B, '4'),
+    (0x248C, '5'),
+    (0x248D, '6'),
+    (0x248E, '7'),
+    (0x248F, '8'),
+    (0x2490, '9'),
+    (0x24EA, '0'),
+    (0x24F5, '1'),
+    (0x24F6, '2'),
+    (0x24F7, '3'),
+    (0x24F8, '4'),
+    (0x24F9, '5'),
+    (0x24FA, '6'),
+    (0x24FB, '7'),
+    (0x24FC, '8'),
+    (0x24FD, '9'),
+    (0x24FF, '0'),
+    (0x2776, '1'),
+    (0x2777, '2'),
+    (0x2778, '3'),
+    (0x2779, '4'),
+    (0x277A, '5'),
+    (0x277B, '6'),
+    (0x277C, '7'),
+    (0x277D, '8'),
+    (0x277E, '9'),
+    (0x2780, '1'),
+    (0x2781, '2'),
+    (0x2782, '3'),
+    (0x2783, '4'),
+    (0x2784, '5'),
+    (0x2785, '6'),
+    (0x2786, '7'),
+    (0x2787, '8'),
+    (0x2788, '9'),
+    (0x278A, '1'),
+    (0x278B, '2'),
+    (0x278C, '3'),
+    (0x278D, '4'),
+    (0x278E, '5'),
+    (0x278F, '6'),
+    (0x2790, '7'),
+    (0x2791, '8'),
+    (0x2792, '9'),
+    (0xA620, '0'),
+    (0xA621, '1'),
+    (0xA622, '2'),
+    (0xA623, '3'),
+    (0xA624, '4'),
+    (0xA625, '5'),
+    (0xA626, '6'),
+    (0xA627, '7'),
+    (0xA628, '8'),
+    (0xA629, '9'),
+    (0xA8D0, '0'),
+    (0xA8D1, '1'),
+    (0xA8D2, '2'),
+    (0xA8D3, '3'),
+    (0xA8D4, '4'),
+    (0xA8D5, '5'),
+    (0xA8D6, '6'),
+    (0xA8D7, '7'),
+    (0xA8D8, '8'),
+    (0xA8D9, '9'),
+    (0xA900, '0'),
+    (0xA901, '1'),
+    (0xA902, '2'),
+    (0xA903, '3'),
+    (0xA904, '4'),
+    (0xA905, '5'),
+    (0xA906, '6'),
+    (0xA907, '7'),
+    (0xA908, '8'),
+    (0xA909, '9'),
+    (0xA9D0, '0'),
+    (0xA9D1, '1'),
+    (0xA9D2, '2'),
+    (0xA9D3, '3'),
+    (0xA9D4, '4'),
+    (0xA9D5, '5'),
+    (0xA9D6, '6'),
+    (0xA9D7, '7'),
+    (0xA9D8, '8'),
+    (0xA9D9, '9'),
+    (0xA9F0, '0'),
+    (0xA9F1, '1'),
+    (0xA9F2, '2'),
+    (0xA9F3, '3'),
+    (0xA9F4, '4'),
+    (0xA9F5, '5'),
+    (0xA9F6, '6'),
+    (0xA9F7, '7'),
+    (0xA9F8, '8'),
+    (0xA9F9, '9'),
+    (0xAA50, '0'),
+    (0xAA51, '1'),
+    (0xAA52, '2'),
+    (0xAA53, '3'),
+    (0xAA54, '4'),
+    (0xAA55, '5'),
+    (0xAA56, '6'),
+    (0xAA57, '7'),
+    (0xAA58, '8'),
+    (0xAA59, '9'),
+    (0xABF0, '0'),
+    (0xABF1, '1'),
+    (0xABF2, '2'),
+    (0xABF3, '3'),
+    (0xABF4, '4'),
+    (0xABF5, '5'),
+    (0xABF6, '6'),
+    (0xABF7, '7'),
+    (0xABF8, '8'),
+    (0xABF9, '9'),
+    (0xFF10, '0'),
+    (0xFF11, '1'),
+    (0xFF12, '2'),
+    (0xFF13, '3'),
+    (0xFF14, '4'),
+    (0xFF15, '5'),
+    (0xFF16, '6'),
+    (0xFF17, '7'),
+    (0xFF18, '8'),
+    (0xFF19, '9'),
+    (0x104A0, '0'),
+    (0x104A1, '1'),
+    (0x104A2, '2'),
+    (0x104A3, '3'),
+    (0x104A4, '4'),
+    (0x104A5, '5'),
+    (0x104A6, '6'),
+    (0x104A7, '7'),
+    (0x104A8, '8'),
+    (0x104A9, '9'),
+    (0x10A40, '1'),
+    (0x10A41, '2'),
+    (0x10A42, '3'),
+    (0x10A43, '4'),
+    (0x10D30, '0'),
+    (0x10D31, '1'),
+    (0x10D32, '2'),
+    (0x10D33, '3'),
+    (0x10D34, '4'),
+    (0x10D35, '5'),
+    (0x10D36, '6'),
+    (0x10D37, '7'),
+    (0x10D38, '8'),
+    (0x10D39, '9'),
+    (0x10D40, '0'),
+    (0x10D41, '1'),
+    (0x10D42, '2'),
+    (0x10D43, '3'),
+    (0x10D44, '4'),
+    (0x10D45, '5'),
+    (0x10D46, '6'),
+    (0x10D47, '7'),
+    (0x10D48, '8'),
+    (0x10D49, '9'),
+    (0x10E60, '1'),
+    (0x10E61, '2'),
+    (0x10E62, '3'),
+    (0x10E63, '4'),
+    (0x10E64, '5'),
+    (0x10E65, '6'),
+    (0x10E66, '7'),
+    (0x10E67, '8'),
+    (0x10E68, '9'),
+    (0x11052, '1'),
+    (0x11053, '2'),
+    (0x11054, '3'),
+    (0x11055, '4'),
+    (0x11056, '5'),
+    (0x11057, '6'),
+    (0x11058, '7'),
+    (0x11059, '8'),
+    (0x1105A, '9'),
+    (0x11066, '0'),
+    (0x11067, '1'),
+    (0x11068, '2'),
+    (0x11069, '3'),
+    (0x1106A, '4'),
+    (0x1106B, '5'),
+    (0x1106C, '6'),
+    (0x1106D, '7'),
+    (0x1106E, '8'),
+    (0x1106F, '9'),
+    (0x110F0, '0'),
+    (0x110F1, '1'),
+    (0x110F2, '2'),
+    (0x110F3, '3'),
+    (0x110F4, '4'),
+    (0x110F5, '5'),
+    (0x110F6, '6'),
+    (0x110F7, '7'),
+    (0x110F8, '8'),
+    (0x110F9, '9'),
+    (0x11136, '0'),
+    (0x11137, '1'),
+    (0x11138, '2'),
+    (0x11139, '3'),
+    (0x1113A, '4'),
+    (0x1113B, '5'),
+    (0x1113C, '6'),
+    (0x1113D, '7'),
+    (0x1113E, '8'),
+    (0x1113F, '9'),
+    (0x111D0, '0'),
+    (0x111D1, '1'),
+    (0x111D2, '2'),
+    (0x111D3, '3'),
+    (0x111D4, '4'),
+    (0x111D5, '5'),
+    (0x111D6, '6'),
+    (0x111D7, '7'),
+    (0x111D8, '8'),
+    (0x111D9, '9'),
+    (0x112F0, '0'),
+    (0x112F1, '1'),
+    (0x112F2, '2'),
+    (0x112F3, '3'),
+    (0x112F4, '4'),
+    (0x112F5, '5'),
+    (0x112F6, '6'),
+    (0x112F7, '7'),
+    (0x112F8, '8'),
+    (0x112F9, '9'),
+    (0x11450, '0'),
+    (0x11451, '1'),
+    (0x11452, '2'),
+    (0x11453, '3'),
+    (0x11454, '4'),
+    (0x11455, '5'),
+    (0x11456, '6'),
+    (0x11457, '7'),
+    (0x11458, '8'),
+    (0x11459, '9'),
+    (0x114D0, '0'),
+    (0x114D1, '1'),
+    (0x114D2, '2'),
+    (0x114D3, '3'),
+    (0x114D4, '4'),
+    (0x114D5, '5'),
+    (0x114D6, '6'),
+    (0x114D7, '7'),
+    (0x114D8, '8'),
+    (0x114D9, '9'),
+    (0x11650, '0'),
+    (0x11651, '1'),
+    (0x11652, '2'),
+    (0x11653, '3'),
+    (0x11654, '4'),
+    (0x11655, '5'),
+    (0x11656, '6'),
+    (0x11657, '7'),
+    (0x11658, '8'),
+    (0x11659, '9'),
+    (0x116C0, '0'),
+    (0x116C1, '1'),
+    (0x116C2, '2'),
+    (0x116C3, '3'),
+    (0x116C4, '4'),
+    (0x116C5, '5'),
+    (0x116C6, '6'),
+    (0x116C7, '7'),
+    (0x116C8, '8'),
+    (0x116C9, '9'),
+    (0x116D0, '0'),
+    (0x116D1, '1'),
+    (0x116D2, '2'),
+    (0x116D3, '3'),
+    (0x116D4, '4'),
+    (0x116D5, '5'),
+    (0x116D6, '6'),
+    (0x116D7, '7'),
+    (0x116D8, '8'),
+    (0x116D9, '9'),
+    (0x116DA, '0'),
+    (0x116DB, '1'),
+    (0x116DC, '2'),
+    (0x116DD, '3'),
+    (0x116DE, '4'),
+    (0x116DF, '5'),
+    (0x116E0, '6'),
+    (0x116E1, '7'),
+    (0x116E2, '8'),
+    (0x116E3, '9'),
+    (0x11730, '0'),
+    (0x11731, '1'),
+    (0x11732, '2'),
+    (0x11733, '3'),
+    (0x11734, '4'),
+    (0x11735, '5'),
+    (0x11736, '6'),
+    (0x11737, '7'),
+    (0x11738, '8'),
+    (0x11739, '9'),
+    (0x118E0, '0'),
+    (0x118E1, '1'),
+    (0x118E2, '2'),
+    (0x118E3, '3'),
+    (0x118E4, '4'),
+    (0x118E5, '5'),
+    (0x118E6, '6'),
+    (0x118E7, '7'),
+    (0x118E8, '8'),
+    (0x118E9, '9'),
+    (0x11950, '0'),
+    (0x11951, '1'),
+    (0x11952, '2'),
+    (0x11953, '3'),
+    (0x11954, '4'),
+    (0x11955, '5'),
+    (0x11956, '6'),
+    (0x11957, '7'),
+    (0x11958, '8'),
+    (0x11959, '9'),
+    (0x11BF0, '0'),
+    (0x11BF1, '1'),
+    (0x11BF2, '2'),
+    (0x11BF3, '3'),
+    (0x11BF4, '4'),
+    (0x11BF5, '5'),
+    (0x11BF6, '6'),
+    (0x11BF7, '7'),
+    (0x11BF8, '8'),
+    (0x11BF9, '9'),
+    (0x11C50, '0'),
+    (0x11C51, '1'),
+    (0x11C52, '2'),
+    (0x11C53, '3'),
+    (0x11C54, '4'),
+    (0x11C55, '5'),
+    (0x11C56, '6'),
+    (0x11C57, '7'),
+    (0x11C58, '8'),
+    (0x11C59, '9'),
+    (0x11D50, '0'),
+    (0x11D51, '1'),
+    (0x11D52, '2'),
+    (0x11D53, '3'),
+    (0x11D54, '4'),
+    (0x11D55, '5'),
+    (0x11D56, '6'),
+    (0x11D57, '7'),
+    (0x11D58, '8'),
+    (0x11D59, '9'),
+    (0x11DA0, '0'),
+    (0x11DA1, '1'),
+    (0x11DA2, '2'),
+    (0x11DA3, '3'),
+    (0x11DA4, '4'),
+    (0x11DA5, '5'),
+    (0x11DA6, '6'),
+    (0x11DA7, '7'),
+    (0x11DA8, '8'),
+    (0x11DA9, '9'),
+    (0x11F50, '0'),
+    (0x11F51, '1'),
+    (0x11F52, '2'),
+    (0x11F53, '3'),
+    (0x11F54, '4'),
+    (0x11F55, '5'),
+    (0x11F56, '6'),
+    (0x11F57, '7'),
+    (0x11F58, '8'),
+    (0x11F59, '9'),
+    (0x16130, '0'),
+    (0x16131, '1'),
+    (0x16132, '2'),
+    (0x16133, '3'),
+    (0x16134, '4'),
+    (0x16135, '5'),
+    (0x16136, '6'),
+    (0x16137, '7'),
+    (0x16138, '8'),
+    (0x16139, '9'),
+    (0x16A60, '0'),
+    (0x16A61, '1'),
+    (0x16A62, '2'),
+    (0x16A63, '3'),
+    (0x16A64, '4'),
+    (0x16A65, '5'),
+    (0x16A66, '6'),
+    (0x16A67, '7'),
+    (0x16A68, '8'),
+    (0x16A69, '9'),
+    (0x16AC0, '0'),
+    (0x16AC1, '1'),
+    (0x16AC2, '2'),
+    (0x16AC3, '3'),
+    (0x16AC4, '4'),
+    (0x16AC5, '5'),
+    (0x16AC6, '6'),
+    (0x16AC7, '7'),
+    (0x16AC8, '8'),
+    (0x16AC9, '9'),
+    (0x16B50, '0'),
+    (0x16B51, '1'),
+    (0x16B52, '2'),
+    (0x16B53, '3'),
+    (0x16B54, '4'),
+    (0x16B55, '5'),
+    (0x16B56, '6'),
+    (0x16B57, '7'),
+    (0x16B58, '8'),
+    (0x16B59, '9'),
+    (0x16D70, '0'),
+    (0x16D71, '1'),
+    (0x16D72, '2'),
+    (0x16D73, '3'),
+    (0x16D74, '4'),
+    (0x16D75, '5'),
+    (0x16D76, '6'),
+    (0x16D77, '7'),
+    (0x16D78, '8'),
+    (0x16D79, '9'),
+    (0x1CCF0, '0'),
+    (0x1CCF1, '1'),
+    (0x1CCF2, '2'),
+    (0x1CCF3, '3'),
+    (0x1CCF4, '4'),
+    (0x1CCF5, '5'),
+    (0x1CCF6, '6'),
+    (0x1CCF7, '7'),
+    (0x1CCF8, '8'),
+    (0x1CCF9, '9'),
+    (0x1D7CE, '0'),
+    (0x1D7CF, '1'),
+    (0x1D7D0, '2'),
+    (0x1D7D1, '3'),
+    (0x1D7D2, '4'),
+    (0x1D7D3, '5'),
+    (0x1D7D4, '6'),
+    (0x1D7D5, '7'),
+    (0x1D7D6, '8'),
+    (0x1D7D7, '9'),
+    (0x1D7D8, '0'),
+    (0x1D7D9, '1'),
+    (0x1D7DA, '2'),
+    (0x1D7DB, '3'),
+    (0x1D7DC, '4'),
+    (0x1D7DD, '5'),
+    (0x1D7DE, '6'),
+    (0x1D7DF, '7'),
+    (0x1D7E0, '8'),
+    (0x1D7E1, '9'),
+    (0x1D7E2, '0'),
+    (0x1D7E3, '1'),
+    (0x1D7E4, '2'),
+    (0x1D7E5, '3'),
+    (0x1D7E6, '4'),
+    (0x1D7E7, '5'),
+    (0x1D7E8, '6'),
+    (0x1D7E9, '7'),
+    (0x1D7EA, '8'),
+    (0x1D7EB, '9'),
+    (0x1D7EC, '0'),
+    (0x1D7ED, '1'),
+    (0x1D7EE, '2'),
+    (0x1D7EF, '3'),
+    (0x1D7F0, '4'),
+    (0x1D7F1, '5'),
+    (0x1D7F2, '6'),
+    (0x1D7F3, '7'),
+    (0x1D7F4, '8'),
+    (0x1D7F5, '9'),
+    (0x1D7F6, '0'),
+    (0x1D7F7, '1'),
+    (0x1D7F8, '2'),
+    (0x1D7F9, '3'),
+    (0x1D7FA, '4'),
+    (0x1D7FB, '5'),
+    (0x1D7FC, '6'),
+    (0x1D7FD, '7'),
+    (0x1D7FE, '8'),
+    (0x1D7FF, '9'),
+    (0x1E140, '0'),
+    (0x1E141, '1'),
+    (0x1E142, '2'),
+    (0x1E143, '3'),
+    (0x1E144, '4'),
+    (0x1E145, '5'),
+    (0x1E146, '6'),
+    (0x1E147, '7'),
+    (0x1E148, '8'),
+    (0x1E149, '9'),
+    (0x1E2F0, '0'),
+    (0x1E2F1, '1'),
+    (0x1E2F2, '2'),
+    (0x1E2F3, '3'),
+    (0x1E2F4, '4'),
+    (0x1E2F5, '5'),
+    (0x1E2F6, '6'),
+    (0x1E2F7, '7'),
+    (0x1E2F8, '8'),
+    (0x1E2F9, '9'),
+    (0x1E4F0, '0'),
+    (0x1E4F1, '1'),
+    (0x1E4F2, '2'),
+    (0x1E4F3, '3'),
+    (0x1E4F4, '4'),
+    (0x1E4F5, '5'),
+    (0x1E4F6, '6'),
+    (0x1E4F7, '7'),
+    (0x1E4F8, '8'),
+    (0x1E4F9, '9'),
+    (0x1E5F1, '0'),
+    (0x1E5F2, '1'),
+    (0x1E5F3, '2'),
+    (0x1E5F4, '3'),
+    (0x1E5F5, '4'),
+    (0x1E5F6, '5'),
+    (0x1E5F7, '6'),
+    (0x1E5F8, '7'),
+    (0x1E5F9, '8'),
+    (0x1E5FA, '9'),
+    (0x1E950, '0'),
+    (0x1E951, '1'),
+    (0x1E952, '2'),
+    (0x1E953, '3'),
+    (0x1E954, '4'),
+    (0x1E955, '5'),
+    (0x1E956, '6'),
+    (0x1E957, '7'),
+    (0x1E958, '8'),
+    (0x1E959, '9'),
+    (0x1F100, '0'),
+    (0x1F101, '0'),
+    (0x1F102, '1'),
+    (0x1F103, '2'),
+    (0x1F104, '3'),
+    (0x1F105, '4'),
+    (0x1F106, '5'),
+    (0x1F107, '6'),
+    (0x1F108, '7'),
+    (0x1F109, '8'),
+    (0x1F10A, '9'),
+    (0x1FBF0, '0'),
+    (0x1FBF1, '1'),
+    (0x1FBF2, '2'),
+    (0x1FBF3, '3'),
+    (0x1FBF4, '4'),
+    (0x1FBF5, '5'),
+    (0x1FBF6, '6'),
+    (0x1FBF7, '7'),
+    (0x1FBF8, '8'),
+    (0x1FBF9, '9'),
+];
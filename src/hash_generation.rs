@@ -1,12 +1,37 @@
-//! Module to generate the rust source file for the hashmap containing the uppercase AZ code points
+//! Module to generate the rust source files for the static tables containing the uppercase AZ code points,
+//! the digits code points, the punctuation and spaces code points and the transliterated code points
+//!
+//! The table is sorted by code point so that it can be searched by dichotomy at runtime without any
+//! initialization nor heap allocation.
 
 use std::fs;
-use std::fs::OpenOptions;
+use std::fs::{File, OpenOptions};
 use std::io::Write;
 
 /// Rust source file to produce
 const OUTPUT_RUST_FILE: &str = "./src/hash_uppercase_az.rs";
 
+/// Rust source file to produce for digits
+const OUTPUT_DIGITS_RUST_FILE: &str = "./src/hash_digits.rs";
+
+/// Rust source file to produce for punctuation and spaces
+const OUTPUT_PUNCTUATION_RUST_FILE: &str = "./src/hash_punctuation.rs";
+
+/// Rust source file to produce for Cyrillic letters
+const OUTPUT_CYRILLIC_RUST_FILE: &str = "./src/hash_cyrillic.rs";
+
+/// Rust source file to produce for Greek letters
+const OUTPUT_GREEK_RUST_FILE: &str = "./src/hash_greek.rs";
+
+/// Rust source file to produce for kana
+const OUTPUT_KANA_RUST_FILE: &str = "./src/hash_kana.rs";
+
+/// Rust source file to produce for combining marks
+const OUTPUT_COMBINING_MARKS_RUST_FILE: &str = "./src/hash_combining_marks.rs";
+
+/// Rust source file to produce for symbols
+const OUTPUT_SYMBOLS_RUST_FILE: &str = "./src/hash_symbols.rs";
+
 /// Structure for uppercase AZ code point
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -28,16 +53,27 @@ pub struct EndPoint {
 
     /// true if additional letter decoration (accent, cedilla, etc)
     is_decoration: bool,
+
+    /// Decoration keywords found in the description (ACUTE, CEDILLA, etc)
+    decorations: Vec<String>,
+
+    /// Symbol category (`Symbol` variant name) if the code point is not a letter (e.g: `RegionalIndicator`)
+    symbol: Option<&'static str>,
+
+    /// Property (`Property` variant name) overriding the capital, small and decoration flags (e.g:
+    /// `RomanNumeral`)
+    property: Option<&'static str>,
 }
 
 impl EndPoint {
-    pub const fn new(
+    pub fn new(
         code_point: u32,
         description: String,
         uppercase_az: String,
         is_capital: bool,
         is_small: bool,
         is_decoration: bool,
+        decorations: Vec<String>,
     ) -> Self {
         Self {
             code_point,
@@ -46,84 +82,436 @@ impl EndPoint {
             is_capital,
             is_small,
             is_decoration,
+            decorations,
+            symbol: None,
+            property: None,
+        }
+    }
+
+    /// Set the property (`Property` variant name) of the code point, overriding the capital, small and
+    /// decoration flags
+    pub fn with_property(mut self, property: &'static str) -> Self {
+        self.property = Some(property);
+        self
+    }
+
+    /// Set the symbol category (`Symbol` variant name) of the code point
+    pub fn with_symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+}
+
+/// Structure for a code point transliterated to uppercase AZ (one equivalent per standard)
+#[derive(Debug, Clone)]
+pub struct Transliteration {
+    /// UTF-8 code point
+    code_point: u32,
+
+    /// Uppercase A-Z equivalent for each standard (may be empty: the letter is dropped)
+    letters: Vec<String>,
+
+    /// true if uppercase letter
+    is_capital: bool,
+
+    /// true if lowercase letter
+    is_small: bool,
+
+    /// Decoration keywords found in the description (ACUTE, CEDILLA, etc)
+    decorations: Vec<String>,
+}
+
+impl Transliteration {
+    pub fn new(
+        code_point: u32,
+        letters: Vec<String>,
+        is_capital: bool,
+        is_small: bool,
+        decorations: Vec<String>,
+    ) -> Self {
+        Self {
+            code_point,
+            letters,
+            is_capital,
+            is_small,
+            decorations,
         }
     }
 }
 
+/// Decoration keywords that only link other keywords in the description and are not decorations
+const DECORATION_CONNECTORS: [&str; 3] = ["AND", "WITH", "WITHOUT"];
+
 /// Header of the rust source file
 const HEADER: &str = r"// DO NOT MODIFY
 // This file is automatically generated by command 'cargo run generate'
 //
 #[rustfmt::skip]
-lazy_static! {
-    static ref UPPERCASE_AZ: HashMap<u32, Uppercase> = {
-        let mut m = HashMap::new();
+static UPPERCASE_AZ: &[(u32, Uppercase)] = &[
 ";
 
-/// Footer of the rust source file
-const FOOTER: &str = r"
-        m
-    };
-}
+/// Footer of the uppercase AZ table
+const FOOTER: &str = r"];
+";
+
+/// Header of the Unicode names table (only with `names` feature)
+const NAMES_HEADER: &str = r#"
+#[cfg(feature = "names")]
+#[rustfmt::skip]
+static UPPERCASE_AZ_NAMES: &[(u32, &str)] = &[
+"#;
+
+/// Footer of the Unicode names table
+const NAMES_FOOTER: &str = r"];
+";
+
+/// Header of the digits rust source file
+const DIGITS_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static DIGITS: &[(u32, char)] = &[
+";
+
+/// Footer of the digits rust source file
+const DIGITS_FOOTER: &str = r"];
+";
+
+/// Header of the punctuation and spaces rust source file
+const PUNCTUATION_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static PUNCTUATION: &[(u32, &str)] = &[
 ";
 
-/// Generate the rust source file for the hashmap containing the uppercase AZ code points
-pub fn generate_rust_file(endpoints: Vec<EndPoint>) {
+/// Footer of the punctuation and spaces rust source file
+const PUNCTUATION_FOOTER: &str = r"];
+";
+
+/// Header of the Cyrillic letters rust source file
+const CYRILLIC_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static CYRILLIC: &[(u32, CyrillicLetter)] = &[
+";
+
+/// Footer of the Cyrillic letters rust source file
+const CYRILLIC_FOOTER: &str = r"];
+";
+
+/// Header of the Greek letters rust source file
+const GREEK_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static GREEK: &[(u32, (Property, Decorations, Letter))] = &[
+";
+
+/// Footer of the Greek letters rust source file
+const GREEK_FOOTER: &str = r"];
+";
+
+/// Header of the kana rust source file
+const KANA_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static KANA: &[(u32, (&str, bool))] = &[
+";
+
+/// Footer of the kana rust source file
+const KANA_FOOTER: &str = r"];
+";
+
+/// Header of the symbols rust source file
+const SYMBOLS_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static SYMBOLS: &[(u32, Symbol)] = &[
+";
+
+/// Footer of the symbols rust source file
+const SYMBOLS_FOOTER: &str = r"];
+";
+
+/// Header of the combining marks rust source file
+const COMBINING_MARKS_HEADER: &str = r"// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static COMBINING_MARKS: &[(u32, Decorations)] = &[
+";
+
+/// Footer of the combining marks rust source file
+const COMBINING_MARKS_FOOTER: &str = r"];
+";
+
+/// Create a new rust source file (previous version is deleted)
+fn create_rust_file(filename: &str) -> File {
     // Try to remove previous file
-    match fs::remove_file(OUTPUT_RUST_FILE) {
-        Ok(()) => println!("Previous version of '{OUTPUT_RUST_FILE}' has been deleted."),
-        Err(_) => println!("A new file '{OUTPUT_RUST_FILE}' will be created."),
+    match fs::remove_file(filename) {
+        Ok(()) => println!("Previous version of '{filename}' has been deleted."),
+        Err(_) => println!("A new file '{filename}' will be created."),
     }
 
     // Open the file in append mode
-    let mut file = OpenOptions::new()
+    OpenOptions::new()
         .append(true)
         .create(true) //Create the file if it does not exist
-        .open(OUTPUT_RUST_FILE)
+        .open(filename)
+        .expect("Unable to write the file")
+}
+
+/// Rust source code of the `Letter` for an uppercase A-Z equivalent (`Letter::Sequence("")` if empty)
+fn letter_source(uppercase_az: &str) -> String {
+    let vec_chars = uppercase_az.chars().collect::<Vec<char>>();
+    match vec_chars.len() {
+        1 => format!("Letter::Letter('{}')", vec_chars[0]),
+        2 => format!("Letter::Letters('{}', '{}')", vec_chars[0], vec_chars[1]),
+        _ => format!("Letter::Sequence(\"{uppercase_az}\")"),
+    }
+}
+
+/// Rust source code of the `Property` for the capital, small and decoration flags
+fn property_source(is_capital: bool, is_small: bool, is_decoration: bool) -> &'static str {
+    match (is_capital, is_small, is_decoration) {
+        (true, false, false) => "Property::Capital",
+        (false, true, false) => "Property::Small",
+        (true, true, false) => "Property::SmallAndCapital",
+        (false, true, true) => "Property::SmallWithDecoration",
+        (true, false, true) => "Property::CapitalWithDecoration",
+        (true, true, true) => "Property::SmallAndCapitalWithDecoration",
+        _ => "Property::Unknown",
+    }
+}
+
+/// Rust source code of the `Decorations` for the decoration keywords (connectors are skipped)
+fn decorations_source(keywords: &[String]) -> String {
+    let decorations: Vec<String> = keywords
+        .iter()
+        .map(|keyword| keyword.trim())
+        .filter(|keyword| !DECORATION_CONNECTORS.contains(keyword))
+        .map(|keyword| format!("Decorations::{}", keyword.replace([' ', '-'], "_")))
+        .collect();
+    match decorations.split_first() {
+        None => "Decorations::empty()".to_string(),
+        Some((first, others)) => format!(
+            "{first}{}",
+            others
+                .iter()
+                .map(|decoration| format!(".union({decoration})"))
+                .collect::<String>()
+        ),
+    }
+}
+
+/// Sort the entries of a table by code point and remove the duplicates: tables are searched by dichotomy
+fn sort_by_code_point<T>(entries: &mut Vec<T>, code_point: impl Fn(&T) -> u32) {
+    entries.sort_by_key(&code_point);
+    entries.dedup_by_key(|entry| code_point(entry));
+}
+
+/// Generate the rust source file for the static table containing the digits code points
+pub fn generate_digits_rust_file(mut digits: Vec<(u32, char)>) {
+    sort_by_code_point(&mut digits, |&(code_point, _)| code_point);
+
+    let mut file = create_rust_file(OUTPUT_DIGITS_RUST_FILE);
+
+    write!(file, "{DIGITS_HEADER}").expect("Unable to write the file");
+    for (code_point, digit) in digits {
+        writeln!(file, "    (0x{code_point:X}, '{digit}'),").expect("Unable to write the file");
+    }
+    write!(file, "{DIGITS_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the punctuation and spaces code points
+pub fn generate_punctuation_rust_file(mut punctuation: Vec<(u32, String)>) {
+    sort_by_code_point(&mut punctuation, |&(code_point, _)| code_point);
+
+    let mut file = create_rust_file(OUTPUT_PUNCTUATION_RUST_FILE);
+
+    write!(file, "{PUNCTUATION_HEADER}").expect("Unable to write the file");
+    for (code_point, ascii) in punctuation {
+        writeln!(file, "    (0x{code_point:X}, {ascii:?}),").expect("Unable to write the file");
+    }
+    write!(file, "{PUNCTUATION_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the kana code points
+pub fn generate_kana_rust_file(mut kana: Vec<(u32, String, bool)>) {
+    sort_by_code_point(&mut kana, |&(code_point, _, _)| code_point);
+
+    let mut file = create_rust_file(OUTPUT_KANA_RUST_FILE);
+
+    write!(file, "{KANA_HEADER}").expect("Unable to write the file");
+    for (code_point, syllable, is_small) in kana {
+        writeln!(file, "    (0x{code_point:X}, ({syllable:?}, {is_small})),")
+            .expect("Unable to write the file");
+    }
+    write!(file, "{KANA_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the symbol category of the uppercase AZ
+/// code points that are not letters (regional indicators, enclosed letters and abbreviations)
+pub fn generate_symbols_rust_file(endpoints: &[EndPoint]) {
+    let mut symbols: Vec<(u32, &str)> = endpoints
+        .iter()
+        .filter_map(|end_point| Some((end_point.code_point, end_point.symbol?)))
+        .collect();
+    sort_by_code_point(&mut symbols, |&(code_point, _)| code_point);
+
+    let mut file = create_rust_file(OUTPUT_SYMBOLS_RUST_FILE);
+
+    write!(file, "{SYMBOLS_HEADER}").expect("Unable to write the file");
+    for (code_point, symbol) in symbols {
+        writeln!(file, "    (0x{code_point:X}, Symbol::{symbol}),")
+            .expect("Unable to write the file");
+    }
+    write!(file, "{SYMBOLS_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the combining marks code points
+pub fn generate_combining_marks_rust_file(mut combining_marks: Vec<(u32, Vec<String>)>) {
+    sort_by_code_point(&mut combining_marks, |&(code_point, _)| code_point);
+
+    let mut file = create_rust_file(OUTPUT_COMBINING_MARKS_RUST_FILE);
+
+    write!(file, "{COMBINING_MARKS_HEADER}").expect("Unable to write the file");
+    for (code_point, decorations) in combining_marks {
+        writeln!(
+            file,
+            "    (0x{code_point:X}, {}),",
+            decorations_source(&decorations)
+        )
         .expect("Unable to write the file");
+    }
+    write!(file, "{COMBINING_MARKS_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the uppercase AZ code points
+pub fn generate_rust_file(mut endpoints: Vec<EndPoint>) {
+    sort_by_code_point(&mut endpoints, |end_point| end_point.code_point);
+
+    let mut file = create_rust_file(OUTPUT_RUST_FILE);
 
     // File header
     write!(file, "{HEADER}").expect("Unable to write the file");
 
     // All endpoints
-    for end_point in endpoints {
-        let str_start = format!("        m.insert(0x{:X}", end_point.code_point);
+    for end_point in &endpoints {
+        let str_start = format!("    (0x{:X}", end_point.code_point);
 
         let str_begin = "Uppercase {";
 
-        let vec_chars = end_point.uppercase_az.chars().collect::<Vec<char>>();
-        let str_letter = match vec_chars.len() {
-            1 => format!("letter: Letter::Letter('{}')", vec_chars[0]),
-            2 => format!(
-                "letter: Letter::Letters('{}', '{}')",
-                vec_chars[0], vec_chars[1]
-            ),
-            _ => panic!(
-                "Invalid uppercase_az '{}': 1 or 2 characters expected",
-                end_point.uppercase_az
+        if end_point.uppercase_az.is_empty() {
+            panic!(
+                "Invalid uppercase_az for code point 0x{:X}: at least 1 character expected",
+                end_point.code_point
+            );
+        }
+        let str_letter = format!("letter: {}", letter_source(&end_point.uppercase_az));
+
+        let str_property = match end_point.property {
+            Some(property) => format!("property: Property::{property}"),
+            None => format!(
+                "property: {}",
+                property_source(
+                    end_point.is_capital,
+                    end_point.is_small,
+                    end_point.is_decoration
+                )
             ),
         };
 
-        let str_property = match (
-            end_point.is_capital,
-            end_point.is_small,
-            end_point.is_decoration,
-        ) {
-            (true, false, false) => "property: Property::Capital",
-            (false, true, false) => "property: Property::Small",
-            (true, true, false) => "property: Property::SmallAndCapital",
-            (false, true, true) => "property: Property::SmallWithDecoration",
-            (true, false, true) => "property: Property::CapitalWithDecoration",
-            (true, true, true) => "property: Property::SmallAndCapitalWithDecoration",
-            _ => "property: Property::Unknown",
-        };
+        let str_decorations = format!(
+            "decorations: {}",
+            decorations_source(&end_point.decorations)
+        );
 
-        let str_end = "});";
+        let str_end = "}),";
 
-        let content = format!("{str_start}, {str_begin}{str_letter}, {str_property}{str_end}\n");
+        let content = format!(
+            "{str_start}, {str_begin}{str_letter}, {str_property}, {str_decorations}{str_end}\n"
+        );
         write!(file, "{content}").expect("Unable to write the file");
     }
 
     // File footer
     write!(file, "{FOOTER}").expect("Unable to write the file");
+
+    // Unicode names of all endpoints
+    write!(file, "{NAMES_HEADER}").expect("Unable to write the file");
+    for end_point in &endpoints {
+        writeln!(
+            file,
+            "    (0x{:X}, {:?}),",
+            end_point.code_point, end_point.description
+        )
+        .expect("Unable to write the file");
+    }
+    write!(file, "{NAMES_FOOTER}").expect("Unable to write the file");
+}
+
+/// Generate the rust source file for the static table containing the Cyrillic code points
+pub fn generate_cyrillic_rust_file(transliterations: Vec<Transliteration>) {
+    generate_transliteration_rust_file(
+        OUTPUT_CYRILLIC_RUST_FILE,
+        CYRILLIC_HEADER,
+        CYRILLIC_FOOTER,
+        transliterations,
+    );
+}
+
+/// Generate the rust source file for the static table containing the Greek code points
+pub fn generate_greek_rust_file(transliterations: Vec<Transliteration>) {
+    generate_transliteration_rust_file(
+        OUTPUT_GREEK_RUST_FILE,
+        GREEK_HEADER,
+        GREEK_FOOTER,
+        transliterations,
+    );
+}
+
+/// Generate a rust source file for a static table of transliterated code points: property, decorations and
+/// uppercase AZ equivalent for each standard (a single `Letter` instead of an array for a single standard)
+fn generate_transliteration_rust_file(
+    filename: &str,
+    header: &str,
+    footer: &str,
+    mut transliterations: Vec<Transliteration>,
+) {
+    sort_by_code_point(&mut transliterations, |transliteration| {
+        transliteration.code_point
+    });
+
+    let mut file = create_rust_file(filename);
+
+    write!(file, "{header}").expect("Unable to write the file");
+    for transliteration in transliterations {
+        let property = property_source(
+            transliteration.is_capital,
+            transliteration.is_small,
+            !transliteration.decorations.is_empty(),
+        );
+        let decorations = decorations_source(&transliteration.decorations);
+        let letters = transliteration
+            .letters
+            .iter()
+            .map(|letters| letter_source(letters))
+            .collect::<Vec<String>>();
+        let letters = match letters.as_slice() {
+            [letter] => letter.clone(),
+            _ => format!("[{}]", letters.join(", ")),
+        };
+        writeln!(
+            file,
+            "    (0x{:X}, ({property}, {decorations}, {letters})),",
+            transliteration.code_point
+        )
+        .expect("Unable to write the file");
+    }
+    write!(file, "{footer}").expect("Unable to write the file");
 }
@@ -0,0 +1,298 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static GREEK: &[(u32, (Property, Decorations, Letter))] = &[
+    (0x386, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('A'))),
+    (0x388, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('E'))),
+    (0x389, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('I'))),
+    (0x38A, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('I'))),
+    (0x38C, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('O'))),
+    (0x38E, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('Y'))),
+    (0x38F, (Property::CapitalWithDecoration, Decorations::TONOS, Letter::Letter('O'))),
+    (0x390, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::TONOS), Letter::Letter('I'))),
+    (0x391, (Property::Capital, Decorations::empty(), Letter::Letter('A'))),
+    (0x392, (Property::Capital, Decorations::empty(), Letter::Letter('V'))),
+    (0x393, (Property::Capital, Decorations::empty(), Letter::Letter('G'))),
+    (0x394, (Property::Capital, Decorations::empty(), Letter::Letter('D'))),
+    (0x395, (Property::Capital, Decorations::empty(), Letter::Letter('E'))),
+    (0x396, (Property::Capital, Decorations::empty(), Letter::Letter('Z'))),
+    (0x397, (Property::Capital, Decorations::empty(), Letter::Letter('I'))),
+    (0x398, (Property::Capital, Decorations::empty(), Letter::Letters('T', 'H'))),
+    (0x399, (Property::Capital, Decorations::empty(), Letter::Letter('I'))),
+    (0x39A, (Property::Capital, Decorations::empty(), Letter::Letter('K'))),
+    (0x39B, (Property::Capital, Decorations::empty(), Letter::Letter('L'))),
+    (0x39C, (Property::Capital, Decorations::empty(), Letter::Letter('M'))),
+    (0x39D, (Property::Capital, Decorations::empty(), Letter::Letter('N'))),
+    (0x39E, (Property::Capital, Decorations::empty(), Letter::Letter('X'))),
+    (0x39F, (Property::Capital, Decorations::empty(), Letter::Letter('O'))),
+    (0x3A0, (Property::Capital, Decorations::empty(), Letter::Letter('P'))),
+    (0x3A1, (Property::Capital, Decorations::empty(), Letter::Letter('R'))),
+    (0x3A3, (Property::Capital, Decorations::empty(), Letter::Letter('S'))),
+    (0x3A4, (Property::Capital, Decorations::empty(), Letter::Letter('T'))),
+    (0x3A5, (Property::Capital, Decorations::empty(), Letter::Letter('Y'))),
+    (0x3A6, (Property::Capital, Decorations::empty(), Letter::Letter('F'))),
+    (0x3A7, (Property::Capital, Decorations::empty(), Letter::Letters('C', 'H'))),
+    (0x3A8, (Property::Capital, Decorations::empty(), Letter::Letters('P', 'S'))),
+    (0x3A9, (Property::Capital, Decorations::empty(), Letter::Letter('O'))),
+    (0x3AA, (Property::CapitalWithDecoration, Decorations::DIALYTIKA, Letter::Letter('I'))),
+    (0x3AB, (Property::CapitalWithDecoration, Decorations::DIALYTIKA, Letter::Letter('Y'))),
+    (0x3AC, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('A'))),
+    (0x3AD, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('E'))),
+    (0x3AE, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('I'))),
+    (0x3AF, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('I'))),
+    (0x3B0, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::TONOS), Letter::Letter('Y'))),
+    (0x3B1, (Property::Small, Decorations::empty(), Letter::Letter('A'))),
+    (0x3B2, (Property::Small, Decorations::empty(), Letter::Letter('V'))),
+    (0x3B3, (Property::Small, Decorations::empty(), Letter::Letter('G'))),
+    (0x3B4, (Property::Small, Decorations::empty(), Letter::Letter('D'))),
+    (0x3B5, (Property::Small, Decorations::empty(), Letter::Letter('E'))),
+    (0x3B6, (Property::Small, Decorations::empty(), Letter::Letter('Z'))),
+    (0x3B7, (Property::Small, Decorations::empty(), Letter::Letter('I'))),
+    (0x3B8, (Property::Small, Decorations::empty(), Letter::Letters('T', 'H'))),
+    (0x3B9, (Property::Small, Decorations::empty(), Letter::Letter('I'))),
+    (0x3BA, (Property::Small, Decorations::empty(), Letter::Letter('K'))),
+    (0x3BB, (Property::Small, Decorations::empty(), Letter::Letter('L'))),
+    (0x3BC, (Property::Small, Decorations::empty(), Letter::Letter('M'))),
+    (0x3BD, (Property::Small, Decorations::empty(), Letter::Letter('N'))),
+    (0x3BE, (Property::Small, Decorations::empty(), Letter::Letter('X'))),
+    (0x3BF, (Property::Small, Decorations::empty(), Letter::Letter('O'))),
+    (0x3C0, (Property::Small, Decorations::empty(), Letter::Letter('P'))),
+    (0x3C1, (Property::Small, Decorations::empty(), Letter::Letter('R'))),
+    (0x3C2, (Property::Small, Decorations::empty(), Letter::Letter('S'))),
+    (0x3C3, (Property::Small, Decorations::empty(), Letter::Letter('S'))),
+    (0x3C4, (Property::Small, Decorations::empty(), Letter::Letter('T'))),
+    (0x3C5, (Property::Small, Decorations::empty(), Letter::Letter('Y'))),
+    (0x3C6, (Property::Small, Decorations::empty(), Letter::Letter('F'))),
+    (0x3C7, (Property::Small, Decorations::empty(), Letter::Letters('C', 'H'))),
+    (0x3C8, (Property::Small, Decorations::empty(), Letter::Letters('P', 'S'))),
+    (0x3C9, (Property::Small, Decorations::empty(), Letter::Letter('O'))),
+    (0x3CA, (Property::SmallWithDecoration, Decorations::DIALYTIKA, Letter::Letter('I'))),
+    (0x3CB, (Property::SmallWithDecoration, Decorations::DIALYTIKA, Letter::Letter('Y'))),
+    (0x3CC, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('O'))),
+    (0x3CD, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('Y'))),
+    (0x3CE, (Property::SmallWithDecoration, Decorations::TONOS, Letter::Letter('O'))),
+    (0x1D26, (Property::SmallAndCapital, Decorations::empty(), Letter::Letter('G'))),
+    (0x1D27, (Property::SmallAndCapital, Decorations::empty(), Letter::Letter('L'))),
+    (0x1D28, (Property::SmallAndCapital, Decorations::empty(), Letter::Letter('P'))),
+    (0x1D29, (Property::SmallAndCapital, Decorations::empty(), Letter::Letter('R'))),
+    (0x1D2A, (Property::SmallAndCapital, Decorations::empty(), Letter::Letters('P', 'S'))),
+    (0x1F00, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('A'))),
+    (0x1F01, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('A'))),
+    (0x1F02, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('A'))),
+    (0x1F03, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('A'))),
+    (0x1F04, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('A'))),
+    (0x1F05, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('A'))),
+    (0x1F06, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('A'))),
+    (0x1F07, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('A'))),
+    (0x1F08, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('A'))),
+    (0x1F09, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('A'))),
+    (0x1F0A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('A'))),
+    (0x1F0B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('A'))),
+    (0x1F0C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('A'))),
+    (0x1F0D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('A'))),
+    (0x1F0E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('A'))),
+    (0x1F0F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('A'))),
+    (0x1F10, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('E'))),
+    (0x1F11, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('E'))),
+    (0x1F12, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('E'))),
+    (0x1F13, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('E'))),
+    (0x1F14, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('E'))),
+    (0x1F15, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('E'))),
+    (0x1F18, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('E'))),
+    (0x1F19, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('E'))),
+    (0x1F1A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('E'))),
+    (0x1F1B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('E'))),
+    (0x1F1C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('E'))),
+    (0x1F1D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('E'))),
+    (0x1F20, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('I'))),
+    (0x1F21, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('I'))),
+    (0x1F22, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F23, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F24, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F25, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F26, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F27, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F28, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('I'))),
+    (0x1F29, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('I'))),
+    (0x1F2A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F2B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F2C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F2D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F2E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F2F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F30, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('I'))),
+    (0x1F31, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('I'))),
+    (0x1F32, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F33, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F34, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F35, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F36, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F37, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F38, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('I'))),
+    (0x1F39, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('I'))),
+    (0x1F3A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F3B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1F3C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F3D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1F3E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F3F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1F40, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('O'))),
+    (0x1F41, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('O'))),
+    (0x1F42, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F43, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F44, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F45, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F48, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('O'))),
+    (0x1F49, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('O'))),
+    (0x1F4A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F4B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F4C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F4D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F50, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('Y'))),
+    (0x1F51, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('Y'))),
+    (0x1F52, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('Y'))),
+    (0x1F53, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('Y'))),
+    (0x1F54, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('Y'))),
+    (0x1F55, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('Y'))),
+    (0x1F56, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('Y'))),
+    (0x1F57, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('Y'))),
+    (0x1F59, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('Y'))),
+    (0x1F5B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('Y'))),
+    (0x1F5D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('Y'))),
+    (0x1F5F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('Y'))),
+    (0x1F60, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('O'))),
+    (0x1F61, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('O'))),
+    (0x1F62, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F63, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F64, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F65, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F66, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('O'))),
+    (0x1F67, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('O'))),
+    (0x1F68, (Property::CapitalWithDecoration, Decorations::PSILI, Letter::Letter('O'))),
+    (0x1F69, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('O'))),
+    (0x1F6A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F6B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA), Letter::Letter('O'))),
+    (0x1F6C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F6D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA), Letter::Letter('O'))),
+    (0x1F6E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI), Letter::Letter('O'))),
+    (0x1F6F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI), Letter::Letter('O'))),
+    (0x1F70, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('A'))),
+    (0x1F71, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('A'))),
+    (0x1F72, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('E'))),
+    (0x1F73, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('E'))),
+    (0x1F74, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('I'))),
+    (0x1F75, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('I'))),
+    (0x1F76, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('I'))),
+    (0x1F77, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('I'))),
+    (0x1F78, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('O'))),
+    (0x1F79, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('O'))),
+    (0x1F7A, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('Y'))),
+    (0x1F7B, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('Y'))),
+    (0x1F7C, (Property::SmallWithDecoration, Decorations::VARIA, Letter::Letter('O'))),
+    (0x1F7D, (Property::SmallWithDecoration, Decorations::OXIA, Letter::Letter('O'))),
+    (0x1F80, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F81, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F82, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F83, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F84, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F85, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F86, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F87, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F88, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F89, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F8F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('A'))),
+    (0x1F90, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F91, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F92, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F93, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F94, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F95, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F96, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F97, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F98, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F99, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9A, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9B, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9C, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9D, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9E, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1F9F, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('I'))),
+    (0x1FA0, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA1, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA2, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA3, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA4, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA5, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA6, (Property::SmallWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA7, (Property::SmallWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA8, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FA9, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAA, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAB, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::VARIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAC, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAD, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::OXIA).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAE, (Property::CapitalWithDecoration, Decorations::PSILI.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FAF, (Property::CapitalWithDecoration, Decorations::DASIA.union(Decorations::PERISPOMENI).union(Decorations::PROSGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FB0, (Property::SmallWithDecoration, Decorations::VRACHY, Letter::Letter('A'))),
+    (0x1FB1, (Property::SmallWithDecoration, Decorations::MACRON, Letter::Letter('A'))),
+    (0x1FB2, (Property::SmallWithDecoration, Decorations::VARIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1FB3, (Property::SmallWithDecoration, Decorations::YPOGEGRAMMENI, Letter::Letter('A'))),
+    (0x1FB4, (Property::SmallWithDecoration, Decorations::OXIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1FB6, (Property::SmallWithDecoration, Decorations::PERISPOMENI, Letter::Letter('A'))),
+    (0x1FB7, (Property::SmallWithDecoration, Decorations::PERISPOMENI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('A'))),
+    (0x1FB8, (Property::CapitalWithDecoration, Decorations::VRACHY, Letter::Letter('A'))),
+    (0x1FB9, (Property::CapitalWithDecoration, Decorations::MACRON, Letter::Letter('A'))),
+    (0x1FBA, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('A'))),
+    (0x1FBB, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('A'))),
+    (0x1FBC, (Property::CapitalWithDecoration, Decorations::PROSGEGRAMMENI, Letter::Letter('A'))),
+    (0x1FC2, (Property::SmallWithDecoration, Decorations::VARIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1FC3, (Property::SmallWithDecoration, Decorations::YPOGEGRAMMENI, Letter::Letter('I'))),
+    (0x1FC4, (Property::SmallWithDecoration, Decorations::OXIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1FC6, (Property::SmallWithDecoration, Decorations::PERISPOMENI, Letter::Letter('I'))),
+    (0x1FC7, (Property::SmallWithDecoration, Decorations::PERISPOMENI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('I'))),
+    (0x1FC8, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('E'))),
+    (0x1FC9, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('E'))),
+    (0x1FCA, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('I'))),
+    (0x1FCB, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('I'))),
+    (0x1FCC, (Property::CapitalWithDecoration, Decorations::PROSGEGRAMMENI, Letter::Letter('I'))),
+    (0x1FD0, (Property::SmallWithDecoration, Decorations::VRACHY, Letter::Letter('I'))),
+    (0x1FD1, (Property::SmallWithDecoration, Decorations::MACRON, Letter::Letter('I'))),
+    (0x1FD2, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::VARIA), Letter::Letter('I'))),
+    (0x1FD3, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::OXIA), Letter::Letter('I'))),
+    (0x1FD6, (Property::SmallWithDecoration, Decorations::PERISPOMENI, Letter::Letter('I'))),
+    (0x1FD7, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::PERISPOMENI), Letter::Letter('I'))),
+    (0x1FD8, (Property::CapitalWithDecoration, Decorations::VRACHY, Letter::Letter('I'))),
+    (0x1FD9, (Property::CapitalWithDecoration, Decorations::MACRON, Letter::Letter('I'))),
+    (0x1FDA, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('I'))),
+    (0x1FDB, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('I'))),
+    (0x1FE0, (Property::SmallWithDecoration, Decorations::VRACHY, Letter::Letter('Y'))),
+    (0x1FE1, (Property::SmallWithDecoration, Decorations::MACRON, Letter::Letter('Y'))),
+    (0x1FE2, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::VARIA), Letter::Letter('Y'))),
+    (0x1FE3, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::OXIA), Letter::Letter('Y'))),
+    (0x1FE4, (Property::SmallWithDecoration, Decorations::PSILI, Letter::Letter('R'))),
+    (0x1FE5, (Property::SmallWithDecoration, Decorations::DASIA, Letter::Letter('R'))),
+    (0x1FE6, (Property::SmallWithDecoration, Decorations::PERISPOMENI, Letter::Letter('Y'))),
+    (0x1FE7, (Property::SmallWithDecoration, Decorations::DIALYTIKA.union(Decorations::PERISPOMENI), Letter::Letter('Y'))),
+    (0x1FE8, (Property::CapitalWithDecoration, Decorations::VRACHY, Letter::Letter('Y'))),
+    (0x1FE9, (Property::CapitalWithDecoration, Decorations::MACRON, Letter::Letter('Y'))),
+    (0x1FEA, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('Y'))),
+    (0x1FEB, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('Y'))),
+    (0x1FEC, (Property::CapitalWithDecoration, Decorations::DASIA, Letter::Letter('R'))),
+    (0x1FF2, (Property::SmallWithDecoration, Decorations::VARIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FF3, (Property::SmallWithDecoration, Decorations::YPOGEGRAMMENI, Letter::Letter('O'))),
+    (0x1FF4, (Property::SmallWithDecoration, Decorations::OXIA.union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FF6, (Property::SmallWithDecoration, Decorations::PERISPOMENI, Letter::Letter('O'))),
+    (0x1FF7, (Property::SmallWithDecoration, Decorations::PERISPOMENI.union(Decorations::YPOGEGRAMMENI), Letter::Letter('O'))),
+    (0x1FF8, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('O'))),
+    (0x1FF9, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('O'))),
+    (0x1FFA, (Property::CapitalWithDecoration, Decorations::VARIA, Letter::Letter('O'))),
+    (0x1FFB, (Property::CapitalWithDecoration, Decorations::OXIA, Letter::Letter('O'))),
+    (0x1FFC, (Property::CapitalWithDecoration, Decorations::PROSGEGRAMMENI, Letter::Letter('O'))),
+    (0xAB65, (Property::SmallAndCapital, Decorations::empty(), Letter::Letter('O'))),
+];
@@ -0,0 +1,262 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static KANA: &[(u32, (&str, bool))] = &[
+    (0x3041, ("A", true)),
+    (0x3042, ("A", false)),
+    (0x3043, ("I", true)),
+    (0x3044, ("I", false)),
+    (0x3045, ("U", true)),
+    (0x3046, ("U", false)),
+    (0x3047, ("E", true)),
+    (0x3048, ("E", false)),
+    (0x3049, ("O", true)),
+    (0x304A, ("O", false)),
+    (0x304B, ("KA", false)),
+    (0x304C, ("GA", false)),
+    (0x304D, ("KI", false)),
+    (0x304E, ("GI", false)),
+    (0x304F, ("KU", false)),
+    (0x3050, ("GU", false)),
+    (0x3051, ("KE", false)),
+    (0x3052, ("GE", false)),
+    (0x3053, ("KO", false)),
+    (0x3054, ("GO", false)),
+    (0x3055, ("SA", false)),
+    (0x3056, ("ZA", false)),
+    (0x3057, ("SHI", false)),
+    (0x3058, ("JI", false)),
+    (0x3059, ("SU", false)),
+    (0x305A, ("ZU", false)),
+    (0x305B, ("SE", false)),
+    (0x305C, ("ZE", false)),
+    (0x305D, ("SO", false)),
+    (0x305E, ("ZO", false)),
+    (0x305F, ("TA", false)),
+    (0x3060, ("DA", false)),
+    (0x3061, ("CHI", false)),
+    (0x3062, ("JI", false)),
+    (0x3063, ("TSU", true)),
+    (0x3064, ("TSU", false)),
+    (0x3065, ("ZU", false)),
+    (0x3066, ("TE", false)),
+    (0x3067, ("DE", false)),
+    (0x3068, ("TO", false)),
+    (0x3069, ("DO", false)),
+    (0x306A, ("NA", false)),
+    (0x306B, ("NI", false)),
+    (0x306C, ("NU", false)),
+    (0x306D, ("NE", false)),
+    (0x306E, ("NO", false)),
+    (0x306F, ("HA", false)),
+    (0x3070, ("BA", false)),
+    (0x3071, ("PA", false)),
+    (0x3072, ("HI", false)),
+    (0x3073, ("BI", false)),
+    (0x3074, ("PI", false)),
+    (0x3075, ("FU", false)),
+    (0x3076, ("BU", false)),
+    (0x3077, ("PU", false)),
+    (0x3078, ("HE", false)),
+    (0x3079, ("BE", false)),
+    (0x307A, ("PE", false)),
+    (0x307B, ("HO", false)),
+    (0x307C, ("BO", false)),
+    (0x307D, ("PO", false)),
+    (0x307E, ("MA", false)),
+    (0x307F, ("MI", false)),
+    (0x3080, ("MU", false)),
+    (0x3081, ("ME", false)),
+    (0x3082, ("MO", false)),
+    (0x3083, ("YA", true)),
+    (0x3084, ("YA", false)),
+    (0x3085, ("YU", true)),
+    (0x3086, ("YU", false)),
+    (0x3087, ("YO", true)),
+    (0x3088, ("YO", false)),
+    (0x3089, ("RA", false)),
+    (0x308A, ("RI", false)),
+    (0x308B, ("RU", false)),
+    (0x308C, ("RE", false)),
+    (0x308D, ("RO", false)),
+    (0x308E, ("WA", true)),
+    (0x308F, ("WA", false)),
+    (0x3090, ("I", false)),
+    (0x3091, ("E", false)),
+    (0x3092, ("O", false)),
+    (0x3093, ("N", false)),
+    (0x3094, ("VU", false)),
+    (0x3095, ("KA", true)),
+    (0x3096, ("KE", true)),
+    (0x30A1, ("A", true)),
+    (0x30A2, ("A", false)),
+    (0x30A3, ("I", true)),
+    (0x30A4, ("I", false)),
+    (0x30A5, ("U", true)),
+    (0x30A6, ("U", false)),
+    (0x30A7, ("E", true)),
+    (0x30A8, ("E", false)),
+    (0x30A9, ("O", true)),
+    (0x30AA, ("O", false)),
+    (0x30AB, ("KA", false)),
+    (0x30AC, ("GA", false)),
+    (0x30AD, ("KI", false)),
+    (0x30AE, ("GI", false)),
+    (0x30AF, ("KU", false)),
+    (0x30B0, ("GU", false)),
+    (0x30B1, ("KE", false)),
+    (0x30B2, ("GE", false)),
+    (0x30B3, ("KO", false)),
+    (0x30B4, ("GO", false)),
+    (0x30B5, ("SA", false)),
+    (0x30B6, ("ZA", false)),
+    (0x30B7, ("SHI", false)),
+    (0x30B8, ("JI", false)),
+    (0x30B9, ("SU", false)),
+    (0x30BA, ("ZU", false)),
+    (0x30BB, ("SE", false)),
+    (0x30BC, ("ZE", false)),
+    (0x30BD, ("SO", false)),
+    (0x30BE, ("ZO", false)),
+    (0x30BF, ("TA", false)),
+    (0x30C0, ("DA", false)),
+    (0x30C1, ("CHI", false)),
+    (0x30C2, ("JI", false)),
+    (0x30C3, ("TSU", true)),
+    (0x30C4, ("TSU", false)),
+    (0x30C5, ("ZU", false)),
+    (0x30C6, ("TE", false)),
+    (0x30C7, ("DE", false)),
+    (0x30C8, ("TO", false)),
+    (0x30C9, ("DO", false)),
+    (0x30CA, ("NA", false)),
+    (0x30CB, ("NI", false)),
+    (0x30CC, ("NU", false)),
+    (0x30CD, ("NE", false)),
+    (0x30CE, ("NO", false)),
+    (0x30CF, ("HA", false)),
+    (0x30D0, ("BA", false)),
+    (0x30D1, ("PA", false)),
+    (0x30D2, ("HI", false)),
+    (0x30D3, ("BI", false)),
+    (0x30D4, ("PI", false)),
+    (0x30D5, ("FU", false)),
+    (0x30D6, ("BU", false)),
+    (0x30D7, ("PU", false)),
+    (0x30D8, ("HE", false)),
+    (0x30D9, ("BE", false)),
+    (0x30DA, ("PE", false)),
+    (0x30DB, ("HO", false)),
+    (0x30DC, ("BO", false)),
+    (0x30DD, ("PO", false)),
+    (0x30DE, ("MA", false)),
+    (0x30DF, ("MI", false)),
+    (0x30E0, ("MU", false)),
+    (0x30E1, ("ME", false)),
+    (0x30E2, ("MO", false)),
+    (0x30E3, ("YA", true)),
+    (0x30E4, ("YA", false)),
+    (0x30E5, ("YU", true)),
+    (0x30E6, ("YU", false)),
+    (0x30E7, ("YO", true)),
+    (0x30E8, ("YO", false)),
+    (0x30E9, ("RA", false)),
+    (0x30EA, ("RI", false)),
+    (0x30EB, ("RU", false)),
+    (0x30EC, ("RE", false)),
+    (0x30ED, ("RO", false)),
+    (0x30EE, ("WA", true)),
+    (0x30EF, ("WA", false)),
+    (0x30F0, ("I", false)),
+    (0x30F1, ("E", false)),
+    (0x30F2, ("O", false)),
+    (0x30F3, ("N", false)),
+    (0x30F4, ("VU", false)),
+    (0x30F5, ("KA", true)),
+    (0x30F6, ("KE", true)),
+    (0x30F7, ("VA", false)),
+    (0x30F8, ("VI", false)),
+    (0x30F9, ("VE", false)),
+    (0x30FA, ("VO", false)),
+    (0x31F0, ("KU", true)),
+    (0x31F1, ("SHI", true)),
+    (0x31F2, ("SU", true)),
+    (0x31F3, ("TO", true)),
+    (0x31F4, ("NU", true)),
+    (0x31F5, ("HA", true)),
+    (0x31F6, ("HI", true)),
+    (0x31F7, ("FU", true)),
+    (0x31F8, ("HE", true)),
+    (0x31F9, ("HO", true)),
+    (0x31FA, ("MU", true)),
+    (0x31FB, ("RA", true)),
+    (0x31FC, ("RI", true)),
+    (0x31FD, ("RU", true)),
+    (0x31FE, ("RE", true)),
+    (0x31FF, ("RO", true)),
+    (0xFF66, ("O", false)),
+    (0xFF67, ("A", true)),
+    (0xFF68, ("I", true)),
+    (0xFF69, ("U", true)),
+    (0xFF6A, ("E", true)),
+    (0xFF6B, ("O", true)),
+    (0xFF6C, ("YA", true)),
+    (0xFF6D, ("YU", true)),
+    (0xFF6E, ("YO", true)),
+    (0xFF6F, ("TSU", true)),
+    (0xFF71, ("A", false)),
+    (0xFF72, ("I", false)),
+    (0xFF73, ("U", false)),
+    (0xFF74, ("E", false)),
+    (0xFF75, ("O", false)),
+    (0xFF76, ("KA", false)),
+    (0xFF77, ("KI", false)),
+    (0xFF78, ("KU", false)),
+    (0xFF79, ("KE", false)),
+    (0xFF7A, ("KO", false)),
+    (0xFF7B, ("SA", false)),
+    (0xFF7C, ("SHI", false)),
+    (0xFF7D, ("SU", false)),
+    (0xFF7E, ("SE", false)),
+    (0xFF7F, ("SO", false)),
+    (0xFF80, ("TA", false)),
+    (0xFF81, ("CHI", false)),
+    (0xFF82, ("TSU", false)),
+    (0xFF83, ("TE", false)),
+    (0xFF84, ("TO", false)),
+    (0xFF85, ("NA", false)),
+    (0xFF86, ("NI", false)),
+    (0xFF87, ("NU", false)),
+    (0xFF88, ("NE", false)),
+    (0xFF89, ("NO", false)),
+    (0xFF8A, ("HA", false)),
+    (0xFF8B, ("HI", false)),
+    (0xFF8C, ("FU", false)),
+    (0xFF8D, ("HE", false)),
+    (0xFF8E, ("HO", false)),
+    (0xFF8F, ("MA", false)),
+    (0xFF90, ("MI", false)),
+    (0xFF91, ("MU", false)),
+    (0xFF92, ("ME", false)),
+    (0xFF93, ("MO", false)),
+    (0xFF94, ("YA", false)),
+    (0xFF95, ("YU", false)),
+    (0xFF96, ("YO", false)),
+    (0xFF97, ("RA", false)),
+    (0xFF98, ("RI", false)),
+    (0xFF99, ("RU", false)),
+    (0xFF9A, ("RE", false)),
+    (0xFF9B, ("RO", false)),
+    (0xFF9C, ("WA", false)),
+    (0xFF9D, ("N", false)),
+    (0x1B132, ("KO", true)),
+    (0x1B150, ("I", true)),
+    (0x1B151, ("E", true)),
+    (0x1B152, ("O", true)),
+    (0x1B155, ("KO", true)),
+    (0x1B164, ("I", true)),
+    (0x1B165, ("E", true)),
+    (0x1B166, ("O", true)),
+    (0x1B167, ("N", true)),
+];
@@ -0,0 +1,200 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static PUNCTUATION: &[(u32, &str)] = &[
+    (0xA0, " "),
+    (0xAB, "\""),
+    (0xBB, "\""),
+    (0x2BC, "'"),
+    (0x2EE, "\""),
+    (0x37E, ";"),
+    (0x55A, "'"),
+    (0x58A, "-"),
+    (0x5BE, "-"),
+    (0x7F4, "'"),
+    (0x7F5, "'"),
+    (0x1400, "-"),
+    (0x1680, " "),
+    (0x1806, "-"),
+    (0x1FEF, "`"),
+    (0x2000, " "),
+    (0x2001, " "),
+    (0x2002, " "),
+    (0x2003, " "),
+    (0x2004, " "),
+    (0x2005, " "),
+    (0x2006, " "),
+    (0x2007, " "),
+    (0x2008, " "),
+    (0x2009, " "),
+    (0x200A, " "),
+    (0x2010, "-"),
+    (0x2011, "-"),
+    (0x2012, "-"),
+    (0x2013, "-"),
+    (0x2014, "-"),
+    (0x2015, "-"),
+    (0x2018, "'"),
+    (0x2019, "'"),
+    (0x201A, "'"),
+    (0x201B, "'"),
+    (0x201C, "\""),
+    (0x201D, "\""),
+    (0x201E, "\""),
+    (0x201F, "\""),
+    (0x2024, "."),
+    (0x2025, ".."),
+    (0x2026, "..."),
+    (0x202F, " "),
+    (0x2039, "'"),
+    (0x203A, "'"),
+    (0x203C, "!!"),
+    (0x2045, "["),
+    (0x2046, "]"),
+    (0x2047, "??"),
+    (0x2048, "?!"),
+    (0x2049, "!?"),
+    (0x205F, " "),
+    (0x207A, "+"),
+    (0x207C, "="),
+    (0x207D, "("),
+    (0x207E, ")"),
+    (0x208A, "+"),
+    (0x208C, "="),
+    (0x208D, "("),
+    (0x208E, ")"),
+    (0x2768, "("),
+    (0x2769, ")"),
+    (0x276A, "("),
+    (0x276B, ")"),
+    (0x276E, "\""),
+    (0x276F, "\""),
+    (0x2774, "{"),
+    (0x2775, "}"),
+    (0x27E6, "["),
+    (0x27E7, "]"),
+    (0x27EE, "("),
+    (0x27EF, ")"),
+    (0x2983, "{"),
+    (0x2984, "}"),
+    (0x2985, "("),
+    (0x2986, ")"),
+    (0x298B, "["),
+    (0x298C, "]"),
+    (0x298D, "["),
+    (0x298E, "]"),
+    (0x298F, "["),
+    (0x2990, "]"),
+    (0x2A74, "::="),
+    (0x2A75, "=="),
+    (0x2A76, "==="),
+    (0x2E17, "-"),
+    (0x2E1A, "-"),
+    (0x2E28, "("),
+    (0x2E29, ")"),
+    (0x2E3A, "-"),
+    (0x2E3B, "-"),
+    (0x2E40, "-"),
+    (0x2E42, "\""),
+    (0x2E55, "["),
+    (0x2E56, "]"),
+    (0x2E57, "["),
+    (0x2E58, "]"),
+    (0x2E59, "("),
+    (0x2E5A, ")"),
+    (0x2E5B, "("),
+    (0x2E5C, ")"),
+    (0x2E5D, "-"),
+    (0x3000, " "),
+    (0x301A, "["),
+    (0x301B, "]"),
+    (0x301C, "-"),
+    (0x301D, "\""),
+    (0x301E, "\""),
+    (0x301F, "\""),
+    (0x3030, "-"),
+    (0x30A0, "-"),
+    (0xFB29, "+"),
+    (0xFD3E, "("),
+    (0xFD3F, ")"),
+    (0xFE10, ","),
+    (0xFE13, ":"),
+    (0xFE14, ";"),
+    (0xFE15, "!"),
+    (0xFE16, "?"),
+    (0xFE19, "..."),
+    (0xFE30, ".."),
+    (0xFE31, "-"),
+    (0xFE32, "-"),
+    (0xFE33, "_"),
+    (0xFE34, "_"),
+    (0xFE35, "("),
+    (0xFE36, ")"),
+    (0xFE37, "{"),
+    (0xFE38, "}"),
+    (0xFE47, "["),
+    (0xFE48, "]"),
+    (0xFE4D, "_"),
+    (0xFE4E, "_"),
+    (0xFE4F, "_"),
+    (0xFE50, ","),
+    (0xFE52, "."),
+    (0xFE54, ";"),
+    (0xFE55, ":"),
+    (0xFE56, "?"),
+    (0xFE57, "!"),
+    (0xFE58, "-"),
+    (0xFE59, "("),
+    (0xFE5A, ")"),
+    (0xFE5B, "{"),
+    (0xFE5C, "}"),
+    (0xFE5F, "#"),
+    (0xFE60, "&"),
+    (0xFE61, "*"),
+    (0xFE62, "+"),
+    (0xFE63, "-"),
+    (0xFE64, "<"),
+    (0xFE65, ">"),
+    (0xFE66, "="),
+    (0xFE68, "\\"),
+    (0xFE69, "$"),
+    (0xFE6A, "%"),
+    (0xFE6B, "@"),
+    (0xFF01, "!"),
+    (0xFF02, "\""),
+    (0xFF03, "#"),
+    (0xFF04, "$"),
+    (0xFF05, "%"),
+    (0xFF06, "&"),
+    (0xFF07, "'"),
+    (0xFF08, "("),
+    (0xFF09, ")"),
+    (0xFF0A, "*"),
+    (0xFF0B, "+"),
+    (0xFF0C, ","),
+    (0xFF0D, "-"),
+    (0xFF0E, "."),
+    (0xFF0F, "/"),
+    (0xFF1A, ":"),
+    (0xFF1B, ";"),
+    (0xFF1C, "<"),
+    (0xFF1D, "="),
+    (0xFF1E, ">"),
+    (0xFF1F, "?"),
+    (0xFF20, "@"),
+    (0xFF3B, "["),
+    (0xFF3C, "\\"),
+    (0xFF3D, "]"),
+    (0xFF3E, "^"),
+    (0xFF3F, "_"),
+    (0xFF40, "`"),
+    (0xFF5B, "{"),
+    (0xFF5C, "|"),
+    (0xFF5D, "}"),
+    (0xFF5E, "~"),
+    (0xFF5F, "("),
+    (0xFF60, ")"),
+    (0x10D6E, "-"),
+    (0x10EAD, "-"),
+];
@@ -0,0 +1,318 @@
+// DO NOT MODIFY
+// This file is automatically generated by command 'cargo run generate'
+//
+#[rustfmt::skip]
+static SYMBOLS: &[(u32, Symbol)] = &[
+    (0x2120, Symbol::Abbreviation),
+    (0x2122, Symbol::Abbreviation),
+    (0x249C, Symbol::EnclosedLetter),
+    (0x249D, Symbol::EnclosedLetter),
+    (0x249E, Symbol::EnclosedLetter),
+    (0x249F, Symbol::EnclosedLetter),
+    (0x24A0, Symbol::EnclosedLetter),
+    (0x24A1, Symbol::EnclosedLetter),
+    (0x24A2, Symbol::EnclosedLetter),
+    (0x24A3, Symbol::EnclosedLetter),
+    (0x24A4, Symbol::EnclosedLetter),
+    (0x24A5, Symbol::EnclosedLetter),
+    (0x24A6, Symbol::EnclosedLetter),
+    (0x24A7, Symbol::EnclosedLetter),
+    (0x24A8, Symbol::EnclosedLetter),
+    (0x24A9, Symbol::EnclosedLetter),
+    (0x24AA, Symbol::EnclosedLetter),
+    (0x24AB, Symbol::EnclosedLetter),
+    (0x24AC, Symbol::EnclosedLetter),
+    (0x24AD, Symbol::EnclosedLetter),
+    (0x24AE, Symbol::EnclosedLetter),
+    (0x24AF, Symbol::EnclosedLetter),
+    (0x24B0, Symbol::EnclosedLetter),
+    (0x24B1, Symbol::EnclosedLetter),
+    (0x24B2, Symbol::EnclosedLetter),
+    (0x24B3, Symbol::EnclosedLetter),
+    (0x24B4, Symbol::EnclosedLetter),
+    (0x24B5, Symbol::EnclosedLetter),
+    (0x24B6, Symbol::EnclosedLetter),
+    (0x24B7, Symbol::EnclosedLetter),
+    (0x24B8, Symbol::EnclosedLetter),
+    (0x24B9, Symbol::EnclosedLetter),
+    (0x24BA, Symbol::EnclosedLetter),
+    (0x24BB, Symbol::EnclosedLetter),
+    (0x24BC, Symbol::EnclosedLetter),
+    (0x24BD, Symbol::EnclosedLetter),
+    (0x24BE, Symbol::EnclosedLetter),
+    (0x24BF, Symbol::EnclosedLetter),
+    (0x24C0, Symbol::EnclosedLetter),
+    (0x24C1, Symbol::EnclosedLetter),
+    (0x24C2, Symbol::EnclosedLetter),
+    (0x24C3, Symbol::EnclosedLetter),
+    (0x24C4, Symbol::EnclosedLetter),
+    (0x24C5, Symbol::EnclosedLetter),
+    (0x24C6, Symbol::EnclosedLetter),
+    (0x24C7, Symbol::EnclosedLetter),
+    (0x24C8, Symbol::EnclosedLetter),
+    (0x24C9, Symbol::EnclosedLetter),
+    (0x24CA, Symbol::EnclosedLetter),
+    (0x24CB, Symbol::EnclosedLetter),
+    (0x24CC, Symbol::EnclosedLetter),
+    (0x24CD, Symbol::EnclosedLetter),
+    (0x24CE, Symbol::EnclosedLetter),
+    (0x24CF, Symbol::EnclosedLetter),
+    (0x24D0, Symbol::EnclosedLetter),
+    (0x24D1, Symbol::EnclosedLetter),
+    (0x24D2, Symbol::EnclosedLetter),
+    (0x24D3, Symbol::EnclosedLetter),
+    (0x24D4, Symbol::EnclosedLetter),
+    (0x24D5, Symbol::EnclosedLetter),
+    (0x24D6, Symbol::EnclosedLetter),
+    (0x24D7, Symbol::EnclosedLetter),
+    (0x24D8, Symbol::EnclosedLetter),
+    (0x24D9, Symbol::EnclosedLetter),
+    (0x24DA, Symbol::EnclosedLetter),
+    (0x24DB, Symbol::EnclosedLetter),
+    (0x24DC, Symbol::EnclosedLetter),
+    (0x24DD, Symbol::EnclosedLetter),
+    (0x24DE, Symbol::EnclosedLetter),
+    (0x24DF, Symbol::EnclosedLetter),
+    (0x24E0, Symbol::EnclosedLetter),
+    (0x24E1, Symbol::EnclosedLetter),
+    (0x24E2, Symbol::EnclosedLetter),
+    (0x24E3, Symbol::EnclosedLetter),
+    (0x24E4, Symbol::EnclosedLetter),
+    (0x24E5, Symbol::EnclosedLetter),
+    (0x24E6, Symbol::EnclosedLetter),
+    (0x24E7, Symbol::EnclosedLetter),
+    (0x24E8, Symbol::EnclosedLetter),
+    (0x24E9, Symbol::EnclosedLetter),
+    (0x3250, Symbol::Abbreviation),
+    (0x32CC, Symbol::Abbreviation),
+    (0x32CD, Symbol::Abbreviation),
+    (0x32CE, Symbol::Abbreviation),
+    (0x32CF, Symbol::Abbreviation),
+    (0x3371, Symbol::Abbreviation),
+    (0x3372, Symbol::Abbreviation),
+    (0x3373, Symbol::Abbreviation),
+    (0x3374, Symbol::Abbreviation),
+    (0x3375, Symbol::Abbreviation),
+    (0x3376, Symbol::Abbreviation),
+    (0x3377, Symbol::Abbreviation),
+    (0x337A, Symbol::Abbreviation),
+    (0x3380, Symbol::Abbreviation),
+    (0x3381, Symbol::Abbreviation),
+    (0x3383, Symbol::Abbreviation),
+    (0x3384, Symbol::Abbreviation),
+    (0x3385, Symbol::Abbreviation),
+    (0x3386, Symbol::Abbreviation),
+    (0x3387, Symbol::Abbreviation),
+    (0x3388, Symbol::Abbreviation),
+    (0x3389, Symbol::Abbreviation),
+    (0x338A, Symbol::Abbreviation),
+    (0x338B, Symbol::Abbreviation),
+    (0x338E, Symbol::Abbreviation),
+    (0x338F, Symbol::Abbreviation),
+    (0x3390, Symbol::Abbreviation),
+    (0x3391, Symbol::Abbreviation),
+    (0x3392, Symbol::Abbreviation),
+    (0x3393, Symbol::Abbreviation),
+    (0x3394, Symbol::Abbreviation),
+    (0x3396, Symbol::Abbreviation),
+    (0x3397, Symbol::Abbreviation),
+    (0x3398, Symbol::Abbreviation),
+    (0x3399, Symbol::Abbreviation),
+    (0x339A, Symbol::Abbreviation),
+    (0x339C, Symbol::Abbreviation),
+    (0x339D, Symbol::Abbreviation),
+    (0x339E, Symbol::Abbreviation),
+    (0x33A9, Symbol::Abbreviation),
+    (0x33AA, Symbol::Abbreviation),
+    (0x33AB, Symbol::Abbreviation),
+    (0x33AC, Symbol::Abbreviation),
+    (0x33AD, Symbol::Abbreviation),
+    (0x33B0, Symbol::Abbreviation),
+    (0x33B1, Symbol::Abbreviation),
+    (0x33B3, Symbol::Abbreviation),
+    (0x33B4, Symbol::Abbreviation),
+    (0x33B5, Symbol::Abbreviation),
+    (0x33B7, Symbol::Abbreviation),
+    (0x33B8, Symbol::Abbreviation),
+    (0x33B9, Symbol::Abbreviation),
+    (0x33BA, Symbol::Abbreviation),
+    (0x33BB, Symbol::Abbreviation),
+    (0x33BD, Symbol::Abbreviation),
+    (0x33BE, Symbol::Abbreviation),
+    (0x33BF, Symbol::Abbreviation),
+    (0x33C2, Symbol::Abbreviation),
+    (0x33C3, Symbol::Abbreviation),
+    (0x33C4, Symbol::Abbreviation),
+    (0x33C5, Symbol::Abbreviation),
+    (0x33C7, Symbol::Abbreviation),
+    (0x33C8, Symbol::Abbreviation),
+    (0x33C9, Symbol::Abbreviation),
+    (0x33CA, Symbol::Abbreviation),
+    (0x33CB, Symbol::Abbreviation),
+    (0x33CC, Symbol::Abbreviation),
+    (0x33CD, Symbol::Abbreviation),
+    (0x33CE, Symbol::Abbreviation),
+    (0x33CF, Symbol::Abbreviation),
+    (0x33D0, Symbol::Abbreviation),
+    (0x33D1, Symbol::Abbreviation),
+    (0x33D2, Symbol::Abbreviation),
+    (0x33D3, Symbol::Abbreviation),
+    (0x33D4, Symbol::Abbreviation),
+    (0x33D5, Symbol::Abbreviation),
+    (0x33D6, Symbol::Abbreviation),
+    (0x33D7, Symbol::Abbreviation),
+    (0x33D8, Symbol::Abbreviation),
+    (0x33D9, Symbol::Abbreviation),
+    (0x33DA, Symbol::Abbreviation),
+    (0x33DB, Symbol::Abbreviation),
+    (0x33DC, Symbol::Abbreviation),
+    (0x33DD, Symbol::Abbreviation),
+    (0x33FF, Symbol::Abbreviation),
+    (0x1F110, Symbol::EnclosedLetter),
+    (0x1F111, Symbol::EnclosedLetter),
+    (0x1F112, Symbol::EnclosedLetter),
+    (0x1F113, Symbol::EnclosedLetter),
+    (0x1F114, Symbol::EnclosedLetter),
+    (0x1F115, Symbol::EnclosedLetter),
+    (0x1F116, Symbol::EnclosedLetter),
+    (0x1F117, Symbol::EnclosedLetter),
+    (0x1F118, Symbol::EnclosedLetter),
+    (0x1F119, Symbol::EnclosedLetter),
+    (0x1F11A, Symbol::EnclosedLetter),
+    (0x1F11B, Symbol::EnclosedLetter),
+    (0x1F11C, Symbol::EnclosedLetter),
+    (0x1F11D, Symbol::EnclosedLetter),
+    (0x1F11E, Symbol::EnclosedLetter),
+    (0x1F11F, Symbol::EnclosedLetter),
+    (0x1F120, Symbol::EnclosedLetter),
+    (0x1F121, Symbol::EnclosedLetter),
+    (0x1F122, Symbol::EnclosedLetter),
+    (0x1F123, Symbol::EnclosedLetter),
+    (0x1F124, Symbol::EnclosedLetter),
+    (0x1F125, Symbol::EnclosedLetter),
+    (0x1F126, Symbol::EnclosedLetter),
+    (0x1F127, Symbol::EnclosedLetter),
+    (0x1F128, Symbol::EnclosedLetter),
+    (0x1F129, Symbol::EnclosedLetter),
+    (0x1F12A, Symbol::EnclosedLetter),
+    (0x1F12B, Symbol::EnclosedLetter),
+    (0x1F12C, Symbol::EnclosedLetter),
+    (0x1F12D, Symbol::Abbreviation),
+    (0x1F12E, Symbol::Abbreviation),
+    (0x1F130, Symbol::EnclosedLetter),
+    (0x1F131, Symbol::EnclosedLetter),
+    (0x1F132, Symbol::EnclosedLetter),
+    (0x1F133, Symbol::EnclosedLetter),
+    (0x1F134, Symbol::EnclosedLetter),
+    (0x1F135, Symbol::EnclosedLetter),
+    (0x1F136, Symbol::EnclosedLetter),
+    (0x1F137, Symbol::EnclosedLetter),
+    (0x1F138, Symbol::EnclosedLetter),
+    (0x1F139, Symbol::EnclosedLetter),
+    (0x1F13A, Symbol::EnclosedLetter),
+    (0x1F13B, Symbol::EnclosedLetter),
+    (0x1F13C, Symbol::EnclosedLetter),
+    (0x1F13D, Symbol::EnclosedLetter),
+    (0x1F13E, Symbol::EnclosedLetter),
+    (0x1F13F, Symbol::EnclosedLetter),
+    (0x1F140, Symbol::EnclosedLetter),
+    (0x1F141, Symbol::EnclosedLetter),
+    (0x1F142, Symbol::EnclosedLetter),
+    (0x1F143, Symbol::EnclosedLetter),
+    (0x1F144, Symbol::EnclosedLetter),
+    (0x1F145, Symbol::EnclosedLetter),
+    (0x1F146, Symbol::EnclosedLetter),
+    (0x1F147, Symbol::EnclosedLetter),
+    (0x1F148, Symbol::EnclosedLetter),
+    (0x1F149, Symbol::EnclosedLetter),
+    (0x1F14A, Symbol::Abbreviation),
+    (0x1F14B, Symbol::Abbreviation),
+    (0x1F14C, Symbol::Abbreviation),
+    (0x1F14D, Symbol::Abbreviation),
+    (0x1F14E, Symbol::Abbreviation),
+    (0x1F14F, Symbol::Abbreviation),
+    (0x1F150, Symbol::EnclosedLetter),
+    (0x1F151, Symbol::EnclosedLetter),
+    (0x1F152, Symbol::EnclosedLetter),
+    (0x1F153, Symbol::EnclosedLetter),
+    (0x1F154, Symbol::EnclosedLetter),
+    (0x1F155, Symbol::EnclosedLetter),
+    (0x1F156, Symbol::EnclosedLetter),
+    (0x1F157, Symbol::EnclosedLetter),
+    (0x1F158, Symbol::EnclosedLetter),
+    (0x1F159, Symbol::EnclosedLetter),
+    (0x1F15A, Symbol::EnclosedLetter),
+    (0x1F15B, Symbol::EnclosedLetter),
+    (0x1F15C, Symbol::EnclosedLetter),
+    (0x1F15D, Symbol::EnclosedLetter),
+    (0x1F15E, Symbol::EnclosedLetter),
+    (0x1F15F, Symbol::EnclosedLetter),
+    (0x1F160, Symbol::EnclosedLetter),
+    (0x1F161, Symbol::EnclosedLetter),
+    (0x1F162, Symbol::EnclosedLetter),
+    (0x1F163, Symbol::EnclosedLetter),
+    (0x1F164, Symbol::EnclosedLetter),
+    (0x1F165, Symbol::EnclosedLetter),
+    (0x1F166, Symbol::EnclosedLetter),
+    (0x1F167, Symbol::EnclosedLetter),
+    (0x1F168, Symbol::EnclosedLetter),
+    (0x1F169, Symbol::EnclosedLetter),
+    (0x1F16A, Symbol::Abbreviation),
+    (0x1F16B, Symbol::Abbreviation),
+    (0x1F16C, Symbol::Abbreviation),
+    (0x1F170, Symbol::EnclosedLetter),
+    (0x1F171, Symbol::EnclosedLetter),
+    (0x1F172, Symbol::EnclosedLetter),
+    (0x1F173, Symbol::EnclosedLetter),
+    (0x1F174, Symbol::EnclosedLetter),
+    (0x1F175, Symbol::EnclosedLetter),
+    (0x1F176, Symbol::EnclosedLetter),
+    (0x1F177, Symbol::EnclosedLetter),
+    (0x1F178, Symbol::EnclosedLetter),
+    (0x1F179, Symbol::EnclosedLetter),
+    (0x1F17A, Symbol::EnclosedLetter),
+    (0x1F17B, Symbol::EnclosedLetter),
+    (0x1F17C, Symbol::EnclosedLetter),
+    (0x1F17D, Symbol::EnclosedLetter),
+    (0x1F17E, Symbol::EnclosedLetter),
+    (0x1F17F, Symbol::EnclosedLetter),
+    (0x1F180, Symbol::EnclosedLetter),
+    (0x1F181, Symbol::EnclosedLetter),
+    (0x1F182, Symbol::EnclosedLetter),
+    (0x1F183, Symbol::EnclosedLetter),
+    (0x1F184, Symbol::EnclosedLetter),
+    (0x1F185, Symbol::EnclosedLetter),
+    (0x1F186, Symbol::EnclosedLetter),
+    (0x1F187, Symbol::EnclosedLetter),
+    (0x1F188, Symbol::EnclosedLetter),
+    (0x1F189, Symbol::EnclosedLetter),
+    (0x1F18A, Symbol::EnclosedLetter),
+    (0x1F190, Symbol::Abbreviation),
+    (0x1F1A5, Symbol::EnclosedLetter),
+    (0x1F1E6, Symbol::RegionalIndicator),
+    (0x1F1E7, Symbol::RegionalIndicator),
+    (0x1F1E8, Symbol::RegionalIndicator),
+    (0x1F1E9, Symbol::RegionalIndicator),
+    (0x1F1EA, Symbol::RegionalIndicator),
+    (0x1F1EB, Symbol::RegionalIndicator),
+    (0x1F1EC, Symbol::RegionalIndicator),
+    (0x1F1ED, Symbol::RegionalIndicator),
+    (0x1F1EE, Symbol::RegionalIndicator),
+    (0x1F1EF, Symbol::RegionalIndicator),
+    (0x1F1F0, Symbol::RegionalIndicator),
+    (0x1F1F1, Symbol::RegionalIndicator),
+    (0x1F1F2, Symbol::RegionalIndicator),
+    (0x1F1F3, Symbol::RegionalIndicator),
+    (0x1F1F4, Symbol::RegionalIndicator),
+    (0x1F1F5, Symbol::RegionalIndicator),
+    (0x1F1F6, Symbol::RegionalIndicator),
+    (0x1F1F7, Symbol::RegionalIndicator),
+    (0x1F1F8, Symbol::RegionalIndicator),
+    (0x1F1F9, Symbol::RegionalIndicator),
+    (0x1F1FA, Symbol::RegionalIndicator),
+    (0x1F1FB, Symbol::RegionalIndicator),
+    (0x1F1FC, Symbol::RegionalIndicator),
+    (0x1F1FD, Symbol::RegionalIndicator),
+    (0x1F1FE, Symbol::RegionalIndicator),
+    (0x1F1FF, Symbol::RegionalIndicator),
+];
@@ -2,1446 +2,4582 @@
 // This file is automatically generated by command 'cargo run generate'
 //
 #[rustfmt::skip]
-lazy_static! {
-    static ref UPPERCASE_AZ: HashMap<u32, Uppercase> = {
-        let mut m = HashMap::new();
-        m.insert(0x41, Uppercase {letter: Letter::Letter('A'), property: Property::Capital});
-        m.insert(0x42, Uppercase {letter: Letter::Letter('B'), property: Property::Capital});
-        m.insert(0x43, Uppercase {letter: Letter::Letter('C'), property: Property::Capital});
-        m.insert(0x44, Uppercase {letter: Letter::Letter('D'), property: Property::Capital});
-        m.insert(0x45, Uppercase {letter: Letter::Letter('E'), property: Property::Capital});
-        m.insert(0x46, Uppercase {letter: Letter::Letter('F'), property: Property::Capital});
-        m.insert(0x47, Uppercase {letter: Letter::Letter('G'), property: Property::Capital});
-        m.insert(0x48, Uppercase {letter: Letter::Letter('H'), property: Property::Capital});
-        m.insert(0x49, Uppercase {letter: Letter::Letter('I'), property: Property::Capital});
-        m.insert(0x4A, Uppercase {letter: Letter::Letter('J'), property: Property::Capital});
-        m.insert(0x4B, Uppercase {letter: Letter::Letter('K'), property: Property::Capital});
-        m.insert(0x4C, Uppercase {letter: Letter::Letter('L'), property: Property::Capital});
-        m.insert(0x4D, Uppercase {letter: Letter::Letter('M'), property: Property::Capital});
-        m.insert(0x4E, Uppercase {letter: Letter::Letter('N'), property: Property::Capital});
-        m.insert(0x4F, Uppercase {letter: Letter::Letter('O'), property: Property::Capital});
-        m.insert(0x50, Uppercase {letter: Letter::Letter('P'), property: Property::Capital});
-        m.insert(0x51, Uppercase {letter: Letter::Letter('Q'), property: Property::Capital});
-        m.insert(0x52, Uppercase {letter: Letter::Letter('R'), property: Property::Capital});
-        m.insert(0x53, Uppercase {letter: Letter::Letter('S'), property: Property::Capital});
-        m.insert(0x54, Uppercase {letter: Letter::Letter('T'), property: Property::Capital});
-        m.insert(0x55, Uppercase {letter: Letter::Letter('U'), property: Property::Capital});
-        m.insert(0x56, Uppercase {letter: Letter::Letter('V'), property: Property::Capital});
-        m.insert(0x57, Uppercase {letter: Letter::Letter('W'), property: Property::Capital});
-        m.insert(0x58, Uppercase {letter: Letter::Letter('X'), property: Property::Capital});
-        m.insert(0x59, Uppercase {letter: Letter::Letter('Y'), property: Property::Capital});
-        m.insert(0x5A, Uppercase {letter: Letter::Letter('Z'), property: Property::Capital});
-        m.insert(0x61, Uppercase {letter: Letter::Letter('A'), property: Property::Small});
-        m.insert(0x62, Uppercase {letter: Letter::Letter('B'), property: Property::Small});
-        m.insert(0x63, Uppercase {letter: Letter::Letter('C'), property: Property::Small});
-        m.insert(0x64, Uppercase {letter: Letter::Letter('D'), property: Property::Small});
-        m.insert(0x65, Uppercase {letter: Letter::Letter('E'), property: Property::Small});
-        m.insert(0x66, Uppercase {letter: Letter::Letter('F'), property: Property::Small});
-        m.insert(0x67, Uppercase {letter: Letter::Letter('G'), property: Property::Small});
-        m.insert(0x68, Uppercase {letter: Letter::Letter('H'), property: Property::Small});
-        m.insert(0x69, Uppercase {letter: Letter::Letter('I'), property: Property::Small});
-        m.insert(0x6A, Uppercase {letter: Letter::Letter('J'), property: Property::Small});
-        m.insert(0x6B, Uppercase {letter: Letter::Letter('K'), property: Property::Small});
-        m.insert(0x6C, Uppercase {letter: Letter::Letter('L'), property: Property::Small});
-        m.insert(0x6D, Uppercase {letter: Letter::Letter('M'), property: Property::Small});
-        m.insert(0x6E, Uppercase {letter: Letter::Letter('N'), property: Property::Small});
-        m.insert(0x6F, Uppercase {letter: Letter::Letter('O'), property: Property::Small});
-        m.insert(0x70, Uppercase {letter: Letter::Letter('P'), property: Property::Small});
-        m.insert(0x71, Uppercase {letter: Letter::Letter('Q'), property: Property::Small});
-        m.insert(0x72, Uppercase {letter: Letter::Letter('R'), property: Property::Small});
-        m.insert(0x73, Uppercase {letter: Letter::Letter('S'), property: Property::Small});
-        m.insert(0x74, Uppercase {letter: Letter::Letter('T'), property: Property::Small});
-        m.insert(0x75, Uppercase {letter: Letter::Letter('U'), property: Property::Small});
-        m.insert(0x76, Uppercase {letter: Letter::Letter('V'), property: Property::Small});
-        m.insert(0x77, Uppercase {letter: Letter::Letter('W'), property: Property::Small});
-        m.insert(0x78, Uppercase {letter: Letter::Letter('X'), property: Property::Small});
-        m.insert(0x79, Uppercase {letter: Letter::Letter('Y'), property: Property::Small});
-        m.insert(0x7A, Uppercase {letter: Letter::Letter('Z'), property: Property::Small});
-        m.insert(0xC0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC1, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC3, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC5, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xC6, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::Capital});
-        m.insert(0xC7, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xC8, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xC9, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xCA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xCB, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xCC, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xCD, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xCE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xCF, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xD1, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0xD2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD3, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD5, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD6, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD8, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xD9, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xDA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xDB, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xDC, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xDD, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0xDF, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xE0, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE2, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE4, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE6, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::Small});
-        m.insert(0xE7, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xE8, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xE9, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xEA, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xEB, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xEC, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xED, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xEE, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xEF, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xF1, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xF2, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF4, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF5, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF6, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF8, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xF9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xFA, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xFB, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xFC, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xFD, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0xFF, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x100, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x101, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x102, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x103, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x104, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x105, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x106, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x107, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x108, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x109, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x10A, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x10B, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x10C, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x10D, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x10E, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x10F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x110, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x111, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x112, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x113, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x114, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x115, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x116, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x117, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x118, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x119, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x11A, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x11B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x11C, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x11D, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x11E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x11F, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x120, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x121, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x122, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x123, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x124, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x125, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x126, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x127, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x128, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x129, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x12A, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x12B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x12C, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x12D, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x12E, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x12F, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x130, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x131, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x132, Uppercase {letter: Letter::Letters('I', 'J'), property: Property::Capital});
-        m.insert(0x133, Uppercase {letter: Letter::Letters('I', 'J'), property: Property::Small});
-        m.insert(0x134, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x135, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x136, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x137, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x139, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x13A, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x13B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x13C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x13D, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x13E, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x13F, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x140, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x141, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x142, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x143, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x144, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x145, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x146, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x147, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x148, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x149, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x14C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x14D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x14E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x14F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x150, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x151, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x152, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::Capital});
-        m.insert(0x153, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::Small});
-        m.insert(0x154, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x155, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x156, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x157, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x158, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x159, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x15A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x15B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x15C, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x15D, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x15E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x15F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x160, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x161, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x162, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x163, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x164, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x165, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x166, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x167, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x168, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x169, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x16A, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x16B, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x16C, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x16D, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x16E, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x16F, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x170, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x171, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x172, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x173, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x174, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x175, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x176, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x177, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x178, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x179, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x17A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x17B, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x17C, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x17D, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x17E, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x17F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x180, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x181, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x182, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x183, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x186, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x187, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x188, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x189, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x18A, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x18B, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x18C, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x18E, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x190, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x191, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x192, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x193, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x195, Uppercase {letter: Letter::Letters('H', 'V'), property: Property::Small});
-        m.insert(0x197, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x198, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x199, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x19A, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x19C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x19D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x19E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x19F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1A0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1A1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1A2, Uppercase {letter: Letter::Letters('O', 'I'), property: Property::Capital});
-        m.insert(0x1A3, Uppercase {letter: Letter::Letters('O', 'I'), property: Property::Small});
-        m.insert(0x1A4, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1A5, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1A6, Uppercase {letter: Letter::Letters('Y', 'R'), property: Property::Unknown});
-        m.insert(0x1AB, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1AC, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1AD, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1AE, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1AF, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1B0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1B2, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1B3, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1B4, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1B5, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1B6, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1C4, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1C5, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1C6, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1C7, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::Capital});
-        m.insert(0x1C8, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1C9, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::Small});
-        m.insert(0x1CA, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::Capital});
-        m.insert(0x1CB, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1CC, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::Small});
-        m.insert(0x1CD, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CE, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1CF, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1D0, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1D1, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1D2, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D3, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1D4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D5, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1D6, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D7, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1D8, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D9, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1DA, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1DB, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1DC, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1DF, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1E0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1E2, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E3, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E4, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E5, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1E6, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E7, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1E8, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E9, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1EA, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1F0, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x1F1, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::Capital});
-        m.insert(0x1F2, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1F3, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::Small});
-        m.insert(0x1F4, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F5, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1F8, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F9, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1FA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1FB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1FC, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1FD, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0x1FE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1FF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x200, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x201, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x202, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x203, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x204, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x205, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x206, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x207, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x208, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x209, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x20A, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x20B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x20C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x20D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x20E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x20F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x210, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x211, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x212, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x213, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x214, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x215, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x216, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x217, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x218, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x219, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x21A, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x21B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x21E, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x21F, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x220, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x221, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x222, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::Capital});
-        m.insert(0x223, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::Small});
-        m.insert(0x224, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x225, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x226, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x227, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x228, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x229, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x22A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x22B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x22C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x22D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x22E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x22F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x230, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x231, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x232, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x233, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x234, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x235, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x236, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x237, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x238, Uppercase {letter: Letter::Letters('D', 'B'), property: Property::SmallWithDecoration});
-        m.insert(0x239, Uppercase {letter: Letter::Letters('Q', 'P'), property: Property::SmallWithDecoration});
-        m.insert(0x23A, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x23B, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x23C, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x23D, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x23E, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x23F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x240, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x243, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x244, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x245, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x246, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x247, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x248, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x249, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x24A, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x24B, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0x24C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x24D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x24E, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x24F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x250, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x253, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x254, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x255, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x256, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x257, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x258, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x25B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x25C, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x25D, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x25E, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x25F, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x260, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x261, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x262, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapital});
-        m.insert(0x265, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x266, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x268, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x26A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapital});
-        m.insert(0x26B, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x26C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x26D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x26F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x270, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x271, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x272, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x273, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x274, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapital});
-        m.insert(0x275, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x276, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallAndCapital});
-        m.insert(0x279, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27E, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x27F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x280, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapital});
-        m.insert(0x281, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x282, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x284, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x287, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x288, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x289, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x28B, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x28C, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x28D, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x28E, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x28F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallAndCapital});
-        m.insert(0x290, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x291, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x297, Uppercase {letter: Letter::Letter('C'), property: Property::Unknown});
-        m.insert(0x299, Uppercase {letter: Letter::Letter('B'), property: Property::SmallAndCapital});
-        m.insert(0x29A, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x29B, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x29C, Uppercase {letter: Letter::Letter('H'), property: Property::SmallAndCapital});
-        m.insert(0x29D, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x29E, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x29F, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapital});
-        m.insert(0x2A0, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0x2A3, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration});
-        m.insert(0x2A5, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration});
-        m.insert(0x2A6, Uppercase {letter: Letter::Letters('T', 'S'), property: Property::SmallWithDecoration});
-        m.insert(0x2A8, Uppercase {letter: Letter::Letters('T', 'C'), property: Property::SmallWithDecoration});
-        m.insert(0x2AA, Uppercase {letter: Letter::Letters('L', 'S'), property: Property::SmallWithDecoration});
-        m.insert(0x2AB, Uppercase {letter: Letter::Letters('L', 'Z'), property: Property::SmallWithDecoration});
-        m.insert(0x2AE, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x2AF, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x363, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x364, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x365, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x366, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x367, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x368, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x369, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x36A, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x36B, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x36C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x36D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x36E, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x36F, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x1ABF, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1AC0, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1ACC, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1ACD, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1ACE, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1D00, Uppercase {letter: Letter::Letter('A'), property: Property::SmallAndCapital});
-        m.insert(0x1D01, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallAndCapital});
-        m.insert(0x1D02, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D03, Uppercase {letter: Letter::Letter('B'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D04, Uppercase {letter: Letter::Letter('C'), property: Property::SmallAndCapital});
-        m.insert(0x1D05, Uppercase {letter: Letter::Letter('D'), property: Property::SmallAndCapital});
-        m.insert(0x1D07, Uppercase {letter: Letter::Letter('E'), property: Property::SmallAndCapital});
-        m.insert(0x1D08, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D09, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1D0A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallAndCapital});
-        m.insert(0x1D0B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallAndCapital});
-        m.insert(0x1D0C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D0D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapital});
-        m.insert(0x1D0E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D0F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallAndCapital});
-        m.insert(0x1D10, Uppercase {letter: Letter::Letter('O'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D11, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D12, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D13, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D14, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D15, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::SmallAndCapital});
-        m.insert(0x1D16, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D17, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D18, Uppercase {letter: Letter::Letter('P'), property: Property::SmallAndCapital});
-        m.insert(0x1D19, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D1A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D1B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallAndCapital});
-        m.insert(0x1D1C, Uppercase {letter: Letter::Letter('U'), property: Property::SmallAndCapital});
-        m.insert(0x1D1D, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D1E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D1F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1D20, Uppercase {letter: Letter::Letter('V'), property: Property::SmallAndCapital});
-        m.insert(0x1D21, Uppercase {letter: Letter::Letter('W'), property: Property::SmallAndCapital});
-        m.insert(0x1D22, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallAndCapital});
-        m.insert(0x1D62, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1D63, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1D64, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1D65, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x1D6B, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::Small});
-        m.insert(0x1D6C, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1D6D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1D6E, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x1D6F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1D70, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1D71, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1D72, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1D73, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1D74, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1D75, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1D76, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1D77, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1D79, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1D7A, Uppercase {letter: Letter::Letters('T', 'H'), property: Property::SmallWithDecoration});
-        m.insert(0x1D7B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D7D, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1D7E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1D80, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1D81, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1D82, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x1D83, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1D84, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1D85, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1D86, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1D87, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1D88, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1D89, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1D8A, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1D8C, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x1D8D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x1D8E, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1D8F, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1D91, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1D92, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D93, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D94, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1D96, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1D97, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1D99, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1DCA, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD4, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD5, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD6, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD7, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x1DD8, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1DDA, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1DDB, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DDC, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1DDD, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1DDE, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DDF, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DE0, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE1, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DE2, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DE3, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE4, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE5, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE6, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1DE8, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1DEB, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x1DEC, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1DED, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1DEE, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF1, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF2, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E00, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E01, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1E02, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E03, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1E04, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E05, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1E06, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E07, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x1E08, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E09, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x1E0A, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E0B, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1E0C, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E0D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1E0E, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E0F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1E10, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E11, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1E12, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E13, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1E14, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E15, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E16, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E17, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E18, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E19, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E1A, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E1B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E1C, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E1D, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1E1E, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E1F, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x1E20, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E21, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1E22, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E23, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E24, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E25, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E26, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E27, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E28, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E29, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E2A, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E2B, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E2C, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E2D, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1E2E, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E2F, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1E30, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E31, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1E32, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E33, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1E34, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E35, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1E36, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E37, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1E38, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E39, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1E3A, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E3B, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1E3C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E3D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1E3E, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E3F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1E40, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E41, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1E42, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E43, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x1E44, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E45, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1E46, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E47, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1E48, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E49, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1E4A, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E4B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1E4C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E4D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1E4E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E4F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1E50, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E51, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1E52, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E53, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1E54, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E55, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1E56, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E57, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x1E58, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E59, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1E5A, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E5B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1E5C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E5D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1E5E, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E5F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1E60, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E61, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E62, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E63, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E64, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E65, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E66, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E67, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E68, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E69, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E6A, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E6B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1E6C, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E6D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1E6E, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E6F, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1E70, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E71, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1E72, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E73, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E74, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E75, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E76, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E77, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E78, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E79, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E7A, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E7B, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1E7C, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E7D, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x1E7E, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E7F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x1E80, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E81, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E82, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E83, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E84, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E85, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E86, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E87, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E88, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E89, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E8A, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E8B, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x1E8C, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E8D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x1E8E, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E8F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1E90, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E91, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1E92, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E93, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1E94, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1E95, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1E96, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x1E97, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1E98, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x1E99, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1E9A, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1E9B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E9C, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E9D, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1E9E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EA2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EA4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EA6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA7, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EA8, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EA9, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EAA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EAB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EAC, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EAD, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EAE, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EAF, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EB0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EB2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EB4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EB6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB7, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x1EB8, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EB9, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EBA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EBB, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EBC, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EBD, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EBE, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EBF, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC0, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EC1, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC2, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EC3, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC4, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EC5, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC6, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EC7, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x1EC8, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EC9, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1ECA, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ECB, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1ECC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ECD, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ECE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ECF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ED0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ED2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ED4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED5, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ED6, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED7, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1ED8, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1ED9, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EDA, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EDB, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EDC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EDD, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EDE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EDF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EE0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EE1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EE2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EE3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1EE4, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EE5, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EE6, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EE7, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EE8, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EE9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EEA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EEB, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EEC, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EED, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EEE, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EEF, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EF0, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EF1, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x1EF2, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EF3, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1EF4, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EF5, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1EF6, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EF7, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1EF8, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EF9, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1EFA, Uppercase {letter: Letter::Letters('L', 'L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EFB, Uppercase {letter: Letter::Letters('L', 'L'), property: Property::SmallWithDecoration});
-        m.insert(0x1EFC, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EFD, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x1EFE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1EFF, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x2071, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x207F, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x2090, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x2091, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x2092, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x2093, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x2095, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x2096, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x2097, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x2098, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x2099, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x209A, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x209B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x209C, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x2184, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x249C, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x249D, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x249E, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x249F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x24A0, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x24A1, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x24A2, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x24A3, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x24A4, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x24A5, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x24A6, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x24A7, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x24A8, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x24A9, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x24AA, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x24AB, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x24AC, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0x24AD, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x24AE, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x24AF, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x24B0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x24B1, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x24B2, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x24B3, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x24B4, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x24B5, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x24B6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x24B7, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x24B8, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x24B9, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BB, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BD, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x24BF, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C1, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C2, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C3, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C5, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C6, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C7, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x24C9, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CB, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CC, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CD, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x24CF, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x24D0, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x24D1, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0x24D2, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x24D3, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x24D4, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x24D5, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0x24D6, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x24D7, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x24D8, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x24D9, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x24DA, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x24DB, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x24DC, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0x24DD, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x24DE, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x24DF, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0x24E0, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0x24E1, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x24E2, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x24E3, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x24E4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0x24E5, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x24E6, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x24E7, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0x24E8, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x24E9, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x2C60, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C61, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x2C62, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C63, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C64, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C65, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0x2C66, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x2C67, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C68, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x2C69, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C6A, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x2C6B, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C6C, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x2C6E, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C6F, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C71, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x2C72, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C73, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0x2C74, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0x2C75, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C76, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0x2C78, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0x2C79, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x2C7A, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x2C7B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x2C7C, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0x2C7E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x2C7F, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0xA728, Uppercase {letter: Letter::Letters('T', 'Z'), property: Property::Capital});
-        m.insert(0xA729, Uppercase {letter: Letter::Letters('T', 'Z'), property: Property::Small});
-        m.insert(0xA730, Uppercase {letter: Letter::Letter('F'), property: Property::SmallAndCapital});
-        m.insert(0xA731, Uppercase {letter: Letter::Letter('S'), property: Property::SmallAndCapital});
-        m.insert(0xA732, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::Capital});
-        m.insert(0xA733, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::Small});
-        m.insert(0xA734, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::Capital});
-        m.insert(0xA735, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::Small});
-        m.insert(0xA736, Uppercase {letter: Letter::Letters('A', 'U'), property: Property::Capital});
-        m.insert(0xA737, Uppercase {letter: Letter::Letters('A', 'U'), property: Property::Small});
-        m.insert(0xA738, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::Capital});
-        m.insert(0xA739, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::Small});
-        m.insert(0xA73A, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::CapitalWithDecoration});
-        m.insert(0xA73B, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::SmallWithDecoration});
-        m.insert(0xA73C, Uppercase {letter: Letter::Letters('A', 'Y'), property: Property::Capital});
-        m.insert(0xA73D, Uppercase {letter: Letter::Letters('A', 'Y'), property: Property::Small});
-        m.insert(0xA73E, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xA73F, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xA740, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xA741, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xA742, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xA743, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xA744, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xA745, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xA746, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xA747, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xA748, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xA749, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xA74A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xA74B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xA74C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xA74D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xA74E, Uppercase {letter: Letter::Letters('O', 'O'), property: Property::Capital});
-        m.insert(0xA74F, Uppercase {letter: Letter::Letters('O', 'O'), property: Property::Small});
-        m.insert(0xA750, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0xA751, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0xA752, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0xA753, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0xA754, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0xA755, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0xA756, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0xA757, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0xA758, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0xA759, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0xA75A, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0xA75B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xA75E, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0xA75F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0xA760, Uppercase {letter: Letter::Letters('V', 'Y'), property: Property::Capital});
-        m.insert(0xA761, Uppercase {letter: Letter::Letters('V', 'Y'), property: Property::Small});
-        m.insert(0xA762, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0xA763, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0xA76A, Uppercase {letter: Letter::Letters('E', 'T'), property: Property::Capital});
-        m.insert(0xA76B, Uppercase {letter: Letter::Letters('E', 'T'), property: Property::Small});
-        m.insert(0xA76C, Uppercase {letter: Letter::Letters('I', 'S'), property: Property::Capital});
-        m.insert(0xA76D, Uppercase {letter: Letter::Letters('I', 'S'), property: Property::Small});
-        m.insert(0xA778, Uppercase {letter: Letter::Letters('U', 'M'), property: Property::Small});
-        m.insert(0xA779, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0xA77A, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0xA77B, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0xA77C, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0xA77D, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xA77E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xA77F, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xA780, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xA781, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xA782, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0xA783, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xA784, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA785, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA786, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0xA787, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0xA78D, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0xA78E, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xA790, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0xA791, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xA792, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xA793, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xA794, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xA795, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0xA796, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0xA797, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0xA798, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0xA799, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0xA79A, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration});
-        m.insert(0xA79B, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xA79C, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::CapitalWithDecoration});
-        m.insert(0xA79D, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xA79E, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::CapitalWithDecoration});
-        m.insert(0xA79F, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xA7A0, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7A1, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xA7A2, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7A3, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xA7A4, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7A5, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xA7A6, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7A7, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xA7A8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7A9, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA7AA, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7AB, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7AC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7AD, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7AE, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapital});
-        m.insert(0xA7AF, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallAndCapital});
-        m.insert(0xA7B0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7B1, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7B2, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7B8, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7B9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xA7BA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7BB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xA7BC, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7BD, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xA7BE, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7BF, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xA7C0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xA7C2, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C3, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0xA7C4, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C5, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C6, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C7, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7C8, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0xA7C9, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7CA, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA7CC, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7CD, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA7D0, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7D1, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xA7D6, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7D7, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA7D8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7D9, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xA7F5, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0xA7F6, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0xA7F7, Uppercase {letter: Letter::Letter('I'), property: Property::Unknown});
-        m.insert(0xA7FA, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0xA7FB, Uppercase {letter: Letter::Letter('F'), property: Property::Unknown});
-        m.insert(0xA7FC, Uppercase {letter: Letter::Letter('P'), property: Property::Unknown});
-        m.insert(0xA7FD, Uppercase {letter: Letter::Letter('M'), property: Property::Unknown});
-        m.insert(0xA7FE, Uppercase {letter: Letter::Letter('I'), property: Property::Unknown});
-        m.insert(0xA7FF, Uppercase {letter: Letter::Letter('M'), property: Property::Unknown});
-        m.insert(0xAB31, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xAB32, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB33, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB34, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB35, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0xAB36, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xAB37, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xAB38, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xAB39, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xAB3A, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0xAB3B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xAB3D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xAB3E, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xAB3F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xAB40, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB41, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB42, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB43, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xAB44, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xAB45, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB46, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0xAB47, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB48, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB49, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB4A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB4B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB4C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xAB4E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xAB4F, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xAB50, Uppercase {letter: Letter::Letters('U', 'I'), property: Property::Small});
-        m.insert(0xAB51, Uppercase {letter: Letter::Letters('U', 'I'), property: Property::SmallWithDecoration});
-        m.insert(0xAB52, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xAB56, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xAB57, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xAB58, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xAB59, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xAB5A, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0xAB61, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB62, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration});
-        m.insert(0xAB63, Uppercase {letter: Letter::Letters('U', 'O'), property: Property::Small});
-        m.insert(0xAB66, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration});
-        m.insert(0xAB67, Uppercase {letter: Letter::Letters('T', 'S'), property: Property::SmallWithDecoration});
-        m.insert(0xAB68, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xFB00, Uppercase {letter: Letter::Letters('F', 'F'), property: Property::Small});
-        m.insert(0xFB01, Uppercase {letter: Letter::Letters('F', 'I'), property: Property::Small});
-        m.insert(0xFB02, Uppercase {letter: Letter::Letters('F', 'L'), property: Property::Small});
-        m.insert(0xFB05, Uppercase {letter: Letter::Letters('S', 'T'), property: Property::SmallWithDecoration});
-        m.insert(0xFB06, Uppercase {letter: Letter::Letters('S', 'T'), property: Property::Small});
-        m.insert(0xFF21, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF22, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF23, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF24, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF25, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF26, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF27, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF28, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF29, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2A, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2B, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2D, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2E, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF2F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF30, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF31, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF32, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF33, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF34, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF35, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF36, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF37, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF38, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF39, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF3A, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0xFF41, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xFF42, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0xFF43, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xFF44, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0xFF45, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xFF46, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0xFF47, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xFF48, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0xFF49, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xFF4F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xFF50, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0xFF51, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0xFF52, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xFF53, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xFF54, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0xFF55, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xFF56, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0xFF57, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0xFF58, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xFF59, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0xFF5A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
-        m.insert(0x1CCD6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCD7, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCD8, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCD9, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDB, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDD, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCDF, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE1, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE2, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE3, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE5, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE6, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE7, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCE9, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCEA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCEB, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCEC, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCED, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCEE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1CCEF, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1DF01, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF02, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DF03, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF04, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DF06, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF08, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF09, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF0D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF0F, Uppercase {letter: Letter::Letter('C'), property: Property::Unknown});
-        m.insert(0x1DF10, Uppercase {letter: Letter::Letter('K'), property: Property::SmallAndCapitalWithDecoration});
-        m.insert(0x1DF11, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF13, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF15, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF16, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF1A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF1B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF1D, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF1E, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF25, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF26, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF27, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF28, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF29, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0x1DF2A, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0x1F110, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F111, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F112, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F113, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F114, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F115, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F116, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F117, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F118, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F119, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F11F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F120, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F121, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F122, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F123, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F124, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F125, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F126, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F127, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F128, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F129, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F12A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F12B, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F12C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F130, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F131, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F132, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F133, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F134, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F135, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F136, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F137, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F138, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F139, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F13F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F140, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F141, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F142, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F143, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F144, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F145, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F146, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F147, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F148, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F149, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F150, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F151, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F152, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F153, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F154, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F155, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F156, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F157, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F158, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F159, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F15F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F160, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F161, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F162, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F163, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F164, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F165, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F166, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F167, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F168, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F169, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F170, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F171, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F172, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F173, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F174, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F175, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F176, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F177, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F178, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F179, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F17F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F180, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F181, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F182, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F183, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F184, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F185, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F186, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F187, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F188, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F189, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F18A, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0x1F1A5, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0xE0041, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0042, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0043, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0044, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0045, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0046, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0047, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0048, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0049, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004A, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004B, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004D, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004E, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration});
-        m.insert(0xE004F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0050, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0051, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0052, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0053, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0054, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0055, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0056, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0057, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0058, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0059, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration});
-        m.insert(0xE005A, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration});
-        m.insert(0xE0061, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration});
-        m.insert(0xE0062, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration});
-        m.insert(0xE0063, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration});
-        m.insert(0xE0064, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration});
-        m.insert(0xE0065, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration});
-        m.insert(0xE0066, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration});
-        m.insert(0xE0067, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration});
-        m.insert(0xE0068, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration});
-        m.insert(0xE0069, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration});
-        m.insert(0xE006A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration});
-        m.insert(0xE006B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration});
-        m.insert(0xE006C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration});
-        m.insert(0xE006D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration});
-        m.insert(0xE006E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration});
-        m.insert(0xE006F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration});
-        m.insert(0xE0070, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration});
-        m.insert(0xE0071, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration});
-        m.insert(0xE0072, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration});
-        m.insert(0xE0073, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration});
-        m.insert(0xE0074, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration});
-        m.insert(0xE0075, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration});
-        m.insert(0xE0076, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration});
-        m.insert(0xE0077, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration});
-        m.insert(0xE0078, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration});
-        m.insert(0xE0079, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration});
-        m.insert(0xE007A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration});
+static UPPERCASE_AZ: &[(u32, Uppercase)] = &[
+    (0x41, Uppercase {letter: Letter::Letter('A'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x42, Uppercase {letter: Letter::Letter('B'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x43, Uppercase {letter: Letter::Letter('C'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x44, Uppercase {letter: Letter::Letter('D'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x45, Uppercase {letter: Letter::Letter('E'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x46, Uppercase {letter: Letter::Letter('F'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x47, Uppercase {letter: Letter::Letter('G'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x48, Uppercase {letter: Letter::Letter('H'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x49, Uppercase {letter: Letter::Letter('I'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4A, Uppercase {letter: Letter::Letter('J'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4B, Uppercase {letter: Letter::Letter('K'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4C, Uppercase {letter: Letter::Letter('L'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4D, Uppercase {letter: Letter::Letter('M'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4E, Uppercase {letter: Letter::Letter('N'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x4F, Uppercase {letter: Letter::Letter('O'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x50, Uppercase {letter: Letter::Letter('P'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x51, Uppercase {letter: Letter::Letter('Q'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x52, Uppercase {letter: Letter::Letter('R'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x53, Uppercase {letter: Letter::Letter('S'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x54, Uppercase {letter: Letter::Letter('T'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x55, Uppercase {letter: Letter::Letter('U'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x56, Uppercase {letter: Letter::Letter('V'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x57, Uppercase {letter: Letter::Letter('W'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x58, Uppercase {letter: Letter::Letter('X'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x59, Uppercase {letter: Letter::Letter('Y'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x5A, Uppercase {letter: Letter::Letter('Z'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x61, Uppercase {letter: Letter::Letter('A'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x62, Uppercase {letter: Letter::Letter('B'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x63, Uppercase {letter: Letter::Letter('C'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x64, Uppercase {letter: Letter::Letter('D'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x65, Uppercase {letter: Letter::Letter('E'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x66, Uppercase {letter: Letter::Letter('F'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x67, Uppercase {letter: Letter::Letter('G'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x68, Uppercase {letter: Letter::Letter('H'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x69, Uppercase {letter: Letter::Letter('I'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6A, Uppercase {letter: Letter::Letter('J'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6B, Uppercase {letter: Letter::Letter('K'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6C, Uppercase {letter: Letter::Letter('L'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6D, Uppercase {letter: Letter::Letter('M'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6E, Uppercase {letter: Letter::Letter('N'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x6F, Uppercase {letter: Letter::Letter('O'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x70, Uppercase {letter: Letter::Letter('P'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x71, Uppercase {letter: Letter::Letter('Q'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x72, Uppercase {letter: Letter::Letter('R'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x73, Uppercase {letter: Letter::Letter('S'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x74, Uppercase {letter: Letter::Letter('T'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x75, Uppercase {letter: Letter::Letter('U'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x76, Uppercase {letter: Letter::Letter('V'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x77, Uppercase {letter: Letter::Letter('W'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x78, Uppercase {letter: Letter::Letter('X'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x79, Uppercase {letter: Letter::Letter('Y'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x7A, Uppercase {letter: Letter::Letter('Z'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xC0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0xC1, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xC2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xC3, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0xC4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xC5, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0xC6, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xC7, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0xC8, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0xC9, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xCA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xCB, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xCC, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0xCD, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xCE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xCF, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xD1, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0xD2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0xD3, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xD4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xD5, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0xD6, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xD8, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0xD9, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0xDA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xDB, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xDC, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xDD, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0xDF, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SHARP}),
+    (0xE0, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0xE1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xE2, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xE3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0xE4, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xE5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0xE6, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xE7, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0xE8, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0xE9, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xEA, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xEB, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xEC, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0xED, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xEE, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xEF, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xF1, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0xF2, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0xF3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xF4, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xF5, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0xF6, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xF8, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0xF9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0xFA, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xFB, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0xFC, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xFD, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0xFF, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x100, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x101, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x102, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x103, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x104, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::OGONEK}),
+    (0x105, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::OGONEK}),
+    (0x106, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x107, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x108, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x109, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x10A, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x10B, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x10C, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x10D, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x10E, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x10F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x110, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x111, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x112, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x113, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x114, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x115, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x116, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x117, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x118, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::OGONEK}),
+    (0x119, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::OGONEK}),
+    (0x11A, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x11B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x11C, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x11D, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x11E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x11F, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x120, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x121, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x122, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x123, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x124, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x125, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x126, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x127, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x128, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0x129, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0x12A, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x12B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x12C, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x12D, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x12E, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::OGONEK}),
+    (0x12F, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::OGONEK}),
+    (0x130, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x131, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::DOTLESS}),
+    (0x132, Uppercase {letter: Letter::Letters('I', 'J'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x133, Uppercase {letter: Letter::Letters('I', 'J'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x134, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x135, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x136, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x137, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x139, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x13A, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x13B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x13C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x13D, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x13E, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x13F, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::DOT)}),
+    (0x140, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::DOT)}),
+    (0x141, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x142, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x143, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x144, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x145, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x146, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x147, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x148, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x149, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::PRECEDED_BY_APOSTROPHE}),
+    (0x14C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x14D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x14E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x14F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x150, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DOUBLE)}),
+    (0x151, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DOUBLE)}),
+    (0x152, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x153, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x154, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x155, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x156, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x157, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x158, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x159, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x15A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x15B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x15C, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x15D, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x15E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x15F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x160, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x161, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x162, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x163, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x164, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x165, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x166, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x167, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x168, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0x169, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0x16A, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x16B, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x16C, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE}),
+    (0x16D, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE}),
+    (0x16E, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0x16F, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0x170, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DOUBLE)}),
+    (0x171, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DOUBLE)}),
+    (0x172, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::OGONEK}),
+    (0x173, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::OGONEK}),
+    (0x174, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x175, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x176, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x177, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x178, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x179, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x17A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x17B, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x17C, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x17D, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x17E, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x17F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::LONG}),
+    (0x180, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x181, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x182, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::TOPBAR}),
+    (0x183, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::TOPBAR}),
+    (0x186, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::OPEN}),
+    (0x187, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x188, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x189, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::AFRICAN}),
+    (0x18A, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x18B, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::TOPBAR}),
+    (0x18C, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::TOPBAR}),
+    (0x18E, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::REVERSED}),
+    (0x190, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::OPEN}),
+    (0x191, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x192, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x193, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x195, Uppercase {letter: Letter::Letters('H', 'V'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x197, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x198, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x199, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x19A, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BAR}),
+    (0x19C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x19D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT)}),
+    (0x19E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::LONG).union(Decorations::RIGHT)}),
+    (0x19F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1A0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::HORN}),
+    (0x1A1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::HORN}),
+    (0x1A2, Uppercase {letter: Letter::Letters('O', 'I'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1A3, Uppercase {letter: Letter::Letters('O', 'I'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x1A4, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x1A5, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x1A6, Uppercase {letter: Letter::Letters('Y', 'R'), property: Property::Unknown, decorations: Decorations::empty()}),
+    (0x1AB, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1AC, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x1AD, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x1AE, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1AF, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::HORN}),
+    (0x1B0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::HORN}),
+    (0x1B2, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x1B3, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x1B4, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x1B5, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x1B6, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x1C4, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1C5, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1C6, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1C7, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1C8, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::empty()}),
+    (0x1C9, Uppercase {letter: Letter::Letters('L', 'J'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x1CA, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1CB, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::empty()}),
+    (0x1CC, Uppercase {letter: Letter::Letters('N', 'J'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x1CD, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1CE, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1CF, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1D0, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1D1, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1D2, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1D3, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1D4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1D5, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1D6, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1D7, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DIAERESIS)}),
+    (0x1D8, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DIAERESIS)}),
+    (0x1D9, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON.union(Decorations::DIAERESIS)}),
+    (0x1DA, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::CARON.union(Decorations::DIAERESIS)}),
+    (0x1DB, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::GRAVE)}),
+    (0x1DC, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::GRAVE)}),
+    (0x1DD, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x1DE, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1DF, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1E0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E2, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x1E3, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x1E4, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x1E5, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x1E6, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1E7, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1E8, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x1E9, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1EA, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::OGONEK}),
+    (0x1EB, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OGONEK}),
+    (0x1EC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON.union(Decorations::OGONEK)}),
+    (0x1ED, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON.union(Decorations::OGONEK)}),
+    (0x1F0, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x1F1, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F2, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::empty()}),
+    (0x1F3, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x1F4, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1F5, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1F8, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1F9, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1FA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::ACUTE).union(Decorations::RING)}),
+    (0x1FB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::ACUTE).union(Decorations::RING)}),
+    (0x1FC, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1FD, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1FE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::STROKE)}),
+    (0x1FF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::STROKE)}),
+    (0x200, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x201, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x202, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x203, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x204, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x205, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x206, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x207, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x208, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x209, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x20A, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x20B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x20C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x20D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x20E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x20F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x210, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x211, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x212, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x213, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x214, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x215, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::GRAVE)}),
+    (0x216, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x217, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::INVERTED)}),
+    (0x218, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMMA)}),
+    (0x219, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMMA)}),
+    (0x21A, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMMA)}),
+    (0x21B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMMA)}),
+    (0x21E, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::CARON}),
+    (0x21F, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::CARON}),
+    (0x220, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::LEG.union(Decorations::LONG).union(Decorations::RIGHT)}),
+    (0x221, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x222, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x223, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x224, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x225, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x226, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x227, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x228, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x229, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x22A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x22B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x22C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON.union(Decorations::TILDE)}),
+    (0x22D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON.union(Decorations::TILDE)}),
+    (0x22E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x22F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x230, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x231, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x232, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x233, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x234, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x235, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x236, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x237, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::DOTLESS}),
+    (0x238, Uppercase {letter: Letter::Letters('D', 'B'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x239, Uppercase {letter: Letter::Letters('Q', 'P'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x23A, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x23B, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x23C, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x23D, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BAR}),
+    (0x23E, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0x23F, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SWASH_TAIL}),
+    (0x240, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SWASH_TAIL}),
+    (0x243, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x244, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BAR}),
+    (0x245, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x246, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x247, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x248, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x249, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x24A, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::HOOK.union(Decorations::TAIL)}),
+    (0x24B, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::TAIL)}),
+    (0x24C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x24D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x24E, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x24F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x250, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x253, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x254, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN}),
+    (0x255, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x256, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::TAIL}),
+    (0x257, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x258, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED}),
+    (0x25B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN}),
+    (0x25C, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN.union(Decorations::REVERSED)}),
+    (0x25D, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::OPEN).union(Decorations::REVERSED)}),
+    (0x25E, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CLOSED.union(Decorations::OPEN).union(Decorations::REVERSED)}),
+    (0x25F, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::DOTLESS.union(Decorations::STROKE)}),
+    (0x260, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x261, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x262, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x265, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x266, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x268, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x26A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x26B, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x26C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELT}),
+    (0x26D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x26F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x270, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::LONG).union(Decorations::TURNED)}),
+    (0x271, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x272, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT)}),
+    (0x273, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x274, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x275, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BARRED}),
+    (0x276, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x279, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x27A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::LONG).union(Decorations::TURNED)}),
+    (0x27B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::TURNED)}),
+    (0x27C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::LONG)}),
+    (0x27D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::TAIL}),
+    (0x27E, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK)}),
+    (0x27F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK).union(Decorations::REVERSED)}),
+    (0x280, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x281, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::INVERTED}),
+    (0x282, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x284, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::DOTLESS.union(Decorations::HOOK).union(Decorations::STROKE)}),
+    (0x287, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x288, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x289, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BAR}),
+    (0x28B, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x28C, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x28D, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x28E, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x28F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x290, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x291, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x297, Uppercase {letter: Letter::Letter('C'), property: Property::Unknown, decorations: Decorations::STRETCHED}),
+    (0x299, Uppercase {letter: Letter::Letter('B'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x29A, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CLOSED.union(Decorations::OPEN)}),
+    (0x29B, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x29C, Uppercase {letter: Letter::Letter('H'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x29D, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL}),
+    (0x29E, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x29F, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x2A0, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x2A3, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x2A5, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration, decorations: Decorations::CURL.union(Decorations::DIGRAPH)}),
+    (0x2A6, Uppercase {letter: Letter::Letters('T', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x2A8, Uppercase {letter: Letter::Letters('T', 'C'), property: Property::SmallWithDecoration, decorations: Decorations::CURL.union(Decorations::DIGRAPH)}),
+    (0x2AA, Uppercase {letter: Letter::Letters('L', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x2AB, Uppercase {letter: Letter::Letters('L', 'Z'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH}),
+    (0x2AE, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK).union(Decorations::TURNED)}),
+    (0x2AF, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK).union(Decorations::TURNED).union(Decorations::TAIL)}),
+    (0x363, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x364, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x365, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x366, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x367, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x368, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x369, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36A, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36B, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36E, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x36F, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1ABF, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMBINING)}),
+    (0x1AC0, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMBINING).union(Decorations::TURNED)}),
+    (0x1ACC, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::INSULAR)}),
+    (0x1ACD, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::INSULAR)}),
+    (0x1ACE, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::INSULAR)}),
+    (0x1D00, Uppercase {letter: Letter::Letter('A'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D01, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D02, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x1D03, Uppercase {letter: Letter::Letter('B'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::BARRED}),
+    (0x1D04, Uppercase {letter: Letter::Letter('C'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D05, Uppercase {letter: Letter::Letter('D'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D07, Uppercase {letter: Letter::Letter('E'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D08, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN.union(Decorations::TURNED)}),
+    (0x1D09, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x1D0A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D0B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D0C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x1D0D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D0E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::REVERSED}),
+    (0x1D0F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D10, Uppercase {letter: Letter::Letter('O'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::OPEN}),
+    (0x1D11, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SIDEWAYS}),
+    (0x1D12, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN.union(Decorations::SIDEWAYS)}),
+    (0x1D13, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SIDEWAYS.union(Decorations::STROKE)}),
+    (0x1D14, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x1D15, Uppercase {letter: Letter::Letters('O', 'U'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D16, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::HALF.union(Decorations::TOP)}),
+    (0x1D17, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BOTTOM.union(Decorations::HALF)}),
+    (0x1D18, Uppercase {letter: Letter::Letter('P'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D19, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::REVERSED}),
+    (0x1D1A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x1D1B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D1C, Uppercase {letter: Letter::Letter('U'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D1D, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SIDEWAYS}),
+    (0x1D1E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIZED.union(Decorations::SIDEWAYS)}),
+    (0x1D1F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SIDEWAYS.union(Decorations::TURNED)}),
+    (0x1D20, Uppercase {letter: Letter::Letter('V'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D21, Uppercase {letter: Letter::Letter('W'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D22, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0x1D62, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x1D63, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x1D64, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x1D65, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x1D6B, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::Small, decorations: Decorations::empty()}),
+    (0x1D6C, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D6D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D6E, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D6F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D70, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D71, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D72, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D73, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK).union(Decorations::MIDDLE).union(Decorations::TILDE)}),
+    (0x1D74, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D75, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D76, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x1D77, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0x1D79, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0x1D7A, Uppercase {letter: Letter::Letters('T', 'H'), property: Property::SmallWithDecoration, decorations: Decorations::STRIKETHROUGH}),
+    (0x1D7B, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x1D7D, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x1D7E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x1D80, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D81, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D82, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D83, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D84, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D85, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D86, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D87, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D88, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D89, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D8A, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D8C, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D8D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D8E, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0x1D8F, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1D91, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::TAIL)}),
+    (0x1D92, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1D93, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::OPEN).union(Decorations::RETROFLEX)}),
+    (0x1D94, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::OPEN).union(Decorations::RETROFLEX).union(Decorations::REVERSED)}),
+    (0x1D96, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1D97, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::OPEN).union(Decorations::RETROFLEX)}),
+    (0x1D99, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1DCA, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::COMBINING)}),
+    (0x1DD3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::COMBINING).union(Decorations::FLATTENED).union(Decorations::OPEN)}),
+    (0x1DD4, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DD5, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DD6, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DD7, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA.union(Decorations::COMBINING)}),
+    (0x1DD8, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::INSULAR)}),
+    (0x1DDA, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DDB, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DDC, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DDD, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DDE, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DDF, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE0, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE1, Uppercase {letter: Letter::Letter('N'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE2, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE3, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::ROTUNDA)}),
+    (0x1DE4, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE5, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::LONG)}),
+    (0x1DE6, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DE8, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DEB, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DEC, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::DOUBLE).union(Decorations::MIDDLE).union(Decorations::TILDE)}),
+    (0x1DED, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::LIGHT_CENTRALIZATION).union(Decorations::STROKE)}),
+    (0x1DEE, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DF0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::LIGHT_CENTRALIZATION).union(Decorations::STROKE)}),
+    (0x1DF1, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING}),
+    (0x1DF2, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::DIAERESIS)}),
+    (0x1DF3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::DIAERESIS)}),
+    (0x1DF4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::COMBINING.union(Decorations::DIAERESIS)}),
+    (0x1E00, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::RING)}),
+    (0x1E01, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::RING)}),
+    (0x1E02, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E03, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E04, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E05, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E06, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E07, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E08, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CEDILLA)}),
+    (0x1E09, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CEDILLA)}),
+    (0x1E0A, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E0B, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E0C, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E0D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E0E, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E0F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E10, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x1E11, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x1E12, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E13, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E14, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE.union(Decorations::MACRON)}),
+    (0x1E15, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE.union(Decorations::MACRON)}),
+    (0x1E16, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::MACRON)}),
+    (0x1E17, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::MACRON)}),
+    (0x1E18, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E19, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E1A, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E1B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E1C, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::CEDILLA)}),
+    (0x1E1D, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::CEDILLA)}),
+    (0x1E1E, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E1F, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E20, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::MACRON}),
+    (0x1E21, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::MACRON}),
+    (0x1E22, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E23, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E24, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E25, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E26, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E27, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E28, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x1E29, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::CEDILLA}),
+    (0x1E2A, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::BREVE)}),
+    (0x1E2B, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::BREVE)}),
+    (0x1E2C, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E2D, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E2E, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DIAERESIS)}),
+    (0x1E2F, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::DIAERESIS)}),
+    (0x1E30, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E31, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E32, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E33, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E34, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E35, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E36, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E37, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E38, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E39, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E3A, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E3B, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E3C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E3D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E3E, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E3F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E40, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E41, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E42, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E43, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E44, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E45, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E46, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E47, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E48, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E49, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E4A, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E4B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E4C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::TILDE)}),
+    (0x1E4D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::TILDE)}),
+    (0x1E4E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::TILDE)}),
+    (0x1E4F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::TILDE)}),
+    (0x1E50, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE.union(Decorations::MACRON)}),
+    (0x1E51, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE.union(Decorations::MACRON)}),
+    (0x1E52, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::MACRON)}),
+    (0x1E53, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::MACRON)}),
+    (0x1E54, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E55, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E56, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E57, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E58, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E59, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E5A, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E5B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E5C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E5D, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::MACRON).union(Decorations::DOT)}),
+    (0x1E5E, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E5F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E60, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E61, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E62, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E63, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E64, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::ACUTE).union(Decorations::DOT)}),
+    (0x1E65, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::ACUTE).union(Decorations::DOT)}),
+    (0x1E66, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CARON).union(Decorations::DOT)}),
+    (0x1E67, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CARON).union(Decorations::DOT)}),
+    (0x1E68, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::BELOW).union(Decorations::DOT)}),
+    (0x1E69, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::BELOW).union(Decorations::DOT)}),
+    (0x1E6A, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E6B, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E6C, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E6D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E6E, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E6F, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E70, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E71, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E72, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DIAERESIS)}),
+    (0x1E73, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DIAERESIS)}),
+    (0x1E74, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E75, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::TILDE)}),
+    (0x1E76, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E77, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX)}),
+    (0x1E78, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::TILDE)}),
+    (0x1E79, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::TILDE)}),
+    (0x1E7A, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1E7B, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS.union(Decorations::MACRON)}),
+    (0x1E7C, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0x1E7D, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0x1E7E, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E7F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E80, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1E81, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1E82, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E83, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE}),
+    (0x1E84, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E85, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E86, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E87, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E88, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E89, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E8A, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E8B, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E8C, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E8D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E8E, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E8F, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::DOT)}),
+    (0x1E90, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x1E91, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX}),
+    (0x1E92, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E93, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1E94, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E95, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E96, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::LINE)}),
+    (0x1E97, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E98, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0x1E99, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0x1E9A, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::HALF.union(Decorations::RIGHT).union(Decorations::RING)}),
+    (0x1E9B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::LONG).union(Decorations::DOT)}),
+    (0x1E9C, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::LONG).union(Decorations::STROKE)}),
+    (0x1E9D, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::HIGH_STROKE.union(Decorations::LONG)}),
+    (0x1E9E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SHARP}),
+    (0x1EA0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EA1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EA2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EA3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EA4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1EA5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1EA6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1EA7, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1EA8, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1EA9, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1EAA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1EAB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1EAC, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1EAD, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1EAE, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::BREVE)}),
+    (0x1EAF, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::BREVE)}),
+    (0x1EB0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::GRAVE)}),
+    (0x1EB1, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::GRAVE)}),
+    (0x1EB2, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::BREVE).union(Decorations::HOOK)}),
+    (0x1EB3, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::BREVE).union(Decorations::HOOK)}),
+    (0x1EB4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BREVE.union(Decorations::TILDE)}),
+    (0x1EB5, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BREVE.union(Decorations::TILDE)}),
+    (0x1EB6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::BREVE).union(Decorations::DOT)}),
+    (0x1EB7, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::BREVE).union(Decorations::DOT)}),
+    (0x1EB8, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EB9, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EBA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EBB, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EBC, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0x1EBD, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0x1EBE, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1EBF, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1EC0, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1EC1, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1EC2, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1EC3, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1EC4, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1EC5, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1EC6, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1EC7, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1EC8, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EC9, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1ECA, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1ECB, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1ECC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1ECD, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1ECE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1ECF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1ED0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1ED1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::CIRCUMFLEX)}),
+    (0x1ED2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1ED3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::GRAVE)}),
+    (0x1ED4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1ED5, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::CIRCUMFLEX).union(Decorations::HOOK)}),
+    (0x1ED6, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1ED7, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCUMFLEX.union(Decorations::TILDE)}),
+    (0x1ED8, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1ED9, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::CIRCUMFLEX).union(Decorations::DOT)}),
+    (0x1EDA, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::HORN)}),
+    (0x1EDB, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::HORN)}),
+    (0x1EDC, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE.union(Decorations::HORN)}),
+    (0x1EDD, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE.union(Decorations::HORN)}),
+    (0x1EDE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK).union(Decorations::HORN)}),
+    (0x1EDF, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK).union(Decorations::HORN)}),
+    (0x1EE0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::HORN.union(Decorations::TILDE)}),
+    (0x1EE1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::HORN.union(Decorations::TILDE)}),
+    (0x1EE2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::HORN).union(Decorations::DOT)}),
+    (0x1EE3, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::HORN).union(Decorations::DOT)}),
+    (0x1EE4, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EE5, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EE6, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EE7, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EE8, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ACUTE.union(Decorations::HORN)}),
+    (0x1EE9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ACUTE.union(Decorations::HORN)}),
+    (0x1EEA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE.union(Decorations::HORN)}),
+    (0x1EEB, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE.union(Decorations::HORN)}),
+    (0x1EEC, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK).union(Decorations::HORN)}),
+    (0x1EED, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK).union(Decorations::HORN)}),
+    (0x1EEE, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::HORN.union(Decorations::TILDE)}),
+    (0x1EEF, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::HORN.union(Decorations::TILDE)}),
+    (0x1EF0, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::HORN).union(Decorations::DOT)}),
+    (0x1EF1, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::HORN).union(Decorations::DOT)}),
+    (0x1EF2, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1EF3, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::GRAVE}),
+    (0x1EF4, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EF5, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BELOW.union(Decorations::DOT)}),
+    (0x1EF6, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EF7, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::HOOK)}),
+    (0x1EF8, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::TILDE}),
+    (0x1EF9, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::TILDE}),
+    (0x1EFA, Uppercase {letter: Letter::Letters('L', 'L'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE_WELSH}),
+    (0x1EFB, Uppercase {letter: Letter::Letters('L', 'L'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE_WELSH}),
+    (0x1EFC, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE_WELSH}),
+    (0x1EFD, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE_WELSH}),
+    (0x1EFE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::LOOP}),
+    (0x1EFF, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::LOOP}),
+    (0x2071, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x207F, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x2090, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2091, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2092, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2093, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2095, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2096, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2097, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2098, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2099, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x209A, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x209B, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x209C, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2102, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x210A, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x210B, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x210C, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BLACK_LETTER}),
+    (0x210D, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x210E, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::empty()}),
+    (0x210F, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x2110, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2111, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BLACK_LETTER}),
+    (0x2112, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2113, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2115, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x2119, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x211A, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x211B, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x211C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BLACK_LETTER}),
+    (0x211D, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x2120, Uppercase {letter: Letter::Letters('S', 'M'), property: Property::CapitalWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x2122, Uppercase {letter: Letter::Letters('T', 'M'), property: Property::CapitalWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x2124, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x2128, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BLACK_LETTER}),
+    (0x212A, Uppercase {letter: Letter::Letter('K'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x212B, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0x212C, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x212D, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BLACK_LETTER}),
+    (0x212F, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2130, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2131, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2133, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2134, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x2139, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::empty()}),
+    (0x2145, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK.union(Decorations::ITALIC)}),
+    (0x2146, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK.union(Decorations::ITALIC)}),
+    (0x2147, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK.union(Decorations::ITALIC)}),
+    (0x2148, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK.union(Decorations::ITALIC)}),
+    (0x2149, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK.union(Decorations::ITALIC)}),
+    (0x2160, Uppercase {letter: Letter::Letter('I'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2161, Uppercase {letter: Letter::Letters('I', 'I'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2162, Uppercase {letter: Letter::Sequence("III"), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2163, Uppercase {letter: Letter::Letters('I', 'V'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2164, Uppercase {letter: Letter::Letter('V'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2165, Uppercase {letter: Letter::Letters('V', 'I'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2166, Uppercase {letter: Letter::Sequence("VII"), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2167, Uppercase {letter: Letter::Sequence("VIII"), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2168, Uppercase {letter: Letter::Letters('I', 'X'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2169, Uppercase {letter: Letter::Letter('X'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216A, Uppercase {letter: Letter::Letters('X', 'I'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216B, Uppercase {letter: Letter::Sequence("XII"), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216C, Uppercase {letter: Letter::Letter('L'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216D, Uppercase {letter: Letter::Letter('C'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216E, Uppercase {letter: Letter::Letter('D'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x216F, Uppercase {letter: Letter::Letter('M'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2170, Uppercase {letter: Letter::Letter('I'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2171, Uppercase {letter: Letter::Letters('I', 'I'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2172, Uppercase {letter: Letter::Sequence("III"), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2173, Uppercase {letter: Letter::Letters('I', 'V'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2174, Uppercase {letter: Letter::Letter('V'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2175, Uppercase {letter: Letter::Letters('V', 'I'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2176, Uppercase {letter: Letter::Sequence("VII"), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2177, Uppercase {letter: Letter::Sequence("VIII"), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2178, Uppercase {letter: Letter::Letters('I', 'X'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2179, Uppercase {letter: Letter::Letter('X'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217A, Uppercase {letter: Letter::Letters('X', 'I'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217B, Uppercase {letter: Letter::Sequence("XII"), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217D, Uppercase {letter: Letter::Letter('C'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217E, Uppercase {letter: Letter::Letter('D'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x217F, Uppercase {letter: Letter::Letter('M'), property: Property::SmallRomanNumeral, decorations: Decorations::empty()}),
+    (0x2180, Uppercase {letter: Letter::Letter('M'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2184, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED}),
+    (0x2185, Uppercase {letter: Letter::Letters('V', 'I'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x2186, Uppercase {letter: Letter::Letter('L'), property: Property::RomanNumeral, decorations: Decorations::empty()}),
+    (0x249C, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x249D, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x249E, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x249F, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A0, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A1, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A2, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A3, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A4, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A5, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A6, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A7, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A8, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24A9, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AA, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AB, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AC, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AD, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AE, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24AF, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B0, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B1, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B2, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B3, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B4, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B5, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x24B6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24B7, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24B8, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24B9, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BB, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BD, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24BF, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C1, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C2, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C3, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C5, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C6, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C7, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24C9, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CB, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CC, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CD, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24CF, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D0, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D1, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D2, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D3, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D4, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D5, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D6, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D7, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D8, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24D9, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DA, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DB, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DC, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DD, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DE, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24DF, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E0, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E1, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E2, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E3, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E4, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E5, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E6, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E7, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E8, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x24E9, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x2C60, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::BAR)}),
+    (0x2C61, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::BAR)}),
+    (0x2C62, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE)}),
+    (0x2C63, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0x2C64, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::TAIL}),
+    (0x2C65, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0x2C66, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0x2C67, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C68, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C69, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C6A, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C6B, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C6C, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER}),
+    (0x2C6E, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x2C6F, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x2C71, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RIGHT)}),
+    (0x2C72, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0x2C73, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK}),
+    (0x2C74, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x2C75, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::HALF}),
+    (0x2C76, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::HALF}),
+    (0x2C78, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::NOTCH}),
+    (0x2C79, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED.union(Decorations::TAIL)}),
+    (0x2C7A, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::INSIDE.union(Decorations::LOW).union(Decorations::RING)}),
+    (0x2C7B, Uppercase {letter: Letter::Letter('E'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x2C7C, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SUBSCRIPT}),
+    (0x2C7E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SWASH_TAIL}),
+    (0x2C7F, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SWASH_TAIL}),
+    (0x3250, Uppercase {letter: Letter::Sequence("PTE"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x32CC, Uppercase {letter: Letter::Letters('H', 'G'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x32CD, Uppercase {letter: Letter::Sequence("ERG"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x32CE, Uppercase {letter: Letter::Letters('E', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x32CF, Uppercase {letter: Letter::Sequence("LTD"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3371, Uppercase {letter: Letter::Sequence("HPA"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3372, Uppercase {letter: Letter::Letters('D', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3373, Uppercase {letter: Letter::Letters('A', 'U'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3374, Uppercase {letter: Letter::Sequence("BAR"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3375, Uppercase {letter: Letter::Letters('O', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3376, Uppercase {letter: Letter::Letters('P', 'C'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3377, Uppercase {letter: Letter::Letters('D', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x337A, Uppercase {letter: Letter::Letters('I', 'U'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3380, Uppercase {letter: Letter::Letters('P', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3381, Uppercase {letter: Letter::Letters('N', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3383, Uppercase {letter: Letter::Letters('M', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3384, Uppercase {letter: Letter::Letters('K', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3385, Uppercase {letter: Letter::Letters('K', 'B'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3386, Uppercase {letter: Letter::Letters('M', 'B'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3387, Uppercase {letter: Letter::Letters('G', 'B'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3388, Uppercase {letter: Letter::Sequence("CAL"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3389, Uppercase {letter: Letter::Sequence("KCAL"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x338A, Uppercase {letter: Letter::Letters('P', 'F'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x338B, Uppercase {letter: Letter::Letters('N', 'F'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x338E, Uppercase {letter: Letter::Letters('M', 'G'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x338F, Uppercase {letter: Letter::Letters('K', 'G'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3390, Uppercase {letter: Letter::Letters('H', 'Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3391, Uppercase {letter: Letter::Sequence("KHZ"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3392, Uppercase {letter: Letter::Sequence("MHZ"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3393, Uppercase {letter: Letter::Sequence("GHZ"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3394, Uppercase {letter: Letter::Sequence("THZ"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3396, Uppercase {letter: Letter::Letters('M', 'L'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3397, Uppercase {letter: Letter::Letters('D', 'L'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3398, Uppercase {letter: Letter::Letters('K', 'L'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x3399, Uppercase {letter: Letter::Letters('F', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x339A, Uppercase {letter: Letter::Letters('N', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x339C, Uppercase {letter: Letter::Letters('M', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x339D, Uppercase {letter: Letter::Letters('C', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x339E, Uppercase {letter: Letter::Letters('K', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33A9, Uppercase {letter: Letter::Letters('P', 'A'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33AA, Uppercase {letter: Letter::Sequence("KPA"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33AB, Uppercase {letter: Letter::Sequence("MPA"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33AC, Uppercase {letter: Letter::Sequence("GPA"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33AD, Uppercase {letter: Letter::Sequence("RAD"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B0, Uppercase {letter: Letter::Letters('P', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B1, Uppercase {letter: Letter::Letters('N', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B3, Uppercase {letter: Letter::Letters('M', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B4, Uppercase {letter: Letter::Letters('P', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B5, Uppercase {letter: Letter::Letters('N', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B7, Uppercase {letter: Letter::Letters('M', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B8, Uppercase {letter: Letter::Letters('K', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33B9, Uppercase {letter: Letter::Letters('M', 'V'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33BA, Uppercase {letter: Letter::Letters('P', 'W'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33BB, Uppercase {letter: Letter::Letters('N', 'W'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33BD, Uppercase {letter: Letter::Letters('M', 'W'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33BE, Uppercase {letter: Letter::Letters('K', 'W'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33BF, Uppercase {letter: Letter::Letters('M', 'W'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C2, Uppercase {letter: Letter::Letters('A', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C3, Uppercase {letter: Letter::Letters('B', 'Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C4, Uppercase {letter: Letter::Letters('C', 'C'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C5, Uppercase {letter: Letter::Letters('C', 'D'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C7, Uppercase {letter: Letter::Letters('C', 'O'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C8, Uppercase {letter: Letter::Letters('D', 'B'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33C9, Uppercase {letter: Letter::Letters('G', 'Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CA, Uppercase {letter: Letter::Letters('H', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CB, Uppercase {letter: Letter::Letters('H', 'P'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CC, Uppercase {letter: Letter::Letters('I', 'N'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CD, Uppercase {letter: Letter::Letters('K', 'K'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CE, Uppercase {letter: Letter::Letters('K', 'M'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33CF, Uppercase {letter: Letter::Letters('K', 'T'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D0, Uppercase {letter: Letter::Letters('L', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D1, Uppercase {letter: Letter::Letters('L', 'N'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D2, Uppercase {letter: Letter::Sequence("LOG"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D3, Uppercase {letter: Letter::Letters('L', 'X'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D4, Uppercase {letter: Letter::Letters('M', 'B'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D5, Uppercase {letter: Letter::Sequence("MIL"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D6, Uppercase {letter: Letter::Sequence("MOL"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D7, Uppercase {letter: Letter::Letters('P', 'H'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D8, Uppercase {letter: Letter::Letters('P', 'M'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33D9, Uppercase {letter: Letter::Sequence("PPM"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33DA, Uppercase {letter: Letter::Letters('P', 'R'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33DB, Uppercase {letter: Letter::Letters('S', 'R'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33DC, Uppercase {letter: Letter::Letters('S', 'V'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33DD, Uppercase {letter: Letter::Letters('W', 'B'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x33FF, Uppercase {letter: Letter::Sequence("GAL"), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0xA728, Uppercase {letter: Letter::Letters('T', 'Z'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA729, Uppercase {letter: Letter::Letters('T', 'Z'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA730, Uppercase {letter: Letter::Letter('F'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0xA731, Uppercase {letter: Letter::Letter('S'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0xA732, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA733, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA734, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA735, Uppercase {letter: Letter::Letters('A', 'O'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA736, Uppercase {letter: Letter::Letters('A', 'U'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA737, Uppercase {letter: Letter::Letters('A', 'U'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA738, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA739, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA73A, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::CapitalWithDecoration, decorations: Decorations::HORIZONTAL.union(Decorations::BAR)}),
+    (0xA73B, Uppercase {letter: Letter::Letters('A', 'V'), property: Property::SmallWithDecoration, decorations: Decorations::HORIZONTAL.union(Decorations::BAR)}),
+    (0xA73C, Uppercase {letter: Letter::Letters('A', 'Y'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA73D, Uppercase {letter: Letter::Letters('A', 'Y'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA73E, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::REVERSED.union(Decorations::DOT)}),
+    (0xA73F, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED.union(Decorations::DOT)}),
+    (0xA740, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0xA741, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0xA742, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA743, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA744, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA745, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA746, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BROKEN}),
+    (0xA747, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BROKEN}),
+    (0xA748, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::HIGH_STROKE}),
+    (0xA749, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::HIGH_STROKE}),
+    (0xA74A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::LONG.union(Decorations::OVERLAY).union(Decorations::STROKE)}),
+    (0xA74B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::LONG.union(Decorations::OVERLAY).union(Decorations::STROKE)}),
+    (0xA74C, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::LOOP}),
+    (0xA74D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::LOOP}),
+    (0xA74E, Uppercase {letter: Letter::Letters('O', 'O'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA74F, Uppercase {letter: Letter::Letters('O', 'O'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA750, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER.union(Decorations::STROKE).union(Decorations::THROUGH)}),
+    (0xA751, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER.union(Decorations::STROKE).union(Decorations::THROUGH)}),
+    (0xA752, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::FLOURISH}),
+    (0xA753, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::FLOURISH}),
+    (0xA754, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUIRREL_TAIL}),
+    (0xA755, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SQUIRREL_TAIL}),
+    (0xA756, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER.union(Decorations::STROKE).union(Decorations::THROUGH)}),
+    (0xA757, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER.union(Decorations::STROKE).union(Decorations::THROUGH)}),
+    (0xA758, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA759, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA75A, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::ROTUNDA}),
+    (0xA75B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::ROTUNDA}),
+    (0xA75E, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA75F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA760, Uppercase {letter: Letter::Letters('V', 'Y'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA761, Uppercase {letter: Letter::Letters('V', 'Y'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA762, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::VISIGOTHIC}),
+    (0xA763, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::VISIGOTHIC}),
+    (0xA76A, Uppercase {letter: Letter::Letters('E', 'T'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA76B, Uppercase {letter: Letter::Letters('E', 'T'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA76C, Uppercase {letter: Letter::Letters('I', 'S'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xA76D, Uppercase {letter: Letter::Letters('I', 'S'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA778, Uppercase {letter: Letter::Letters('U', 'M'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xA779, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA77A, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA77B, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA77C, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA77D, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA77E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR.union(Decorations::TURNED)}),
+    (0xA77F, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR.union(Decorations::TURNED)}),
+    (0xA780, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0xA781, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0xA782, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA783, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA784, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA785, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA786, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA787, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::INSULAR}),
+    (0xA78D, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0xA78E, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELT.union(Decorations::HOOK).union(Decorations::RETROFLEX)}),
+    (0xA790, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::DESCENDER}),
+    (0xA791, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::DESCENDER}),
+    (0xA792, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BAR}),
+    (0xA793, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::BAR}),
+    (0xA794, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0xA795, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0xA796, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::FLOURISH}),
+    (0xA797, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::FLOURISH}),
+    (0xA798, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0xA799, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0xA79A, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA79B, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA79C, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA79D, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA79E, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA79F, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::VOLAPUK}),
+    (0xA7A0, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A1, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A2, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A3, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A4, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A5, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A6, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A7, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7A9, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::OBLIQUE.union(Decorations::STROKE)}),
+    (0xA7AA, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0xA7AB, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::OPEN.union(Decorations::REVERSED)}),
+    (0xA7AC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0xA7AD, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BELT}),
+    (0xA7AE, Uppercase {letter: Letter::Letter('I'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0xA7AF, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallAndCapital, decorations: Decorations::empty()}),
+    (0xA7B0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0xA7B1, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0xA7B2, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::CROSSED_TAIL}),
+    (0xA7B8, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0xA7B9, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+    (0xA7BA, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7BB, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7BC, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7BD, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7BE, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7BF, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::GLOTTAL}),
+    (0xA7C0, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::POLISH.union(Decorations::OLD)}),
+    (0xA7C1, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::POLISH.union(Decorations::OLD)}),
+    (0xA7C2, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::ANGLICANA}),
+    (0xA7C3, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::ANGLICANA}),
+    (0xA7C4, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0xA7C5, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK}),
+    (0xA7C6, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL)}),
+    (0xA7C7, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::STROKE)}),
+    (0xA7C8, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::STROKE)}),
+    (0xA7C9, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::STROKE)}),
+    (0xA7CA, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::OVERLAY.union(Decorations::SHORT).union(Decorations::STROKE)}),
+    (0xA7CC, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA7CD, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::DIAGONAL.union(Decorations::STROKE)}),
+    (0xA7D0, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CLOSED.union(Decorations::INSULAR)}),
+    (0xA7D1, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CLOSED.union(Decorations::INSULAR)}),
+    (0xA7D6, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::SCOTS)}),
+    (0xA7D7, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::SCOTS)}),
+    (0xA7D8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SIGMOID}),
+    (0xA7D9, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SIGMOID}),
+    (0xA7F5, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::HALF.union(Decorations::REVERSED)}),
+    (0xA7F6, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::HALF.union(Decorations::REVERSED)}),
+    (0xA7F7, Uppercase {letter: Letter::Letter('I'), property: Property::Unknown, decorations: Decorations::EPIGRAPHIC.union(Decorations::SIDEWAYS)}),
+    (0xA7FA, Uppercase {letter: Letter::Letter('M'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0xA7FB, Uppercase {letter: Letter::Letter('F'), property: Property::Unknown, decorations: Decorations::EPIGRAPHIC.union(Decorations::REVERSED)}),
+    (0xA7FC, Uppercase {letter: Letter::Letter('P'), property: Property::Unknown, decorations: Decorations::EPIGRAPHIC.union(Decorations::REVERSED)}),
+    (0xA7FD, Uppercase {letter: Letter::Letter('M'), property: Property::Unknown, decorations: Decorations::EPIGRAPHIC.union(Decorations::INVERTED)}),
+    (0xA7FE, Uppercase {letter: Letter::Letter('I'), property: Property::Unknown, decorations: Decorations::EPIGRAPHIC.union(Decorations::LONGA)}),
+    (0xA7FF, Uppercase {letter: Letter::Letter('M'), property: Property::Unknown, decorations: Decorations::ARCHAIC.union(Decorations::EPIGRAPHIC)}),
+    (0xAB31, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED_SCHWA}),
+    (0xAB32, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BLACK}),
+    (0xAB33, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BARRED}),
+    (0xAB34, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::FLOURISH}),
+    (0xAB35, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::LENIS}),
+    (0xAB36, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL.union(Decorations::SCRIPT)}),
+    (0xAB37, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::INVERTED.union(Decorations::LAZY_S)}),
+    (0xAB38, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE.union(Decorations::MIDDLE).union(Decorations::TILDE)}),
+    (0xAB39, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::RING)}),
+    (0xAB3A, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL}),
+    (0xAB3B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL}),
+    (0xAB3D, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BLACK}),
+    (0xAB3E, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BLACK.union(Decorations::STROKE)}),
+    (0xAB3F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN.union(Decorations::STROKE)}),
+    (0xAB40, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::INVERTED}),
+    (0xAB41, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE.union(Decorations::TURNED)}),
+    (0xAB42, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::HORIZONTAL.union(Decorations::STROKE).union(Decorations::TURNED)}),
+    (0xAB43, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN_O.union(Decorations::TURNED)}),
+    (0xAB44, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN_O.union(Decorations::STROKE).union(Decorations::TURNED)}),
+    (0xAB45, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::STIRRUP}),
+    (0xAB46, Uppercase {letter: Letter::Letter('R'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::LEG.union(Decorations::RIGHT)}),
+    (0xAB47, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HANDLE}),
+    (0xAB48, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE}),
+    (0xAB49, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL}),
+    (0xAB4A, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::CROSSED_TAIL.union(Decorations::DOUBLE)}),
+    (0xAB4B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0xAB4C, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::RING.union(Decorations::SCRIPT)}),
+    (0xAB4E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::RIGHT).union(Decorations::SHORT)}),
+    (0xAB4F, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::RIGHT).union(Decorations::SHORT).union(Decorations::BAR)}),
+    (0xAB50, Uppercase {letter: Letter::Letters('U', 'I'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xAB51, Uppercase {letter: Letter::Letters('U', 'I'), property: Property::SmallWithDecoration, decorations: Decorations::TURNED}),
+    (0xAB52, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT)}),
+    (0xAB56, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::LOW.union(Decorations::RIGHT).union(Decorations::RING)}),
+    (0xAB57, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::LEFT.union(Decorations::LEG).union(Decorations::LONG)}),
+    (0xAB58, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::LEFT.union(Decorations::LEG).union(Decorations::LONG).union(Decorations::LOW).union(Decorations::RIGHT).union(Decorations::RING)}),
+    (0xAB59, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::LEFT.union(Decorations::LEG).union(Decorations::LONG).union(Decorations::SERIF)}),
+    (0xAB5A, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::LEG.union(Decorations::RIGHT).union(Decorations::SHORT)}),
+    (0xAB61, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::IOTIFIED}),
+    (0xAB62, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::OPEN}),
+    (0xAB63, Uppercase {letter: Letter::Letters('U', 'O'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xAB66, Uppercase {letter: Letter::Letters('D', 'Z'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH.union(Decorations::HOOK).union(Decorations::RETROFLEX)}),
+    (0xAB67, Uppercase {letter: Letter::Letters('T', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::DIGRAPH.union(Decorations::HOOK).union(Decorations::RETROFLEX)}),
+    (0xAB68, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::MIDDLE.union(Decorations::TILDE).union(Decorations::TURNED)}),
+    (0xFB00, Uppercase {letter: Letter::Letters('F', 'F'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFB01, Uppercase {letter: Letter::Letters('F', 'I'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFB02, Uppercase {letter: Letter::Letters('F', 'L'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFB03, Uppercase {letter: Letter::Sequence("FFI"), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFB04, Uppercase {letter: Letter::Sequence("FFL"), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFB05, Uppercase {letter: Letter::Letters('S', 'T'), property: Property::SmallWithDecoration, decorations: Decorations::LONG}),
+    (0xFB06, Uppercase {letter: Letter::Letters('S', 'T'), property: Property::Small, decorations: Decorations::empty()}),
+    (0xFF21, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF22, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF23, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF24, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF25, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF26, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF27, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF28, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF29, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2A, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2B, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2D, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2E, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF2F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF30, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF31, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF32, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF33, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF34, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF35, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF36, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF37, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF38, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF39, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF3A, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF41, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF42, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF43, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF44, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF45, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF46, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF47, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF48, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF49, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF4F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF50, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF51, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF52, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF53, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF54, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF55, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF56, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF57, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF58, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF59, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0xFF5A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::FULLWIDTH}),
+    (0x1CCD6, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCD7, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCD8, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCD9, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDA, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDB, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDC, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDD, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDE, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCDF, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE0, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE1, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE2, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE3, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE4, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE5, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE6, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE7, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE8, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCE9, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCEA, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCEB, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCEC, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCED, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCEE, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1CCEF, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::OUTLINED}),
+    (0x1D400, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D401, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D402, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D403, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D404, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D405, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D406, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D407, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D408, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D409, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D40F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D410, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D411, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D412, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D413, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D414, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D415, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D416, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D417, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D418, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D419, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41A, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41B, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41C, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41E, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D41F, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D420, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D421, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D422, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D423, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D424, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D425, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D426, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D427, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D428, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D429, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42A, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42C, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D42F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D430, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D431, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D432, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D433, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD}),
+    (0x1D434, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D435, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D436, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D437, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D438, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D439, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43A, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43B, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43C, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43D, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43E, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D43F, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D440, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D441, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D442, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D443, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D444, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D445, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D446, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D447, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D448, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D449, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44A, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44B, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44C, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44D, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44E, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D44F, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D450, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D451, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D452, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D453, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D454, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D456, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D457, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D458, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D459, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45A, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45C, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45D, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45E, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D45F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D460, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D461, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D462, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D463, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D464, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D465, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D466, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D467, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC}),
+    (0x1D468, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D469, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46A, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46B, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46C, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46D, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D46F, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D470, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D471, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D472, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D473, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D474, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D475, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D476, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D477, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D478, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D479, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47B, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47C, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47D, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47E, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D47F, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D480, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D481, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D482, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D483, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D484, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D485, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D486, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D487, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D488, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D489, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48B, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48C, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48E, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D48F, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D490, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D491, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D492, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D493, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D494, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D495, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D496, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D497, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D498, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D499, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D49A, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D49B, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::ITALIC)}),
+    (0x1D49C, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D49E, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D49F, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4A2, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4A5, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4A6, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4A9, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4AA, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4AB, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4AC, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4AE, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4AF, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B0, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B1, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B2, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B3, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B4, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B5, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B6, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B7, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B8, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4B9, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4BB, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4BD, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4BE, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4BF, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C0, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C1, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C2, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C3, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C5, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C6, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C7, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C8, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4C9, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CA, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CB, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CC, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CD, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CE, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4CF, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SCRIPT}),
+    (0x1D4D0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D1, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D2, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D3, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D4, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D5, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D6, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D7, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D8, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4D9, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DA, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DB, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DC, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DD, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4DF, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E0, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E1, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E2, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E3, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E4, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E5, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E6, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E7, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E8, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4E9, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4EA, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4EB, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4EC, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4ED, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4EE, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4EF, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F0, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F1, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F2, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F3, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F4, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F5, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F6, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F7, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F8, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4F9, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FA, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FB, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FC, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FD, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FE, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D4FF, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D500, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D501, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D502, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D503, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::SCRIPT)}),
+    (0x1D504, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D505, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D507, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D508, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D509, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D50A, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D50D, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D50E, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D50F, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D510, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D511, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D512, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D513, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D514, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D516, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D517, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D518, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D519, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D51A, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D51B, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D51C, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D51E, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D51F, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D520, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D521, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D522, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D523, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D524, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D525, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D526, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D527, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D528, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D529, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52A, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52B, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52C, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52D, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52E, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D52F, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D530, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D531, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D532, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D533, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D534, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D535, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D536, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D537, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::FRAKTUR}),
+    (0x1D538, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D539, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D53B, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D53C, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D53D, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D53E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D540, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D541, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D542, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D543, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D544, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D546, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54B, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54C, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54D, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54E, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D54F, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D550, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D552, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D553, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D554, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D555, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D556, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D557, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D558, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D559, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55B, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55C, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55E, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D55F, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D560, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D561, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D562, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D563, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D564, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D565, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D566, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D567, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D568, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D569, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D56A, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D56B, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::DOUBLE_STRUCK}),
+    (0x1D56C, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D56D, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D56E, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D56F, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D570, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D571, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D572, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D573, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D574, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D575, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D576, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D577, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D578, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D579, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57B, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57C, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57D, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D57F, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D580, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D581, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D582, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D583, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D584, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D585, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D586, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D587, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D588, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D589, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58A, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58B, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58C, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58D, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58E, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D58F, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D590, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D591, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D592, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D593, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D594, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D595, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D596, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D597, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D598, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D599, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59A, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59B, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59C, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59E, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D59F, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::BOLD.union(Decorations::FRAKTUR)}),
+    (0x1D5A0, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A1, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A2, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A3, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A4, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A5, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A6, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A7, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A8, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5A9, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AA, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AB, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AC, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AD, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AE, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5AF, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B0, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B1, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B2, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B3, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B4, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B5, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B6, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B7, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B8, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5B9, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BA, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BB, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BC, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BD, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BE, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5BF, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C0, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C1, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C2, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C3, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C4, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C5, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C6, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C7, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C8, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5C9, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CA, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CB, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CC, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CD, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CE, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5CF, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5D0, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5D1, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5D2, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5D3, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF}),
+    (0x1D5D4, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5D5, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5D6, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5D7, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5D8, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5D9, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DA, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DB, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DC, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DD, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DE, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5DF, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E0, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E1, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E2, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E3, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E4, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E5, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E6, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E7, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E8, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5E9, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5EA, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5EB, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5EC, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5ED, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5EE, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5EF, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F0, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F1, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F2, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F3, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F4, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F5, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F6, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F7, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F8, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5F9, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FA, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FB, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FC, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FD, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FE, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D5FF, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D600, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D601, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D602, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D603, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D604, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D605, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D606, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D607, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD)}),
+    (0x1D608, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D609, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60A, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60B, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60C, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60D, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60E, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D60F, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D610, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D611, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D612, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D613, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D614, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D615, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D616, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D617, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D618, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D619, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61B, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61C, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61D, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61E, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D61F, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D620, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D621, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D622, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D623, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D624, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D625, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D626, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D627, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D628, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D629, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62B, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62C, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62D, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62E, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D62F, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D630, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D631, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D632, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D633, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D634, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D635, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D636, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D637, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D638, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D639, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D63A, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D63B, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::ITALIC)}),
+    (0x1D63C, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D63D, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D63E, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D63F, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D640, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D641, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D642, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D643, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D644, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D645, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D646, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D647, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D648, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D649, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64A, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64B, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64C, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64D, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64E, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D64F, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D650, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D651, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D652, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D653, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D654, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D655, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D656, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D657, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D658, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D659, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65A, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65B, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65C, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65D, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65E, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D65F, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D660, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D661, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D662, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D663, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D664, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D665, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D666, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D667, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D668, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D669, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66A, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66B, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66C, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66D, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66E, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D66F, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::SANS_SERIF.union(Decorations::BOLD).union(Decorations::ITALIC)}),
+    (0x1D670, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D671, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D672, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D673, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D674, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D675, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D676, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D677, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D678, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D679, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D67F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D680, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D681, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D682, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D683, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D684, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D685, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D686, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D687, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D688, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D689, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68A, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68B, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68C, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68D, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68E, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D68F, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D690, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D691, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D692, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D693, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D694, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D695, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D696, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D697, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D698, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D699, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69A, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69B, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69C, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69E, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D69F, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D6A0, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D6A1, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D6A2, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D6A3, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::MONOSPACE}),
+    (0x1D6A4, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC.union(Decorations::DOTLESS)}),
+    (0x1D6A5, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::ITALIC.union(Decorations::DOTLESS)}),
+    (0x1DF01, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED.union(Decorations::SCRIPT)}),
+    (0x1DF02, Uppercase {letter: Letter::Letter('G'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x1DF03, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::REVERSED}),
+    (0x1DF04, Uppercase {letter: Letter::Letter('L'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::BELT}),
+    (0x1DF06, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::BELT.union(Decorations::TURNED)}),
+    (0x1DF08, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEG).union(Decorations::LONG).union(Decorations::RETROFLEX).union(Decorations::TURNED)}),
+    (0x1DF09, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1DF0D, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::CURL.union(Decorations::TURNED)}),
+    (0x1DF0F, Uppercase {letter: Letter::Letter('C'), property: Property::Unknown, decorations: Decorations::CURL.union(Decorations::STRETCHED)}),
+    (0x1DF10, Uppercase {letter: Letter::Letter('K'), property: Property::SmallAndCapitalWithDecoration, decorations: Decorations::TURNED}),
+    (0x1DF11, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK)}),
+    (0x1DF13, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::BELT.union(Decorations::HOOK).union(Decorations::PALATAL)}),
+    (0x1DF15, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::PALATAL).union(Decorations::TURNED)}),
+    (0x1DF16, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::FISH.union(Decorations::HOOK).union(Decorations::PALATAL)}),
+    (0x1DF1A, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX).union(Decorations::STROKE)}),
+    (0x1DF1B, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1DF1D, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::RETROFLEX)}),
+    (0x1DF1E, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::CURL}),
+    (0x1DF25, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1DF26, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1DF27, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1DF28, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1DF29, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1DF2A, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::HOOK.union(Decorations::LEFT).union(Decorations::MID_HEIGHT)}),
+    (0x1F110, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F111, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F112, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F113, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F114, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F115, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F116, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F117, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F118, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F119, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F11F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F120, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F121, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F122, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F123, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F124, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F125, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F126, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F127, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F128, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F129, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::PARENTHESIZED}),
+    (0x1F12A, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::BRACKETED.union(Decorations::SHELL).union(Decorations::TORTOISE)}),
+    (0x1F12B, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::ITALIC)}),
+    (0x1F12C, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::ITALIC)}),
+    (0x1F12D, Uppercase {letter: Letter::Letters('C', 'D'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x1F12E, Uppercase {letter: Letter::Letters('W', 'Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED}),
+    (0x1F130, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F131, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F132, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F133, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F134, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F135, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F136, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F137, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F138, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F139, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F13F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F140, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F141, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F142, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F143, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F144, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F145, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F146, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F147, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F148, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F149, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14A, Uppercase {letter: Letter::Letters('H', 'V'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14B, Uppercase {letter: Letter::Letters('M', 'V'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14C, Uppercase {letter: Letter::Letters('S', 'D'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14D, Uppercase {letter: Letter::Letters('S', 'S'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14E, Uppercase {letter: Letter::Sequence("PPV"), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F14F, Uppercase {letter: Letter::Letters('W', 'C'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F150, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F151, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F152, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F153, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F154, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F155, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F156, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F157, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F158, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F159, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F15F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F160, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F161, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F162, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F163, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F164, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F165, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F166, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F167, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F168, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F169, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::CIRCLED.union(Decorations::NEGATIVE)}),
+    (0x1F16A, Uppercase {letter: Letter::Letters('M', 'C'), property: Property::CapitalWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x1F16B, Uppercase {letter: Letter::Letters('M', 'D'), property: Property::CapitalWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x1F16C, Uppercase {letter: Letter::Letters('M', 'R'), property: Property::CapitalWithDecoration, decorations: Decorations::SUPERSCRIPT}),
+    (0x1F170, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F171, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F172, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F173, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F174, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F175, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F176, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F177, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F178, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F179, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17A, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17B, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17C, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17D, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17E, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F17F, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F180, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F181, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F182, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F183, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F184, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F185, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F186, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F187, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F188, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F189, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED)}),
+    (0x1F18A, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::NEGATIVE.union(Decorations::SQUARED).union(Decorations::CROSSED)}),
+    (0x1F190, Uppercase {letter: Letter::Letters('D', 'J'), property: Property::CapitalWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F1A5, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::SQUARED}),
+    (0x1F1E6, Uppercase {letter: Letter::Letter('A'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1E7, Uppercase {letter: Letter::Letter('B'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1E8, Uppercase {letter: Letter::Letter('C'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1E9, Uppercase {letter: Letter::Letter('D'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1EA, Uppercase {letter: Letter::Letter('E'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1EB, Uppercase {letter: Letter::Letter('F'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1EC, Uppercase {letter: Letter::Letter('G'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1ED, Uppercase {letter: Letter::Letter('H'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1EE, Uppercase {letter: Letter::Letter('I'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1EF, Uppercase {letter: Letter::Letter('J'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F0, Uppercase {letter: Letter::Letter('K'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F1, Uppercase {letter: Letter::Letter('L'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F2, Uppercase {letter: Letter::Letter('M'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F3, Uppercase {letter: Letter::Letter('N'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F4, Uppercase {letter: Letter::Letter('O'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F5, Uppercase {letter: Letter::Letter('P'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F6, Uppercase {letter: Letter::Letter('Q'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F7, Uppercase {letter: Letter::Letter('R'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F8, Uppercase {letter: Letter::Letter('S'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1F9, Uppercase {letter: Letter::Letter('T'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FA, Uppercase {letter: Letter::Letter('U'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FB, Uppercase {letter: Letter::Letter('V'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FC, Uppercase {letter: Letter::Letter('W'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FD, Uppercase {letter: Letter::Letter('X'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FE, Uppercase {letter: Letter::Letter('Y'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x1F1FF, Uppercase {letter: Letter::Letter('Z'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xE0041, Uppercase {letter: Letter::Letter('A'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0042, Uppercase {letter: Letter::Letter('B'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0043, Uppercase {letter: Letter::Letter('C'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0044, Uppercase {letter: Letter::Letter('D'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0045, Uppercase {letter: Letter::Letter('E'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0046, Uppercase {letter: Letter::Letter('F'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0047, Uppercase {letter: Letter::Letter('G'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0048, Uppercase {letter: Letter::Letter('H'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0049, Uppercase {letter: Letter::Letter('I'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004A, Uppercase {letter: Letter::Letter('J'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004B, Uppercase {letter: Letter::Letter('K'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004C, Uppercase {letter: Letter::Letter('L'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004D, Uppercase {letter: Letter::Letter('M'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004E, Uppercase {letter: Letter::Letter('N'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE004F, Uppercase {letter: Letter::Letter('O'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0050, Uppercase {letter: Letter::Letter('P'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0051, Uppercase {letter: Letter::Letter('Q'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0052, Uppercase {letter: Letter::Letter('R'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0053, Uppercase {letter: Letter::Letter('S'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0054, Uppercase {letter: Letter::Letter('T'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0055, Uppercase {letter: Letter::Letter('U'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0056, Uppercase {letter: Letter::Letter('V'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0057, Uppercase {letter: Letter::Letter('W'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0058, Uppercase {letter: Letter::Letter('X'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0059, Uppercase {letter: Letter::Letter('Y'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE005A, Uppercase {letter: Letter::Letter('Z'), property: Property::CapitalWithDecoration, decorations: Decorations::TAG}),
+    (0xE0061, Uppercase {letter: Letter::Letter('A'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0062, Uppercase {letter: Letter::Letter('B'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0063, Uppercase {letter: Letter::Letter('C'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0064, Uppercase {letter: Letter::Letter('D'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0065, Uppercase {letter: Letter::Letter('E'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0066, Uppercase {letter: Letter::Letter('F'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0067, Uppercase {letter: Letter::Letter('G'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0068, Uppercase {letter: Letter::Letter('H'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0069, Uppercase {letter: Letter::Letter('I'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006A, Uppercase {letter: Letter::Letter('J'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006B, Uppercase {letter: Letter::Letter('K'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006C, Uppercase {letter: Letter::Letter('L'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006D, Uppercase {letter: Letter::Letter('M'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006E, Uppercase {letter: Letter::Letter('N'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE006F, Uppercase {letter: Letter::Letter('O'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0070, Uppercase {letter: Letter::Letter('P'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0071, Uppercase {letter: Letter::Letter('Q'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0072, Uppercase {letter: Letter::Letter('R'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0073, Uppercase {letter: Letter::Letter('S'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0074, Uppercase {letter: Letter::Letter('T'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0075, Uppercase {letter: Letter::Letter('U'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0076, Uppercase {letter: Letter::Letter('V'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0077, Uppercase {letter: Letter::Letter('W'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0078, Uppercase {letter: Letter::Letter('X'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE0079, Uppercase {letter: Letter::Letter('Y'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+    (0xE007A, Uppercase {letter: Letter::Letter('Z'), property: Property::SmallWithDecoration, decorations: Decorations::TAG}),
+];
 
-        m
-    };
-}
+#[cfg(feature = "names")]
+#[rustfmt::skip]
+static UPPERCASE_AZ_NAMES: &[(u32, &str)] = &[
+    (0x41, "LATIN CAPITAL LETTER A"),
+    (0x42, "LATIN CAPITAL LETTER B"),
+    (0x43, "LATIN CAPITAL LETTER C"),
+    (0x44, "LATIN CAPITAL LETTER D"),
+    (0x45, "LATIN CAPITAL LETTER E"),
+    (0x46, "LATIN CAPITAL LETTER F"),
+    (0x47, "LATIN CAPITAL LETTER G"),
+    (0x48, "LATIN CAPITAL LETTER H"),
+    (0x49, "LATIN CAPITAL LETTER I"),
+    (0x4A, "LATIN CAPITAL LETTER J"),
+    (0x4B, "LATIN CAPITAL LETTER K"),
+    (0x4C, "LATIN CAPITAL LETTER L"),
+    (0x4D, "LATIN CAPITAL LETTER M"),
+    (0x4E, "LATIN CAPITAL LETTER N"),
+    (0x4F, "LATIN CAPITAL LETTER O"),
+    (0x50, "LATIN CAPITAL LETTER P"),
+    (0x51, "LATIN CAPITAL LETTER Q"),
+    (0x52, "LATIN CAPITAL LETTER R"),
+    (0x53, "LATIN CAPITAL LETTER S"),
+    (0x54, "LATIN CAPITAL LETTER T"),
+    (0x55, "LATIN CAPITAL LETTER U"),
+    (0x56, "LATIN CAPITAL LETTER V"),
+    (0x57, "LATIN CAPITAL LETTER W"),
+    (0x58, "LATIN CAPITAL LETTER X"),
+    (0x59, "LATIN CAPITAL LETTER Y"),
+    (0x5A, "LATIN CAPITAL LETTER Z"),
+    (0x61, "LATIN SMALL LETTER A"),
+    (0x62, "LATIN SMALL LETTER B"),
+    (0x63, "LATIN SMALL LETTER C"),
+    (0x64, "LATIN SMALL LETTER D"),
+    (0x65, "LATIN SMALL LETTER E"),
+    (0x66, "LATIN SMALL LETTER F"),
+    (0x67, "LATIN SMALL LETTER G"),
+    (0x68, "LATIN SMALL LETTER H"),
+    (0x69, "LATIN SMALL LETTER I"),
+    (0x6A, "LATIN SMALL LETTER J"),
+    (0x6B, "LATIN SMALL LETTER K"),
+    (0x6C, "LATIN SMALL LETTER L"),
+    (0x6D, "LATIN SMALL LETTER M"),
+    (0x6E, "LATIN SMALL LETTER N"),
+    (0x6F, "LATIN SMALL LETTER O"),
+    (0x70, "LATIN SMALL LETTER P"),
+    (0x71, "LATIN SMALL LETTER Q"),
+    (0x72, "LATIN SMALL LETTER R"),
+    (0x73, "LATIN SMALL LETTER S"),
+    (0x74, "LATIN SMALL LETTER T"),
+    (0x75, "LATIN SMALL LETTER U"),
+    (0x76, "LATIN SMALL LETTER V"),
+    (0x77, "LATIN SMALL LETTER W"),
+    (0x78, "LATIN SMALL LETTER X"),
+    (0x79, "LATIN SMALL LETTER Y"),
+    (0x7A, "LATIN SMALL LETTER Z"),
+    (0xC0, "LATIN CAPITAL LETTER A WITH GRAVE"),
+    (0xC1, "LATIN CAPITAL LETTER A WITH ACUTE"),
+    (0xC2, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    (0xC3, "LATIN CAPITAL LETTER A WITH TILDE"),
+    (0xC4, "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    (0xC5, "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    (0xC6, "LATIN CAPITAL LETTER AE"),
+    (0xC7, "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    (0xC8, "LATIN CAPITAL LETTER E WITH GRAVE"),
+    (0xC9, "LATIN CAPITAL LETTER E WITH ACUTE"),
+    (0xCA, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    (0xCB, "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    (0xCC, "LATIN CAPITAL LETTER I WITH GRAVE"),
+    (0xCD, "LATIN CAPITAL LETTER I WITH ACUTE"),
+    (0xCE, "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    (0xCF, "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    (0xD1, "LATIN CAPITAL LETTER N WITH TILDE"),
+    (0xD2, "LATIN CAPITAL LETTER O WITH GRAVE"),
+    (0xD3, "LATIN CAPITAL LETTER O WITH ACUTE"),
+    (0xD4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    (0xD5, "LATIN CAPITAL LETTER O WITH TILDE"),
+    (0xD6, "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    (0xD8, "LATIN CAPITAL LETTER O WITH STROKE"),
+    (0xD9, "LATIN CAPITAL LETTER U WITH GRAVE"),
+    (0xDA, "LATIN CAPITAL LETTER U WITH ACUTE"),
+    (0xDB, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    (0xDC, "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    (0xDD, "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    (0xDF, "LATIN SMALL LETTER SHARP S"),
+    (0xE0, "LATIN SMALL LETTER A WITH GRAVE"),
+    (0xE1, "LATIN SMALL LETTER A WITH ACUTE"),
+    (0xE2, "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    (0xE3, "LATIN SMALL LETTER A WITH TILDE"),
+    (0xE4, "LATIN SMALL LETTER A WITH DIAERESIS"),
+    (0xE5, "LATIN SMALL LETTER A WITH RING ABOVE"),
+    (0xE6, "LATIN SMALL LETTER AE"),
+    (0xE7, "LATIN SMALL LETTER C WITH CEDILLA"),
+    (0xE8, "LATIN SMALL LETTER E WITH GRAVE"),
+    (0xE9, "LATIN SMALL LETTER E WITH ACUTE"),
+    (0xEA, "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    (0xEB, "LATIN SMALL LETTER E WITH DIAERESIS"),
+    (0xEC, "LATIN SMALL LETTER I WITH GRAVE"),
+    (0xED, "LATIN SMALL LETTER I WITH ACUTE"),
+    (0xEE, "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    (0xEF, "LATIN SMALL LETTER I WITH DIAERESIS"),
+    (0xF1, "LATIN SMALL LETTER N WITH TILDE"),
+    (0xF2, "LATIN SMALL LETTER O WITH GRAVE"),
+    (0xF3, "LATIN SMALL LETTER O WITH ACUTE"),
+    (0xF4, "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    (0xF5, "LATIN SMALL LETTER O WITH TILDE"),
+    (0xF6, "LATIN SMALL LETTER O WITH DIAERESIS"),
+    (0xF8, "LATIN SMALL LETTER O WITH STROKE"),
+    (0xF9, "LATIN SMALL LETTER U WITH GRAVE"),
+    (0xFA, "LATIN SMALL LETTER U WITH ACUTE"),
+    (0xFB, "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    (0xFC, "LATIN SMALL LETTER U WITH DIAERESIS"),
+    (0xFD, "LATIN SMALL LETTER Y WITH ACUTE"),
+    (0xFF, "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    (0x100, "LATIN CAPITAL LETTER A WITH MACRON"),
+    (0x101, "LATIN SMALL LETTER A WITH MACRON"),
+    (0x102, "LATIN CAPITAL LETTER A WITH BREVE"),
+    (0x103, "LATIN SMALL LETTER A WITH BREVE"),
+    (0x104, "LATIN CAPITAL LETTER A WITH OGONEK"),
+    (0x105, "LATIN SMALL LETTER A WITH OGONEK"),
+    (0x106, "LATIN CAPITAL LETTER C WITH ACUTE"),
+    (0x107, "LATIN SMALL LETTER C WITH ACUTE"),
+    (0x108, "LATIN CAPITAL LETTER C WITH CIRCUMFLEX"),
+    (0x109, "LATIN SMALL LETTER C WITH CIRCUMFLEX"),
+    (0x10A, "LATIN CAPITAL LETTER C WITH DOT ABOVE"),
+    (0x10B, "LATIN SMALL LETTER C WITH DOT ABOVE"),
+    (0x10C, "LATIN CAPITAL LETTER C WITH CARON"),
+    (0x10D, "LATIN SMALL LETTER C WITH CARON"),
+    (0x10E, "LATIN CAPITAL LETTER D WITH CARON"),
+    (0x10F, "LATIN SMALL LETTER D WITH CARON"),
+    (0x110, "LATIN CAPITAL LETTER D WITH STROKE"),
+    (0x111, "LATIN SMALL LETTER D WITH STROKE"),
+    (0x112, "LATIN CAPITAL LETTER E WITH MACRON"),
+    (0x113, "LATIN SMALL LETTER E WITH MACRON"),
+    (0x114, "LATIN CAPITAL LETTER E WITH BREVE"),
+    (0x115, "LATIN SMALL LETTER E WITH BREVE"),
+    (0x116, "LATIN CAPITAL LETTER E WITH DOT ABOVE"),
+    (0x117, "LATIN SMALL LETTER E WITH DOT ABOVE"),
+    (0x118, "LATIN CAPITAL LETTER E WITH OGONEK"),
+    (0x119, "LATIN SMALL LETTER E WITH OGONEK"),
+    (0x11A, "LATIN CAPITAL LETTER E WITH CARON"),
+    (0x11B, "LATIN SMALL LETTER E WITH CARON"),
+    (0x11C, "LATIN CAPITAL LETTER G WITH CIRCUMFLEX"),
+    (0x11D, "LATIN SMALL LETTER G WITH CIRCUMFLEX"),
+    (0x11E, "LATIN CAPITAL LETTER G WITH BREVE"),
+    (0x11F, "LATIN SMALL LETTER G WITH BREVE"),
+    (0x120, "LATIN CAPITAL LETTER G WITH DOT ABOVE"),
+    (0x121, "LATIN SMALL LETTER G WITH DOT ABOVE"),
+    (0x122, "LATIN CAPITAL LETTER G WITH CEDILLA"),
+    (0x123, "LATIN SMALL LETTER G WITH CEDILLA"),
+    (0x124, "LATIN CAPITAL LETTER H WITH CIRCUMFLEX"),
+    (0x125, "LATIN SMALL LETTER H WITH CIRCUMFLEX"),
+    (0x126, "LATIN CAPITAL LETTER H WITH STROKE"),
+    (0x127, "LATIN SMALL LETTER H WITH STROKE"),
+    (0x128, "LATIN CAPITAL LETTER I WITH TILDE"),
+    (0x129, "LATIN SMALL LETTER I WITH TILDE"),
+    (0x12A, "LATIN CAPITAL LETTER I WITH MACRON"),
+    (0x12B, "LATIN SMALL LETTER I WITH MACRON"),
+    (0x12C, "LATIN CAPITAL LETTER I WITH BREVE"),
+    (0x12D, "LATIN SMALL LETTER I WITH BREVE"),
+    (0x12E, "LATIN CAPITAL LETTER I WITH OGONEK"),
+    (0x12F, "LATIN SMALL LETTER I WITH OGONEK"),
+    (0x130, "LATIN CAPITAL LETTER I WITH DOT ABOVE"),
+    (0x131, "LATIN SMALL LETTER DOTLESS I"),
+    (0x132, "LATIN CAPITAL LIGATURE IJ"),
+    (0x133, "LATIN SMALL LIGATURE IJ"),
+    (0x134, "LATIN CAPITAL LETTER J WITH CIRCUMFLEX"),
+    (0x135, "LATIN SMALL LETTER J WITH CIRCUMFLEX"),
+    (0x136, "LATIN CAPITAL LETTER K WITH CEDILLA"),
+    (0x137, "LATIN SMALL LETTER K WITH CEDILLA"),
+    (0x139, "LATIN CAPITAL LETTER L WITH ACUTE"),
+    (0x13A, "LATIN SMALL LETTER L WITH ACUTE"),
+    (0x13B, "LATIN CAPITAL LETTER L WITH CEDILLA"),
+    (0x13C, "LATIN SMALL LETTER L WITH CEDILLA"),
+    (0x13D, "LATIN CAPITAL LETTER L WITH CARON"),
+    (0x13E, "LATIN SMALL LETTER L WITH CARON"),
+    (0x13F, "LATIN CAPITAL LETTER L WITH MIDDLE DOT"),
+    (0x140, "LATIN SMALL LETTER L WITH MIDDLE DOT"),
+    (0x141, "LATIN CAPITAL LETTER L WITH STROKE"),
+    (0x142, "LATIN SMALL LETTER L WITH STROKE"),
+    (0x143, "LATIN CAPITAL LETTER N WITH ACUTE"),
+    (0x144, "LATIN SMALL LETTER N WITH ACUTE"),
+    (0x145, "LATIN CAPITAL LETTER N WITH CEDILLA"),
+    (0x146, "LATIN SMALL LETTER N WITH CEDILLA"),
+    (0x147, "LATIN CAPITAL LETTER N WITH CARON"),
+    (0x148, "LATIN SMALL LETTER N WITH CARON"),
+    (0x149, "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE"),
+    (0x14C, "LATIN CAPITAL LETTER O WITH MACRON"),
+    (0x14D, "LATIN SMALL LETTER O WITH MACRON"),
+    (0x14E, "LATIN CAPITAL LETTER O WITH BREVE"),
+    (0x14F, "LATIN SMALL LETTER O WITH BREVE"),
+    (0x150, "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"),
+    (0x151, "LATIN SMALL LETTER O WITH DOUBLE ACUTE"),
+    (0x152, "LATIN CAPITAL LIGATURE OE"),
+    (0x153, "LATIN SMALL LIGATURE OE"),
+    (0x154, "LATIN CAPITAL LETTER R WITH ACUTE"),
+    (0x155, "LATIN SMALL LETTER R WITH ACUTE"),
+    (0x156, "LATIN CAPITAL LETTER R WITH CEDILLA"),
+    (0x157, "LATIN SMALL LETTER R WITH CEDILLA"),
+    (0x158, "LATIN CAPITAL LETTER R WITH CARON"),
+    (0x159, "LATIN SMALL LETTER R WITH CARON"),
+    (0x15A, "LATIN CAPITAL LETTER S WITH ACUTE"),
+    (0x15B, "LATIN SMALL LETTER S WITH ACUTE"),
+    (0x15C, "LATIN CAPITAL LETTER S WITH CIRCUMFLEX"),
+    (0x15D, "LATIN SMALL LETTER S WITH CIRCUMFLEX"),
+    (0x15E, "LATIN CAPITAL LETTER S WITH CEDILLA"),
+    (0x15F, "LATIN SMALL LETTER S WITH CEDILLA"),
+    (0x160, "LATIN CAPITAL LETTER S WITH CARON"),
+    (0x161, "LATIN SMALL LETTER S WITH CARON"),
+    (0x162, "LATIN CAPITAL LETTER T WITH CEDILLA"),
+    (0x163, "LATIN SMALL LETTER T WITH CEDILLA"),
+    (0x164, "LATIN CAPITAL LETTER T WITH CARON"),
+    (0x165, "LATIN SMALL LETTER T WITH CARON"),
+    (0x166, "LATIN CAPITAL LETTER T WITH STROKE"),
+    (0x167, "LATIN SMALL LETTER T WITH STROKE"),
+    (0x168, "LATIN CAPITAL LETTER U WITH TILDE"),
+    (0x169, "LATIN SMALL LETTER U WITH TILDE"),
+    (0x16A, "LATIN CAPITAL LETTER U WITH MACRON"),
+    (0x16B, "LATIN SMALL LETTER U WITH MACRON"),
+    (0x16C, "LATIN CAPITAL LETTER U WITH BREVE"),
+    (0x16D, "LATIN SMALL LETTER U WITH BREVE"),
+    (0x16E, "LATIN CAPITAL LETTER U WITH RING ABOVE"),
+    (0x16F, "LATIN SMALL LETTER U WITH RING ABOVE"),
+    (0x170, "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    (0x171, "LATIN SMALL LETTER U WITH DOUBLE ACUTE"),
+    (0x172, "LATIN CAPITAL LETTER U WITH OGONEK"),
+    (0x173, "LATIN SMALL LETTER U WITH OGONEK"),
+    (0x174, "LATIN CAPITAL LETTER W WITH CIRCUMFLEX"),
+    (0x175, "LATIN SMALL LETTER W WITH CIRCUMFLEX"),
+    (0x176, "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"),
+    (0x177, "LATIN SMALL LETTER Y WITH CIRCUMFLEX"),
+    (0x178, "LATIN CAPITAL LETTER Y WITH DIAERESIS"),
+    (0x179, "LATIN CAPITAL LETTER Z WITH ACUTE"),
+    (0x17A, "LATIN SMALL LETTER Z WITH ACUTE"),
+    (0x17B, "LATIN CAPITAL LETTER Z WITH DOT ABOVE"),
+    (0x17C, "LATIN SMALL LETTER Z WITH DOT ABOVE"),
+    (0x17D, "LATIN CAPITAL LETTER Z WITH CARON"),
+    (0x17E, "LATIN SMALL LETTER Z WITH CARON"),
+    (0x17F, "LATIN SMALL LETTER LONG S"),
+    (0x180, "LATIN SMALL LETTER B WITH STROKE"),
+    (0x181, "LATIN CAPITAL LETTER B WITH HOOK"),
+    (0x182, "LATIN CAPITAL LETTER B WITH TOPBAR"),
+    (0x183, "LATIN SMALL LETTER B WITH TOPBAR"),
+    (0x186, "LATIN CAPITAL LETTER OPEN O"),
+    (0x187, "LATIN CAPITAL LETTER C WITH HOOK"),
+    (0x188, "LATIN SMALL LETTER C WITH HOOK"),
+    (0x189, "LATIN CAPITAL LETTER AFRICAN D"),
+    (0x18A, "LATIN CAPITAL LETTER D WITH HOOK"),
+    (0x18B, "LATIN CAPITAL LETTER D WITH TOPBAR"),
+    (0x18C, "LATIN SMALL LETTER D WITH TOPBAR"),
+    (0x18E, "LATIN CAPITAL LETTER REVERSED E"),
+    (0x190, "LATIN CAPITAL LETTER OPEN E"),
+    (0x191, "LATIN CAPITAL LETTER F WITH HOOK"),
+    (0x192, "LATIN SMALL LETTER F WITH HOOK"),
+    (0x193, "LATIN CAPITAL LETTER G WITH HOOK"),
+    (0x195, "LATIN SMALL LETTER HV"),
+    (0x197, "LATIN CAPITAL LETTER I WITH STROKE"),
+    (0x198, "LATIN CAPITAL LETTER K WITH HOOK"),
+    (0x199, "LATIN SMALL LETTER K WITH HOOK"),
+    (0x19A, "LATIN SMALL LETTER L WITH BAR"),
+    (0x19C, "LATIN CAPITAL LETTER TURNED M"),
+    (0x19D, "LATIN CAPITAL LETTER N WITH LEFT HOOK"),
+    (0x19E, "LATIN SMALL LETTER N WITH LONG RIGHT LEG"),
+    (0x19F, "LATIN CAPITAL LETTER O WITH MIDDLE TILDE"),
+    (0x1A0, "LATIN CAPITAL LETTER O WITH HORN"),
+    (0x1A1, "LATIN SMALL LETTER O WITH HORN"),
+    (0x1A2, "LATIN CAPITAL LETTER OI"),
+    (0x1A3, "LATIN SMALL LETTER OI"),
+    (0x1A4, "LATIN CAPITAL LETTER P WITH HOOK"),
+    (0x1A5, "LATIN SMALL LETTER P WITH HOOK"),
+    (0x1A6, "LATIN LETTER YR"),
+    (0x1AB, "LATIN SMALL LETTER T WITH PALATAL HOOK"),
+    (0x1AC, "LATIN CAPITAL LETTER T WITH HOOK"),
+    (0x1AD, "LATIN SMALL LETTER T WITH HOOK"),
+    (0x1AE, "LATIN CAPITAL LETTER T WITH RETROFLEX HOOK"),
+    (0x1AF, "LATIN CAPITAL LETTER U WITH HORN"),
+    (0x1B0, "LATIN SMALL LETTER U WITH HORN"),
+    (0x1B2, "LATIN CAPITAL LETTER V WITH HOOK"),
+    (0x1B3, "LATIN CAPITAL LETTER Y WITH HOOK"),
+    (0x1B4, "LATIN SMALL LETTER Y WITH HOOK"),
+    (0x1B5, "LATIN CAPITAL LETTER Z WITH STROKE"),
+    (0x1B6, "LATIN SMALL LETTER Z WITH STROKE"),
+    (0x1C4, "LATIN CAPITAL LETTER DZ WITH CARON"),
+    (0x1C5, "LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON"),
+    (0x1C6, "LATIN SMALL LETTER DZ WITH CARON"),
+    (0x1C7, "LATIN CAPITAL LETTER LJ"),
+    (0x1C8, "LATIN CAPITAL LETTER L WITH SMALL LETTER J"),
+    (0x1C9, "LATIN SMALL LETTER LJ"),
+    (0x1CA, "LATIN CAPITAL LETTER NJ"),
+    (0x1CB, "LATIN CAPITAL LETTER N WITH SMALL LETTER J"),
+    (0x1CC, "LATIN SMALL LETTER NJ"),
+    (0x1CD, "LATIN CAPITAL LETTER A WITH CARON"),
+    (0x1CE, "LATIN SMALL LETTER A WITH CARON"),
+    (0x1CF, "LATIN CAPITAL LETTER I WITH CARON"),
+    (0x1D0, "LATIN SMALL LETTER I WITH CARON"),
+    (0x1D1, "LATIN CAPITAL LETTER O WITH CARON"),
+    (0x1D2, "LATIN SMALL LETTER O WITH CARON"),
+    (0x1D3, "LATIN CAPITAL LETTER U WITH CARON"),
+    (0x1D4, "LATIN SMALL LETTER U WITH CARON"),
+    (0x1D5, "LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON"),
+    (0x1D6, "LATIN SMALL LETTER U WITH DIAERESIS AND MACRON"),
+    (0x1D7, "LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE"),
+    (0x1D8, "LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE"),
+    (0x1D9, "LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON"),
+    (0x1DA, "LATIN SMALL LETTER U WITH DIAERESIS AND CARON"),
+    (0x1DB, "LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE"),
+    (0x1DC, "LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE"),
+    (0x1DD, "LATIN SMALL LETTER TURNED E"),
+    (0x1DE, "LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON"),
+    (0x1DF, "LATIN SMALL LETTER A WITH DIAERESIS AND MACRON"),
+    (0x1E0, "LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON"),
+    (0x1E1, "LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON"),
+    (0x1E2, "LATIN CAPITAL LETTER AE WITH MACRON"),
+    (0x1E3, "LATIN SMALL LETTER AE WITH MACRON"),
+    (0x1E4, "LATIN CAPITAL LETTER G WITH STROKE"),
+    (0x1E5, "LATIN SMALL LETTER G WITH STROKE"),
+    (0x1E6, "LATIN CAPITAL LETTER G WITH CARON"),
+    (0x1E7, "LATIN SMALL LETTER G WITH CARON"),
+    (0x1E8, "LATIN CAPITAL LETTER K WITH CARON"),
+    (0x1E9, "LATIN SMALL LETTER K WITH CARON"),
+    (0x1EA, "LATIN CAPITAL LETTER O WITH OGONEK"),
+    (0x1EB, "LATIN SMALL LETTER O WITH OGONEK"),
+    (0x1EC, "LATIN CAPITAL LETTER O WITH OGONEK AND MACRON"),
+    (0x1ED, "LATIN SMALL LETTER O WITH OGONEK AND MACRON"),
+    (0x1F0, "LATIN SMALL LETTER J WITH CARON"),
+    (0x1F1, "LATIN CAPITAL LETTER DZ"),
+    (0x1F2, "LATIN CAPITAL LETTER D WITH SMALL LETTER Z"),
+    (0x1F3, "LATIN SMALL LETTER DZ"),
+    (0x1F4, "LATIN CAPITAL LETTER G WITH ACUTE"),
+    (0x1F5, "LATIN SMALL LETTER G WITH ACUTE"),
+    (0x1F8, "LATIN CAPITAL LETTER N WITH GRAVE"),
+    (0x1F9, "LATIN SMALL LETTER N WITH GRAVE"),
+    (0x1FA, "LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE"),
+    (0x1FB, "LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE"),
+    (0x1FC, "LATIN CAPITAL LETTER AE WITH ACUTE"),
+    (0x1FD, "LATIN SMALL LETTER AE WITH ACUTE"),
+    (0x1FE, "LATIN CAPITAL LETTER O WITH STROKE AND ACUTE"),
+    (0x1FF, "LATIN SMALL LETTER O WITH STROKE AND ACUTE"),
+    (0x200, "LATIN CAPITAL LETTER A WITH DOUBLE GRAVE"),
+    (0x201, "LATIN SMALL LETTER A WITH DOUBLE GRAVE"),
+    (0x202, "LATIN CAPITAL LETTER A WITH INVERTED BREVE"),
+    (0x203, "LATIN SMALL LETTER A WITH INVERTED BREVE"),
+    (0x204, "LATIN CAPITAL LETTER E WITH DOUBLE GRAVE"),
+    (0x205, "LATIN SMALL LETTER E WITH DOUBLE GRAVE"),
+    (0x206, "LATIN CAPITAL LETTER E WITH INVERTED BREVE"),
+    (0x207, "LATIN SMALL LETTER E WITH INVERTED BREVE"),
+    (0x208, "LATIN CAPITAL LETTER I WITH DOUBLE GRAVE"),
+    (0x209, "LATIN SMALL LETTER I WITH DOUBLE GRAVE"),
+    (0x20A, "LATIN CAPITAL LETTER I WITH INVERTED BREVE"),
+    (0x20B, "LATIN SMALL LETTER I WITH INVERTED BREVE"),
+    (0x20C, "LATIN CAPITAL LETTER O WITH DOUBLE GRAVE"),
+    (0x20D, "LATIN SMALL LETTER O WITH DOUBLE GRAVE"),
+    (0x20E, "LATIN CAPITAL LETTER O WITH INVERTED BREVE"),
+    (0x20F, "LATIN SMALL LETTER O WITH INVERTED BREVE"),
+    (0x210, "LATIN CAPITAL LETTER R WITH DOUBLE GRAVE"),
+    (0x211, "LATIN SMALL LETTER R WITH DOUBLE GRAVE"),
+    (0x212, "LATIN CAPITAL LETTER R WITH INVERTED BREVE"),
+    (0x213, "LATIN SMALL LETTER R WITH INVERTED BREVE"),
+    (0x214, "LATIN CAPITAL LETTER U WITH DOUBLE GRAVE"),
+    (0x215, "LATIN SMALL LETTER U WITH DOUBLE GRAVE"),
+    (0x216, "LATIN CAPITAL LETTER U WITH INVERTED BREVE"),
+    (0x217, "LATIN SMALL LETTER U WITH INVERTED BREVE"),
+    (0x218, "LATIN CAPITAL LETTER S WITH COMMA BELOW"),
+    (0x219, "LATIN SMALL LETTER S WITH COMMA BELOW"),
+    (0x21A, "LATIN CAPITAL LETTER T WITH COMMA BELOW"),
+    (0x21B, "LATIN SMALL LETTER T WITH COMMA BELOW"),
+    (0x21E, "LATIN CAPITAL LETTER H WITH CARON"),
+    (0x21F, "LATIN SMALL LETTER H WITH CARON"),
+    (0x220, "LATIN CAPITAL LETTER N WITH LONG RIGHT LEG"),
+    (0x221, "LATIN SMALL LETTER D WITH CURL"),
+    (0x222, "LATIN CAPITAL LETTER OU"),
+    (0x223, "LATIN SMALL LETTER OU"),
+    (0x224, "LATIN CAPITAL LETTER Z WITH HOOK"),
+    (0x225, "LATIN SMALL LETTER Z WITH HOOK"),
+    (0x226, "LATIN CAPITAL LETTER A WITH DOT ABOVE"),
+    (0x227, "LATIN SMALL LETTER A WITH DOT ABOVE"),
+    (0x228, "LATIN CAPITAL LETTER E WITH CEDILLA"),
+    (0x229, "LATIN SMALL LETTER E WITH CEDILLA"),
+    (0x22A, "LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON"),
+    (0x22B, "LATIN SMALL LETTER O WITH DIAERESIS AND MACRON"),
+    (0x22C, "LATIN CAPITAL LETTER O WITH TILDE AND MACRON"),
+    (0x22D, "LATIN SMALL LETTER O WITH TILDE AND MACRON"),
+    (0x22E, "LATIN CAPITAL LETTER O WITH DOT ABOVE"),
+    (0x22F, "LATIN SMALL LETTER O WITH DOT ABOVE"),
+    (0x230, "LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON"),
+    (0x231, "LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON"),
+    (0x232, "LATIN CAPITAL LETTER Y WITH MACRON"),
+    (0x233, "LATIN SMALL LETTER Y WITH MACRON"),
+    (0x234, "LATIN SMALL LETTER L WITH CURL"),
+    (0x235, "LATIN SMALL LETTER N WITH CURL"),
+    (0x236, "LATIN SMALL LETTER T WITH CURL"),
+    (0x237, "LATIN SMALL LETTER DOTLESS J"),
+    (0x238, "LATIN SMALL LETTER DB DIGRAPH"),
+    (0x239, "LATIN SMALL LETTER QP DIGRAPH"),
+    (0x23A, "LATIN CAPITAL LETTER A WITH STROKE"),
+    (0x23B, "LATIN CAPITAL LETTER C WITH STROKE"),
+    (0x23C, "LATIN SMALL LETTER C WITH STROKE"),
+    (0x23D, "LATIN CAPITAL LETTER L WITH BAR"),
+    (0x23E, "LATIN CAPITAL LETTER T WITH DIAGONAL STROKE"),
+    (0x23F, "LATIN SMALL LETTER S WITH SWASH TAIL"),
+    (0x240, "LATIN SMALL LETTER Z WITH SWASH TAIL"),
+    (0x243, "LATIN CAPITAL LETTER B WITH STROKE"),
+    (0x244, "LATIN CAPITAL LETTER U BAR"),
+    (0x245, "LATIN CAPITAL LETTER TURNED V"),
+    (0x246, "LATIN CAPITAL LETTER E WITH STROKE"),
+    (0x247, "LATIN SMALL LETTER E WITH STROKE"),
+    (0x248, "LATIN CAPITAL LETTER J WITH STROKE"),
+    (0x249, "LATIN SMALL LETTER J WITH STROKE"),
+    (0x24A, "LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL"),
+    (0x24B, "LATIN SMALL LETTER Q WITH HOOK TAIL"),
+    (0x24C, "LATIN CAPITAL LETTER R WITH STROKE"),
+    (0x24D, "LATIN SMALL LETTER R WITH STROKE"),
+    (0x24E, "LATIN CAPITAL LETTER Y WITH STROKE"),
+    (0x24F, "LATIN SMALL LETTER Y WITH STROKE"),
+    (0x250, "LATIN SMALL LETTER TURNED A"),
+    (0x253, "LATIN SMALL LETTER B WITH HOOK"),
+    (0x254, "LATIN SMALL LETTER OPEN O"),
+    (0x255, "LATIN SMALL LETTER C WITH CURL"),
+    (0x256, "LATIN SMALL LETTER D WITH TAIL"),
+    (0x257, "LATIN SMALL LETTER D WITH HOOK"),
+    (0x258, "LATIN SMALL LETTER REVERSED E"),
+    (0x25B, "LATIN SMALL LETTER OPEN E"),
+    (0x25C, "LATIN SMALL LETTER REVERSED OPEN E"),
+    (0x25D, "LATIN SMALL LETTER REVERSED OPEN E WITH HOOK"),
+    (0x25E, "LATIN SMALL LETTER CLOSED REVERSED OPEN E"),
+    (0x25F, "LATIN SMALL LETTER DOTLESS J WITH STROKE"),
+    (0x260, "LATIN SMALL LETTER G WITH HOOK"),
+    (0x261, "LATIN SMALL LETTER SCRIPT G"),
+    (0x262, "LATIN LETTER SMALL CAPITAL G"),
+    (0x265, "LATIN SMALL LETTER TURNED H"),
+    (0x266, "LATIN SMALL LETTER H WITH HOOK"),
+    (0x268, "LATIN SMALL LETTER I WITH STROKE"),
+    (0x26A, "LATIN LETTER SMALL CAPITAL I"),
+    (0x26B, "LATIN SMALL LETTER L WITH MIDDLE TILDE"),
+    (0x26C, "LATIN SMALL LETTER L WITH BELT"),
+    (0x26D, "LATIN SMALL LETTER L WITH RETROFLEX HOOK"),
+    (0x26F, "LATIN SMALL LETTER TURNED M"),
+    (0x270, "LATIN SMALL LETTER TURNED M WITH LONG LEG"),
+    (0x271, "LATIN SMALL LETTER M WITH HOOK"),
+    (0x272, "LATIN SMALL LETTER N WITH LEFT HOOK"),
+    (0x273, "LATIN SMALL LETTER N WITH RETROFLEX HOOK"),
+    (0x274, "LATIN LETTER SMALL CAPITAL N"),
+    (0x275, "LATIN SMALL LETTER BARRED O"),
+    (0x276, "LATIN LETTER SMALL CAPITAL OE"),
+    (0x279, "LATIN SMALL LETTER TURNED R"),
+    (0x27A, "LATIN SMALL LETTER TURNED R WITH LONG LEG"),
+    (0x27B, "LATIN SMALL LETTER TURNED R WITH HOOK"),
+    (0x27C, "LATIN SMALL LETTER R WITH LONG LEG"),
+    (0x27D, "LATIN SMALL LETTER R WITH TAIL"),
+    (0x27E, "LATIN SMALL LETTER R WITH FISHHOOK"),
+    (0x27F, "LATIN SMALL LETTER REVERSED R WITH FISHHOOK"),
+    (0x280, "LATIN LETTER SMALL CAPITAL R"),
+    (0x281, "LATIN LETTER SMALL CAPITAL INVERTED R"),
+    (0x282, "LATIN SMALL LETTER S WITH HOOK"),
+    (0x284, "LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK"),
+    (0x287, "LATIN SMALL LETTER TURNED T"),
+    (0x288, "LATIN SMALL LETTER T WITH RETROFLEX HOOK"),
+    (0x289, "LATIN SMALL LETTER U BAR"),
+    (0x28B, "LATIN SMALL LETTER V WITH HOOK"),
+    (0x28C, "LATIN SMALL LETTER TURNED V"),
+    (0x28D, "LATIN SMALL LETTER TURNED W"),
+    (0x28E, "LATIN SMALL LETTER TURNED Y"),
+    (0x28F, "LATIN LETTER SMALL CAPITAL Y"),
+    (0x290, "LATIN SMALL LETTER Z WITH RETROFLEX HOOK"),
+    (0x291, "LATIN SMALL LETTER Z WITH CURL"),
+    (0x297, "LATIN LETTER STRETCHED C"),
+    (0x299, "LATIN LETTER SMALL CAPITAL B"),
+    (0x29A, "LATIN SMALL LETTER CLOSED OPEN E"),
+    (0x29B, "LATIN LETTER SMALL CAPITAL G WITH HOOK"),
+    (0x29C, "LATIN LETTER SMALL CAPITAL H"),
+    (0x29D, "LATIN SMALL LETTER J WITH CROSSED-TAIL"),
+    (0x29E, "LATIN SMALL LETTER TURNED K"),
+    (0x29F, "LATIN LETTER SMALL CAPITAL L"),
+    (0x2A0, "LATIN SMALL LETTER Q WITH HOOK"),
+    (0x2A3, "LATIN SMALL LETTER DZ DIGRAPH"),
+    (0x2A5, "LATIN SMALL LETTER DZ DIGRAPH WITH CURL"),
+    (0x2A6, "LATIN SMALL LETTER TS DIGRAPH"),
+    (0x2A8, "LATIN SMALL LETTER TC DIGRAPH WITH CURL"),
+    (0x2AA, "LATIN SMALL LETTER LS DIGRAPH"),
+    (0x2AB, "LATIN SMALL LETTER LZ DIGRAPH"),
+    (0x2AE, "LATIN SMALL LETTER TURNED H WITH FISHHOOK"),
+    (0x2AF, "LATIN SMALL LETTER TURNED H WITH FISHHOOK AND TAIL"),
+    (0x363, "COMBINING LATIN SMALL LETTER A"),
+    (0x364, "COMBINING LATIN SMALL LETTER E"),
+    (0x365, "COMBINING LATIN SMALL LETTER I"),
+    (0x366, "COMBINING LATIN SMALL LETTER O"),
+    (0x367, "COMBINING LATIN SMALL LETTER U"),
+    (0x368, "COMBINING LATIN SMALL LETTER C"),
+    (0x369, "COMBINING LATIN SMALL LETTER D"),
+    (0x36A, "COMBINING LATIN SMALL LETTER H"),
+    (0x36B, "COMBINING LATIN SMALL LETTER M"),
+    (0x36C, "COMBINING LATIN SMALL LETTER R"),
+    (0x36D, "COMBINING LATIN SMALL LETTER T"),
+    (0x36E, "COMBINING LATIN SMALL LETTER V"),
+    (0x36F, "COMBINING LATIN SMALL LETTER X"),
+    (0x1ABF, "COMBINING LATIN SMALL LETTER W BELOW"),
+    (0x1AC0, "COMBINING LATIN SMALL LETTER TURNED W BELOW"),
+    (0x1ACC, "COMBINING LATIN SMALL LETTER INSULAR G"),
+    (0x1ACD, "COMBINING LATIN SMALL LETTER INSULAR R"),
+    (0x1ACE, "COMBINING LATIN SMALL LETTER INSULAR T"),
+    (0x1D00, "LATIN LETTER SMALL CAPITAL A"),
+    (0x1D01, "LATIN LETTER SMALL CAPITAL AE"),
+    (0x1D02, "LATIN SMALL LETTER TURNED AE"),
+    (0x1D03, "LATIN LETTER SMALL CAPITAL BARRED B"),
+    (0x1D04, "LATIN LETTER SMALL CAPITAL C"),
+    (0x1D05, "LATIN LETTER SMALL CAPITAL D"),
+    (0x1D07, "LATIN LETTER SMALL CAPITAL E"),
+    (0x1D08, "LATIN SMALL LETTER TURNED OPEN E"),
+    (0x1D09, "LATIN SMALL LETTER TURNED I"),
+    (0x1D0A, "LATIN LETTER SMALL CAPITAL J"),
+    (0x1D0B, "LATIN LETTER SMALL CAPITAL K"),
+    (0x1D0C, "LATIN LETTER SMALL CAPITAL L WITH STROKE"),
+    (0x1D0D, "LATIN LETTER SMALL CAPITAL M"),
+    (0x1D0E, "LATIN LETTER SMALL CAPITAL REVERSED N"),
+    (0x1D0F, "LATIN LETTER SMALL CAPITAL O"),
+    (0x1D10, "LATIN LETTER SMALL CAPITAL OPEN O"),
+    (0x1D11, "LATIN SMALL LETTER SIDEWAYS O"),
+    (0x1D12, "LATIN SMALL LETTER SIDEWAYS OPEN O"),
+    (0x1D13, "LATIN SMALL LETTER SIDEWAYS O WITH STROKE"),
+    (0x1D14, "LATIN SMALL LETTER TURNED OE"),
+    (0x1D15, "LATIN LETTER SMALL CAPITAL OU"),
+    (0x1D16, "LATIN SMALL LETTER TOP HALF O"),
+    (0x1D17, "LATIN SMALL LETTER BOTTOM HALF O"),
+    (0x1D18, "LATIN LETTER SMALL CAPITAL P"),
+    (0x1D19, "LATIN LETTER SMALL CAPITAL REVERSED R"),
+    (0x1D1A, "LATIN LETTER SMALL CAPITAL TURNED R"),
+    (0x1D1B, "LATIN LETTER SMALL CAPITAL T"),
+    (0x1D1C, "LATIN LETTER SMALL CAPITAL U"),
+    (0x1D1D, "LATIN SMALL LETTER SIDEWAYS U"),
+    (0x1D1E, "LATIN SMALL LETTER SIDEWAYS DIAERESIZED U"),
+    (0x1D1F, "LATIN SMALL LETTER SIDEWAYS TURNED M"),
+    (0x1D20, "LATIN LETTER SMALL CAPITAL V"),
+    (0x1D21, "LATIN LETTER SMALL CAPITAL W"),
+    (0x1D22, "LATIN LETTER SMALL CAPITAL Z"),
+    (0x1D62, "LATIN SUBSCRIPT SMALL LETTER I"),
+    (0x1D63, "LATIN SUBSCRIPT SMALL LETTER R"),
+    (0x1D64, "LATIN SUBSCRIPT SMALL LETTER U"),
+    (0x1D65, "LATIN SUBSCRIPT SMALL LETTER V"),
+    (0x1D6B, "LATIN SMALL LETTER UE"),
+    (0x1D6C, "LATIN SMALL LETTER B WITH MIDDLE TILDE"),
+    (0x1D6D, "LATIN SMALL LETTER D WITH MIDDLE TILDE"),
+    (0x1D6E, "LATIN SMALL LETTER F WITH MIDDLE TILDE"),
+    (0x1D6F, "LATIN SMALL LETTER M WITH MIDDLE TILDE"),
+    (0x1D70, "LATIN SMALL LETTER N WITH MIDDLE TILDE"),
+    (0x1D71, "LATIN SMALL LETTER P WITH MIDDLE TILDE"),
+    (0x1D72, "LATIN SMALL LETTER R WITH MIDDLE TILDE"),
+    (0x1D73, "LATIN SMALL LETTER R WITH FISHHOOK AND MIDDLE TILDE"),
+    (0x1D74, "LATIN SMALL LETTER S WITH MIDDLE TILDE"),
+    (0x1D75, "LATIN SMALL LETTER T WITH MIDDLE TILDE"),
+    (0x1D76, "LATIN SMALL LETTER Z WITH MIDDLE TILDE"),
+    (0x1D77, "LATIN SMALL LETTER TURNED G"),
+    (0x1D79, "LATIN SMALL LETTER INSULAR G"),
+    (0x1D7A, "LATIN SMALL LETTER TH WITH STRIKETHROUGH"),
+    (0x1D7B, "LATIN SMALL CAPITAL LETTER I WITH STROKE"),
+    (0x1D7D, "LATIN SMALL LETTER P WITH STROKE"),
+    (0x1D7E, "LATIN SMALL CAPITAL LETTER U WITH STROKE"),
+    (0x1D80, "LATIN SMALL LETTER B WITH PALATAL HOOK"),
+    (0x1D81, "LATIN SMALL LETTER D WITH PALATAL HOOK"),
+    (0x1D82, "LATIN SMALL LETTER F WITH PALATAL HOOK"),
+    (0x1D83, "LATIN SMALL LETTER G WITH PALATAL HOOK"),
+    (0x1D84, "LATIN SMALL LETTER K WITH PALATAL HOOK"),
+    (0x1D85, "LATIN SMALL LETTER L WITH PALATAL HOOK"),
+    (0x1D86, "LATIN SMALL LETTER M WITH PALATAL HOOK"),
+    (0x1D87, "LATIN SMALL LETTER N WITH PALATAL HOOK"),
+    (0x1D88, "LATIN SMALL LETTER P WITH PALATAL HOOK"),
+    (0x1D89, "LATIN SMALL LETTER R WITH PALATAL HOOK"),
+    (0x1D8A, "LATIN SMALL LETTER S WITH PALATAL HOOK"),
+    (0x1D8C, "LATIN SMALL LETTER V WITH PALATAL HOOK"),
+    (0x1D8D, "LATIN SMALL LETTER X WITH PALATAL HOOK"),
+    (0x1D8E, "LATIN SMALL LETTER Z WITH PALATAL HOOK"),
+    (0x1D8F, "LATIN SMALL LETTER A WITH RETROFLEX HOOK"),
+    (0x1D91, "LATIN SMALL LETTER D WITH HOOK AND TAIL"),
+    (0x1D92, "LATIN SMALL LETTER E WITH RETROFLEX HOOK"),
+    (0x1D93, "LATIN SMALL LETTER OPEN E WITH RETROFLEX HOOK"),
+    (0x1D94, "LATIN SMALL LETTER REVERSED OPEN E WITH RETROFLEX HOOK"),
+    (0x1D96, "LATIN SMALL LETTER I WITH RETROFLEX HOOK"),
+    (0x1D97, "LATIN SMALL LETTER OPEN O WITH RETROFLEX HOOK"),
+    (0x1D99, "LATIN SMALL LETTER U WITH RETROFLEX HOOK"),
+    (0x1DCA, "COMBINING LATIN SMALL LETTER R BELOW"),
+    (0x1DD3, "COMBINING LATIN SMALL LETTER FLATTENED OPEN A ABOVE"),
+    (0x1DD4, "COMBINING LATIN SMALL LETTER AE"),
+    (0x1DD5, "COMBINING LATIN SMALL LETTER AO"),
+    (0x1DD6, "COMBINING LATIN SMALL LETTER AV"),
+    (0x1DD7, "COMBINING LATIN SMALL LETTER C CEDILLA"),
+    (0x1DD8, "COMBINING LATIN SMALL LETTER INSULAR D"),
+    (0x1DDA, "COMBINING LATIN SMALL LETTER G"),
+    (0x1DDB, "COMBINING LATIN LETTER SMALL CAPITAL G"),
+    (0x1DDC, "COMBINING LATIN SMALL LETTER K"),
+    (0x1DDD, "COMBINING LATIN SMALL LETTER L"),
+    (0x1DDE, "COMBINING LATIN LETTER SMALL CAPITAL L"),
+    (0x1DDF, "COMBINING LATIN LETTER SMALL CAPITAL M"),
+    (0x1DE0, "COMBINING LATIN SMALL LETTER N"),
+    (0x1DE1, "COMBINING LATIN LETTER SMALL CAPITAL N"),
+    (0x1DE2, "COMBINING LATIN LETTER SMALL CAPITAL R"),
+    (0x1DE3, "COMBINING LATIN SMALL LETTER R ROTUNDA"),
+    (0x1DE4, "COMBINING LATIN SMALL LETTER S"),
+    (0x1DE5, "COMBINING LATIN SMALL LETTER LONG S"),
+    (0x1DE6, "COMBINING LATIN SMALL LETTER Z"),
+    (0x1DE8, "COMBINING LATIN SMALL LETTER B"),
+    (0x1DEB, "COMBINING LATIN SMALL LETTER F"),
+    (0x1DEC, "COMBINING LATIN SMALL LETTER L WITH DOUBLE MIDDLE TILDE"),
+    (0x1DED, "COMBINING LATIN SMALL LETTER O WITH LIGHT CENTRALIZATION STROKE"),
+    (0x1DEE, "COMBINING LATIN SMALL LETTER P"),
+    (0x1DF0, "COMBINING LATIN SMALL LETTER U WITH LIGHT CENTRALIZATION STROKE"),
+    (0x1DF1, "COMBINING LATIN SMALL LETTER W"),
+    (0x1DF2, "COMBINING LATIN SMALL LETTER A WITH DIAERESIS"),
+    (0x1DF3, "COMBINING LATIN SMALL LETTER O WITH DIAERESIS"),
+    (0x1DF4, "COMBINING LATIN SMALL LETTER U WITH DIAERESIS"),
+    (0x1E00, "LATIN CAPITAL LETTER A WITH RING BELOW"),
+    (0x1E01, "LATIN SMALL LETTER A WITH RING BELOW"),
+    (0x1E02, "LATIN CAPITAL LETTER B WITH DOT ABOVE"),
+    (0x1E03, "LATIN SMALL LETTER B WITH DOT ABOVE"),
+    (0x1E04, "LATIN CAPITAL LETTER B WITH DOT BELOW"),
+    (0x1E05, "LATIN SMALL LETTER B WITH DOT BELOW"),
+    (0x1E06, "LATIN CAPITAL LETTER B WITH LINE BELOW"),
+    (0x1E07, "LATIN SMALL LETTER B WITH LINE BELOW"),
+    (0x1E08, "LATIN CAPITAL LETTER C WITH CEDILLA AND ACUTE"),
+    (0x1E09, "LATIN SMALL LETTER C WITH CEDILLA AND ACUTE"),
+    (0x1E0A, "LATIN CAPITAL LETTER D WITH DOT ABOVE"),
+    (0x1E0B, "LATIN SMALL LETTER D WITH DOT ABOVE"),
+    (0x1E0C, "LATIN CAPITAL LETTER D WITH DOT BELOW"),
+    (0x1E0D, "LATIN SMALL LETTER D WITH DOT BELOW"),
+    (0x1E0E, "LATIN CAPITAL LETTER D WITH LINE BELOW"),
+    (0x1E0F, "LATIN SMALL LETTER D WITH LINE BELOW"),
+    (0x1E10, "LATIN CAPITAL LETTER D WITH CEDILLA"),
+    (0x1E11, "LATIN SMALL LETTER D WITH CEDILLA"),
+    (0x1E12, "LATIN CAPITAL LETTER D WITH CIRCUMFLEX BELOW"),
+    (0x1E13, "LATIN SMALL LETTER D WITH CIRCUMFLEX BELOW"),
+    (0x1E14, "LATIN CAPITAL LETTER E WITH MACRON AND GRAVE"),
+    (0x1E15, "LATIN SMALL LETTER E WITH MACRON AND GRAVE"),
+    (0x1E16, "LATIN CAPITAL LETTER E WITH MACRON AND ACUTE"),
+    (0x1E17, "LATIN SMALL LETTER E WITH MACRON AND ACUTE"),
+    (0x1E18, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX BELOW"),
+    (0x1E19, "LATIN SMALL LETTER E WITH CIRCUMFLEX BELOW"),
+    (0x1E1A, "LATIN CAPITAL LETTER E WITH TILDE BELOW"),
+    (0x1E1B, "LATIN SMALL LETTER E WITH TILDE BELOW"),
+    (0x1E1C, "LATIN CAPITAL LETTER E WITH CEDILLA AND BREVE"),
+    (0x1E1D, "LATIN SMALL LETTER E WITH CEDILLA AND BREVE"),
+    (0x1E1E, "LATIN CAPITAL LETTER F WITH DOT ABOVE"),
+    (0x1E1F, "LATIN SMALL LETTER F WITH DOT ABOVE"),
+    (0x1E20, "LATIN CAPITAL LETTER G WITH MACRON"),
+    (0x1E21, "LATIN SMALL LETTER G WITH MACRON"),
+    (0x1E22, "LATIN CAPITAL LETTER H WITH DOT ABOVE"),
+    (0x1E23, "LATIN SMALL LETTER H WITH DOT ABOVE"),
+    (0x1E24, "LATIN CAPITAL LETTER H WITH DOT BELOW"),
+    (0x1E25, "LATIN SMALL LETTER H WITH DOT BELOW"),
+    (0x1E26, "LATIN CAPITAL LETTER H WITH DIAERESIS"),
+    (0x1E27, "LATIN SMALL LETTER H WITH DIAERESIS"),
+    (0x1E28, "LATIN CAPITAL LETTER H WITH CEDILLA"),
+    (0x1E29, "LATIN SMALL LETTER H WITH CEDILLA"),
+    (0x1E2A, "LATIN CAPITAL LETTER H WITH BREVE BELOW"),
+    (0x1E2B, "LATIN SMALL LETTER H WITH BREVE BELOW"),
+    (0x1E2C, "LATIN CAPITAL LETTER I WITH TILDE BELOW"),
+    (0x1E2D, "LATIN SMALL LETTER I WITH TILDE BELOW"),
+    (0x1E2E, "LATIN CAPITAL LETTER I WITH DIAERESIS AND ACUTE"),
+    (0x1E2F, "LATIN SMALL LETTER I WITH DIAERESIS AND ACUTE"),
+    (0x1E30, "LATIN CAPITAL LETTER K WITH ACUTE"),
+    (0x1E31, "LATIN SMALL LETTER K WITH ACUTE"),
+    (0x1E32, "LATIN CAPITAL LETTER K WITH DOT BELOW"),
+    (0x1E33, "LATIN SMALL LETTER K WITH DOT BELOW"),
+    (0x1E34, "LATIN CAPITAL LETTER K WITH LINE BELOW"),
+    (0x1E35, "LATIN SMALL LETTER K WITH LINE BELOW"),
+    (0x1E36, "LATIN CAPITAL LETTER L WITH DOT BELOW"),
+    (0x1E37, "LATIN SMALL LETTER L WITH DOT BELOW"),
+    (0x1E38, "LATIN CAPITAL LETTER L WITH DOT BELOW AND MACRON"),
+    (0x1E39, "LATIN SMALL LETTER L WITH DOT BELOW AND MACRON"),
+    (0x1E3A, "LATIN CAPITAL LETTER L WITH LINE BELOW"),
+    (0x1E3B, "LATIN SMALL LETTER L WITH LINE BELOW"),
+    (0x1E3C, "LATIN CAPITAL LETTER L WITH CIRCUMFLEX BELOW"),
+    (0x1E3D, "LATIN SMALL LETTER L WITH CIRCUMFLEX BELOW"),
+    (0x1E3E, "LATIN CAPITAL LETTER M WITH ACUTE"),
+    (0x1E3F, "LATIN SMALL LETTER M WITH ACUTE"),
+    (0x1E40, "LATIN CAPITAL LETTER M WITH DOT ABOVE"),
+    (0x1E41, "LATIN SMALL LETTER M WITH DOT ABOVE"),
+    (0x1E42, "LATIN CAPITAL LETTER M WITH DOT BELOW"),
+    (0x1E43, "LATIN SMALL LETTER M WITH DOT BELOW"),
+    (0x1E44, "LATIN CAPITAL LETTER N WITH DOT ABOVE"),
+    (0x1E45, "LATIN SMALL LETTER N WITH DOT ABOVE"),
+    (0x1E46, "LATIN CAPITAL LETTER N WITH DOT BELOW"),
+    (0x1E47, "LATIN SMALL LETTER N WITH DOT BELOW"),
+    (0x1E48, "LATIN CAPITAL LETTER N WITH LINE BELOW"),
+    (0x1E49, "LATIN SMALL LETTER N WITH LINE BELOW"),
+    (0x1E4A, "LATIN CAPITAL LETTER N WITH CIRCUMFLEX BELOW"),
+    (0x1E4B, "LATIN SMALL LETTER N WITH CIRCUMFLEX BELOW"),
+    (0x1E4C, "LATIN CAPITAL LETTER O WITH TILDE AND ACUTE"),
+    (0x1E4D, "LATIN SMALL LETTER O WITH TILDE AND ACUTE"),
+    (0x1E4E, "LATIN CAPITAL LETTER O WITH TILDE AND DIAERESIS"),
+    (0x1E4F, "LATIN SMALL LETTER O WITH TILDE AND DIAERESIS"),
+    (0x1E50, "LATIN CAPITAL LETTER O WITH MACRON AND GRAVE"),
+    (0x1E51, "LATIN SMALL LETTER O WITH MACRON AND GRAVE"),
+    (0x1E52, "LATIN CAPITAL LETTER O WITH MACRON AND ACUTE"),
+    (0x1E53, "LATIN SMALL LETTER O WITH MACRON AND ACUTE"),
+    (0x1E54, "LATIN CAPITAL LETTER P WITH ACUTE"),
+    (0x1E55, "LATIN SMALL LETTER P WITH ACUTE"),
+    (0x1E56, "LATIN CAPITAL LETTER P WITH DOT ABOVE"),
+    (0x1E57, "LATIN SMALL LETTER P WITH DOT ABOVE"),
+    (0x1E58, "LATIN CAPITAL LETTER R WITH DOT ABOVE"),
+    (0x1E59, "LATIN SMALL LETTER R WITH DOT ABOVE"),
+    (0x1E5A, "LATIN CAPITAL LETTER R WITH DOT BELOW"),
+    (0x1E5B, "LATIN SMALL LETTER R WITH DOT BELOW"),
+    (0x1E5C, "LATIN CAPITAL LETTER R WITH DOT BELOW AND MACRON"),
+    (0x1E5D, "LATIN SMALL LETTER R WITH DOT BELOW AND MACRON"),
+    (0x1E5E, "LATIN CAPITAL LETTER R WITH LINE BELOW"),
+    (0x1E5F, "LATIN SMALL LETTER R WITH LINE BELOW"),
+    (0x1E60, "LATIN CAPITAL LETTER S WITH DOT ABOVE"),
+    (0x1E61, "LATIN SMALL LETTER S WITH DOT ABOVE"),
+    (0x1E62, "LATIN CAPITAL LETTER S WITH DOT BELOW"),
+    (0x1E63, "LATIN SMALL LETTER S WITH DOT BELOW"),
+    (0x1E64, "LATIN CAPITAL LETTER S WITH ACUTE AND DOT ABOVE"),
+    (0x1E65, "LATIN SMALL LETTER S WITH ACUTE AND DOT ABOVE"),
+    (0x1E66, "LATIN CAPITAL LETTER S WITH CARON AND DOT ABOVE"),
+    (0x1E67, "LATIN SMALL LETTER S WITH CARON AND DOT ABOVE"),
+    (0x1E68, "LATIN CAPITAL LETTER S WITH DOT BELOW AND DOT ABOVE"),
+    (0x1E69, "LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE"),
+    (0x1E6A, "LATIN CAPITAL LETTER T WITH DOT ABOVE"),
+    (0x1E6B, "LATIN SMALL LETTER T WITH DOT ABOVE"),
+    (0x1E6C, "LATIN CAPITAL LETTER T WITH DOT BELOW"),
+    (0x1E6D, "LATIN SMALL LETTER T WITH DOT BELOW"),
+    (0x1E6E, "LATIN CAPITAL LETTER T WITH LINE BELOW"),
+    (0x1E6F, "LATIN SMALL LETTER T WITH LINE BELOW"),
+    (0x1E70, "LATIN CAPITAL LETTER T WITH CIRCUMFLEX BELOW"),
+    (0x1E71, "LATIN SMALL LETTER T WITH CIRCUMFLEX BELOW"),
+    (0x1E72, "LATIN CAPITAL LETTER U WITH DIAERESIS BELOW"),
+    (0x1E73, "LATIN SMALL LETTER U WITH DIAERESIS BELOW"),
+    (0x1E74, "LATIN CAPITAL LETTER U WITH TILDE BELOW"),
+    (0x1E75, "LATIN SMALL LETTER U WITH TILDE BELOW"),
+    (0x1E76, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX BELOW"),
+    (0x1E77, "LATIN SMALL LETTER U WITH CIRCUMFLEX BELOW"),
+    (0x1E78, "LATIN CAPITAL LETTER U WITH TILDE AND ACUTE"),
+    (0x1E79, "LATIN SMALL LETTER U WITH TILDE AND ACUTE"),
+    (0x1E7A, "LATIN CAPITAL LETTER U WITH MACRON AND DIAERESIS"),
+    (0x1E7B, "LATIN SMALL LETTER U WITH MACRON AND DIAERESIS"),
+    (0x1E7C, "LATIN CAPITAL LETTER V WITH TILDE"),
+    (0x1E7D, "LATIN SMALL LETTER V WITH TILDE"),
+    (0x1E7E, "LATIN CAPITAL LETTER V WITH DOT BELOW"),
+    (0x1E7F, "LATIN SMALL LETTER V WITH DOT BELOW"),
+    (0x1E80, "LATIN CAPITAL LETTER W WITH GRAVE"),
+    (0x1E81, "LATIN SMALL LETTER W WITH GRAVE"),
+    (0x1E82, "LATIN CAPITAL LETTER W WITH ACUTE"),
+    (0x1E83, "LATIN SMALL LETTER W WITH ACUTE"),
+    (0x1E84, "LATIN CAPITAL LETTER W WITH DIAERESIS"),
+    (0x1E85, "LATIN SMALL LETTER W WITH DIAERESIS"),
+    (0x1E86, "LATIN CAPITAL LETTER W WITH DOT ABOVE"),
+    (0x1E87, "LATIN SMALL LETTER W WITH DOT ABOVE"),
+    (0x1E88, "LATIN CAPITAL LETTER W WITH DOT BELOW"),
+    (0x1E89, "LATIN SMALL LETTER W WITH DOT BELOW"),
+    (0x1E8A, "LATIN CAPITAL LETTER X WITH DOT ABOVE"),
+    (0x1E8B, "LATIN SMALL LETTER X WITH DOT ABOVE"),
+    (0x1E8C, "LATIN CAPITAL LETTER X WITH DIAERESIS"),
+    (0x1E8D, "LATIN SMALL LETTER X WITH DIAERESIS"),
+    (0x1E8E, "LATIN CAPITAL LETTER Y WITH DOT ABOVE"),
+    (0x1E8F, "LATIN SMALL LETTER Y WITH DOT ABOVE"),
+    (0x1E90, "LATIN CAPITAL LETTER Z WITH CIRCUMFLEX"),
+    (0x1E91, "LATIN SMALL LETTER Z WITH CIRCUMFLEX"),
+    (0x1E92, "LATIN CAPITAL LETTER Z WITH DOT BELOW"),
+    (0x1E93, "LATIN SMALL LETTER Z WITH DOT BELOW"),
+    (0x1E94, "LATIN CAPITAL LETTER Z WITH LINE BELOW"),
+    (0x1E95, "LATIN SMALL LETTER Z WITH LINE BELOW"),
+    (0x1E96, "LATIN SMALL LETTER H WITH LINE BELOW"),
+    (0x1E97, "LATIN SMALL LETTER T WITH DIAERESIS"),
+    (0x1E98, "LATIN SMALL LETTER W WITH RING ABOVE"),
+    (0x1E99, "LATIN SMALL LETTER Y WITH RING ABOVE"),
+    (0x1E9A, "LATIN SMALL LETTER A WITH RIGHT HALF RING"),
+    (0x1E9B, "LATIN SMALL LETTER LONG S WITH DOT ABOVE"),
+    (0x1E9C, "LATIN SMALL LETTER LONG S WITH DIAGONAL STROKE"),
+    (0x1E9D, "LATIN SMALL LETTER LONG S WITH HIGH STROKE"),
+    (0x1E9E, "LATIN CAPITAL LETTER SHARP S"),
+    (0x1EA0, "LATIN CAPITAL LETTER A WITH DOT BELOW"),
+    (0x1EA1, "LATIN SMALL LETTER A WITH DOT BELOW"),
+    (0x1EA2, "LATIN CAPITAL LETTER A WITH HOOK ABOVE"),
+    (0x1EA3, "LATIN SMALL LETTER A WITH HOOK ABOVE"),
+    (0x1EA4, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE"),
+    (0x1EA5, "LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE"),
+    (0x1EA6, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE"),
+    (0x1EA7, "LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE"),
+    (0x1EA8, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1EA9, "LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1EAA, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE"),
+    (0x1EAB, "LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE"),
+    (0x1EAC, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1EAD, "LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1EAE, "LATIN CAPITAL LETTER A WITH BREVE AND ACUTE"),
+    (0x1EAF, "LATIN SMALL LETTER A WITH BREVE AND ACUTE"),
+    (0x1EB0, "LATIN CAPITAL LETTER A WITH BREVE AND GRAVE"),
+    (0x1EB1, "LATIN SMALL LETTER A WITH BREVE AND GRAVE"),
+    (0x1EB2, "LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE"),
+    (0x1EB3, "LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE"),
+    (0x1EB4, "LATIN CAPITAL LETTER A WITH BREVE AND TILDE"),
+    (0x1EB5, "LATIN SMALL LETTER A WITH BREVE AND TILDE"),
+    (0x1EB6, "LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW"),
+    (0x1EB7, "LATIN SMALL LETTER A WITH BREVE AND DOT BELOW"),
+    (0x1EB8, "LATIN CAPITAL LETTER E WITH DOT BELOW"),
+    (0x1EB9, "LATIN SMALL LETTER E WITH DOT BELOW"),
+    (0x1EBA, "LATIN CAPITAL LETTER E WITH HOOK ABOVE"),
+    (0x1EBB, "LATIN SMALL LETTER E WITH HOOK ABOVE"),
+    (0x1EBC, "LATIN CAPITAL LETTER E WITH TILDE"),
+    (0x1EBD, "LATIN SMALL LETTER E WITH TILDE"),
+    (0x1EBE, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE"),
+    (0x1EBF, "LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE"),
+    (0x1EC0, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE"),
+    (0x1EC1, "LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE"),
+    (0x1EC2, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1EC3, "LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1EC4, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE"),
+    (0x1EC5, "LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE"),
+    (0x1EC6, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1EC7, "LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1EC8, "LATIN CAPITAL LETTER I WITH HOOK ABOVE"),
+    (0x1EC9, "LATIN SMALL LETTER I WITH HOOK ABOVE"),
+    (0x1ECA, "LATIN CAPITAL LETTER I WITH DOT BELOW"),
+    (0x1ECB, "LATIN SMALL LETTER I WITH DOT BELOW"),
+    (0x1ECC, "LATIN CAPITAL LETTER O WITH DOT BELOW"),
+    (0x1ECD, "LATIN SMALL LETTER O WITH DOT BELOW"),
+    (0x1ECE, "LATIN CAPITAL LETTER O WITH HOOK ABOVE"),
+    (0x1ECF, "LATIN SMALL LETTER O WITH HOOK ABOVE"),
+    (0x1ED0, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE"),
+    (0x1ED1, "LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE"),
+    (0x1ED2, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE"),
+    (0x1ED3, "LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE"),
+    (0x1ED4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1ED5, "LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE"),
+    (0x1ED6, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE"),
+    (0x1ED7, "LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE"),
+    (0x1ED8, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1ED9, "LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW"),
+    (0x1EDA, "LATIN CAPITAL LETTER O WITH HORN AND ACUTE"),
+    (0x1EDB, "LATIN SMALL LETTER O WITH HORN AND ACUTE"),
+    (0x1EDC, "LATIN CAPITAL LETTER O WITH HORN AND GRAVE"),
+    (0x1EDD, "LATIN SMALL LETTER O WITH HORN AND GRAVE"),
+    (0x1EDE, "LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE"),
+    (0x1EDF, "LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE"),
+    (0x1EE0, "LATIN CAPITAL LETTER O WITH HORN AND TILDE"),
+    (0x1EE1, "LATIN SMALL LETTER O WITH HORN AND TILDE"),
+    (0x1EE2, "LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW"),
+    (0x1EE3, "LATIN SMALL LETTER O WITH HORN AND DOT BELOW"),
+    (0x1EE4, "LATIN CAPITAL LETTER U WITH DOT BELOW"),
+    (0x1EE5, "LATIN SMALL LETTER U WITH DOT BELOW"),
+    (0x1EE6, "LATIN CAPITAL LETTER U WITH HOOK ABOVE"),
+    (0x1EE7, "LATIN SMALL LETTER U WITH HOOK ABOVE"),
+    (0x1EE8, "LATIN CAPITAL LETTER U WITH HORN AND ACUTE"),
+    (0x1EE9, "LATIN SMALL LETTER U WITH HORN AND ACUTE"),
+    (0x1EEA, "LATIN CAPITAL LETTER U WITH HORN AND GRAVE"),
+    (0x1EEB, "LATIN SMALL LETTER U WITH HORN AND GRAVE"),
+    (0x1EEC, "LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE"),
+    (0x1EED, "LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE"),
+    (0x1EEE, "LATIN CAPITAL LETTER U WITH HORN AND TILDE"),
+    (0x1EEF, "LATIN SMALL LETTER U WITH HORN AND TILDE"),
+    (0x1EF0, "LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW"),
+    (0x1EF1, "LATIN SMALL LETTER U WITH HORN AND DOT BELOW"),
+    (0x1EF2, "LATIN CAPITAL LETTER Y WITH GRAVE"),
+    (0x1EF3, "LATIN SMALL LETTER Y WITH GRAVE"),
+    (0x1EF4, "LATIN CAPITAL LETTER Y WITH DOT BELOW"),
+    (0x1EF5, "LATIN SMALL LETTER Y WITH DOT BELOW"),
+    (0x1EF6, "LATIN CAPITAL LETTER Y WITH HOOK ABOVE"),
+    (0x1EF7, "LATIN SMALL LETTER Y WITH HOOK ABOVE"),
+    (0x1EF8, "LATIN CAPITAL LETTER Y WITH TILDE"),
+    (0x1EF9, "LATIN SMALL LETTER Y WITH TILDE"),
+    (0x1EFA, "LATIN CAPITAL LETTER MIDDLE-WELSH LL"),
+    (0x1EFB, "LATIN SMALL LETTER MIDDLE-WELSH LL"),
+    (0x1EFC, "LATIN CAPITAL LETTER MIDDLE-WELSH V"),
+    (0x1EFD, "LATIN SMALL LETTER MIDDLE-WELSH V"),
+    (0x1EFE, "LATIN CAPITAL LETTER Y WITH LOOP"),
+    (0x1EFF, "LATIN SMALL LETTER Y WITH LOOP"),
+    (0x2071, "SUPERSCRIPT LATIN SMALL LETTER I"),
+    (0x207F, "SUPERSCRIPT LATIN SMALL LETTER N"),
+    (0x2090, "LATIN SUBSCRIPT SMALL LETTER A"),
+    (0x2091, "LATIN SUBSCRIPT SMALL LETTER E"),
+    (0x2092, "LATIN SUBSCRIPT SMALL LETTER O"),
+    (0x2093, "LATIN SUBSCRIPT SMALL LETTER X"),
+    (0x2095, "LATIN SUBSCRIPT SMALL LETTER H"),
+    (0x2096, "LATIN SUBSCRIPT SMALL LETTER K"),
+    (0x2097, "LATIN SUBSCRIPT SMALL LETTER L"),
+    (0x2098, "LATIN SUBSCRIPT SMALL LETTER M"),
+    (0x2099, "LATIN SUBSCRIPT SMALL LETTER N"),
+    (0x209A, "LATIN SUBSCRIPT SMALL LETTER P"),
+    (0x209B, "LATIN SUBSCRIPT SMALL LETTER S"),
+    (0x209C, "LATIN SUBSCRIPT SMALL LETTER T"),
+    (0x2102, "DOUBLE-STRUCK CAPITAL C"),
+    (0x210A, "SCRIPT SMALL G"),
+    (0x210B, "SCRIPT CAPITAL H"),
+    (0x210C, "BLACK-LETTER CAPITAL H"),
+    (0x210D, "DOUBLE-STRUCK CAPITAL H"),
+    (0x210E, "PLANCK CONSTANT"),
+    (0x210F, "PLANCK CONSTANT OVER TWO PI"),
+    (0x2110, "SCRIPT CAPITAL I"),
+    (0x2111, "BLACK-LETTER CAPITAL I"),
+    (0x2112, "SCRIPT CAPITAL L"),
+    (0x2113, "SCRIPT SMALL L"),
+    (0x2115, "DOUBLE-STRUCK CAPITAL N"),
+    (0x2119, "DOUBLE-STRUCK CAPITAL P"),
+    (0x211A, "DOUBLE-STRUCK CAPITAL Q"),
+    (0x211B, "SCRIPT CAPITAL R"),
+    (0x211C, "BLACK-LETTER CAPITAL R"),
+    (0x211D, "DOUBLE-STRUCK CAPITAL R"),
+    (0x2120, "SERVICE MARK"),
+    (0x2122, "TRADE MARK SIGN"),
+    (0x2124, "DOUBLE-STRUCK CAPITAL Z"),
+    (0x2128, "BLACK-LETTER CAPITAL Z"),
+    (0x212A, "KELVIN SIGN"),
+    (0x212B, "ANGSTROM SIGN"),
+    (0x212C, "SCRIPT CAPITAL B"),
+    (0x212D, "BLACK-LETTER CAPITAL C"),
+    (0x212F, "SCRIPT SMALL E"),
+    (0x2130, "SCRIPT CAPITAL E"),
+    (0x2131, "SCRIPT CAPITAL F"),
+    (0x2133, "SCRIPT CAPITAL M"),
+    (0x2134, "SCRIPT SMALL O"),
+    (0x2139, "INFORMATION SOURCE"),
+    (0x2145, "DOUBLE-STRUCK ITALIC CAPITAL D"),
+    (0x2146, "DOUBLE-STRUCK ITALIC SMALL D"),
+    (0x2147, "DOUBLE-STRUCK ITALIC SMALL E"),
+    (0x2148, "DOUBLE-STRUCK ITALIC SMALL I"),
+    (0x2149, "DOUBLE-STRUCK ITALIC SMALL J"),
+    (0x2160, "ROMAN NUMERAL ONE"),
+    (0x2161, "ROMAN NUMERAL TWO"),
+    (0x2162, "ROMAN NUMERAL THREE"),
+    (0x2163, "ROMAN NUMERAL FOUR"),
+    (0x2164, "ROMAN NUMERAL FIVE"),
+    (0x2165, "ROMAN NUMERAL SIX"),
+    (0x2166, "ROMAN NUMERAL SEVEN"),
+    (0x2167, "ROMAN NUMERAL EIGHT"),
+    (0x2168, "ROMAN NUMERAL NINE"),
+    (0x2169, "ROMAN NUMERAL TEN"),
+    (0x216A, "ROMAN NUMERAL ELEVEN"),
+    (0x216B, "ROMAN NUMERAL TWELVE"),
+    (0x216C, "ROMAN NUMERAL FIFTY"),
+    (0x216D, "ROMAN NUMERAL ONE HUNDRED"),
+    (0x216E, "ROMAN NUMERAL FIVE HUNDRED"),
+    (0x216F, "ROMAN NUMERAL ONE THOUSAND"),
+    (0x2170, "SMALL ROMAN NUMERAL ONE"),
+    (0x2171, "SMALL ROMAN NUMERAL TWO"),
+    (0x2172, "SMALL ROMAN NUMERAL THREE"),
+    (0x2173, "SMALL ROMAN NUMERAL FOUR"),
+    (0x2174, "SMALL ROMAN NUMERAL FIVE"),
+    (0x2175, "SMALL ROMAN NUMERAL SIX"),
+    (0x2176, "SMALL ROMAN NUMERAL SEVEN"),
+    (0x2177, "SMALL ROMAN NUMERAL EIGHT"),
+    (0x2178, "SMALL ROMAN NUMERAL NINE"),
+    (0x2179, "SMALL ROMAN NUMERAL TEN"),
+    (0x217A, "SMALL ROMAN NUMERAL ELEVEN"),
+    (0x217B, "SMALL ROMAN NUMERAL TWELVE"),
+    (0x217C, "SMALL ROMAN NUMERAL FIFTY"),
+    (0x217D, "SMALL ROMAN NUMERAL ONE HUNDRED"),
+    (0x217E, "SMALL ROMAN NUMERAL FIVE HUNDRED"),
+    (0x217F, "SMALL ROMAN NUMERAL ONE THOUSAND"),
+    (0x2180, "ROMAN NUMERAL ONE THOUSAND C D"),
+    (0x2184, "LATIN SMALL LETTER REVERSED C"),
+    (0x2185, "ROMAN NUMERAL SIX LATE FORM"),
+    (0x2186, "ROMAN NUMERAL FIFTY EARLY FORM"),
+    (0x249C, "PARENTHESIZED LATIN SMALL LETTER A"),
+    (0x249D, "PARENTHESIZED LATIN SMALL LETTER B"),
+    (0x249E, "PARENTHESIZED LATIN SMALL LETTER C"),
+    (0x249F, "PARENTHESIZED LATIN SMALL LETTER D"),
+    (0x24A0, "PARENTHESIZED LATIN SMALL LETTER E"),
+    (0x24A1, "PARENTHESIZED LATIN SMALL LETTER F"),
+    (0x24A2, "PARENTHESIZED LATIN SMALL LETTER G"),
+    (0x24A3, "PARENTHESIZED LATIN SMALL LETTER H"),
+    (0x24A4, "PARENTHESIZED LATIN SMALL LETTER I"),
+    (0x24A5, "PARENTHESIZED LATIN SMALL LETTER J"),
+    (0x24A6, "PARENTHESIZED LATIN SMALL LETTER K"),
+    (0x24A7, "PARENTHESIZED LATIN SMALL LETTER L"),
+    (0x24A8, "PARENTHESIZED LATIN SMALL LETTER M"),
+    (0x24A9, "PARENTHESIZED LATIN SMALL LETTER N"),
+    (0x24AA, "PARENTHESIZED LATIN SMALL LETTER O"),
+    (0x24AB, "PARENTHESIZED LATIN SMALL LETTER P"),
+    (0x24AC, "PARENTHESIZED LATIN SMALL LETTER Q"),
+    (0x24AD, "PARENTHESIZED LATIN SMALL LETTER R"),
+    (0x24AE, "PARENTHESIZED LATIN SMALL LETTER S"),
+    (0x24AF, "PARENTHESIZED LATIN SMALL LETTER T"),
+    (0x24B0, "PARENTHESIZED LATIN SMALL LETTER U"),
+    (0x24B1, "PARENTHESIZED LATIN SMALL LETTER V"),
+    (0x24B2, "PARENTHESIZED LATIN SMALL LETTER W"),
+    (0x24B3, "PARENTHESIZED LATIN SMALL LETTER X"),
+    (0x24B4, "PARENTHESIZED LATIN SMALL LETTER Y"),
+    (0x24B5, "PARENTHESIZED LATIN SMALL LETTER Z"),
+    (0x24B6, "CIRCLED LATIN CAPITAL LETTER A"),
+    (0x24B7, "CIRCLED LATIN CAPITAL LETTER B"),
+    (0x24B8, "CIRCLED LATIN CAPITAL LETTER C"),
+    (0x24B9, "CIRCLED LATIN CAPITAL LETTER D"),
+    (0x24BA, "CIRCLED LATIN CAPITAL LETTER E"),
+    (0x24BB, "CIRCLED LATIN CAPITAL LETTER F"),
+    (0x24BC, "CIRCLED LATIN CAPITAL LETTER G"),
+    (0x24BD, "CIRCLED LATIN CAPITAL LETTER H"),
+    (0x24BE, "CIRCLED LATIN CAPITAL LETTER I"),
+    (0x24BF, "CIRCLED LATIN CAPITAL LETTER J"),
+    (0x24C0, "CIRCLED LATIN CAPITAL LETTER K"),
+    (0x24C1, "CIRCLED LATIN CAPITAL LETTER L"),
+    (0x24C2, "CIRCLED LATIN CAPITAL LETTER M"),
+    (0x24C3, "CIRCLED LATIN CAPITAL LETTER N"),
+    (0x24C4, "CIRCLED LATIN CAPITAL LETTER O"),
+    (0x24C5, "CIRCLED LATIN CAPITAL LETTER P"),
+    (0x24C6, "CIRCLED LATIN CAPITAL LETTER Q"),
+    (0x24C7, "CIRCLED LATIN CAPITAL LETTER R"),
+    (0x24C8, "CIRCLED LATIN CAPITAL LETTER S"),
+    (0x24C9, "CIRCLED LATIN CAPITAL LETTER T"),
+    (0x24CA, "CIRCLED LATIN CAPITAL LETTER U"),
+    (0x24CB, "CIRCLED LATIN CAPITAL LETTER V"),
+    (0x24CC, "CIRCLED LATIN CAPITAL LETTER W"),
+    (0x24CD, "CIRCLED LATIN CAPITAL LETTER X"),
+    (0x24CE, "CIRCLED LATIN CAPITAL LETTER Y"),
+    (0x24CF, "CIRCLED LATIN CAPITAL LETTER Z"),
+    (0x24D0, "CIRCLED LATIN SMALL LETTER A"),
+    (0x24D1, "CIRCLED LATIN SMALL LETTER B"),
+    (0x24D2, "CIRCLED LATIN SMALL LETTER C"),
+    (0x24D3, "CIRCLED LATIN SMALL LETTER D"),
+    (0x24D4, "CIRCLED LATIN SMALL LETTER E"),
+    (0x24D5, "CIRCLED LATIN SMALL LETTER F"),
+    (0x24D6, "CIRCLED LATIN SMALL LETTER G"),
+    (0x24D7, "CIRCLED LATIN SMALL LETTER H"),
+    (0x24D8, "CIRCLED LATIN SMALL LETTER I"),
+    (0x24D9, "CIRCLED LATIN SMALL LETTER J"),
+    (0x24DA, "CIRCLED LATIN SMALL LETTER K"),
+    (0x24DB, "CIRCLED LATIN SMALL LETTER L"),
+    (0x24DC, "CIRCLED LATIN SMALL LETTER M"),
+    (0x24DD, "CIRCLED LATIN SMALL LETTER N"),
+    (0x24DE, "CIRCLED LATIN SMALL LETTER O"),
+    (0x24DF, "CIRCLED LATIN SMALL LETTER P"),
+    (0x24E0, "CIRCLED LATIN SMALL LETTER Q"),
+    (0x24E1, "CIRCLED LATIN SMALL LETTER R"),
+    (0x24E2, "CIRCLED LATIN SMALL LETTER S"),
+    (0x24E3, "CIRCLED LATIN SMALL LETTER T"),
+    (0x24E4, "CIRCLED LATIN SMALL LETTER U"),
+    (0x24E5, "CIRCLED LATIN SMALL LETTER V"),
+    (0x24E6, "CIRCLED LATIN SMALL LETTER W"),
+    (0x24E7, "CIRCLED LATIN SMALL LETTER X"),
+    (0x24E8, "CIRCLED LATIN SMALL LETTER Y"),
+    (0x24E9, "CIRCLED LATIN SMALL LETTER Z"),
+    (0x2C60, "LATIN CAPITAL LETTER L WITH DOUBLE BAR"),
+    (0x2C61, "LATIN SMALL LETTER L WITH DOUBLE BAR"),
+    (0x2C62, "LATIN CAPITAL LETTER L WITH MIDDLE TILDE"),
+    (0x2C63, "LATIN CAPITAL LETTER P WITH STROKE"),
+    (0x2C64, "LATIN CAPITAL LETTER R WITH TAIL"),
+    (0x2C65, "LATIN SMALL LETTER A WITH STROKE"),
+    (0x2C66, "LATIN SMALL LETTER T WITH DIAGONAL STROKE"),
+    (0x2C67, "LATIN CAPITAL LETTER H WITH DESCENDER"),
+    (0x2C68, "LATIN SMALL LETTER H WITH DESCENDER"),
+    (0x2C69, "LATIN CAPITAL LETTER K WITH DESCENDER"),
+    (0x2C6A, "LATIN SMALL LETTER K WITH DESCENDER"),
+    (0x2C6B, "LATIN CAPITAL LETTER Z WITH DESCENDER"),
+    (0x2C6C, "LATIN SMALL LETTER Z WITH DESCENDER"),
+    (0x2C6E, "LATIN CAPITAL LETTER M WITH HOOK"),
+    (0x2C6F, "LATIN CAPITAL LETTER TURNED A"),
+    (0x2C71, "LATIN SMALL LETTER V WITH RIGHT HOOK"),
+    (0x2C72, "LATIN CAPITAL LETTER W WITH HOOK"),
+    (0x2C73, "LATIN SMALL LETTER W WITH HOOK"),
+    (0x2C74, "LATIN SMALL LETTER V WITH CURL"),
+    (0x2C75, "LATIN CAPITAL LETTER HALF H"),
+    (0x2C76, "LATIN SMALL LETTER HALF H"),
+    (0x2C78, "LATIN SMALL LETTER E WITH NOTCH"),
+    (0x2C79, "LATIN SMALL LETTER TURNED R WITH TAIL"),
+    (0x2C7A, "LATIN SMALL LETTER O WITH LOW RING INSIDE"),
+    (0x2C7B, "LATIN LETTER SMALL CAPITAL TURNED E"),
+    (0x2C7C, "LATIN SUBSCRIPT SMALL LETTER J"),
+    (0x2C7E, "LATIN CAPITAL LETTER S WITH SWASH TAIL"),
+    (0x2C7F, "LATIN CAPITAL LETTER Z WITH SWASH TAIL"),
+    (0x3250, "PARTNERSHIP SIGN"),
+    (0x32CC, "SQUARE HG"),
+    (0x32CD, "SQUARE ERG"),
+    (0x32CE, "SQUARE EV"),
+    (0x32CF, "LIMITED LIABILITY SIGN"),
+    (0x3371, "SQUARE HPA"),
+    (0x3372, "SQUARE DA"),
+    (0x3373, "SQUARE AU"),
+    (0x3374, "SQUARE BAR"),
+    (0x3375, "SQUARE OV"),
+    (0x3376, "SQUARE PC"),
+    (0x3377, "SQUARE DM"),
+    (0x337A, "SQUARE IU"),
+    (0x3380, "SQUARE PA AMPS"),
+    (0x3381, "SQUARE NA"),
+    (0x3383, "SQUARE MA"),
+    (0x3384, "SQUARE KA"),
+    (0x3385, "SQUARE KB"),
+    (0x3386, "SQUARE MB"),
+    (0x3387, "SQUARE GB"),
+    (0x3388, "SQUARE CAL"),
+    (0x3389, "SQUARE KCAL"),
+    (0x338A, "SQUARE PF"),
+    (0x338B, "SQUARE NF"),
+    (0x338E, "SQUARE MG"),
+    (0x338F, "SQUARE KG"),
+    (0x3390, "SQUARE HZ"),
+    (0x3391, "SQUARE KHZ"),
+    (0x3392, "SQUARE MHZ"),
+    (0x3393, "SQUARE GHZ"),
+    (0x3394, "SQUARE THZ"),
+    (0x3396, "SQUARE ML"),
+    (0x3397, "SQUARE DL"),
+    (0x3398, "SQUARE KL"),
+    (0x3399, "SQUARE FM"),
+    (0x339A, "SQUARE NM"),
+    (0x339C, "SQUARE MM"),
+    (0x339D, "SQUARE CM"),
+    (0x339E, "SQUARE KM"),
+    (0x33A9, "SQUARE PA"),
+    (0x33AA, "SQUARE KPA"),
+    (0x33AB, "SQUARE MPA"),
+    (0x33AC, "SQUARE GPA"),
+    (0x33AD, "SQUARE RAD"),
+    (0x33B0, "SQUARE PS"),
+    (0x33B1, "SQUARE NS"),
+    (0x33B3, "SQUARE MS"),
+    (0x33B4, "SQUARE PV"),
+    (0x33B5, "SQUARE NV"),
+    (0x33B7, "SQUARE MV"),
+    (0x33B8, "SQUARE KV"),
+    (0x33B9, "SQUARE MV MEGA"),
+    (0x33BA, "SQUARE PW"),
+    (0x33BB, "SQUARE NW"),
+    (0x33BD, "SQUARE MW"),
+    (0x33BE, "SQUARE KW"),
+    (0x33BF, "SQUARE MW MEGA"),
+    (0x33C2, "SQUARE AM"),
+    (0x33C3, "SQUARE BQ"),
+    (0x33C4, "SQUARE CC"),
+    (0x33C5, "SQUARE CD"),
+    (0x33C7, "SQUARE CO"),
+    (0x33C8, "SQUARE DB"),
+    (0x33C9, "SQUARE GY"),
+    (0x33CA, "SQUARE HA"),
+    (0x33CB, "SQUARE HP"),
+    (0x33CC, "SQUARE IN"),
+    (0x33CD, "SQUARE KK"),
+    (0x33CE, "SQUARE KM CAPITAL"),
+    (0x33CF, "SQUARE KT"),
+    (0x33D0, "SQUARE LM"),
+    (0x33D1, "SQUARE LN"),
+    (0x33D2, "SQUARE LOG"),
+    (0x33D3, "SQUARE LX"),
+    (0x33D4, "SQUARE MB SMALL"),
+    (0x33D5, "SQUARE MIL"),
+    (0x33D6, "SQUARE MOL"),
+    (0x33D7, "SQUARE PH"),
+    (0x33D8, "SQUARE PM"),
+    (0x33D9, "SQUARE PPM"),
+    (0x33DA, "SQUARE PR"),
+    (0x33DB, "SQUARE SR"),
+    (0x33DC, "SQUARE SV"),
+    (0x33DD, "SQUARE WB"),
+    (0x33FF, "SQUARE GAL"),
+    (0xA728, "LATIN CAPITAL LETTER TZ"),
+    (0xA729, "LATIN SMALL LETTER TZ"),
+    (0xA730, "LATIN LETTER SMALL CAPITAL F"),
+    (0xA731, "LATIN LETTER SMALL CAPITAL S"),
+    (0xA732, "LATIN CAPITAL LETTER AA"),
+    (0xA733, "LATIN SMALL LETTER AA"),
+    (0xA734, "LATIN CAPITAL LETTER AO"),
+    (0xA735, "LATIN SMALL LETTER AO"),
+    (0xA736, "LATIN CAPITAL LETTER AU"),
+    (0xA737, "LATIN SMALL LETTER AU"),
+    (0xA738, "LATIN CAPITAL LETTER AV"),
+    (0xA739, "LATIN SMALL LETTER AV"),
+    (0xA73A, "LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR"),
+    (0xA73B, "LATIN SMALL LETTER AV WITH HORIZONTAL BAR"),
+    (0xA73C, "LATIN CAPITAL LETTER AY"),
+    (0xA73D, "LATIN SMALL LETTER AY"),
+    (0xA73E, "LATIN CAPITAL LETTER REVERSED C WITH DOT"),
+    (0xA73F, "LATIN SMALL LETTER REVERSED C WITH DOT"),
+    (0xA740, "LATIN CAPITAL LETTER K WITH STROKE"),
+    (0xA741, "LATIN SMALL LETTER K WITH STROKE"),
+    (0xA742, "LATIN CAPITAL LETTER K WITH DIAGONAL STROKE"),
+    (0xA743, "LATIN SMALL LETTER K WITH DIAGONAL STROKE"),
+    (0xA744, "LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE"),
+    (0xA745, "LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE"),
+    (0xA746, "LATIN CAPITAL LETTER BROKEN L"),
+    (0xA747, "LATIN SMALL LETTER BROKEN L"),
+    (0xA748, "LATIN CAPITAL LETTER L WITH HIGH STROKE"),
+    (0xA749, "LATIN SMALL LETTER L WITH HIGH STROKE"),
+    (0xA74A, "LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY"),
+    (0xA74B, "LATIN SMALL LETTER O WITH LONG STROKE OVERLAY"),
+    (0xA74C, "LATIN CAPITAL LETTER O WITH LOOP"),
+    (0xA74D, "LATIN SMALL LETTER O WITH LOOP"),
+    (0xA74E, "LATIN CAPITAL LETTER OO"),
+    (0xA74F, "LATIN SMALL LETTER OO"),
+    (0xA750, "LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER"),
+    (0xA751, "LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER"),
+    (0xA752, "LATIN CAPITAL LETTER P WITH FLOURISH"),
+    (0xA753, "LATIN SMALL LETTER P WITH FLOURISH"),
+    (0xA754, "LATIN CAPITAL LETTER P WITH SQUIRREL TAIL"),
+    (0xA755, "LATIN SMALL LETTER P WITH SQUIRREL TAIL"),
+    (0xA756, "LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER"),
+    (0xA757, "LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER"),
+    (0xA758, "LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE"),
+    (0xA759, "LATIN SMALL LETTER Q WITH DIAGONAL STROKE"),
+    (0xA75A, "LATIN CAPITAL LETTER R ROTUNDA"),
+    (0xA75B, "LATIN SMALL LETTER R ROTUNDA"),
+    (0xA75E, "LATIN CAPITAL LETTER V WITH DIAGONAL STROKE"),
+    (0xA75F, "LATIN SMALL LETTER V WITH DIAGONAL STROKE"),
+    (0xA760, "LATIN CAPITAL LETTER VY"),
+    (0xA761, "LATIN SMALL LETTER VY"),
+    (0xA762, "LATIN CAPITAL LETTER VISIGOTHIC Z"),
+    (0xA763, "LATIN SMALL LETTER VISIGOTHIC Z"),
+    (0xA76A, "LATIN CAPITAL LETTER ET"),
+    (0xA76B, "LATIN SMALL LETTER ET"),
+    (0xA76C, "LATIN CAPITAL LETTER IS"),
+    (0xA76D, "LATIN SMALL LETTER IS"),
+    (0xA778, "LATIN SMALL LETTER UM"),
+    (0xA779, "LATIN CAPITAL LETTER INSULAR D"),
+    (0xA77A, "LATIN SMALL LETTER INSULAR D"),
+    (0xA77B, "LATIN CAPITAL LETTER INSULAR F"),
+    (0xA77C, "LATIN SMALL LETTER INSULAR F"),
+    (0xA77D, "LATIN CAPITAL LETTER INSULAR G"),
+    (0xA77E, "LATIN CAPITAL LETTER TURNED INSULAR G"),
+    (0xA77F, "LATIN SMALL LETTER TURNED INSULAR G"),
+    (0xA780, "LATIN CAPITAL LETTER TURNED L"),
+    (0xA781, "LATIN SMALL LETTER TURNED L"),
+    (0xA782, "LATIN CAPITAL LETTER INSULAR R"),
+    (0xA783, "LATIN SMALL LETTER INSULAR R"),
+    (0xA784, "LATIN CAPITAL LETTER INSULAR S"),
+    (0xA785, "LATIN SMALL LETTER INSULAR S"),
+    (0xA786, "LATIN CAPITAL LETTER INSULAR T"),
+    (0xA787, "LATIN SMALL LETTER INSULAR T"),
+    (0xA78D, "LATIN CAPITAL LETTER TURNED H"),
+    (0xA78E, "LATIN SMALL LETTER L WITH RETROFLEX HOOK AND BELT"),
+    (0xA790, "LATIN CAPITAL LETTER N WITH DESCENDER"),
+    (0xA791, "LATIN SMALL LETTER N WITH DESCENDER"),
+    (0xA792, "LATIN CAPITAL LETTER C WITH BAR"),
+    (0xA793, "LATIN SMALL LETTER C WITH BAR"),
+    (0xA794, "LATIN SMALL LETTER C WITH PALATAL HOOK"),
+    (0xA795, "LATIN SMALL LETTER H WITH PALATAL HOOK"),
+    (0xA796, "LATIN CAPITAL LETTER B WITH FLOURISH"),
+    (0xA797, "LATIN SMALL LETTER B WITH FLOURISH"),
+    (0xA798, "LATIN CAPITAL LETTER F WITH STROKE"),
+    (0xA799, "LATIN SMALL LETTER F WITH STROKE"),
+    (0xA79A, "LATIN CAPITAL LETTER VOLAPUK AE"),
+    (0xA79B, "LATIN SMALL LETTER VOLAPUK AE"),
+    (0xA79C, "LATIN CAPITAL LETTER VOLAPUK OE"),
+    (0xA79D, "LATIN SMALL LETTER VOLAPUK OE"),
+    (0xA79E, "LATIN CAPITAL LETTER VOLAPUK UE"),
+    (0xA79F, "LATIN SMALL LETTER VOLAPUK UE"),
+    (0xA7A0, "LATIN CAPITAL LETTER G WITH OBLIQUE STROKE"),
+    (0xA7A1, "LATIN SMALL LETTER G WITH OBLIQUE STROKE"),
+    (0xA7A2, "LATIN CAPITAL LETTER K WITH OBLIQUE STROKE"),
+    (0xA7A3, "LATIN SMALL LETTER K WITH OBLIQUE STROKE"),
+    (0xA7A4, "LATIN CAPITAL LETTER N WITH OBLIQUE STROKE"),
+    (0xA7A5, "LATIN SMALL LETTER N WITH OBLIQUE STROKE"),
+    (0xA7A6, "LATIN CAPITAL LETTER R WITH OBLIQUE STROKE"),
+    (0xA7A7, "LATIN SMALL LETTER R WITH OBLIQUE STROKE"),
+    (0xA7A8, "LATIN CAPITAL LETTER S WITH OBLIQUE STROKE"),
+    (0xA7A9, "LATIN SMALL LETTER S WITH OBLIQUE STROKE"),
+    (0xA7AA, "LATIN CAPITAL LETTER H WITH HOOK"),
+    (0xA7AB, "LATIN CAPITAL LETTER REVERSED OPEN E"),
+    (0xA7AC, "LATIN CAPITAL LETTER SCRIPT G"),
+    (0xA7AD, "LATIN CAPITAL LETTER L WITH BELT"),
+    (0xA7AE, "LATIN CAPITAL LETTER SMALL CAPITAL I"),
+    (0xA7AF, "LATIN LETTER SMALL CAPITAL Q"),
+    (0xA7B0, "LATIN CAPITAL LETTER TURNED K"),
+    (0xA7B1, "LATIN CAPITAL LETTER TURNED T"),
+    (0xA7B2, "LATIN CAPITAL LETTER J WITH CROSSED-TAIL"),
+    (0xA7B8, "LATIN CAPITAL LETTER U WITH STROKE"),
+    (0xA7B9, "LATIN SMALL LETTER U WITH STROKE"),
+    (0xA7BA, "LATIN CAPITAL LETTER GLOTTAL A"),
+    (0xA7BB, "LATIN SMALL LETTER GLOTTAL A"),
+    (0xA7BC, "LATIN CAPITAL LETTER GLOTTAL I"),
+    (0xA7BD, "LATIN SMALL LETTER GLOTTAL I"),
+    (0xA7BE, "LATIN CAPITAL LETTER GLOTTAL U"),
+    (0xA7BF, "LATIN SMALL LETTER GLOTTAL U"),
+    (0xA7C0, "LATIN CAPITAL LETTER OLD POLISH O"),
+    (0xA7C1, "LATIN SMALL LETTER OLD POLISH O"),
+    (0xA7C2, "LATIN CAPITAL LETTER ANGLICANA W"),
+    (0xA7C3, "LATIN SMALL LETTER ANGLICANA W"),
+    (0xA7C4, "LATIN CAPITAL LETTER C WITH PALATAL HOOK"),
+    (0xA7C5, "LATIN CAPITAL LETTER S WITH HOOK"),
+    (0xA7C6, "LATIN CAPITAL LETTER Z WITH PALATAL HOOK"),
+    (0xA7C7, "LATIN CAPITAL LETTER D WITH SHORT STROKE OVERLAY"),
+    (0xA7C8, "LATIN SMALL LETTER D WITH SHORT STROKE OVERLAY"),
+    (0xA7C9, "LATIN CAPITAL LETTER S WITH SHORT STROKE OVERLAY"),
+    (0xA7CA, "LATIN SMALL LETTER S WITH SHORT STROKE OVERLAY"),
+    (0xA7CC, "LATIN CAPITAL LETTER S WITH DIAGONAL STROKE"),
+    (0xA7CD, "LATIN SMALL LETTER S WITH DIAGONAL STROKE"),
+    (0xA7D0, "LATIN CAPITAL LETTER CLOSED INSULAR G"),
+    (0xA7D1, "LATIN SMALL LETTER CLOSED INSULAR G"),
+    (0xA7D6, "LATIN CAPITAL LETTER MIDDLE SCOTS S"),
+    (0xA7D7, "LATIN SMALL LETTER MIDDLE SCOTS S"),
+    (0xA7D8, "LATIN CAPITAL LETTER SIGMOID S"),
+    (0xA7D9, "LATIN SMALL LETTER SIGMOID S"),
+    (0xA7F5, "LATIN CAPITAL LETTER REVERSED HALF H"),
+    (0xA7F6, "LATIN SMALL LETTER REVERSED HALF H"),
+    (0xA7F7, "LATIN EPIGRAPHIC LETTER SIDEWAYS I"),
+    (0xA7FA, "LATIN LETTER SMALL CAPITAL TURNED M"),
+    (0xA7FB, "LATIN EPIGRAPHIC LETTER REVERSED F"),
+    (0xA7FC, "LATIN EPIGRAPHIC LETTER REVERSED P"),
+    (0xA7FD, "LATIN EPIGRAPHIC LETTER INVERTED M"),
+    (0xA7FE, "LATIN EPIGRAPHIC LETTER I LONGA"),
+    (0xA7FF, "LATIN EPIGRAPHIC LETTER ARCHAIC M"),
+    (0xAB31, "LATIN SMALL LETTER A REVERSED-SCHWA"),
+    (0xAB32, "LATIN SMALL LETTER BLACKLETTER E"),
+    (0xAB33, "LATIN SMALL LETTER BARRED E"),
+    (0xAB34, "LATIN SMALL LETTER E WITH FLOURISH"),
+    (0xAB35, "LATIN SMALL LETTER LENIS F"),
+    (0xAB36, "LATIN SMALL LETTER SCRIPT G WITH CROSSED-TAIL"),
+    (0xAB37, "LATIN SMALL LETTER L WITH INVERTED LAZY S"),
+    (0xAB38, "LATIN SMALL LETTER L WITH DOUBLE MIDDLE TILDE"),
+    (0xAB39, "LATIN SMALL LETTER L WITH MIDDLE RING"),
+    (0xAB3A, "LATIN SMALL LETTER M WITH CROSSED-TAIL"),
+    (0xAB3B, "LATIN SMALL LETTER N WITH CROSSED-TAIL"),
+    (0xAB3D, "LATIN SMALL LETTER BLACKLETTER O"),
+    (0xAB3E, "LATIN SMALL LETTER BLACKLETTER O WITH STROKE"),
+    (0xAB3F, "LATIN SMALL LETTER OPEN O WITH STROKE"),
+    (0xAB40, "LATIN SMALL LETTER INVERTED OE"),
+    (0xAB41, "LATIN SMALL LETTER TURNED OE WITH STROKE"),
+    (0xAB42, "LATIN SMALL LETTER TURNED OE WITH HORIZONTAL STROKE"),
+    (0xAB43, "LATIN SMALL LETTER TURNED O OPEN-O"),
+    (0xAB44, "LATIN SMALL LETTER TURNED O OPEN-O WITH STROKE"),
+    (0xAB45, "LATIN SMALL LETTER STIRRUP R"),
+    (0xAB46, "LATIN LETTER SMALL CAPITAL R WITH RIGHT LEG"),
+    (0xAB47, "LATIN SMALL LETTER R WITHOUT HANDLE"),
+    (0xAB48, "LATIN SMALL LETTER DOUBLE R"),
+    (0xAB49, "LATIN SMALL LETTER R WITH CROSSED-TAIL"),
+    (0xAB4A, "LATIN SMALL LETTER DOUBLE R WITH CROSSED-TAIL"),
+    (0xAB4B, "LATIN SMALL LETTER SCRIPT R"),
+    (0xAB4C, "LATIN SMALL LETTER SCRIPT R WITH RING"),
+    (0xAB4E, "LATIN SMALL LETTER U WITH SHORT RIGHT LEG"),
+    (0xAB4F, "LATIN SMALL LETTER U BAR WITH SHORT RIGHT LEG"),
+    (0xAB50, "LATIN SMALL LETTER UI"),
+    (0xAB51, "LATIN SMALL LETTER TURNED UI"),
+    (0xAB52, "LATIN SMALL LETTER U WITH LEFT HOOK"),
+    (0xAB56, "LATIN SMALL LETTER X WITH LOW RIGHT RING"),
+    (0xAB57, "LATIN SMALL LETTER X WITH LONG LEFT LEG"),
+    (0xAB58, "LATIN SMALL LETTER X WITH LONG LEFT LEG AND LOW RIGHT RING"),
+    (0xAB59, "LATIN SMALL LETTER X WITH LONG LEFT LEG WITH SERIF"),
+    (0xAB5A, "LATIN SMALL LETTER Y WITH SHORT RIGHT LEG"),
+    (0xAB61, "LATIN SMALL LETTER IOTIFIED E"),
+    (0xAB62, "LATIN SMALL LETTER OPEN OE"),
+    (0xAB63, "LATIN SMALL LETTER UO"),
+    (0xAB66, "LATIN SMALL LETTER DZ DIGRAPH WITH RETROFLEX HOOK"),
+    (0xAB67, "LATIN SMALL LETTER TS DIGRAPH WITH RETROFLEX HOOK"),
+    (0xAB68, "LATIN SMALL LETTER TURNED R WITH MIDDLE TILDE"),
+    (0xFB00, "LATIN SMALL LIGATURE FF"),
+    (0xFB01, "LATIN SMALL LIGATURE FI"),
+    (0xFB02, "LATIN SMALL LIGATURE FL"),
+    (0xFB03, "LATIN SMALL LIGATURE FFI"),
+    (0xFB04, "LATIN SMALL LIGATURE FFL"),
+    (0xFB05, "LATIN SMALL LIGATURE LONG S T"),
+    (0xFB06, "LATIN SMALL LIGATURE ST"),
+    (0xFF21, "FULLWIDTH LATIN CAPITAL LETTER A"),
+    (0xFF22, "FULLWIDTH LATIN CAPITAL LETTER B"),
+    (0xFF23, "FULLWIDTH LATIN CAPITAL LETTER C"),
+    (0xFF24, "FULLWIDTH LATIN CAPITAL LETTER D"),
+    (0xFF25, "FULLWIDTH LATIN CAPITAL LETTER E"),
+    (0xFF26, "FULLWIDTH LATIN CAPITAL LETTER F"),
+    (0xFF27, "FULLWIDTH LATIN CAPITAL LETTER G"),
+    (0xFF28, "FULLWIDTH LATIN CAPITAL LETTER H"),
+    (0xFF29, "FULLWIDTH LATIN CAPITAL LETTER I"),
+    (0xFF2A, "FULLWIDTH LATIN CAPITAL LETTER J"),
+    (0xFF2B, "FULLWIDTH LATIN CAPITAL LETTER K"),
+    (0xFF2C, "FULLWIDTH LATIN CAPITAL LETTER L"),
+    (0xFF2D, "FULLWIDTH LATIN CAPITAL LETTER M"),
+    (0xFF2E, "FULLWIDTH LATIN CAPITAL LETTER N"),
+    (0xFF2F, "FULLWIDTH LATIN CAPITAL LETTER O"),
+    (0xFF30, "FULLWIDTH LATIN CAPITAL LETTER P"),
+    (0xFF31, "FULLWIDTH LATIN CAPITAL LETTER Q"),
+    (0xFF32, "FULLWIDTH LATIN CAPITAL LETTER R"),
+    (0xFF33, "FULLWIDTH LATIN CAPITAL LETTER S"),
+    (0xFF34, "FULLWIDTH LATIN CAPITAL LETTER T"),
+    (0xFF35, "FULLWIDTH LATIN CAPITAL LETTER U"),
+    (0xFF36, "FULLWIDTH LATIN CAPITAL LETTER V"),
+    (0xFF37, "FULLWIDTH LATIN CAPITAL LETTER W"),
+    (0xFF38, "FULLWIDTH LATIN CAPITAL LETTER X"),
+    (0xFF39, "FULLWIDTH LATIN CAPITAL LETTER Y"),
+    (0xFF3A, "FULLWIDTH LATIN CAPITAL LETTER Z"),
+    (0xFF41, "FULLWIDTH LATIN SMALL LETTER A"),
+    (0xFF42, "FULLWIDTH LATIN SMALL LETTER B"),
+    (0xFF43, "FULLWIDTH LATIN SMALL LETTER C"),
+    (0xFF44, "FULLWIDTH LATIN SMALL LETTER D"),
+    (0xFF45, "FULLWIDTH LATIN SMALL LETTER E"),
+    (0xFF46, "FULLWIDTH LATIN SMALL LETTER F"),
+    (0xFF47, "FULLWIDTH LATIN SMALL LETTER G"),
+    (0xFF48, "FULLWIDTH LATIN SMALL LETTER H"),
+    (0xFF49, "FULLWIDTH LATIN SMALL LETTER I"),
+    (0xFF4A, "FULLWIDTH LATIN SMALL LETTER J"),
+    (0xFF4B, "FULLWIDTH LATIN SMALL LETTER K"),
+    (0xFF4C, "FULLWIDTH LATIN SMALL LETTER L"),
+    (0xFF4D, "FULLWIDTH LATIN SMALL LETTER M"),
+    (0xFF4E, "FULLWIDTH LATIN SMALL LETTER N"),
+    (0xFF4F, "FULLWIDTH LATIN SMALL LETTER O"),
+    (0xFF50, "FULLWIDTH LATIN SMALL LETTER P"),
+    (0xFF51, "FULLWIDTH LATIN SMALL LETTER Q"),
+    (0xFF52, "FULLWIDTH LATIN SMALL LETTER R"),
+    (0xFF53, "FULLWIDTH LATIN SMALL LETTER S"),
+    (0xFF54, "FULLWIDTH LATIN SMALL LETTER T"),
+    (0xFF55, "FULLWIDTH LATIN SMALL LETTER U"),
+    (0xFF56, "FULLWIDTH LATIN SMALL LETTER V"),
+    (0xFF57, "FULLWIDTH LATIN SMALL LETTER W"),
+    (0xFF58, "FULLWIDTH LATIN SMALL LETTER X"),
+    (0xFF59, "FULLWIDTH LATIN SMALL LETTER Y"),
+    (0xFF5A, "FULLWIDTH LATIN SMALL LETTER Z"),
+    (0x1CCD6, "OUTLINED LATIN CAPITAL LETTER A"),
+    (0x1CCD7, "OUTLINED LATIN CAPITAL LETTER B"),
+    (0x1CCD8, "OUTLINED LATIN CAPITAL LETTER C"),
+    (0x1CCD9, "OUTLINED LATIN CAPITAL LETTER D"),
+    (0x1CCDA, "OUTLINED LATIN CAPITAL LETTER E"),
+    (0x1CCDB, "OUTLINED LATIN CAPITAL LETTER F"),
+    (0x1CCDC, "OUTLINED LATIN CAPITAL LETTER G"),
+    (0x1CCDD, "OUTLINED LATIN CAPITAL LETTER H"),
+    (0x1CCDE, "OUTLINED LATIN CAPITAL LETTER I"),
+    (0x1CCDF, "OUTLINED LATIN CAPITAL LETTER J"),
+    (0x1CCE0, "OUTLINED LATIN CAPITAL LETTER K"),
+    (0x1CCE1, "OUTLINED LATIN CAPITAL LETTER L"),
+    (0x1CCE2, "OUTLINED LATIN CAPITAL LETTER M"),
+    (0x1CCE3, "OUTLINED LATIN CAPITAL LETTER N"),
+    (0x1CCE4, "OUTLINED LATIN CAPITAL LETTER O"),
+    (0x1CCE5, "OUTLINED LATIN CAPITAL LETTER P"),
+    (0x1CCE6, "OUTLINED LATIN CAPITAL LETTER Q"),
+    (0x1CCE7, "OUTLINED LATIN CAPITAL LETTER R"),
+    (0x1CCE8, "OUTLINED LATIN CAPITAL LETTER S"),
+    (0x1CCE9, "OUTLINED LATIN CAPITAL LETTER T"),
+    (0x1CCEA, "OUTLINED LATIN CAPITAL LETTER U"),
+    (0x1CCEB, "OUTLINED LATIN CAPITAL LETTER V"),
+    (0x1CCEC, "OUTLINED LATIN CAPITAL LETTER W"),
+    (0x1CCED, "OUTLINED LATIN CAPITAL LETTER X"),
+    (0x1CCEE, "OUTLINED LATIN CAPITAL LETTER Y"),
+    (0x1CCEF, "OUTLINED LATIN CAPITAL LETTER Z"),
+    (0x1D400, "MATHEMATICAL BOLD CAPITAL A"),
+    (0x1D401, "MATHEMATICAL BOLD CAPITAL B"),
+    (0x1D402, "MATHEMATICAL BOLD CAPITAL C"),
+    (0x1D403, "MATHEMATICAL BOLD CAPITAL D"),
+    (0x1D404, "MATHEMATICAL BOLD CAPITAL E"),
+    (0x1D405, "MATHEMATICAL BOLD CAPITAL F"),
+    (0x1D406, "MATHEMATICAL BOLD CAPITAL G"),
+    (0x1D407, "MATHEMATICAL BOLD CAPITAL H"),
+    (0x1D408, "MATHEMATICAL BOLD CAPITAL I"),
+    (0x1D409, "MATHEMATICAL BOLD CAPITAL J"),
+    (0x1D40A, "MATHEMATICAL BOLD CAPITAL K"),
+    (0x1D40B, "MATHEMATICAL BOLD CAPITAL L"),
+    (0x1D40C, "MATHEMATICAL BOLD CAPITAL M"),
+    (0x1D40D, "MATHEMATICAL BOLD CAPITAL N"),
+    (0x1D40E, "MATHEMATICAL BOLD CAPITAL O"),
+    (0x1D40F, "MATHEMATICAL BOLD CAPITAL P"),
+    (0x1D410, "MATHEMATICAL BOLD CAPITAL Q"),
+    (0x1D411, "MATHEMATICAL BOLD CAPITAL R"),
+    (0x1D412, "MATHEMATICAL BOLD CAPITAL S"),
+    (0x1D413, "MATHEMATICAL BOLD CAPITAL T"),
+    (0x1D414, "MATHEMATICAL BOLD CAPITAL U"),
+    (0x1D415, "MATHEMATICAL BOLD CAPITAL V"),
+    (0x1D416, "MATHEMATICAL BOLD CAPITAL W"),
+    (0x1D417, "MATHEMATICAL BOLD CAPITAL X"),
+    (0x1D418, "MATHEMATICAL BOLD CAPITAL Y"),
+    (0x1D419, "MATHEMATICAL BOLD CAPITAL Z"),
+    (0x1D41A, "MATHEMATICAL BOLD SMALL A"),
+    (0x1D41B, "MATHEMATICAL BOLD SMALL B"),
+    (0x1D41C, "MATHEMATICAL BOLD SMALL C"),
+    (0x1D41D, "MATHEMATICAL BOLD SMALL D"),
+    (0x1D41E, "MATHEMATICAL BOLD SMALL E"),
+    (0x1D41F, "MATHEMATICAL BOLD SMALL F"),
+    (0x1D420, "MATHEMATICAL BOLD SMALL G"),
+    (0x1D421, "MATHEMATICAL BOLD SMALL H"),
+    (0x1D422, "MATHEMATICAL BOLD SMALL I"),
+    (0x1D423, "MATHEMATICAL BOLD SMALL J"),
+    (0x1D424, "MATHEMATICAL BOLD SMALL K"),
+    (0x1D425, "MATHEMATICAL BOLD SMALL L"),
+    (0x1D426, "MATHEMATICAL BOLD SMALL M"),
+    (0x1D427, "MATHEMATICAL BOLD SMALL N"),
+    (0x1D428, "MATHEMATICAL BOLD SMALL O"),
+    (0x1D429, "MATHEMATICAL BOLD SMALL P"),
+    (0x1D42A, "MATHEMATICAL BOLD SMALL Q"),
+    (0x1D42B, "MATHEMATICAL BOLD SMALL R"),
+    (0x1D42C, "MATHEMATICAL BOLD SMALL S"),
+    (0x1D42D, "MATHEMATICAL BOLD SMALL T"),
+    (0x1D42E, "MATHEMATICAL BOLD SMALL U"),
+    (0x1D42F, "MATHEMATICAL BOLD SMALL V"),
+    (0x1D430, "MATHEMATICAL BOLD SMALL W"),
+    (0x1D431, "MATHEMATICAL BOLD SMALL X"),
+    (0x1D432, "MATHEMATICAL BOLD SMALL Y"),
+    (0x1D433, "MATHEMATICAL BOLD SMALL Z"),
+    (0x1D434, "MATHEMATICAL ITALIC CAPITAL A"),
+    (0x1D435, "MATHEMATICAL ITALIC CAPITAL B"),
+    (0x1D436, "MATHEMATICAL ITALIC CAPITAL C"),
+    (0x1D437, "MATHEMATICAL ITALIC CAPITAL D"),
+    (0x1D438, "MATHEMATICAL ITALIC CAPITAL E"),
+    (0x1D439, "MATHEMATICAL ITALIC CAPITAL F"),
+    (0x1D43A, "MATHEMATICAL ITALIC CAPITAL G"),
+    (0x1D43B, "MATHEMATICAL ITALIC CAPITAL H"),
+    (0x1D43C, "MATHEMATICAL ITALIC CAPITAL I"),
+    (0x1D43D, "MATHEMATICAL ITALIC CAPITAL J"),
+    (0x1D43E, "MATHEMATICAL ITALIC CAPITAL K"),
+    (0x1D43F, "MATHEMATICAL ITALIC CAPITAL L"),
+    (0x1D440, "MATHEMATICAL ITALIC CAPITAL M"),
+    (0x1D441, "MATHEMATICAL ITALIC CAPITAL N"),
+    (0x1D442, "MATHEMATICAL ITALIC CAPITAL O"),
+    (0x1D443, "MATHEMATICAL ITALIC CAPITAL P"),
+    (0x1D444, "MATHEMATICAL ITALIC CAPITAL Q"),
+    (0x1D445, "MATHEMATICAL ITALIC CAPITAL R"),
+    (0x1D446, "MATHEMATICAL ITALIC CAPITAL S"),
+    (0x1D447, "MATHEMATICAL ITALIC CAPITAL T"),
+    (0x1D448, "MATHEMATICAL ITALIC CAPITAL U"),
+    (0x1D449, "MATHEMATICAL ITALIC CAPITAL V"),
+    (0x1D44A, "MATHEMATICAL ITALIC CAPITAL W"),
+    (0x1D44B, "MATHEMATICAL ITALIC CAPITAL X"),
+    (0x1D44C, "MATHEMATICAL ITALIC CAPITAL Y"),
+    (0x1D44D, "MATHEMATICAL ITALIC CAPITAL Z"),
+    (0x1D44E, "MATHEMATICAL ITALIC SMALL A"),
+    (0x1D44F, "MATHEMATICAL ITALIC SMALL B"),
+    (0x1D450, "MATHEMATICAL ITALIC SMALL C"),
+    (0x1D451, "MATHEMATICAL ITALIC SMALL D"),
+    (0x1D452, "MATHEMATICAL ITALIC SMALL E"),
+    (0x1D453, "MATHEMATICAL ITALIC SMALL F"),
+    (0x1D454, "MATHEMATICAL ITALIC SMALL G"),
+    (0x1D456, "MATHEMATICAL ITALIC SMALL I"),
+    (0x1D457, "MATHEMATICAL ITALIC SMALL J"),
+    (0x1D458, "MATHEMATICAL ITALIC SMALL K"),
+    (0x1D459, "MATHEMATICAL ITALIC SMALL L"),
+    (0x1D45A, "MATHEMATICAL ITALIC SMALL M"),
+    (0x1D45B, "MATHEMATICAL ITALIC SMALL N"),
+    (0x1D45C, "MATHEMATICAL ITALIC SMALL O"),
+    (0x1D45D, "MATHEMATICAL ITALIC SMALL P"),
+    (0x1D45E, "MATHEMATICAL ITALIC SMALL Q"),
+    (0x1D45F, "MATHEMATICAL ITALIC SMALL R"),
+    (0x1D460, "MATHEMATICAL ITALIC SMALL S"),
+    (0x1D461, "MATHEMATICAL ITALIC SMALL T"),
+    (0x1D462, "MATHEMATICAL ITALIC SMALL U"),
+    (0x1D463, "MATHEMATICAL ITALIC SMALL V"),
+    (0x1D464, "MATHEMATICAL ITALIC SMALL W"),
+    (0x1D465, "MATHEMATICAL ITALIC SMALL X"),
+    (0x1D466, "MATHEMATICAL ITALIC SMALL Y"),
+    (0x1D467, "MATHEMATICAL ITALIC SMALL Z"),
+    (0x1D468, "MATHEMATICAL BOLD ITALIC CAPITAL A"),
+    (0x1D469, "MATHEMATICAL BOLD ITALIC CAPITAL B"),
+    (0x1D46A, "MATHEMATICAL BOLD ITALIC CAPITAL C"),
+    (0x1D46B, "MATHEMATICAL BOLD ITALIC CAPITAL D"),
+    (0x1D46C, "MATHEMATICAL BOLD ITALIC CAPITAL E"),
+    (0x1D46D, "MATHEMATICAL BOLD ITALIC CAPITAL F"),
+    (0x1D46E, "MATHEMATICAL BOLD ITALIC CAPITAL G"),
+    (0x1D46F, "MATHEMATICAL BOLD ITALIC CAPITAL H"),
+    (0x1D470, "MATHEMATICAL BOLD ITALIC CAPITAL I"),
+    (0x1D471, "MATHEMATICAL BOLD ITALIC CAPITAL J"),
+    (0x1D472, "MATHEMATICAL BOLD ITALIC CAPITAL K"),
+    (0x1D473, "MATHEMATICAL BOLD ITALIC CAPITAL L"),
+    (0x1D474, "MATHEMATICAL BOLD ITALIC CAPITAL M"),
+    (0x1D475, "MATHEMATICAL BOLD ITALIC CAPITAL N"),
+    (0x1D476, "MATHEMATICAL BOLD ITALIC CAPITAL O"),
+    (0x1D477, "MATHEMATICAL BOLD ITALIC CAPITAL P"),
+    (0x1D478, "MATHEMATICAL BOLD ITALIC CAPITAL Q"),
+    (0x1D479, "MATHEMATICAL BOLD ITALIC CAPITAL R"),
+    (0x1D47A, "MATHEMATICAL BOLD ITALIC CAPITAL S"),
+    (0x1D47B, "MATHEMATICAL BOLD ITALIC CAPITAL T"),
+    (0x1D47C, "MATHEMATICAL BOLD ITALIC CAPITAL U"),
+    (0x1D47D, "MATHEMATICAL BOLD ITALIC CAPITAL V"),
+    (0x1D47E, "MATHEMATICAL BOLD ITALIC CAPITAL W"),
+    (0x1D47F, "MATHEMATICAL BOLD ITALIC CAPITAL X"),
+    (0x1D480, "MATHEMATICAL BOLD ITALIC CAPITAL Y"),
+    (0x1D481, "MATHEMATICAL BOLD ITALIC CAPITAL Z"),
+    (0x1D482, "MATHEMATICAL BOLD ITALIC SMALL A"),
+    (0x1D483, "MATHEMATICAL BOLD ITALIC SMALL B"),
+    (0x1D484, "MATHEMATICAL BOLD ITALIC SMALL C"),
+    (0x1D485, "MATHEMATICAL BOLD ITALIC SMALL D"),
+    (0x1D486, "MATHEMATICAL BOLD ITALIC SMALL E"),
+    (0x1D487, "MATHEMATICAL BOLD ITALIC SMALL F"),
+    (0x1D488, "MATHEMATICAL BOLD ITALIC SMALL G"),
+    (0x1D489, "MATHEMATICAL BOLD ITALIC SMALL H"),
+    (0x1D48A, "MATHEMATICAL BOLD ITALIC SMALL I"),
+    (0x1D48B, "MATHEMATICAL BOLD ITALIC SMALL J"),
+    (0x1D48C, "MATHEMATICAL BOLD ITALIC SMALL K"),
+    (0x1D48D, "MATHEMATICAL BOLD ITALIC SMALL L"),
+    (0x1D48E, "MATHEMATICAL BOLD ITALIC SMALL M"),
+    (0x1D48F, "MATHEMATICAL BOLD ITALIC SMALL N"),
+    (0x1D490, "MATHEMATICAL BOLD ITALIC SMALL O"),
+    (0x1D491, "MATHEMATICAL BOLD ITALIC SMALL P"),
+    (0x1D492, "MATHEMATICAL BOLD ITALIC SMALL Q"),
+    (0x1D493, "MATHEMATICAL BOLD ITALIC SMALL R"),
+    (0x1D494, "MATHEMATICAL BOLD ITALIC SMALL S"),
+    (0x1D495, "MATHEMATICAL BOLD ITALIC SMALL T"),
+    (0x1D496, "MATHEMATICAL BOLD ITALIC SMALL U"),
+    (0x1D497, "MATHEMATICAL BOLD ITALIC SMALL V"),
+    (0x1D498, "MATHEMATICAL BOLD ITALIC SMALL W"),
+    (0x1D499, "MATHEMATICAL BOLD ITALIC SMALL X"),
+    (0x1D49A, "MATHEMATICAL BOLD ITALIC SMALL Y"),
+    (0x1D49B, "MATHEMATICAL BOLD ITALIC SMALL Z"),
+    (0x1D49C, "MATHEMATICAL SCRIPT CAPITAL A"),
+    (0x1D49E, "MATHEMATICAL SCRIPT CAPITAL C"),
+    (0x1D49F, "MATHEMATICAL SCRIPT CAPITAL D"),
+    (0x1D4A2, "MATHEMATICAL SCRIPT CAPITAL G"),
+    (0x1D4A5, "MATHEMATICAL SCRIPT CAPITAL J"),
+    (0x1D4A6, "MATHEMATICAL SCRIPT CAPITAL K"),
+    (0x1D4A9, "MATHEMATICAL SCRIPT CAPITAL N"),
+    (0x1D4AA, "MATHEMATICAL SCRIPT CAPITAL O"),
+    (0x1D4AB, "MATHEMATICAL SCRIPT CAPITAL P"),
+    (0x1D4AC, "MATHEMATICAL SCRIPT CAPITAL Q"),
+    (0x1D4AE, "MATHEMATICAL SCRIPT CAPITAL S"),
+    (0x1D4AF, "MATHEMATICAL SCRIPT CAPITAL T"),
+    (0x1D4B0, "MATHEMATICAL SCRIPT CAPITAL U"),
+    (0x1D4B1, "MATHEMATICAL SCRIPT CAPITAL V"),
+    (0x1D4B2, "MATHEMATICAL SCRIPT CAPITAL W"),
+    (0x1D4B3, "MATHEMATICAL SCRIPT CAPITAL X"),
+    (0x1D4B4, "MATHEMATICAL SCRIPT CAPITAL Y"),
+    (0x1D4B5, "MATHEMATICAL SCRIPT CAPITAL Z"),
+    (0x1D4B6, "MATHEMATICAL SCRIPT SMALL A"),
+    (0x1D4B7, "MATHEMATICAL SCRIPT SMALL B"),
+    (0x1D4B8, "MATHEMATICAL SCRIPT SMALL C"),
+    (0x1D4B9, "MATHEMATICAL SCRIPT SMALL D"),
+    (0x1D4BB, "MATHEMATICAL SCRIPT SMALL F"),
+    (0x1D4BD, "MATHEMATICAL SCRIPT SMALL H"),
+    (0x1D4BE, "MATHEMATICAL SCRIPT SMALL I"),
+    (0x1D4BF, "MATHEMATICAL SCRIPT SMALL J"),
+    (0x1D4C0, "MATHEMATICAL SCRIPT SMALL K"),
+    (0x1D4C1, "MATHEMATICAL SCRIPT SMALL L"),
+    (0x1D4C2, "MATHEMATICAL SCRIPT SMALL M"),
+    (0x1D4C3, "MATHEMATICAL SCRIPT SMALL N"),
+    (0x1D4C5, "MATHEMATICAL SCRIPT SMALL P"),
+    (0x1D4C6, "MATHEMATICAL SCRIPT SMALL Q"),
+    (0x1D4C7, "MATHEMATICAL SCRIPT SMALL R"),
+    (0x1D4C8, "MATHEMATICAL SCRIPT SMALL S"),
+    (0x1D4C9, "MATHEMATICAL SCRIPT SMALL T"),
+    (0x1D4CA, "MATHEMATICAL SCRIPT SMALL U"),
+    (0x1D4CB, "MATHEMATICAL SCRIPT SMALL V"),
+    (0x1D4CC, "MATHEMATICAL SCRIPT SMALL W"),
+    (0x1D4CD, "MATHEMATICAL SCRIPT SMALL X"),
+    (0x1D4CE, "MATHEMATICAL SCRIPT SMALL Y"),
+    (0x1D4CF, "MATHEMATICAL SCRIPT SMALL Z"),
+    (0x1D4D0, "MATHEMATICAL BOLD SCRIPT CAPITAL A"),
+    (0x1D4D1, "MATHEMATICAL BOLD SCRIPT CAPITAL B"),
+    (0x1D4D2, "MATHEMATICAL BOLD SCRIPT CAPITAL C"),
+    (0x1D4D3, "MATHEMATICAL BOLD SCRIPT CAPITAL D"),
+    (0x1D4D4, "MATHEMATICAL BOLD SCRIPT CAPITAL E"),
+    (0x1D4D5, "MATHEMATICAL BOLD SCRIPT CAPITAL F"),
+    (0x1D4D6, "MATHEMATICAL BOLD SCRIPT CAPITAL G"),
+    (0x1D4D7, "MATHEMATICAL BOLD SCRIPT CAPITAL H"),
+    (0x1D4D8, "MATHEMATICAL BOLD SCRIPT CAPITAL I"),
+    (0x1D4D9, "MATHEMATICAL BOLD SCRIPT CAPITAL J"),
+    (0x1D4DA, "MATHEMATICAL BOLD SCRIPT CAPITAL K"),
+    (0x1D4DB, "MATHEMATICAL BOLD SCRIPT CAPITAL L"),
+    (0x1D4DC, "MATHEMATICAL BOLD SCRIPT CAPITAL M"),
+    (0x1D4DD, "MATHEMATICAL BOLD SCRIPT CAPITAL N"),
+    (0x1D4DE, "MATHEMATICAL BOLD SCRIPT CAPITAL O"),
+    (0x1D4DF, "MATHEMATICAL BOLD SCRIPT CAPITAL P"),
+    (0x1D4E0, "MATHEMATICAL BOLD SCRIPT CAPITAL Q"),
+    (0x1D4E1, "MATHEMATICAL BOLD SCRIPT CAPITAL R"),
+    (0x1D4E2, "MATHEMATICAL BOLD SCRIPT CAPITAL S"),
+    (0x1D4E3, "MATHEMATICAL BOLD SCRIPT CAPITAL T"),
+    (0x1D4E4, "MATHEMATICAL BOLD SCRIPT CAPITAL U"),
+    (0x1D4E5, "MATHEMATICAL BOLD SCRIPT CAPITAL V"),
+    (0x1D4E6, "MATHEMATICAL BOLD SCRIPT CAPITAL W"),
+    (0x1D4E7, "MATHEMATICAL BOLD SCRIPT CAPITAL X"),
+    (0x1D4E8, "MATHEMATICAL BOLD SCRIPT CAPITAL Y"),
+    (0x1D4E9, "MATHEMATICAL BOLD SCRIPT CAPITAL Z"),
+    (0x1D4EA, "MATHEMATICAL BOLD SCRIPT SMALL A"),
+    (0x1D4EB, "MATHEMATICAL BOLD SCRIPT SMALL B"),
+    (0x1D4EC, "MATHEMATICAL BOLD SCRIPT SMALL C"),
+    (0x1D4ED, "MATHEMATICAL BOLD SCRIPT SMALL D"),
+    (0x1D4EE, "MATHEMATICAL BOLD SCRIPT SMALL E"),
+    (0x1D4EF, "MATHEMATICAL BOLD SCRIPT SMALL F"),
+    (0x1D4F0, "MATHEMATICAL BOLD SCRIPT SMALL G"),
+    (0x1D4F1, "MATHEMATICAL BOLD SCRIPT SMALL H"),
+    (0x1D4F2, "MATHEMATICAL BOLD SCRIPT SMALL I"),
+    (0x1D4F3, "MATHEMATICAL BOLD SCRIPT SMALL J"),
+    (0x1D4F4, "MATHEMATICAL BOLD SCRIPT SMALL K"),
+    (0x1D4F5, "MATHEMATICAL BOLD SCRIPT SMALL L"),
+    (0x1D4F6, "MATHEMATICAL BOLD SCRIPT SMALL M"),
+    (0x1D4F7, "MATHEMATICAL BOLD SCRIPT SMALL N"),
+    (0x1D4F8, "MATHEMATICAL BOLD SCRIPT SMALL O"),
+    (0x1D4F9, "MATHEMATICAL BOLD SCRIPT SMALL P"),
+    (0x1D4FA, "MATHEMATICAL BOLD SCRIPT SMALL Q"),
+    (0x1D4FB, "MATHEMATICAL BOLD SCRIPT SMALL R"),
+    (0x1D4FC, "MATHEMATICAL BOLD SCRIPT SMALL S"),
+    (0x1D4FD, "MATHEMATICAL BOLD SCRIPT SMALL T"),
+    (0x1D4FE, "MATHEMATICAL BOLD SCRIPT SMALL U"),
+    (0x1D4FF, "MATHEMATICAL BOLD SCRIPT SMALL V"),
+    (0x1D500, "MATHEMATICAL BOLD SCRIPT SMALL W"),
+    (0x1D501, "MATHEMATICAL BOLD SCRIPT SMALL X"),
+    (0x1D502, "MATHEMATICAL BOLD SCRIPT SMALL Y"),
+    (0x1D503, "MATHEMATICAL BOLD SCRIPT SMALL Z"),
+    (0x1D504, "MATHEMATICAL FRAKTUR CAPITAL A"),
+    (0x1D505, "MATHEMATICAL FRAKTUR CAPITAL B"),
+    (0x1D507, "MATHEMATICAL FRAKTUR CAPITAL D"),
+    (0x1D508, "MATHEMATICAL FRAKTUR CAPITAL E"),
+    (0x1D509, "MATHEMATICAL FRAKTUR CAPITAL F"),
+    (0x1D50A, "MATHEMATICAL FRAKTUR CAPITAL G"),
+    (0x1D50D, "MATHEMATICAL FRAKTUR CAPITAL J"),
+    (0x1D50E, "MATHEMATICAL FRAKTUR CAPITAL K"),
+    (0x1D50F, "MATHEMATICAL FRAKTUR CAPITAL L"),
+    (0x1D510, "MATHEMATICAL FRAKTUR CAPITAL M"),
+    (0x1D511, "MATHEMATICAL FRAKTUR CAPITAL N"),
+    (0x1D512, "MATHEMATICAL FRAKTUR CAPITAL O"),
+    (0x1D513, "MATHEMATICAL FRAKTUR CAPITAL P"),
+    (0x1D514, "MATHEMATICAL FRAKTUR CAPITAL Q"),
+    (0x1D516, "MATHEMATICAL FRAKTUR CAPITAL S"),
+    (0x1D517, "MATHEMATICAL FRAKTUR CAPITAL T"),
+    (0x1D518, "MATHEMATICAL FRAKTUR CAPITAL U"),
+    (0x1D519, "MATHEMATICAL FRAKTUR CAPITAL V"),
+    (0x1D51A, "MATHEMATICAL FRAKTUR CAPITAL W"),
+    (0x1D51B, "MATHEMATICAL FRAKTUR CAPITAL X"),
+    (0x1D51C, "MATHEMATICAL FRAKTUR CAPITAL Y"),
+    (0x1D51E, "MATHEMATICAL FRAKTUR SMALL A"),
+    (0x1D51F, "MATHEMATICAL FRAKTUR SMALL B"),
+    (0x1D520, "MATHEMATICAL FRAKTUR SMALL C"),
+    (0x1D521, "MATHEMATICAL FRAKTUR SMALL D"),
+    (0x1D522, "MATHEMATICAL FRAKTUR SMALL E"),
+    (0x1D523, "MATHEMATICAL FRAKTUR SMALL F"),
+    (0x1D524, "MATHEMATICAL FRAKTUR SMALL G"),
+    (0x1D525, "MATHEMATICAL FRAKTUR SMALL H"),
+    (0x1D526, "MATHEMATICAL FRAKTUR SMALL I"),
+    (0x1D527, "MATHEMATICAL FRAKTUR SMALL J"),
+    (0x1D528, "MATHEMATICAL FRAKTUR SMALL K"),
+    (0x1D529, "MATHEMATICAL FRAKTUR SMALL L"),
+    (0x1D52A, "MATHEMATICAL FRAKTUR SMALL M"),
+    (0x1D52B, "MATHEMATICAL FRAKTUR SMALL N"),
+    (0x1D52C, "MATHEMATICAL FRAKTUR SMALL O"),
+    (0x1D52D, "MATHEMATICAL FRAKTUR SMALL P"),
+    (0x1D52E, "MATHEMATICAL FRAKTUR SMALL Q"),
+    (0x1D52F, "MATHEMATICAL FRAKTUR SMALL R"),
+    (0x1D530, "MATHEMATICAL FRAKTUR SMALL S"),
+    (0x1D531, "MATHEMATICAL FRAKTUR SMALL T"),
+    (0x1D532, "MATHEMATICAL FRAKTUR SMALL U"),
+    (0x1D533, "MATHEMATICAL FRAKTUR SMALL V"),
+    (0x1D534, "MATHEMATICAL FRAKTUR SMALL W"),
+    (0x1D535, "MATHEMATICAL FRAKTUR SMALL X"),
+    (0x1D536, "MATHEMATICAL FRAKTUR SMALL Y"),
+    (0x1D537, "MATHEMATICAL FRAKTUR SMALL Z"),
+    (0x1D538, "MATHEMATICAL DOUBLE-STRUCK CAPITAL A"),
+    (0x1D539, "MATHEMATICAL DOUBLE-STRUCK CAPITAL B"),
+    (0x1D53B, "MATHEMATICAL DOUBLE-STRUCK CAPITAL D"),
+    (0x1D53C, "MATHEMATICAL DOUBLE-STRUCK CAPITAL E"),
+    (0x1D53D, "MATHEMATICAL DOUBLE-STRUCK CAPITAL F"),
+    (0x1D53E, "MATHEMATICAL DOUBLE-STRUCK CAPITAL G"),
+    (0x1D540, "MATHEMATICAL DOUBLE-STRUCK CAPITAL I"),
+    (0x1D541, "MATHEMATICAL DOUBLE-STRUCK CAPITAL J"),
+    (0x1D542, "MATHEMATICAL DOUBLE-STRUCK CAPITAL K"),
+    (0x1D543, "MATHEMATICAL DOUBLE-STRUCK CAPITAL L"),
+    (0x1D544, "MATHEMATICAL DOUBLE-STRUCK CAPITAL M"),
+    (0x1D546, "MATHEMATICAL DOUBLE-STRUCK CAPITAL O"),
+    (0x1D54A, "MATHEMATICAL DOUBLE-STRUCK CAPITAL S"),
+    (0x1D54B, "MATHEMATICAL DOUBLE-STRUCK CAPITAL T"),
+    (0x1D54C, "MATHEMATICAL DOUBLE-STRUCK CAPITAL U"),
+    (0x1D54D, "MATHEMATICAL DOUBLE-STRUCK CAPITAL V"),
+    (0x1D54E, "MATHEMATICAL DOUBLE-STRUCK CAPITAL W"),
+    (0x1D54F, "MATHEMATICAL DOUBLE-STRUCK CAPITAL X"),
+    (0x1D550, "MATHEMATICAL DOUBLE-STRUCK CAPITAL Y"),
+    (0x1D552, "MATHEMATICAL DOUBLE-STRUCK SMALL A"),
+    (0x1D553, "MATHEMATICAL DOUBLE-STRUCK SMALL B"),
+    (0x1D554, "MATHEMATICAL DOUBLE-STRUCK SMALL C"),
+    (0x1D555, "MATHEMATICAL DOUBLE-STRUCK SMALL D"),
+    (0x1D556, "MATHEMATICAL DOUBLE-STRUCK SMALL E"),
+    (0x1D557, "MATHEMATICAL DOUBLE-STRUCK SMALL F"),
+    (0x1D558, "MATHEMATICAL DOUBLE-STRUCK SMALL G"),
+    (0x1D559, "MATHEMATICAL DOUBLE-STRUCK SMALL H"),
+    (0x1D55A, "MATHEMATICAL DOUBLE-STRUCK SMALL I"),
+    (0x1D55B, "MATHEMATICAL DOUBLE-STRUCK SMALL J"),
+    (0x1D55C, "MATHEMATICAL DOUBLE-STRUCK SMALL K"),
+    (0x1D55D, "MATHEMATICAL DOUBLE-STRUCK SMALL L"),
+    (0x1D55E, "MATHEMATICAL DOUBLE-STRUCK SMALL M"),
+    (0x1D55F, "MATHEMATICAL DOUBLE-STRUCK SMALL N"),
+    (0x1D560, "MATHEMATICAL DOUBLE-STRUCK SMALL O"),
+    (0x1D561, "MATHEMATICAL DOUBLE-STRUCK SMALL P"),
+    (0x1D562, "MATHEMATICAL DOUBLE-STRUCK SMALL Q"),
+    (0x1D563, "MATHEMATICAL DOUBLE-STRUCK SMALL R"),
+    (0x1D564, "MATHEMATICAL DOUBLE-STRUCK SMALL S"),
+    (0x1D565, "MATHEMATICAL DOUBLE-STRUCK SMALL T"),
+    (0x1D566, "MATHEMATICAL DOUBLE-STRUCK SMALL U"),
+    (0x1D567, "MATHEMATICAL DOUBLE-STRUCK SMALL V"),
+    (0x1D568, "MATHEMATICAL DOUBLE-STRUCK SMALL W"),
+    (0x1D569, "MATHEMATICAL DOUBLE-STRUCK SMALL X"),
+    (0x1D56A, "MATHEMATICAL DOUBLE-STRUCK SMALL Y"),
+    (0x1D56B, "MATHEMATICAL DOUBLE-STRUCK SMALL Z"),
+    (0x1D56C, "MATHEMATICAL BOLD FRAKTUR CAPITAL A"),
+    (0x1D56D, "MATHEMATICAL BOLD FRAKTUR CAPITAL B"),
+    (0x1D56E, "MATHEMATICAL BOLD FRAKTUR CAPITAL C"),
+    (0x1D56F, "MATHEMATICAL BOLD FRAKTUR CAPITAL D"),
+    (0x1D570, "MATHEMATICAL BOLD FRAKTUR CAPITAL E"),
+    (0x1D571, "MATHEMATICAL BOLD FRAKTUR CAPITAL F"),
+    (0x1D572, "MATHEMATICAL BOLD FRAKTUR CAPITAL G"),
+    (0x1D573, "MATHEMATICAL BOLD FRAKTUR CAPITAL H"),
+    (0x1D574, "MATHEMATICAL BOLD FRAKTUR CAPITAL I"),
+    (0x1D575, "MATHEMATICAL BOLD FRAKTUR CAPITAL J"),
+    (0x1D576, "MATHEMATICAL BOLD FRAKTUR CAPITAL K"),
+    (0x1D577, "MATHEMATICAL BOLD FRAKTUR CAPITAL L"),
+    (0x1D578, "MATHEMATICAL BOLD FRAKTUR CAPITAL M"),
+    (0x1D579, "MATHEMATICAL BOLD FRAKTUR CAPITAL N"),
+    (0x1D57A, "MATHEMATICAL BOLD FRAKTUR CAPITAL O"),
+    (0x1D57B, "MATHEMATICAL BOLD FRAKTUR CAPITAL P"),
+    (0x1D57C, "MATHEMATICAL BOLD FRAKTUR CAPITAL Q"),
+    (0x1D57D, "MATHEMATICAL BOLD FRAKTUR CAPITAL R"),
+    (0x1D57E, "MATHEMATICAL BOLD FRAKTUR CAPITAL S"),
+    (0x1D57F, "MATHEMATICAL BOLD FRAKTUR CAPITAL T"),
+    (0x1D580, "MATHEMATICAL BOLD FRAKTUR CAPITAL U"),
+    (0x1D581, "MATHEMATICAL BOLD FRAKTUR CAPITAL V"),
+    (0x1D582, "MATHEMATICAL BOLD FRAKTUR CAPITAL W"),
+    (0x1D583, "MATHEMATICAL BOLD FRAKTUR CAPITAL X"),
+    (0x1D584, "MATHEMATICAL BOLD FRAKTUR CAPITAL Y"),
+    (0x1D585, "MATHEMATICAL BOLD FRAKTUR CAPITAL Z"),
+    (0x1D586, "MATHEMATICAL BOLD FRAKTUR SMALL A"),
+    (0x1D587, "MATHEMATICAL BOLD FRAKTUR SMALL B"),
+    (0x1D588, "MATHEMATICAL BOLD FRAKTUR SMALL C"),
+    (0x1D589, "MATHEMATICAL BOLD FRAKTUR SMALL D"),
+    (0x1D58A, "MATHEMATICAL BOLD FRAKTUR SMALL E"),
+    (0x1D58B, "MATHEMATICAL BOLD FRAKTUR SMALL F"),
+    (0x1D58C, "MATHEMATICAL BOLD FRAKTUR SMALL G"),
+    (0x1D58D, "MATHEMATICAL BOLD FRAKTUR SMALL H"),
+    (0x1D58E, "MATHEMATICAL BOLD FRAKTUR SMALL I"),
+    (0x1D58F, "MATHEMATICAL BOLD FRAKTUR SMALL J"),
+    (0x1D590, "MATHEMATICAL BOLD FRAKTUR SMALL K"),
+    (0x1D591, "MATHEMATICAL BOLD FRAKTUR SMALL L"),
+    (0x1D592, "MATHEMATICAL BOLD FRAKTUR SMALL M"),
+    (0x1D593, "MATHEMATICAL BOLD FRAKTUR SMALL N"),
+    (0x1D594, "MATHEMATICAL BOLD FRAKTUR SMALL O"),
+    (0x1D595, "MATHEMATICAL BOLD FRAKTUR SMALL P"),
+    (0x1D596, "MATHEMATICAL BOLD FRAKTUR SMALL Q"),
+    (0x1D597, "MATHEMATICAL BOLD FRAKTUR SMALL R"),
+    (0x1D598, "MATHEMATICAL BOLD FRAKTUR SMALL S"),
+    (0x1D599, "MATHEMATICAL BOLD FRAKTUR SMALL T"),
+    (0x1D59A, "MATHEMATICAL BOLD FRAKTUR SMALL U"),
+    (0x1D59B, "MATHEMATICAL BOLD FRAKTUR SMALL V"),
+    (0x1D59C, "MATHEMATICAL BOLD FRAKTUR SMALL W"),
+    (0x1D59D, "MATHEMATICAL BOLD FRAKTUR SMALL X"),
+    (0x1D59E, "MATHEMATICAL BOLD FRAKTUR SMALL Y"),
+    (0x1D59F, "MATHEMATICAL BOLD FRAKTUR SMALL Z"),
+    (0x1D5A0, "MATHEMATICAL SANS-SERIF CAPITAL A"),
+    (0x1D5A1, "MATHEMATICAL SANS-SERIF CAPITAL B"),
+    (0x1D5A2, "MATHEMATICAL SANS-SERIF CAPITAL C"),
+    (0x1D5A3, "MATHEMATICAL SANS-SERIF CAPITAL D"),
+    (0x1D5A4, "MATHEMATICAL SANS-SERIF CAPITAL E"),
+    (0x1D5A5, "MATHEMATICAL SANS-SERIF CAPITAL F"),
+    (0x1D5A6, "MATHEMATICAL SANS-SERIF CAPITAL G"),
+    (0x1D5A7, "MATHEMATICAL SANS-SERIF CAPITAL H"),
+    (0x1D5A8, "MATHEMATICAL SANS-SERIF CAPITAL I"),
+    (0x1D5A9, "MATHEMATICAL SANS-SERIF CAPITAL J"),
+    (0x1D5AA, "MATHEMATICAL SANS-SERIF CAPITAL K"),
+    (0x1D5AB, "MATHEMATICAL SANS-SERIF CAPITAL L"),
+    (0x1D5AC, "MATHEMATICAL SANS-SERIF CAPITAL M"),
+    (0x1D5AD, "MATHEMATICAL SANS-SERIF CAPITAL N"),
+    (0x1D5AE, "MATHEMATICAL SANS-SERIF CAPITAL O"),
+    (0x1D5AF, "MATHEMATICAL SANS-SERIF CAPITAL P"),
+    (0x1D5B0, "MATHEMATICAL SANS-SERIF CAPITAL Q"),
+    (0x1D5B1, "MATHEMATICAL SANS-SERIF CAPITAL R"),
+    (0x1D5B2, "MATHEMATICAL SANS-SERIF CAPITAL S"),
+    (0x1D5B3, "MATHEMATICAL SANS-SERIF CAPITAL T"),
+    (0x1D5B4, "MATHEMATICAL SANS-SERIF CAPITAL U"),
+    (0x1D5B5, "MATHEMATICAL SANS-SERIF CAPITAL V"),
+    (0x1D5B6, "MATHEMATICAL SANS-SERIF CAPITAL W"),
+    (0x1D5B7, "MATHEMATICAL SANS-SERIF CAPITAL X"),
+    (0x1D5B8, "MATHEMATICAL SANS-SERIF CAPITAL Y"),
+    (0x1D5B9, "MATHEMATICAL SANS-SERIF CAPITAL Z"),
+    (0x1D5BA, "MATHEMATICAL SANS-SERIF SMALL A"),
+    (0x1D5BB, "MATHEMATICAL SANS-SERIF SMALL B"),
+    (0x1D5BC, "MATHEMATICAL SANS-SERIF SMALL C"),
+    (0x1D5BD, "MATHEMATICAL SANS-SERIF SMALL D"),
+    (0x1D5BE, "MATHEMATICAL SANS-SERIF SMALL E"),
+    (0x1D5BF, "MATHEMATICAL SANS-SERIF SMALL F"),
+    (0x1D5C0, "MATHEMATICAL SANS-SERIF SMALL G"),
+    (0x1D5C1, "MATHEMATICAL SANS-SERIF SMALL H"),
+    (0x1D5C2, "MATHEMATICAL SANS-SERIF SMALL I"),
+    (0x1D5C3, "MATHEMATICAL SANS-SERIF SMALL J"),
+    (0x1D5C4, "MATHEMATICAL SANS-SERIF SMALL K"),
+    (0x1D5C5, "MATHEMATICAL SANS-SERIF SMALL L"),
+    (0x1D5C6, "MATHEMATICAL SANS-SERIF SMALL M"),
+    (0x1D5C7, "MATHEMATICAL SANS-SERIF SMALL N"),
+    (0x1D5C8, "MATHEMATICAL SANS-SERIF SMALL O"),
+    (0x1D5C9, "MATHEMATICAL SANS-SERIF SMALL P"),
+    (0x1D5CA, "MATHEMATICAL SANS-SERIF SMALL Q"),
+    (0x1D5CB, "MATHEMATICAL SANS-SERIF SMALL R"),
+    (0x1D5CC, "MATHEMATICAL SANS-SERIF SMALL S"),
+    (0x1D5CD, "MATHEMATICAL SANS-SERIF SMALL T"),
+    (0x1D5CE, "MATHEMATICAL SANS-SERIF SMALL U"),
+    (0x1D5CF, "MATHEMATICAL SANS-SERIF SMALL V"),
+    (0x1D5D0, "MATHEMATICAL SANS-SERIF SMALL W"),
+    (0x1D5D1, "MATHEMATICAL SANS-SERIF SMALL X"),
+    (0x1D5D2, "MATHEMATICAL SANS-SERIF SMALL Y"),
+    (0x1D5D3, "MATHEMATICAL SANS-SERIF SMALL Z"),
+    (0x1D5D4, "MATHEMATICAL SANS-SERIF BOLD CAPITAL A"),
+    (0x1D5D5, "MATHEMATICAL SANS-SERIF BOLD CAPITAL B"),
+    (0x1D5D6, "MATHEMATICAL SANS-SERIF BOLD CAPITAL C"),
+    (0x1D5D7, "MATHEMATICAL SANS-SERIF BOLD CAPITAL D"),
+    (0x1D5D8, "MATHEMATICAL SANS-SERIF BOLD CAPITAL E"),
+    (0x1D5D9, "MATHEMATICAL SANS-SERIF BOLD CAPITAL F"),
+    (0x1D5DA, "MATHEMATICAL SANS-SERIF BOLD CAPITAL G"),
+    (0x1D5DB, "MATHEMATICAL SANS-SERIF BOLD CAPITAL H"),
+    (0x1D5DC, "MATHEMATICAL SANS-SERIF BOLD CAPITAL I"),
+    (0x1D5DD, "MATHEMATICAL SANS-SERIF BOLD CAPITAL J"),
+    (0x1D5DE, "MATHEMATICAL SANS-SERIF BOLD CAPITAL K"),
+    (0x1D5DF, "MATHEMATICAL SANS-SERIF BOLD CAPITAL L"),
+    (0x1D5E0, "MATHEMATICAL SANS-SERIF BOLD CAPITAL M"),
+    (0x1D5E1, "MATHEMATICAL SANS-SERIF BOLD CAPITAL N"),
+    (0x1D5E2, "MATHEMATICAL SANS-SERIF BOLD CAPITAL O"),
+    (0x1D5E3, "MATHEMATICAL SANS-SERIF BOLD CAPITAL P"),
+    (0x1D5E4, "MATHEMATICAL SANS-SERIF BOLD CAPITAL Q"),
+    (0x1D5E5, "MATHEMATICAL SANS-SERIF BOLD CAPITAL R"),
+    (0x1D5E6, "MATHEMATICAL SANS-SERIF BOLD CAPITAL S"),
+    (0x1D5E7, "MATHEMATICAL SANS-SERIF BOLD CAPITAL T"),
+    (0x1D5E8, "MATHEMATICAL SANS-SERIF BOLD CAPITAL U"),
+    (0x1D5E9, "MATHEMATICAL SANS-SERIF BOLD CAPITAL V"),
+    (0x1D5EA, "MATHEMATICAL SANS-SERIF BOLD CAPITAL W"),
+    (0x1D5EB, "MATHEMATICAL SANS-SERIF BOLD CAPITAL X"),
+    (0x1D5EC, "MATHEMATICAL SANS-SERIF BOLD CAPITAL Y"),
+    (0x1D5ED, "MATHEMATICAL SANS-SERIF BOLD CAPITAL Z"),
+    (0x1D5EE, "MATHEMATICAL SANS-SERIF BOLD SMALL A"),
+    (0x1D5EF, "MATHEMATICAL SANS-SERIF BOLD SMALL B"),
+    (0x1D5F0, "MATHEMATICAL SANS-SERIF BOLD SMALL C"),
+    (0x1D5F1, "MATHEMATICAL SANS-SERIF BOLD SMALL D"),
+    (0x1D5F2, "MATHEMATICAL SANS-SERIF BOLD SMALL E"),
+    (0x1D5F3, "MATHEMATICAL SANS-SERIF BOLD SMALL F"),
+    (0x1D5F4, "MATHEMATICAL SANS-SERIF BOLD SMALL G"),
+    (0x1D5F5, "MATHEMATICAL SANS-SERIF BOLD SMALL H"),
+    (0x1D5F6, "MATHEMATICAL SANS-SERIF BOLD SMALL I"),
+    (0x1D5F7, "MATHEMATICAL SANS-SERIF BOLD SMALL J"),
+    (0x1D5F8, "MATHEMATICAL SANS-SERIF BOLD SMALL K"),
+    (0x1D5F9, "MATHEMATICAL SANS-SERIF BOLD SMALL L"),
+    (0x1D5FA, "MATHEMATICAL SANS-SERIF BOLD SMALL M"),
+    (0x1D5FB, "MATHEMATICAL SANS-SERIF BOLD SMALL N"),
+    (0x1D5FC, "MATHEMATICAL SANS-SERIF BOLD SMALL O"),
+    (0x1D5FD, "MATHEMATICAL SANS-SERIF BOLD SMALL P"),
+    (0x1D5FE, "MATHEMATICAL SANS-SERIF BOLD SMALL Q"),
+    (0x1D5FF, "MATHEMATICAL SANS-SERIF BOLD SMALL R"),
+    (0x1D600, "MATHEMATICAL SANS-SERIF BOLD SMALL S"),
+    (0x1D601, "MATHEMATICAL SANS-SERIF BOLD SMALL T"),
+    (0x1D602, "MATHEMATICAL SANS-SERIF BOLD SMALL U"),
+    (0x1D603, "MATHEMATICAL SANS-SERIF BOLD SMALL V"),
+    (0x1D604, "MATHEMATICAL SANS-SERIF BOLD SMALL W"),
+    (0x1D605, "MATHEMATICAL SANS-SERIF BOLD SMALL X"),
+    (0x1D606, "MATHEMATICAL SANS-SERIF BOLD SMALL Y"),
+    (0x1D607, "MATHEMATICAL SANS-SERIF BOLD SMALL Z"),
+    (0x1D608, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL A"),
+    (0x1D609, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL B"),
+    (0x1D60A, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL C"),
+    (0x1D60B, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL D"),
+    (0x1D60C, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL E"),
+    (0x1D60D, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL F"),
+    (0x1D60E, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL G"),
+    (0x1D60F, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL H"),
+    (0x1D610, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL I"),
+    (0x1D611, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL J"),
+    (0x1D612, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL K"),
+    (0x1D613, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL L"),
+    (0x1D614, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL M"),
+    (0x1D615, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL N"),
+    (0x1D616, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL O"),
+    (0x1D617, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL P"),
+    (0x1D618, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL Q"),
+    (0x1D619, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL R"),
+    (0x1D61A, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL S"),
+    (0x1D61B, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL T"),
+    (0x1D61C, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL U"),
+    (0x1D61D, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL V"),
+    (0x1D61E, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL W"),
+    (0x1D61F, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL X"),
+    (0x1D620, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL Y"),
+    (0x1D621, "MATHEMATICAL SANS-SERIF ITALIC CAPITAL Z"),
+    (0x1D622, "MATHEMATICAL SANS-SERIF ITALIC SMALL A"),
+    (0x1D623, "MATHEMATICAL SANS-SERIF ITALIC SMALL B"),
+    (0x1D624, "MATHEMATICAL SANS-SERIF ITALIC SMALL C"),
+    (0x1D625, "MATHEMATICAL SANS-SERIF ITALIC SMALL D"),
+    (0x1D626, "MATHEMATICAL SANS-SERIF ITALIC SMALL E"),
+    (0x1D627, "MATHEMATICAL SANS-SERIF ITALIC SMALL F"),
+    (0x1D628, "MATHEMATICAL SANS-SERIF ITALIC SMALL G"),
+    (0x1D629, "MATHEMATICAL SANS-SERIF ITALIC SMALL H"),
+    (0x1D62A, "MATHEMATICAL SANS-SERIF ITALIC SMALL I"),
+    (0x1D62B, "MATHEMATICAL SANS-SERIF ITALIC SMALL J"),
+    (0x1D62C, "MATHEMATICAL SANS-SERIF ITALIC SMALL K"),
+    (0x1D62D, "MATHEMATICAL SANS-SERIF ITALIC SMALL L"),
+    (0x1D62E, "MATHEMATICAL SANS-SERIF ITALIC SMALL M"),
+    (0x1D62F, "MATHEMATICAL SANS-SERIF ITALIC SMALL N"),
+    (0x1D630, "MATHEMATICAL SANS-SERIF ITALIC SMALL O"),
+    (0x1D631, "MATHEMATICAL SANS-SERIF ITALIC SMALL P"),
+    (0x1D632, "MATHEMATICAL SANS-SERIF ITALIC SMALL Q"),
+    (0x1D633, "MATHEMATICAL SANS-SERIF ITALIC SMALL R"),
+    (0x1D634, "MATHEMATICAL SANS-SERIF ITALIC SMALL S"),
+    (0x1D635, "MATHEMATICAL SANS-SERIF ITALIC SMALL T"),
+    (0x1D636, "MATHEMATICAL SANS-SERIF ITALIC SMALL U"),
+    (0x1D637, "MATHEMATICAL SANS-SERIF ITALIC SMALL V"),
+    (0x1D638, "MATHEMATICAL SANS-SERIF ITALIC SMALL W"),
+    (0x1D639, "MATHEMATICAL SANS-SERIF ITALIC SMALL X"),
+    (0x1D63A, "MATHEMATICAL SANS-SERIF ITALIC SMALL Y"),
+    (0x1D63B, "MATHEMATICAL SANS-SERIF ITALIC SMALL Z"),
+    (0x1D63C, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL A"),
+    (0x1D63D, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL B"),
+    (0x1D63E, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL C"),
+    (0x1D63F, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL D"),
+    (0x1D640, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL E"),
+    (0x1D641, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL F"),
+    (0x1D642, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL G"),
+    (0x1D643, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL H"),
+    (0x1D644, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL I"),
+    (0x1D645, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL J"),
+    (0x1D646, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL K"),
+    (0x1D647, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL L"),
+    (0x1D648, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL M"),
+    (0x1D649, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL N"),
+    (0x1D64A, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL O"),
+    (0x1D64B, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL P"),
+    (0x1D64C, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Q"),
+    (0x1D64D, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL R"),
+    (0x1D64E, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL S"),
+    (0x1D64F, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL T"),
+    (0x1D650, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL U"),
+    (0x1D651, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL V"),
+    (0x1D652, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL W"),
+    (0x1D653, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL X"),
+    (0x1D654, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Y"),
+    (0x1D655, "MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Z"),
+    (0x1D656, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL A"),
+    (0x1D657, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL B"),
+    (0x1D658, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL C"),
+    (0x1D659, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL D"),
+    (0x1D65A, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL E"),
+    (0x1D65B, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL F"),
+    (0x1D65C, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL G"),
+    (0x1D65D, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL H"),
+    (0x1D65E, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL I"),
+    (0x1D65F, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL J"),
+    (0x1D660, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL K"),
+    (0x1D661, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL L"),
+    (0x1D662, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL M"),
+    (0x1D663, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL N"),
+    (0x1D664, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL O"),
+    (0x1D665, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL P"),
+    (0x1D666, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Q"),
+    (0x1D667, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL R"),
+    (0x1D668, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL S"),
+    (0x1D669, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL T"),
+    (0x1D66A, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL U"),
+    (0x1D66B, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL V"),
+    (0x1D66C, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL W"),
+    (0x1D66D, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL X"),
+    (0x1D66E, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Y"),
+    (0x1D66F, "MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Z"),
+    (0x1D670, "MATHEMATICAL MONOSPACE CAPITAL A"),
+    (0x1D671, "MATHEMATICAL MONOSPACE CAPITAL B"),
+    (0x1D672, "MATHEMATICAL MONOSPACE CAPITAL C"),
+    (0x1D673, "MATHEMATICAL MONOSPACE CAPITAL D"),
+    (0x1D674, "MATHEMATICAL MONOSPACE CAPITAL E"),
+    (0x1D675, "MATHEMATICAL MONOSPACE CAPITAL F"),
+    (0x1D676, "MATHEMATICAL MONOSPACE CAPITAL G"),
+    (0x1D677, "MATHEMATICAL MONOSPACE CAPITAL H"),
+    (0x1D678, "MATHEMATICAL MONOSPACE CAPITAL I"),
+    (0x1D679, "MATHEMATICAL MONOSPACE CAPITAL J"),
+    (0x1D67A, "MATHEMATICAL MONOSPACE CAPITAL K"),
+    (0x1D67B, "MATHEMATICAL MONOSPACE CAPITAL L"),
+    (0x1D67C, "MATHEMATICAL MONOSPACE CAPITAL M"),
+    (0x1D67D, "MATHEMATICAL MONOSPACE CAPITAL N"),
+    (0x1D67E, "MATHEMATICAL MONOSPACE CAPITAL O"),
+    (0x1D67F, "MATHEMATICAL MONOSPACE CAPITAL P"),
+    (0x1D680, "MATHEMATICAL MONOSPACE CAPITAL Q"),
+    (0x1D681, "MATHEMATICAL MONOSPACE CAPITAL R"),
+    (0x1D682, "MATHEMATICAL MONOSPACE CAPITAL S"),
+    (0x1D683, "MATHEMATICAL MONOSPACE CAPITAL T"),
+    (0x1D684, "MATHEMATICAL MONOSPACE CAPITAL U"),
+    (0x1D685, "MATHEMATICAL MONOSPACE CAPITAL V"),
+    (0x1D686, "MATHEMATICAL MONOSPACE CAPITAL W"),
+    (0x1D687, "MATHEMATICAL MONOSPACE CAPITAL X"),
+    (0x1D688, "MATHEMATICAL MONOSPACE CAPITAL Y"),
+    (0x1D689, "MATHEMATICAL MONOSPACE CAPITAL Z"),
+    (0x1D68A, "MATHEMATICAL MONOSPACE SMALL A"),
+    (0x1D68B, "MATHEMATICAL MONOSPACE SMALL B"),
+    (0x1D68C, "MATHEMATICAL MONOSPACE SMALL C"),
+    (0x1D68D, "MATHEMATICAL MONOSPACE SMALL D"),
+    (0x1D68E, "MATHEMATICAL MONOSPACE SMALL E"),
+    (0x1D68F, "MATHEMATICAL MONOSPACE SMALL F"),
+    (0x1D690, "MATHEMATICAL MONOSPACE SMALL G"),
+    (0x1D691, "MATHEMATICAL MONOSPACE SMALL H"),
+    (0x1D692, "MATHEMATICAL MONOSPACE SMALL I"),
+    (0x1D693, "MATHEMATICAL MONOSPACE SMALL J"),
+    (0x1D694, "MATHEMATICAL MONOSPACE SMALL K"),
+    (0x1D695, "MATHEMATICAL MONOSPACE SMALL L"),
+    (0x1D696, "MATHEMATICAL MONOSPACE SMALL M"),
+    (0x1D697, "MATHEMATICAL MONOSPACE SMALL N"),
+    (0x1D698, "MATHEMATICAL MONOSPACE SMALL O"),
+    (0x1D699, "MATHEMATICAL MONOSPACE SMALL P"),
+    (0x1D69A, "MATHEMATICAL MONOSPACE SMALL Q"),
+    (0x1D69B, "MATHEMATICAL MONOSPACE SMALL R"),
+    (0x1D69C, "MATHEMATICAL MONOSPACE SMALL S"),
+    (0x1D69D, "MATHEMATICAL MONOSPACE SMALL T"),
+    (0x1D69E, "MATHEMATICAL MONOSPACE SMALL U"),
+    (0x1D69F, "MATHEMATICAL MONOSPACE SMALL V"),
+    (0x1D6A0, "MATHEMATICAL MONOSPACE SMALL W"),
+    (0x1D6A1, "MATHEMATICAL MONOSPACE SMALL X"),
+    (0x1D6A2, "MATHEMATICAL MONOSPACE SMALL Y"),
+    (0x1D6A3, "MATHEMATICAL MONOSPACE SMALL Z"),
+    (0x1D6A4, "MATHEMATICAL ITALIC SMALL DOTLESS I"),
+    (0x1D6A5, "MATHEMATICAL ITALIC SMALL DOTLESS J"),
+    (0x1DF01, "LATIN SMALL LETTER REVERSED SCRIPT G"),
+    (0x1DF02, "LATIN LETTER SMALL CAPITAL TURNED G"),
+    (0x1DF03, "LATIN SMALL LETTER REVERSED K"),
+    (0x1DF04, "LATIN LETTER SMALL CAPITAL L WITH BELT"),
+    (0x1DF06, "LATIN SMALL LETTER TURNED Y WITH BELT"),
+    (0x1DF08, "LATIN SMALL LETTER TURNED R WITH LONG LEG AND RETROFLEX HOOK"),
+    (0x1DF09, "LATIN SMALL LETTER T WITH HOOK AND RETROFLEX HOOK"),
+    (0x1DF0D, "LATIN SMALL LETTER TURNED T WITH CURL"),
+    (0x1DF0F, "LATIN LETTER STRETCHED C WITH CURL"),
+    (0x1DF10, "LATIN LETTER SMALL CAPITAL TURNED K"),
+    (0x1DF11, "LATIN SMALL LETTER L WITH FISHHOOK"),
+    (0x1DF13, "LATIN SMALL LETTER L WITH BELT AND PALATAL HOOK"),
+    (0x1DF15, "LATIN SMALL LETTER TURNED R WITH PALATAL HOOK"),
+    (0x1DF16, "LATIN SMALL LETTER R WITH FISHHOOK AND PALATAL HOOK"),
+    (0x1DF1A, "LATIN SMALL LETTER I WITH STROKE AND RETROFLEX HOOK"),
+    (0x1DF1B, "LATIN SMALL LETTER O WITH RETROFLEX HOOK"),
+    (0x1DF1D, "LATIN SMALL LETTER C WITH RETROFLEX HOOK"),
+    (0x1DF1E, "LATIN SMALL LETTER S WITH CURL"),
+    (0x1DF25, "LATIN SMALL LETTER D WITH MID-HEIGHT LEFT HOOK"),
+    (0x1DF26, "LATIN SMALL LETTER L WITH MID-HEIGHT LEFT HOOK"),
+    (0x1DF27, "LATIN SMALL LETTER N WITH MID-HEIGHT LEFT HOOK"),
+    (0x1DF28, "LATIN SMALL LETTER R WITH MID-HEIGHT LEFT HOOK"),
+    (0x1DF29, "LATIN SMALL LETTER S WITH MID-HEIGHT LEFT HOOK"),
+    (0x1DF2A, "LATIN SMALL LETTER T WITH MID-HEIGHT LEFT HOOK"),
+    (0x1F110, "PARENTHESIZED LATIN CAPITAL LETTER A"),
+    (0x1F111, "PARENTHESIZED LATIN CAPITAL LETTER B"),
+    (0x1F112, "PARENTHESIZED LATIN CAPITAL LETTER C"),
+    (0x1F113, "PARENTHESIZED LATIN CAPITAL LETTER D"),
+    (0x1F114, "PARENTHESIZED LATIN CAPITAL LETTER E"),
+    (0x1F115, "PARENTHESIZED LATIN CAPITAL LETTER F"),
+    (0x1F116, "PARENTHESIZED LATIN CAPITAL LETTER G"),
+    (0x1F117, "PARENTHESIZED LATIN CAPITAL LETTER H"),
+    (0x1F118, "PARENTHESIZED LATIN CAPITAL LETTER I"),
+    (0x1F119, "PARENTHESIZED LATIN CAPITAL LETTER J"),
+    (0x1F11A, "PARENTHESIZED LATIN CAPITAL LETTER K"),
+    (0x1F11B, "PARENTHESIZED LATIN CAPITAL LETTER L"),
+    (0x1F11C, "PARENTHESIZED LATIN CAPITAL LETTER M"),
+    (0x1F11D, "PARENTHESIZED LATIN CAPITAL LETTER N"),
+    (0x1F11E, "PARENTHESIZED LATIN CAPITAL LETTER O"),
+    (0x1F11F, "PARENTHESIZED LATIN CAPITAL LETTER P"),
+    (0x1F120, "PARENTHESIZED LATIN CAPITAL LETTER Q"),
+    (0x1F121, "PARENTHESIZED LATIN CAPITAL LETTER R"),
+    (0x1F122, "PARENTHESIZED LATIN CAPITAL LETTER S"),
+    (0x1F123, "PARENTHESIZED LATIN CAPITAL LETTER T"),
+    (0x1F124, "PARENTHESIZED LATIN CAPITAL LETTER U"),
+    (0x1F125, "PARENTHESIZED LATIN CAPITAL LETTER V"),
+    (0x1F126, "PARENTHESIZED LATIN CAPITAL LETTER W"),
+    (0x1F127, "PARENTHESIZED LATIN CAPITAL LETTER X"),
+    (0x1F128, "PARENTHESIZED LATIN CAPITAL LETTER Y"),
+    (0x1F129, "PARENTHESIZED LATIN CAPITAL LETTER Z"),
+    (0x1F12A, "TORTOISE SHELL BRACKETED LATIN CAPITAL LETTER S"),
+    (0x1F12B, "CIRCLED ITALIC LATIN CAPITAL LETTER C"),
+    (0x1F12C, "CIRCLED ITALIC LATIN CAPITAL LETTER R"),
+    (0x1F12D, "CIRCLED CD"),
+    (0x1F12E, "CIRCLED WZ"),
+    (0x1F130, "SQUARED LATIN CAPITAL LETTER A"),
+    (0x1F131, "SQUARED LATIN CAPITAL LETTER B"),
+    (0x1F132, "SQUARED LATIN CAPITAL LETTER C"),
+    (0x1F133, "SQUARED LATIN CAPITAL LETTER D"),
+    (0x1F134, "SQUARED LATIN CAPITAL LETTER E"),
+    (0x1F135, "SQUARED LATIN CAPITAL LETTER F"),
+    (0x1F136, "SQUARED LATIN CAPITAL LETTER G"),
+    (0x1F137, "SQUARED LATIN CAPITAL LETTER H"),
+    (0x1F138, "SQUARED LATIN CAPITAL LETTER I"),
+    (0x1F139, "SQUARED LATIN CAPITAL LETTER J"),
+    (0x1F13A, "SQUARED LATIN CAPITAL LETTER K"),
+    (0x1F13B, "SQUARED LATIN CAPITAL LETTER L"),
+    (0x1F13C, "SQUARED LATIN CAPITAL LETTER M"),
+    (0x1F13D, "SQUARED LATIN CAPITAL LETTER N"),
+    (0x1F13E, "SQUARED LATIN CAPITAL LETTER O"),
+    (0x1F13F, "SQUARED LATIN CAPITAL LETTER P"),
+    (0x1F140, "SQUARED LATIN CAPITAL LETTER Q"),
+    (0x1F141, "SQUARED LATIN CAPITAL LETTER R"),
+    (0x1F142, "SQUARED LATIN CAPITAL LETTER S"),
+    (0x1F143, "SQUARED LATIN CAPITAL LETTER T"),
+    (0x1F144, "SQUARED LATIN CAPITAL LETTER U"),
+    (0x1F145, "SQUARED LATIN CAPITAL LETTER V"),
+    (0x1F146, "SQUARED LATIN CAPITAL LETTER W"),
+    (0x1F147, "SQUARED LATIN CAPITAL LETTER X"),
+    (0x1F148, "SQUARED LATIN CAPITAL LETTER Y"),
+    (0x1F149, "SQUARED LATIN CAPITAL LETTER Z"),
+    (0x1F14A, "SQUARED HV"),
+    (0x1F14B, "SQUARED MV"),
+    (0x1F14C, "SQUARED SD"),
+    (0x1F14D, "SQUARED SS"),
+    (0x1F14E, "SQUARED PPV"),
+    (0x1F14F, "SQUARED WC"),
+    (0x1F150, "NEGATIVE CIRCLED LATIN CAPITAL LETTER A"),
+    (0x1F151, "NEGATIVE CIRCLED LATIN CAPITAL LETTER B"),
+    (0x1F152, "NEGATIVE CIRCLED LATIN CAPITAL LETTER C"),
+    (0x1F153, "NEGATIVE CIRCLED LATIN CAPITAL LETTER D"),
+    (0x1F154, "NEGATIVE CIRCLED LATIN CAPITAL LETTER E"),
+    (0x1F155, "NEGATIVE CIRCLED LATIN CAPITAL LETTER F"),
+    (0x1F156, "NEGATIVE CIRCLED LATIN CAPITAL LETTER G"),
+    (0x1F157, "NEGATIVE CIRCLED LATIN CAPITAL LETTER H"),
+    (0x1F158, "NEGATIVE CIRCLED LATIN CAPITAL LETTER I"),
+    (0x1F159, "NEGATIVE CIRCLED LATIN CAPITAL LETTER J"),
+    (0x1F15A, "NEGATIVE CIRCLED LATIN CAPITAL LETTER K"),
+    (0x1F15B, "NEGATIVE CIRCLED LATIN CAPITAL LETTER L"),
+    (0x1F15C, "NEGATIVE CIRCLED LATIN CAPITAL LETTER M"),
+    (0x1F15D, "NEGATIVE CIRCLED LATIN CAPITAL LETTER N"),
+    (0x1F15E, "NEGATIVE CIRCLED LATIN CAPITAL LETTER O"),
+    (0x1F15F, "NEGATIVE CIRCLED LATIN CAPITAL LETTER P"),
+    (0x1F160, "NEGATIVE CIRCLED LATIN CAPITAL LETTER Q"),
+    (0x1F161, "NEGATIVE CIRCLED LATIN CAPITAL LETTER R"),
+    (0x1F162, "NEGATIVE CIRCLED LATIN CAPITAL LETTER S"),
+    (0x1F163, "NEGATIVE CIRCLED LATIN CAPITAL LETTER T"),
+    (0x1F164, "NEGATIVE CIRCLED LATIN CAPITAL LETTER U"),
+    (0x1F165, "NEGATIVE CIRCLED LATIN CAPITAL LETTER V"),
+    (0x1F166, "NEGATIVE CIRCLED LATIN CAPITAL LETTER W"),
+    (0x1F167, "NEGATIVE CIRCLED LATIN CAPITAL LETTER X"),
+    (0x1F168, "NEGATIVE CIRCLED LATIN CAPITAL LETTER Y"),
+    (0x1F169, "NEGATIVE CIRCLED LATIN CAPITAL LETTER Z"),
+    (0x1F16A, "RAISED MC SIGN"),
+    (0x1F16B, "RAISED MD SIGN"),
+    (0x1F16C, "RAISED MR SIGN"),
+    (0x1F170, "NEGATIVE SQUARED LATIN CAPITAL LETTER A"),
+    (0x1F171, "NEGATIVE SQUARED LATIN CAPITAL LETTER B"),
+    (0x1F172, "NEGATIVE SQUARED LATIN CAPITAL LETTER C"),
+    (0x1F173, "NEGATIVE SQUARED LATIN CAPITAL LETTER D"),
+    (0x1F174, "NEGATIVE SQUARED LATIN CAPITAL LETTER E"),
+    (0x1F175, "NEGATIVE SQUARED LATIN CAPITAL LETTER F"),
+    (0x1F176, "NEGATIVE SQUARED LATIN CAPITAL LETTER G"),
+    (0x1F177, "NEGATIVE SQUARED LATIN CAPITAL LETTER H"),
+    (0x1F178, "NEGATIVE SQUARED LATIN CAPITAL LETTER I"),
+    (0x1F179, "NEGATIVE SQUARED LATIN CAPITAL LETTER J"),
+    (0x1F17A, "NEGATIVE SQUARED LATIN CAPITAL LETTER K"),
+    (0x1F17B, "NEGATIVE SQUARED LATIN CAPITAL LETTER L"),
+    (0x1F17C, "NEGATIVE SQUARED LATIN CAPITAL LETTER M"),
+    (0x1F17D, "NEGATIVE SQUARED LATIN CAPITAL LETTER N"),
+    (0x1F17E, "NEGATIVE SQUARED LATIN CAPITAL LETTER O"),
+    (0x1F17F, "NEGATIVE SQUARED LATIN CAPITAL LETTER P"),
+    (0x1F180, "NEGATIVE SQUARED LATIN CAPITAL LETTER Q"),
+    (0x1F181, "NEGATIVE SQUARED LATIN CAPITAL LETTER R"),
+    (0x1F182, "NEGATIVE SQUARED LATIN CAPITAL LETTER S"),
+    (0x1F183, "NEGATIVE SQUARED LATIN CAPITAL LETTER T"),
+    (0x1F184, "NEGATIVE SQUARED LATIN CAPITAL LETTER U"),
+    (0x1F185, "NEGATIVE SQUARED LATIN CAPITAL LETTER V"),
+    (0x1F186, "NEGATIVE SQUARED LATIN CAPITAL LETTER W"),
+    (0x1F187, "NEGATIVE SQUARED LATIN CAPITAL LETTER X"),
+    (0x1F188, "NEGATIVE SQUARED LATIN CAPITAL LETTER Y"),
+    (0x1F189, "NEGATIVE SQUARED LATIN CAPITAL LETTER Z"),
+    (0x1F18A, "CROSSED NEGATIVE SQUARED LATIN CAPITAL LETTER P"),
+    (0x1F190, "SQUARE DJ"),
+    (0x1F1A5, "SQUARED LATIN SMALL LETTER D"),
+    (0x1F1E6, "REGIONAL INDICATOR SYMBOL LETTER A"),
+    (0x1F1E7, "REGIONAL INDICATOR SYMBOL LETTER B"),
+    (0x1F1E8, "REGIONAL INDICATOR SYMBOL LETTER C"),
+    (0x1F1E9, "REGIONAL INDICATOR SYMBOL LETTER D"),
+    (0x1F1EA, "REGIONAL INDICATOR SYMBOL LETTER E"),
+    (0x1F1EB, "REGIONAL INDICATOR SYMBOL LETTER F"),
+    (0x1F1EC, "REGIONAL INDICATOR SYMBOL LETTER G"),
+    (0x1F1ED, "REGIONAL INDICATOR SYMBOL LETTER H"),
+    (0x1F1EE, "REGIONAL INDICATOR SYMBOL LETTER I"),
+    (0x1F1EF, "REGIONAL INDICATOR SYMBOL LETTER J"),
+    (0x1F1F0, "REGIONAL INDICATOR SYMBOL LETTER K"),
+    (0x1F1F1, "REGIONAL INDICATOR SYMBOL LETTER L"),
+    (0x1F1F2, "REGIONAL INDICATOR SYMBOL LETTER M"),
+    (0x1F1F3, "REGIONAL INDICATOR SYMBOL LETTER N"),
+    (0x1F1F4, "REGIONAL INDICATOR SYMBOL LETTER O"),
+    (0x1F1F5, "REGIONAL INDICATOR SYMBOL LETTER P"),
+    (0x1F1F6, "REGIONAL INDICATOR SYMBOL LETTER Q"),
+    (0x1F1F7, "REGIONAL INDICATOR SYMBOL LETTER R"),
+    (0x1F1F8, "REGIONAL INDICATOR SYMBOL LETTER S"),
+    (0x1F1F9, "REGIONAL INDICATOR SYMBOL LETTER T"),
+    (0x1F1FA, "REGIONAL INDICATOR SYMBOL LETTER U"),
+    (0x1F1FB, "REGIONAL INDICATOR SYMBOL LETTER V"),
+    (0x1F1FC, "REGIONAL INDICATOR SYMBOL LETTER W"),
+    (0x1F1FD, "REGIONAL INDICATOR SYMBOL LETTER X"),
+    (0x1F1FE, "REGIONAL INDICATOR SYMBOL LETTER Y"),
+    (0x1F1FF, "REGIONAL INDICATOR SYMBOL LETTER Z"),
+    (0xE0041, "TAG LATIN CAPITAL LETTER A"),
+    (0xE0042, "TAG LATIN CAPITAL LETTER B"),
+    (0xE0043, "TAG LATIN CAPITAL LETTER C"),
+    (0xE0044, "TAG LATIN CAPITAL LETTER D"),
+    (0xE0045, "TAG LATIN CAPITAL LETTER E"),
+    (0xE0046, "TAG LATIN CAPITAL LETTER F"),
+    (0xE0047, "TAG LATIN CAPITAL LETTER G"),
+    (0xE0048, "TAG LATIN CAPITAL LETTER H"),
+    (0xE0049, "TAG LATIN CAPITAL LETTER I"),
+    (0xE004A, "TAG LATIN CAPITAL LETTER J"),
+    (0xE004B, "TAG LATIN CAPITAL LETTER K"),
+    (0xE004C, "TAG LATIN CAPITAL LETTER L"),
+    (0xE004D, "TAG LATIN CAPITAL LETTER M"),
+    (0xE004E, "TAG LATIN CAPITAL LETTER N"),
+    (0xE004F, "TAG LATIN CAPITAL LETTER O"),
+    (0xE0050, "TAG LATIN CAPITAL LETTER P"),
+    (0xE0051, "TAG LATIN CAPITAL LETTER Q"),
+    (0xE0052, "TAG LATIN CAPITAL LETTER R"),
+    (0xE0053, "TAG LATIN CAPITAL LETTER S"),
+    (0xE0054, "TAG LATIN CAPITAL LETTER T"),
+    (0xE0055, "TAG LATIN CAPITAL LETTER U"),
+    (0xE0056, "TAG LATIN CAPITAL LETTER V"),
+    (0xE0057, "TAG LATIN CAPITAL LETTER W"),
+    (0xE0058, "TAG LATIN CAPITAL LETTER X"),
+    (0xE0059, "TAG LATIN CAPITAL LETTER Y"),
+    (0xE005A, "TAG LATIN CAPITAL LETTER Z"),
+    (0xE0061, "TAG LATIN SMALL LETTER A"),
+    (0xE0062, "TAG LATIN SMALL LETTER B"),
+    (0xE0063, "TAG LATIN SMALL LETTER C"),
+    (0xE0064, "TAG LATIN SMALL LETTER D"),
+    (0xE0065, "TAG LATIN SMALL LETTER E"),
+    (0xE0066, "TAG LATIN SMALL LETTER F"),
+    (0xE0067, "TAG LATIN SMALL LETTER G"),
+    (0xE0068, "TAG LATIN SMALL LETTER H"),
+    (0xE0069, "TAG LATIN SMALL LETTER I"),
+    (0xE006A, "TAG LATIN SMALL LETTER J"),
+    (0xE006B, "TAG LATIN SMALL LETTER K"),
+    (0xE006C, "TAG LATIN SMALL LETTER L"),
+    (0xE006D, "TAG LATIN SMALL LETTER M"),
+    (0xE006E, "TAG LATIN SMALL LETTER N"),
+    (0xE006F, "TAG LATIN SMALL LETTER O"),
+    (0xE0070, "TAG LATIN SMALL LETTER P"),
+    (0xE0071, "TAG LATIN SMALL LETTER Q"),
+    (0xE0072, "TAG LATIN SMALL LETTER R"),
+    (0xE0073, "TAG LATIN SMALL LETTER S"),
+    (0xE0074, "TAG LATIN SMALL LETTER T"),
+    (0xE0075, "TAG LATIN SMALL LETTER U"),
+    (0xE0076, "TAG LATIN SMALL LETTER V"),
+    (0xE0077, "TAG LATIN SMALL LETTER W"),
+    (0xE0078, "TAG LATIN SMALL LETTER X"),
+    (0xE0079, "TAG LATIN SMALL LETTER Y"),
+    (0xE007A, "TAG LATIN SMALL LETTER Z"),
+];
@@ -0,0 +1,234 @@
+//! Modified Hepburn romanization of kana: hiragana, katakana and halfwidth katakana
+//!
+//! Context rules across chars:
+//! * voiced and semi-voiced sound marks (e.g: `ｶﾞ` -> `GA`, `ﾊﾟ` -> `PA`)
+//! * yōon and extended katakana with a small kana (e.g: `きゃ` -> `KYA`, `しょ` -> `SHO`, `ファ` -> `FA`)
+//! * gemination with a small tsu (e.g: `がっこう` -> `GAKKOU`, `まっちゃ` -> `MATCHA`)
+//! * long vowel mark `ー`, dropped as the macron of modified Hepburn (e.g: `ラーメン` -> `RAMEN`)
+
+// Static table (sorted by code point) for kana: modified Hepburn romanization and true for small kana
+include!("hash_kana.rs");
+
+use crate::table::lookup;
+
+/// Voiced sound marks (combining, spacing and halfwidth)
+const VOICED_SOUND_MARKS: [char; 3] = ['\u{3099}', '\u{309B}', '\u{FF9E}'];
+
+/// Semi-voiced sound marks (combining, spacing and halfwidth)
+const SEMI_VOICED_SOUND_MARKS: [char; 3] = ['\u{309A}', '\u{309C}', '\u{FF9F}'];
+
+/// Prolonged sound marks (long vowel, fullwidth and halfwidth)
+const PROLONGED_SOUND_MARKS: [char; 2] = ['\u{30FC}', '\u{FF70}'];
+
+/// Syllables with a voiced sound mark
+const VOICED: [(&str, &str); 23] = [
+    ("KA", "GA"),
+    ("KI", "GI"),
+    ("KU", "GU"),
+    ("KE", "GE"),
+    ("KO", "GO"),
+    ("SA", "ZA"),
+    ("SHI", "JI"),
+    ("SU", "ZU"),
+    ("SE", "ZE"),
+    ("SO", "ZO"),
+    ("TA", "DA"),
+    ("CHI", "JI"),
+    ("TSU", "ZU"),
+    ("TE", "DE"),
+    ("TO", "DO"),
+    ("HA", "BA"),
+    ("HI", "BI"),
+    ("FU", "BU"),
+    ("HE", "BE"),
+    ("HO", "BO"),
+    ("U", "VU"),
+    ("WA", "VA"),
+    ("O", "VO"),
+];
+
+/// Syllables with a semi-voiced sound mark
+const SEMI_VOICED: [(&str, &str); 5] = [
+    ("HA", "PA"),
+    ("HI", "PI"),
+    ("FU", "PU"),
+    ("HE", "PE"),
+    ("HO", "PO"),
+];
+
+/// Small kana combined with the previous kana (yōon and extended katakana)
+const COMBINING: [&str; 9] = ["A", "I", "U", "E", "O", "YA", "YU", "YO", "WA"];
+
+/// Maximum length of the romanization of a kana with its following chars: longest (voiced) syllable combined
+/// with the longest small kana (e.g: `SHI` + `YA` -> `SHA`, `FU` + `A` -> `FA`)
+pub(crate) const MAX_KANA_LEN: usize = {
+    let mut max_syllable = 0;
+    let mut index = 0;
+    while index < KANA.len() {
+        let (syllable, _) = KANA[index].1;
+        if syllable.len() > max_syllable {
+            max_syllable = syllable.len();
+        }
+        index += 1;
+    }
+    let mut index = 0;
+    while index < VOICED.len() {
+        if VOICED[index].1.len() > max_syllable {
+            max_syllable = VOICED[index].1.len();
+        }
+        index += 1;
+    }
+    let mut index = 0;
+    while index < SEMI_VOICED.len() {
+        if SEMI_VOICED[index].1.len() > max_syllable {
+            max_syllable = SEMI_VOICED[index].1.len();
+        }
+        index += 1;
+    }
+    let mut max_small = 0;
+    let mut index = 0;
+    while index < COMBINING.len() {
+        if COMBINING[index].len() > max_small {
+            max_small = COMBINING[index].len();
+        }
+        index += 1;
+    }
+    max_syllable + max_small
+};
+
+/// Returns the modified Hepburn romanization of a kana and true for a small kana, without context rules
+/// (e.g: `し` -> `SHI`, `ゃ` -> `YA`)
+pub fn kana_of(c: char) -> Option<(&'static str, bool)> {
+    lookup(KANA, c)
+}
+
+/// Returns the modified Hepburn romanization (in parts) of a kana according to the following chars, and the
+/// number of following chars that are part of the romanization (sound marks, small kana, long vowel mark)
+pub(crate) fn kana_in_context(
+    c: char,
+    mut following: impl Iterator<Item = char> + Clone,
+) -> Option<([&'static str; 3], usize)> {
+    let (mut syllable, is_small) = kana_of(c)?;
+
+    // Small tsu: gemination of the consonant of the following kana (`CH` -> `TCH`), none before another
+    // small tsu
+    if is_small && syllable == "TSU" {
+        let next = following
+            .next()
+            .filter(|&next| kana_of(next) != Some(("TSU", true)))
+            .and_then(|next| kana_in_context(next, following));
+        let consonant =
+            match next.and_then(|(parts, _)| parts.into_iter().find(|part| !part.is_empty())) {
+                Some(part) if part.starts_with("CH") => "T",
+                Some(part) if !part.starts_with(['A', 'E', 'I', 'O', 'U']) => &part[..1],
+                _ => "",
+            };
+        return Some(([consonant, "", ""], 0));
+    }
+
+    let mut len = 0;
+    let mut next = following.next();
+
+    // Voiced and semi-voiced sound marks
+    let marks = next.and_then(|next| {
+        if VOICED_SOUND_MARKS.contains(&next) {
+            Some(VOICED.as_slice())
+        } else if SEMI_VOICED_SOUND_MARKS.contains(&next) {
+            Some(SEMI_VOICED.as_slice())
+        } else {
+            None
+        }
+    });
+    if let Some((_, marked)) =
+        marks.and_then(|marks| marks.iter().find(|(plain, _)| *plain == syllable))
+    {
+        syllable = marked;
+        len += 1;
+        next = following.next();
+    }
+
+    // Small kana combined with the syllable
+    let mut parts = [syllable, "", ""];
+    let small = next
+        .and_then(kana_of)
+        .filter(|&(small, is_small)| is_small && COMBINING.contains(&small))
+        .map(|(small, _)| small);
+    if let (false, Some(small)) = (is_small, small) {
+        if let Some(combined) = combine(syllable, small) {
+            parts = combined;
+            len += 1;
+            next = following.next();
+        }
+    }
+
+    // Long vowel mark
+    if next.is_some_and(|next| PROLONGED_SOUND_MARKS.contains(&next)) {
+        len += 1;
+    }
+
+    Some((parts, len))
+}
+
+/// Returns the romanization of a syllable combined with a small kana (e.g: `KI` + `YA` -> `KYA`, `SHI` +
+/// `YA` -> `SHA`, `FU` + `A` -> `FA`, `U` + `I` -> `WI`)
+fn combine(syllable: &'static str, small: &'static str) -> Option<[&'static str; 3]> {
+    // The vowel of the syllable is replaced
+    let stem = syllable.strip_suffix(['A', 'E', 'I', 'O', 'U'])?;
+    match (stem, syllable, small.strip_prefix('Y')) {
+        ("", "U", _) => Some(["", "W", small]),
+        ("", "I", _) => Some(["", "Y", small]),
+        ("", _, _) => None,
+        (stem, _, Some(vowel)) if stem.ends_with("SH") || stem.ends_with("CH") || stem == "J" => {
+            Some([stem, "", vowel])
+        }
+        (stem, _, _) => Some([stem, "", small]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_kana_table_sorted() {
+        assert!(is_sorted_by_code_point(KANA));
+    }
+
+    #[test]
+    fn test_kana_of() {
+        assert_eq!(kana_of('か'), Some(("KA", false)));
+        assert_eq!(kana_of('シ'), Some(("SHI", false)));
+        assert_eq!(kana_of('ﾂ'), Some(("TSU", false)));
+        assert_eq!(kana_of('ふ'), Some(("FU", false)));
+        assert_eq!(kana_of('ゃ'), Some(("YA", true)));
+        assert_eq!(kana_of('ッ'), Some(("TSU", true)));
+        assert_eq!(kana_of('を'), Some(("O", false)));
+        assert_eq!(kana_of('ー'), None);
+        assert_eq!(kana_of('A'), None);
+        assert_eq!(MAX_KANA_LEN, 5);
+    }
+
+    #[test]
+    fn test_kana_in_context() {
+        let romaji = |txt: &str| {
+            let mut chars = txt.chars();
+            let c = chars.next().unwrap();
+            kana_in_context(c, chars).map(|(parts, len)| (parts.concat(), len))
+        };
+        assert_eq!(romaji("きゃ"), Some(("KYA".to_string(), 1)));
+        assert_eq!(romaji("しょう"), Some(("SHO".to_string(), 1)));
+        assert_eq!(romaji("ぢゃ"), Some(("JA".to_string(), 1)));
+        assert_eq!(romaji("ファ"), Some(("FA".to_string(), 1)));
+        assert_eq!(romaji("ウィ"), Some(("WI".to_string(), 1)));
+        assert_eq!(romaji("ｶﾞ"), Some(("GA".to_string(), 1)));
+        assert_eq!(romaji("ﾋﾟｮ"), Some(("PYO".to_string(), 2)));
+        assert_eq!(romaji("ラー"), Some(("RA".to_string(), 1)));
+        assert_eq!(romaji("っか"), Some(("K".to_string(), 0)));
+        assert_eq!(romaji("っち"), Some(("T".to_string(), 0)));
+        assert_eq!(romaji("っ"), Some((String::new(), 0)));
+        assert_eq!(romaji("っっか"), Some((String::new(), 0)));
+        assert_eq!(romaji("ゃ"), Some(("YA".to_string(), 0)));
+        assert_eq!(romaji("A"), None);
+    }
+}
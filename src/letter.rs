@@ -1,5 +1,5 @@
-//! Structure for one or two letters equivalent for uppercase AZ code point
-use std::fmt::Display;
+//! Structure for one or more letters equivalent for uppercase AZ code point
+use core::fmt::Display;
 
 /// Enumeration for uppercase AZ letter(s) equivalent
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,14 +9,101 @@ pub enum Letter {
 
     /// Double letters uppercase equivalent
     Letters(char, char),
+
+    /// Three or more letters uppercase equivalent (e.g: `FFI` for `ﬃ`)
+    Sequence(&'static str),
+}
+
+impl Letter {
+    /// Number of letters
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Letter(_) => 1,
+            Self::Letters(_, _) => 2,
+            Self::Sequence(s) => {
+                // Count the UTF-8 bytes that are not continuation bytes (`chars().count()` is not const)
+                let bytes = s.as_bytes();
+                let mut count = 0;
+                let mut index = 0;
+                while index < bytes.len() {
+                    if bytes[index] & 0xC0 != 0x80 {
+                        count += 1;
+                    }
+                    index += 1;
+                }
+                count
+            }
+        }
+    }
+
+    /// Returns true if there is no letter (empty sequence)
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Letter at `index`
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<char> {
+        match (self, index) {
+            (Self::Letter(c), 0) | (Self::Letters(c, _), 0) | (Self::Letters(_, c), 1) => Some(*c),
+            (Self::Sequence(s), _) => s.chars().nth(index),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Letter {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Letter(c) => write!(f, "{c}"),
             Self::Letters(c1, c2) => write!(f, "{c1}{c2}"),
+            Self::Sequence(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Serialized as a string of letters: `"A"`, `"AE"`, `"FFI"`.
+///
+/// Sequences of three or more letters can only be deserialized if they exist in the uppercase AZ table.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Letter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Letter {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LetterVisitor;
+
+        impl serde::de::Visitor<'_> for LetterVisitor {
+            type Value = Letter;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a string of uppercase letters")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let mut chars = v.chars();
+                match (chars.next(), chars.next(), chars.next()) {
+                    (None, _, _) => Ok(Letter::Sequence("")),
+                    (Some(c), None, _) => Ok(Letter::Letter(c)),
+                    (Some(c1), Some(c2), None) => Ok(Letter::Letters(c1, c2)),
+                    // Sequences are static: look for the same one in the tables
+                    _ => crate::UppercaseAZ::default()
+                        .iter()
+                        .map(|(_, uppercase)| uppercase.letter)
+                        .find(|letter| matches!(letter, Letter::Sequence(s) if *s == v))
+                        .or_else(|| crate::cyrillic::cyrillic_sequence(v).map(Letter::Sequence))
+                        .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+                }
+            }
         }
+
+        deserializer.deserialize_str(LetterVisitor)
     }
 }
 
@@ -35,4 +122,58 @@ mod tests {
         let letter = Letter::Letters('A', 'B');
         assert_eq!(letter.to_string(), "AB");
     }
+
+    #[test]
+    fn test_display_sequence() {
+        let letter = Letter::Sequence("FFI");
+        assert_eq!(letter.to_string(), "FFI");
+    }
+
+    #[test]
+    fn test_len_and_get() {
+        assert_eq!(Letter::Letter('A').len(), 1);
+        assert_eq!(Letter::Letters('A', 'B').len(), 2);
+        assert_eq!(Letter::Sequence("FFL").len(), 3);
+        assert_eq!(Letter::Sequence("ÆØÅ").len(), 3);
+        assert_eq!(Letter::Sequence("").len(), 0);
+
+        assert_eq!(Letter::Letter('A').get(0), Some('A'));
+        assert_eq!(Letter::Letter('A').get(1), None);
+        assert_eq!(Letter::Letters('A', 'B').get(1), Some('B'));
+        assert_eq!(Letter::Letters('A', 'B').get(2), None);
+        assert_eq!(Letter::Sequence("FFL").get(2), Some('L'));
+        assert_eq!(Letter::Sequence("FFL").get(3), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        for (letter, json) in [
+            (Letter::Letter('A'), r#""A""#),
+            (Letter::Letters('A', 'E'), r#""AE""#),
+            (Letter::Sequence("FFI"), r#""FFI""#),
+            (Letter::Sequence("SHCH"), r#""SHCH""#),
+            (Letter::Sequence(""), r#""""#),
+        ] {
+            assert_eq!(serde_json::to_string(&letter).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Letter>(json).unwrap(), letter);
+        }
+        assert!(serde_json::from_str::<Letter>(r#""XYZ""#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_cyrillic() {
+        use crate::cyrillic::{cyrillic_of, Cyrillic};
+
+        for standard in [Cyrillic::Icao9303, Cyrillic::Gost779B, Cyrillic::BgnPcgn] {
+            for letter in ('\u{400}'..'\u{530}')
+                .filter_map(|c| cyrillic_of(c, standard))
+                .map(|uppercase| uppercase.letter)
+            {
+                let json = serde_json::to_string(&letter).unwrap();
+                assert_eq!(serde_json::from_str::<Letter>(&json).unwrap(), letter);
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-//! Library returning the matching upper case letter A-Z (or a double letter AA-ZZ) for a given UTF-8 code point.
+//! Library returning the matching upper case letter A-Z (or a double letter AA-ZZ, or a sequence of letters such as FFI) for a given UTF-8 code point.
 //!
 //! The uppercase AZ equivalent is based on the [Unicode database file](https://www.unicode.org/Public/UNIDATA/UnicodeData.txt).
 //!
@@ -15,6 +15,182 @@
 //! assert_eq!(uppercase_az.to_string("à l'œil"), "A L'OEIL");
 //! ```
 //!
+//! Conversion without intermediate allocation :
+//!
+//! ```rust
+//! use to_uppercase_az::UppercaseAZ;
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//!
+//! assert!(uppercase_az.chars("œil").eq(['O', 'E', 'I', 'L']));
+//! assert_eq!(format!("{}", uppercase_az.display("à l'œil")), "A L'OEIL");
+//! ```
+//!
+//! Chars without uppercase AZ equivalent are kept unchanged by default. This can be changed with
+//! [`Conversion`] options by category of char (digits, whitespaces, ASCII punctuation, others) :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, ConversionError, Unmapped, UppercaseAZ};
+//!
+//! let conversion = Conversion::new(Unmapped::Fail)
+//!     .digit(Unmapped::Drop)
+//!     .whitespace(Unmapped::Keep)
+//!     .punctuation(Unmapped::Replace('?'));
+//! let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+//!
+//! assert_eq!(uppercase_az.try_to_string("à l'œil 2"), Ok("A L?OEIL ".to_string()));
+//! assert_eq!(uppercase_az.try_to_string("à 中"), Err(ConversionError { c: '中', position: 3 }));
+//! ```
+//!
+//! Unicode digits (fullwidth, circled, superscript, Arabic-Indic, ...) can also be converted to 0-9 :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().fold_digits(true));
+//!
+//! assert_eq!(uppercase_az.digit_of('①'), Some('1'));
+//! assert_eq!(uppercase_az.to_string("ａ０１２ ①② x² h₃ ٣ ३"), "A012 12 X2 H3 3 3");
+//! ```
+//!
+//! Unicode punctuation and spaces (quotes, dashes, no-break spaces, ...) can also be converted to their closest ASCII equivalent :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().fold_punctuation(true));
+//!
+//! assert_eq!(uppercase_az.punctuation_of('’'), Some("'"));
+//! assert_eq!(uppercase_az.to_string("«\u{A0}l’œil\u{A0}» – …"), "\" L'OEIL \" - ...");
+//! ```
+//!
+//! Language-specific tailorings override some chars (the others keep their default equivalent) :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Locale, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().locale(Some(Locale::German)));
+//!
+//! assert_eq!(uppercase_az.to_string("Jürgen Größe"), "JUERGEN GROESSE");
+//! ```
+//!
+//! Cyrillic letters can be transliterated according to a standard (ICAO 9303, GOST 7.79 system B or BGN/PCGN) :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Cyrillic, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().cyrillic(Some(Cyrillic::Icao9303)));
+//!
+//! assert_eq!(uppercase_az.to_string("Юлия Щербакова"), "IULIIA SHCHERBAKOVA");
+//! ```
+//!
+//! Greek letters can be transliterated according to ELOT 743 / ISO 843, including its context rules :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().greek(true));
+//!
+//! assert_eq!(uppercase_az.to_string("Ευάγγελος Αυγερινός"), "EVANGELOS AVGERINOS");
+//! ```
+//!
+//! Hangul syllables can be romanized according to the Revised Romanization of Korean, including liaison
+//! and `ㄹ` rules, with an optional separator between syllables :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, UppercaseAZ};
+//!
+//! let conversion = Conversion::default().hangul(true);
+//! let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+//! assert_eq!(uppercase_az.to_string("김민준"), "GIMMINJUN");
+//! assert_eq!(uppercase_az.to_string("한국어"), "HANGUGEO");
+//!
+//! let uppercase_az = uppercase_az.with_conversion(conversion.hangul_separator(Some('-')));
+//! assert_eq!(uppercase_az.to_string("김민준"), "GIM-MIN-JUN");
+//! ```
+//!
+//! Hiragana, katakana and halfwidth katakana can be romanized according to the modified Hepburn romanization,
+//! including yōon, small tsu gemination and long vowel marks :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default().kana(true));
+//!
+//! assert_eq!(uppercase_az.to_string("きょうと まっちゃ ラーメン"), "KYOUTO MATCHA RAMEN");
+//! ```
+//!
+//! Mathematical Alphanumeric Symbols and Letterlike Symbols are letters with a decoration (e.g: `BOLD`,
+//! `DOUBLE-STRUCK`, `SCRIPT`) :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Decorations, Unmapped, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//! assert_eq!(uppercase_az.to_string("𝐁𝐨𝐥𝐝 ℍ𝕖𝕝𝕝𝕠 ℓ"), "BOLD HELLO L");
+//!
+//! let conversion = Conversion::default()
+//!     .decorations(Decorations::empty())
+//!     .other(Unmapped::Drop);
+//! let uppercase_az = uppercase_az.with_conversion(conversion);
+//! assert_eq!(uppercase_az.to_string("𝐁𝐨𝐥𝐝 ok"), " OK");
+//! ```
+//!
+//! Regional indicators (flags), enclosed letters and abbreviations are converted too, each category of
+//! [`Symbol`] can be turned off :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Symbol, Unmapped, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//! assert_eq!(uppercase_az.to_string("🇫🇷 Ⓐ🄰🅰 ㎏ ㏂ ™"), "FR AAA KG AM TM");
+//!
+//! let conversion = Conversion::default()
+//!     .symbols(&[Symbol::RegionalIndicator])
+//!     .other(Unmapped::Drop);
+//! let uppercase_az = uppercase_az.with_conversion(conversion);
+//! assert_eq!(uppercase_az.to_string("🇫🇷 Ⓐ ㎏"), "FR  ");
+//! ```
+//!
+//! Roman numerals are converted to their A-Z spelling with the [`Property::RomanNumeral`] property
+//! ([`Property::SmallRomanNumeral`] for small ones) :
+//!
+//! ```rust
+//! use to_uppercase_az::{Property, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//! assert_eq!(uppercase_az.to_string("Chapitre Ⅻ, article ⅷ"), "CHAPITRE XII, ARTICLE VIII");
+//! assert_eq!(uppercase_az['Ⅻ'].property, Property::RomanNumeral);
+//! assert_eq!(uppercase_az['ⅷ'].property, Property::SmallRomanNumeral);
+//! ```
+//!
+//! Combining marks following a letter (e.g: decomposed text) are absorbed by the letter as decorations :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Decorations, Unmapped, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//! assert_eq!(uppercase_az.to_string("e\u{301}te\u{301}"), "ETE");
+//!
+//! let conversion = Conversion::default()
+//!     .decorations(Decorations::GRAVE)
+//!     .other(Unmapped::Drop);
+//! let uppercase_az = uppercase_az.with_conversion(conversion);
+//! assert_eq!(uppercase_az.to_string("e\u{301}e\u{300}"), "E");
+//! ```
+//!
+//! Conversion can also be restricted to some [`Property`]. Chars with another property are then handled
+//! as chars without uppercase AZ equivalent :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Property, Unmapped, UppercaseAZ};
+//!
+//! let conversion = Conversion::new(Unmapped::Replace('?')).properties(&[Property::Capital, Property::Small]);
+//! let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+//!
+//! assert_eq!(uppercase_az.to_string("Àℍaæ"), "??AAE");
+//! ```
+//!
 //! Extra-information [`Letter`] and [`Property`] also available for uppercase AZ equivalent :
 //!
 //! ```rust
@@ -46,15 +222,111 @@
 //! assert_eq!(uppercase_az['ǅ'].letter, Letter::Letters('D', 'Z'));
 //! assert_eq!(uppercase_az['ǅ'].property, Property::SmallAndCapitalWithDecoration);
 //! ```
+//!
+//! Case-preserving conversion to A-Z and a-z :
+//!
+//! ```rust
+//! use to_uppercase_az::UppercaseAZ;
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//!
+//! assert_eq!(uppercase_az.fold_preserving_case("à l'œil"), "a l'oeil");
+//! assert_eq!(uppercase_az.fold_preserving_case("Élodie Æ ǅ"), "Elodie AE Dz");
+//! ```
+//!
+//! Decorations (accent, cedilla, stroke, ...) of each uppercase AZ equivalent are also available and conversion can be restricted to some [`Decorations`] :
+//!
+//! ```rust
+//! use to_uppercase_az::{Conversion, Decorations, Unmapped, UppercaseAZ};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//!
+//! assert_eq!(uppercase_az['é'].decorations, Decorations::ACUTE);
+//! assert_eq!(uppercase_az['Å'].decorations, Decorations::RING | Decorations::ABOVE);
+//! assert!(uppercase_az['ø'].decorations.contains(Decorations::STROKE));
+//!
+//! let conversion = Conversion::default().other(Unmapped::Replace('?')).decorations(Decorations::ACUTE | Decorations::GRAVE);
+//! let uppercase_az = uppercase_az.with_conversion(conversion);
+//!
+//! assert_eq!(uppercase_az.to_string("Hélène Løvik"), "HELENE L?VIK");
+//! ```
+//!
+//! Reverse lookup of all the chars with a given uppercase AZ equivalent :
+//!
+//! ```rust
+//! use to_uppercase_az::{UppercaseAZ, Letter, Property};
+//!
+//! let uppercase_az = UppercaseAZ::default();
+//!
+//! assert!(uppercase_az.sources_of(Letter::Letter('E')).any(|source| source == ('É', Property::CapitalWithDecoration)));
+//! assert!(uppercase_az.iter().any(|(c, uppercase)| c == 'æ' && uppercase.letter == Letter::Letters('A', 'E')));
+//! ```
+//!
+//! # Features
+//!
+//! * `std` (default) - Standard library support, implies `alloc`.
+//! * `alloc` - String conversions such as [`UppercaseAZ::to_string`].
+//! * `names` - Unicode names of the chars with an uppercase AZ equivalent (`UppercaseAZ::name_of`).
+//! * `serde` - `Serialize`/`Deserialize` for [`Letter`] (`"AE"`), [`Property`] (`"Capital"`),
+//!   [`Decorations`] (`["ACUTE"]`) and [`Uppercase`], and `Serialize` for a [`UppercaseAZ`] table snapshot
+//!   (map of all the chars with an uppercase AZ equivalent).
+//!
+//! Without any feature, the crate is `no_std` and the lookups (`get`, `get_ref` and `Index<char>`)
+//! only rely on a `core` static table.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod combining_marks;
+
+mod conversion;
+pub use conversion::{Conversion, ConversionError, Unmapped};
+
+mod cyrillic;
+pub use cyrillic::Cyrillic;
+
+mod decorations;
+pub use decorations::Decorations;
+
+mod digits;
+
+mod greek;
+
+mod hangul;
+
+mod kana;
+
+mod punctuation;
 
 mod letter;
 pub use letter::Letter;
 
+mod locale;
+pub use locale::Locale;
+
+#[cfg(feature = "alloc")]
+mod offset_map;
+#[cfg(feature = "alloc")]
+pub use offset_map::OffsetMap;
+
 mod property;
 pub use property::Property;
 
+mod symbol;
+pub use symbol::Symbol;
+
+mod table;
+
 mod uppercase;
 pub use uppercase::{Uppercase, NOT_AN_UPPERCASE};
 
 mod uppercase_az;
 pub use uppercase_az::UppercaseAZ;
+
+mod uppercase_chars;
+pub use uppercase_chars::{TryUppercaseChars, UppercaseChars};
+
+mod uppercase_display;
+pub use uppercase_display::UppercaseDisplay;
@@ -0,0 +1,128 @@
+//! Language-specific tailorings of the uppercase AZ equivalent: German, Danish, Norwegian, Turkish, Spanish
+use crate::decorations::Decorations;
+use crate::letter::Letter;
+use crate::property::Property;
+use crate::table::lookup;
+use crate::uppercase::Uppercase;
+
+/// Language whose conventions override some entries of the uppercase AZ table (e.g: German `Ä` -> `AE`
+/// instead of `A`)
+///
+/// Chars without tailoring keep their default uppercase AZ equivalent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// `Ä` -> `AE`, `Ö` -> `OE`, `Ü` -> `UE`, `ß` -> `SS`
+    German,
+
+    /// `Å` -> `AA`, `Ø` -> `OE`
+    Danish,
+
+    /// `Å` -> `AA`, `Ø` -> `OE`
+    Norwegian,
+
+    /// `ı` and `İ` are letters of the alphabet: converted to `I` without decoration
+    Turkish,
+
+    /// `Ñ` is a letter of the alphabet: converted to `N` without decoration
+    Spanish,
+}
+
+/// German tailorings (sorted by code point)
+#[rustfmt::skip]
+const GERMAN: [(u32, Uppercase); 8] = [
+    (0xC4, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xD6, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xDC, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xDF, Uppercase {letter: Letter::Letters('S', 'S'), property: Property::SmallWithDecoration, decorations: Decorations::SHARP}),
+    (0xE4, Uppercase {letter: Letter::Letters('A', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xF6, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0xFC, Uppercase {letter: Letter::Letters('U', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::DIAERESIS}),
+    (0x1E9E, Uppercase {letter: Letter::Letters('S', 'S'), property: Property::CapitalWithDecoration, decorations: Decorations::SHARP}),
+];
+
+/// Danish and Norwegian tailorings (sorted by code point)
+#[rustfmt::skip]
+const DANISH_NORWEGIAN: [(u32, Uppercase); 4] = [
+    (0xC5, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::CapitalWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0xD8, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::CapitalWithDecoration, decorations: Decorations::STROKE}),
+    (0xE5, Uppercase {letter: Letter::Letters('A', 'A'), property: Property::SmallWithDecoration, decorations: Decorations::ABOVE.union(Decorations::RING)}),
+    (0xF8, Uppercase {letter: Letter::Letters('O', 'E'), property: Property::SmallWithDecoration, decorations: Decorations::STROKE}),
+];
+
+/// Turkish tailorings (sorted by code point)
+#[rustfmt::skip]
+const TURKISH: [(u32, Uppercase); 2] = [
+    (0x130, Uppercase {letter: Letter::Letter('I'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0x131, Uppercase {letter: Letter::Letter('I'), property: Property::Small, decorations: Decorations::empty()}),
+];
+
+/// Spanish tailorings (sorted by code point)
+#[rustfmt::skip]
+const SPANISH: [(u32, Uppercase); 2] = [
+    (0xD1, Uppercase {letter: Letter::Letter('N'), property: Property::Capital, decorations: Decorations::empty()}),
+    (0xF1, Uppercase {letter: Letter::Letter('N'), property: Property::Small, decorations: Decorations::empty()}),
+];
+
+impl Locale {
+    /// Tailorings of the locale (sorted by code point)
+    const fn table(self) -> &'static [(u32, Uppercase)] {
+        match self {
+            Self::German => &GERMAN,
+            Self::Danish | Self::Norwegian => &DANISH_NORWEGIAN,
+            Self::Turkish => &TURKISH,
+            Self::Spanish => &SPANISH,
+        }
+    }
+}
+
+/// Returns the uppercase AZ equivalent of a char tailored for the locale, if any
+pub fn locale_of(c: char, locale: Locale) -> Option<Uppercase> {
+    lookup(locale.table(), c)
+}
+
+/// Iterator over the chars tailored for the locale with their uppercase AZ equivalent
+pub(crate) fn locale_tailorings(locale: Locale) -> impl Iterator<Item = (char, Uppercase)> {
+    locale
+        .table()
+        .iter()
+        .filter_map(|&(code_point, uppercase)| Some((char::from_u32(code_point)?, uppercase)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_locale_tables_sorted() {
+        for locale in [
+            Locale::German,
+            Locale::Danish,
+            Locale::Norwegian,
+            Locale::Turkish,
+            Locale::Spanish,
+        ] {
+            assert!(is_sorted_by_code_point(locale.table()));
+        }
+    }
+
+    #[test]
+    fn test_locale_of() {
+        let letter = |c, locale| locale_of(c, locale).map(|uppercase| uppercase.letter);
+        assert_eq!(letter('Ä', Locale::German), Some(Letter::Letters('A', 'E')));
+        assert_eq!(letter('ß', Locale::German), Some(Letter::Letters('S', 'S')));
+        assert_eq!(letter('Å', Locale::German), None);
+        assert_eq!(letter('å', Locale::Danish), Some(Letter::Letters('A', 'A')));
+        assert_eq!(
+            letter('Ø', Locale::Norwegian),
+            Some(Letter::Letters('O', 'E'))
+        );
+        assert_eq!(letter('Ä', Locale::Danish), None);
+        assert_eq!(letter('ı', Locale::Turkish), Some(Letter::Letter('I')));
+        assert_eq!(letter('ñ', Locale::Spanish), Some(Letter::Letter('N')));
+
+        let uppercase = locale_of('İ', Locale::Turkish).unwrap();
+        assert_eq!(uppercase.property, Property::Capital);
+        assert!(uppercase.decorations.is_empty());
+    }
+}
@@ -1,45 +1,99 @@
 //!
 //! This tool uses then [Unicode database file](https://www.unicode.org/Public/UNIDATA/UnicodeData.txt) to
-//! identify `UTF_8` endpoints that can be converted to uppercase A-Z.
+//! identify `UTF_8` endpoints that can be converted to uppercase A-Z (and digits 0-9, ASCII punctuation
+//! and spaces, Cyrillic and Greek letters transliteration, kana romanization, combining marks).
 //!
 //! ```cmd
 //! $ cargo run
 //! ```
 //!
-//! When used with the `generate` argument, this tool will generate the rust source file for the
-//! uppercase AZ equivalent.
+//! When used with the `generate` argument, this tool will generate the rust source files for the
+//! uppercase AZ, symbols, digits, punctuation, Cyrillic, Greek and kana equivalent and for the combining marks.
 //!
 //! ```cmd
 //! $ cargo run generate```
 //!
+//! When the uppercase AZ equivalent guessed from the description of a latin letter and its decomposition
+//! mapping disagree, the description is used unless the `--decomposition` argument is given.
+//!
+//! ```cmd
+//! $ cargo run generate --decomposition```
+//!
 
 mod database_parsing;
-use database_parsing::parse_unicode_database_file;
+use database_parsing::{
+    parse_unicode_database_combining_marks, parse_unicode_database_cyrillic,
+    parse_unicode_database_digits, parse_unicode_database_file, parse_unicode_database_greek,
+    parse_unicode_database_kana, parse_unicode_database_punctuation, MappingSource,
+};
 
 mod hash_generation;
-use hash_generation::generate_rust_file;
+use hash_generation::{
+    generate_combining_marks_rust_file, generate_cyrillic_rust_file, generate_digits_rust_file,
+    generate_greek_rust_file, generate_kana_rust_file, generate_punctuation_rust_file,
+    generate_rust_file, generate_symbols_rust_file,
+};
 
 /// Unicode database data file
 const UNICODE_DATA_FILE: &str = "./unicode_database/UnicodeData.txt";
 
-fn main() {
-    println!("Unicode database file parser now running on file {UNICODE_DATA_FILE}...");
-    let all_uppercase_az = match parse_unicode_database_file(UNICODE_DATA_FILE) {
-        Ok(content) => content,
+/// Parse the Unicode database file with the given parser and print the number of `found` items (exits on a
+/// reading error)
+fn parse<T>(parser: impl FnOnce(&str) -> Result<Vec<T>, std::io::Error>, found: &str) -> Vec<T> {
+    match parser(UNICODE_DATA_FILE) {
+        Ok(content) => {
+            println!("Found {} {found}", content.len());
+            content
+        }
         Err(err) => {
             eprintln!("Error reading file {UNICODE_DATA_FILE}: {err}");
             std::process::exit(1);
         }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let preferred = if args.iter().any(|arg| arg == "--decomposition") {
+        MappingSource::Decomposition
+    } else {
+        MappingSource::Name
     };
-    println!(
-        "Found {} code points with A-Z equivalent",
-        all_uppercase_az.len()
+
+    println!("Unicode database file parser now running on file {UNICODE_DATA_FILE}...");
+    let all_uppercase_az = parse(
+        |filename| parse_unicode_database_file(filename, preferred),
+        "code points with A-Z equivalent",
+    );
+    let all_digits = parse(
+        parse_unicode_database_digits,
+        "code points with 0-9 equivalent",
     );
+    let all_punctuation = parse(
+        parse_unicode_database_punctuation,
+        "code points with ASCII punctuation or space equivalent",
+    );
+    let all_cyrillic = parse(
+        parse_unicode_database_cyrillic,
+        "Cyrillic code points with A-Z transliteration",
+    );
+    let all_greek = parse(
+        parse_unicode_database_greek,
+        "Greek code points with A-Z transliteration",
+    );
+    let all_kana = parse(parse_unicode_database_kana, "kana with A-Z romanization");
+    let all_combining_marks = parse(parse_unicode_database_combining_marks, "combining marks");
     println!("Unicode database file parsing done.");
-    let args: Vec<String> = std::env::args().collect();
     if args.len() > 1 && args[1] == "generate" {
+        generate_symbols_rust_file(&all_uppercase_az);
         generate_rust_file(all_uppercase_az);
+        generate_digits_rust_file(all_digits);
+        generate_punctuation_rust_file(all_punctuation);
+        generate_cyrillic_rust_file(all_cyrillic);
+        generate_greek_rust_file(all_greek);
+        generate_kana_rust_file(all_kana);
+        generate_combining_marks_rust_file(all_combining_marks);
     } else {
-        println!("Usage: 'cargo run generate' to generate the rust source file for this crate.");
+        println!("Usage: 'cargo run generate [--decomposition]' to generate the rust source file for this crate.");
     }
 }
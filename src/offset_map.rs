@@ -0,0 +1,172 @@
+//! Offsets mapping between a text and its uppercase AZ equivalent
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+/// Offsets mapping between a text (input) and its uppercase AZ equivalent (output).
+///
+/// The mapping is a list of segments: each segment is the byte range of one source char in the input
+/// and the byte range of its uppercase AZ equivalent in the output (e.g. `Æ` -> `AE`).
+/// Dropped chars have an empty output range.
+///
+/// This `struct` is created by [`crate::UppercaseAZ::to_string_with_offsets`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    // Segments (input range, output range) sorted by input and output offsets
+    segments: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl OffsetMap {
+    /// Add the output range for an input range.
+    /// Input ranges are added in order; an input range equal to the last one extends its output range.
+    pub(crate) fn push(&mut self, input: Range<usize>, output: Range<usize>) {
+        if let Some(last) = self.segments.last_mut() {
+            if last.0 == input {
+                last.1.end = output.end;
+                return;
+            }
+        }
+        self.fill_to(input.start, output.start);
+        self.segments.push((input, output));
+    }
+
+    /// Add an empty output range (at `output_end`) for the input not yet mapped up to `input_end`
+    pub(crate) fn fill_to(&mut self, input_end: usize, output_end: usize) {
+        let input_start = self.segments.last().map_or(0, |last| last.0.end);
+        if input_start < input_end {
+            self.segments
+                .push((input_start..input_end, output_end..output_end));
+        }
+    }
+
+    /// Segments (input byte range, output byte range)
+    pub fn segments(&self) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+        self.segments.iter().cloned()
+    }
+
+    /// Input byte range of the source of the output byte at `offset`
+    #[must_use]
+    pub fn to_input(&self, offset: usize) -> Option<Range<usize>> {
+        let index = self
+            .segments
+            .binary_search_by(|(_, output)| compare(output, offset))
+            .ok()?;
+        Some(self.segments[index].0.clone())
+    }
+
+    /// Output byte range of the uppercase AZ equivalent of the input byte at `offset`
+    #[must_use]
+    pub fn to_output(&self, offset: usize) -> Option<Range<usize>> {
+        let index = self
+            .segments
+            .binary_search_by(|(input, _)| compare(input, offset))
+            .ok()?;
+        Some(self.segments[index].1.clone())
+    }
+
+    /// Input byte range of the sources of an output byte range (e.g. a search hit in the output)
+    #[must_use]
+    pub fn to_input_range(&self, range: Range<usize>) -> Option<Range<usize>> {
+        if range.is_empty() {
+            return None;
+        }
+        let start = self.to_input(range.start)?.start;
+        let end = self.to_input(range.end - 1)?.end;
+        Some(start..end)
+    }
+
+    /// Output byte range of the uppercase AZ equivalent of an input byte range
+    #[must_use]
+    pub fn to_output_range(&self, range: Range<usize>) -> Option<Range<usize>> {
+        if range.is_empty() {
+            return None;
+        }
+        let start = self.to_output(range.start)?.start;
+        let end = self.to_output(range.end - 1)?.end;
+        Some(start..end)
+    }
+}
+
+/// Compare a range with an offset for dichotomy search (empty ranges are before the offset)
+fn compare(range: &Range<usize>, offset: usize) -> core::cmp::Ordering {
+    if range.end <= offset {
+        core::cmp::Ordering::Less
+    } else if range.start > offset {
+        core::cmp::Ordering::Greater
+    } else {
+        core::cmp::Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conversion::{Conversion, Unmapped};
+    use crate::uppercase_az::UppercaseAZ;
+
+    #[test]
+    fn test_offset_map() {
+        let uppercase_az = UppercaseAZ::default();
+        let (result, offsets) = uppercase_az.to_string_with_offsets("àÆb");
+        assert_eq!(result, "AAEB");
+
+        assert_eq!(offsets.to_input(0), Some(0..2));
+        assert_eq!(offsets.to_input(1), Some(2..4));
+        assert_eq!(offsets.to_input(2), Some(2..4));
+        assert_eq!(offsets.to_input(3), Some(4..5));
+        assert_eq!(offsets.to_input(4), None);
+
+        assert_eq!(offsets.to_output(0), Some(0..1));
+        assert_eq!(offsets.to_output(1), Some(0..1));
+        assert_eq!(offsets.to_output(3), Some(1..3));
+        assert_eq!(offsets.to_output(4), Some(3..4));
+        assert_eq!(offsets.to_output(5), None);
+
+        // Search hit "EB" in output
+        assert_eq!(offsets.to_input_range(2..4), Some(2..5));
+        assert_eq!(offsets.to_output_range(0..4), Some(0..3));
+    }
+
+    #[test]
+    fn test_offset_map_dropped_chars() {
+        let conversion = Conversion::default().digit(Unmapped::Drop);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let (result, offsets) = uppercase_az.to_string_with_offsets("1a22é3");
+        assert_eq!(result, "AE");
+
+        assert_eq!(
+            offsets.segments().collect::<Vec<_>>(),
+            vec![
+                (0..1, 0..0),
+                (1..2, 0..1),
+                (2..4, 1..1),
+                (4..6, 1..2),
+                (6..7, 2..2)
+            ]
+        );
+        assert_eq!(offsets.to_output(2), Some(1..1));
+        assert_eq!(offsets.to_input(1), Some(4..6));
+    }
+
+    #[test]
+    fn test_offset_map_combining_marks() {
+        let uppercase_az = UppercaseAZ::default();
+        let (result, offsets) = uppercase_az.to_string_with_offsets("e\u{301}\u{327}b");
+        assert_eq!(result, "EB");
+
+        assert_eq!(offsets.to_input(0), Some(0..5));
+        assert_eq!(offsets.to_input(1), Some(5..6));
+        assert_eq!(offsets.to_output(3), Some(0..1));
+    }
+
+    #[test]
+    fn test_offset_map_conversion_error() {
+        let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::new(Unmapped::Fail));
+        let err = uppercase_az.try_to_string_with_offsets("ab2").unwrap_err();
+        assert_eq!(err.position, 2);
+
+        let (result, offsets) = uppercase_az.to_string_with_offsets("ab2");
+        assert_eq!(result, "AB");
+        assert_eq!(offsets.to_output(1), Some(1..2));
+        assert_eq!(offsets.to_output(2), None);
+    }
+}
@@ -1,8 +1,11 @@
 //! Structure for uppercase AZ property : Capital, Small, Decoration
 
 /// Enumeration for uppercase AZ property
+///
+/// Serialized (`serde` feature) as the variant name: `"Capital"`, `"SmallWithDecoration"`, ...
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Property {
     /// Unicode end point cannot be converted to uppercase A-Z. e.g: 1, 2 ...
     NotAnUppercase,
@@ -27,6 +30,12 @@ pub enum Property {
 
     /// Unicode end point small capital letter with extra decoration
     SmallAndCapitalWithDecoration,
+
+    /// Unicode end point roman numeral such as Ⅻ, Ⅷ, ... (not a letter, e.g: `XII`, `VIII`)
+    RomanNumeral,
+
+    /// Unicode end point small roman numeral such as ⅻ, ⅷ, ... (not a letter, e.g: `XII`, `VIII`)
+    SmallRomanNumeral,
 }
 
 impl Property {
@@ -61,6 +70,22 @@ impl Property {
                 | Self::SmallAndCapitalWithDecoration
         )
     }
+
+    #[must_use]
+    pub const fn is_roman_numeral(self) -> bool {
+        matches!(self, Self::RomanNumeral | Self::SmallRomanNumeral)
+    }
+
+    /// Same property with an extra decoration (e.g: `Capital` -> `CapitalWithDecoration`)
+    #[must_use]
+    pub const fn with_decoration(self) -> Self {
+        match self {
+            Self::Capital => Self::CapitalWithDecoration,
+            Self::Small => Self::SmallWithDecoration,
+            Self::SmallAndCapital => Self::SmallAndCapitalWithDecoration,
+            _ => self,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +114,44 @@ mod tests {
         assert!(!Property::Small.is_decoration());
         assert!(!Property::NotAnUppercase.is_decoration());
     }
+
+    #[test]
+    fn test_is_roman_numeral() {
+        assert!(Property::RomanNumeral.is_roman_numeral());
+        assert!(!Property::RomanNumeral.is_capital());
+        assert!(!Property::RomanNumeral.is_small());
+        assert!(Property::SmallRomanNumeral.is_roman_numeral());
+        assert!(!Property::SmallRomanNumeral.is_small());
+        assert!(!Property::SmallRomanNumeral.is_capital());
+        assert!(!Property::Capital.is_roman_numeral());
+    }
+
+    #[test]
+    fn test_with_decoration() {
+        assert_eq!(
+            Property::Capital.with_decoration(),
+            Property::CapitalWithDecoration
+        );
+        assert_eq!(
+            Property::SmallWithDecoration.with_decoration(),
+            Property::SmallWithDecoration
+        );
+        assert_eq!(
+            Property::NotAnUppercase.with_decoration(),
+            Property::NotAnUppercase
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        assert_eq!(
+            serde_json::to_string(&Property::SmallWithDecoration).unwrap(),
+            r#""SmallWithDecoration""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Property>(r#""Capital""#).unwrap(),
+            Property::Capital
+        );
+    }
 }
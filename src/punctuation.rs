@@ -0,0 +1,57 @@
+//! ASCII equivalent for Unicode punctuation and spaces: quotes, dashes, no-break spaces, ...
+use crate::table::lookup;
+
+// Static table (sorted by code point) for ASCII punctuation and spaces equivalent
+include!("hash_punctuation.rs");
+
+/// Maximum length of an ASCII punctuation or spaces equivalent (e.g: `...` for `…`)
+pub(crate) const MAX_PUNCTUATION_LEN: usize = {
+    let mut max = 0;
+    let mut index = 0;
+    while index < PUNCTUATION.len() {
+        if PUNCTUATION[index].1.len() > max {
+            max = PUNCTUATION[index].1.len();
+        }
+        index += 1;
+    }
+    max
+};
+
+/// Returns the ASCII punctuation or spaces equivalent of a Unicode punctuation or space
+pub fn punctuation_of(c: char) -> Option<&'static str> {
+    lookup(PUNCTUATION, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_punctuation_table_sorted() {
+        assert!(is_sorted_by_code_point(PUNCTUATION));
+    }
+
+    #[test]
+    fn test_punctuation_of() {
+        assert_eq!(punctuation_of('’'), Some("'"));
+        assert_eq!(punctuation_of('‘'), Some("'"));
+        assert_eq!(punctuation_of('“'), Some("\""));
+        assert_eq!(punctuation_of('”'), Some("\""));
+        assert_eq!(punctuation_of('„'), Some("\""));
+        assert_eq!(punctuation_of('«'), Some("\""));
+        assert_eq!(punctuation_of('»'), Some("\""));
+        assert_eq!(punctuation_of('–'), Some("-"));
+        assert_eq!(punctuation_of('—'), Some("-"));
+        assert_eq!(punctuation_of('…'), Some("..."));
+        assert_eq!(punctuation_of('\u{A0}'), Some(" "));
+        assert_eq!(punctuation_of('\u{202F}'), Some(" "));
+        assert_eq!(punctuation_of('\u{3000}'), Some(" "));
+        assert_eq!(punctuation_of('（'), Some("("));
+        assert_eq!(punctuation_of('ʼ'), Some("'"));
+        assert_eq!(punctuation_of('\''), None);
+        assert_eq!(punctuation_of('A'), None);
+        assert_eq!(punctuation_of('０'), None);
+        assert_eq!(MAX_PUNCTUATION_LEN, 3);
+    }
+}
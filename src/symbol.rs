@@ -0,0 +1,49 @@
+//! Categories of symbols with an uppercase AZ equivalent that are not letters: regional indicators, enclosed
+//! letters and abbreviations
+use crate::table::lookup;
+
+/// Category of a symbol with an uppercase AZ equivalent that is not a letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symbol {
+    /// Regional indicator symbol letter (e.g: 🇫 and 🇷 of the 🇫🇷 flag)
+    RegionalIndicator,
+
+    /// Circled, parenthesized or squared letter (e.g: Ⓐ, ⒜, 🄰, 🅰)
+    EnclosedLetter,
+
+    /// Squared, circled or superscript abbreviation (e.g: ㎏, ㏂, ™)
+    Abbreviation,
+}
+
+// Static table (sorted by code point) for the symbol category of uppercase AZ code points
+include!("hash_symbols.rs");
+
+/// Returns the symbol category of a code point with an uppercase AZ equivalent (None for a letter)
+pub fn symbol_of(c: char) -> Option<Symbol> {
+    lookup(SYMBOLS, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::is_sorted_by_code_point;
+
+    #[test]
+    fn test_symbols_table_sorted() {
+        assert!(is_sorted_by_code_point(SYMBOLS));
+    }
+
+    #[test]
+    fn test_symbol_of() {
+        assert_eq!(symbol_of('🇫'), Some(Symbol::RegionalIndicator));
+        assert_eq!(symbol_of('Ⓐ'), Some(Symbol::EnclosedLetter));
+        assert_eq!(symbol_of('⒜'), Some(Symbol::EnclosedLetter));
+        assert_eq!(symbol_of('🄰'), Some(Symbol::EnclosedLetter));
+        assert_eq!(symbol_of('🅰'), Some(Symbol::EnclosedLetter));
+        assert_eq!(symbol_of('㎏'), Some(Symbol::Abbreviation));
+        assert_eq!(symbol_of('㏂'), Some(Symbol::Abbreviation));
+        assert_eq!(symbol_of('™'), Some(Symbol::Abbreviation));
+        assert_eq!(symbol_of('A'), None);
+        assert_eq!(symbol_of('ℍ'), None);
+    }
+}
@@ -0,0 +1,34 @@
+//! Search of the static tables sorted by code point (generated by `cargo run generate` or locale tailorings)
+
+/// Returns the value of a char in a table sorted by code point (searched by dichotomy)
+pub(crate) fn lookup<T: Copy>(table: &[(u32, T)], c: char) -> Option<T> {
+    let code_point = c as u32;
+    table
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Returns true if the table is sorted by code point without duplicates, as required by [`lookup`]
+#[cfg(test)]
+pub(crate) fn is_sorted_by_code_point<T>(table: &[(u32, T)]) -> bool {
+    table.windows(2).all(|w| w[0].0 < w[1].0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let table = [(0x41, 'a'), (0x43, 'c'), (0x1F1EB, 'f')];
+        assert!(is_sorted_by_code_point(&table));
+        assert_eq!(lookup(&table, 'A'), Some('a'));
+        assert_eq!(lookup(&table, 'C'), Some('c'));
+        assert_eq!(lookup(&table, '🇫'), Some('f'));
+        assert_eq!(lookup(&table, 'B'), None);
+        assert_eq!(lookup(&[] as &[(u32, char)], 'A'), None);
+        assert!(!is_sorted_by_code_point(&[(0x43, 'c'), (0x41, 'a')]));
+        assert!(!is_sorted_by_code_point(&[(0x41, 'a'), (0x41, 'b')]));
+    }
+}
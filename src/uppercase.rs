@@ -1,23 +1,30 @@
 /// DO NOT MODIFY
 /// This file is automatically generated by command 'cargo run <filename>
 ///
-use std::fmt::Display;
+use core::fmt::Display;
 
+use crate::decorations::Decorations;
 use crate::letter::Letter;
 use crate::property::Property;
 
 /// Unicode code point letter(s) and property
+///
+/// Serialized (`serde` feature) as a structure: `{"letter":"E","property":"SmallWithDecoration","decorations":["ACUTE"]}`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uppercase {
     // Uppercase letter(s) equivalent
     pub letter: Letter,
 
     // Uppercase letter(s) properties
     pub property: Property,
+
+    // Decorations of the letter(s) (accent, cedilla, etc)
+    pub decorations: Decorations,
 }
 
 impl Display for Uppercase {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.letter)
     }
 }
@@ -26,6 +33,7 @@ impl Display for Uppercase {
 pub const NOT_AN_UPPERCASE: Uppercase = Uppercase {
     letter: Letter::Letter('?'),
     property: Property::NotAnUppercase,
+    decorations: Decorations::empty(),
 };
 
 #[cfg(test)]
@@ -37,6 +45,7 @@ mod tests {
         let uppercase = Uppercase {
             letter: Letter::Letter('A'),
             property: Property::Capital,
+            decorations: Decorations::empty(),
         };
         assert_eq!(format!("{uppercase}"), "A");
     }
@@ -46,7 +55,21 @@ mod tests {
         let uppercase = Uppercase {
             letter: Letter::Letters('A', 'B'),
             property: Property::Capital,
+            decorations: Decorations::empty(),
         };
         assert_eq!(format!("{uppercase}"), "AB");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let uppercase = Uppercase {
+            letter: Letter::Letter('E'),
+            property: Property::SmallWithDecoration,
+            decorations: Decorations::ACUTE,
+        };
+        let json = r#"{"letter":"E","property":"SmallWithDecoration","decorations":["ACUTE"]}"#;
+        assert_eq!(serde_json::to_string(&uppercase).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Uppercase>(json).unwrap(), uppercase);
+    }
 }
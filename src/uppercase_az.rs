@@ -1,25 +1,66 @@
 /// DO NOT MODIFY
 /// This file is automatically generated by command 'cargo run <filename>
 ///
-use lazy_static::lazy_static;
+use core::fmt;
+use core::ops::Index;
 
-use std::collections::HashMap;
-use std::ops::Index;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::conversion::Conversion;
+#[cfg(feature = "alloc")]
+use crate::conversion::ConversionError;
+use crate::cyrillic::cyrillic_of;
+use crate::decorations::Decorations;
+use crate::digits::digit_of;
+use crate::hangul::hangul_of;
+use crate::kana::kana_of;
 use crate::letter::Letter;
+use crate::locale::{locale_of, Locale};
+#[cfg(feature = "alloc")]
+use crate::offset_map::OffsetMap;
 use crate::property::Property;
+use crate::punctuation::punctuation_of;
+use crate::symbol::{symbol_of, Symbol};
+#[cfg(feature = "names")]
+use crate::table::lookup;
 use crate::uppercase::{Uppercase, NOT_AN_UPPERCASE};
+use crate::uppercase_chars::{TryUppercaseChars, UppercaseChars};
+use crate::uppercase_display::UppercaseDisplay;
 
-// Construct a Hashmap for uppercase AZ equivalent
+// Static table (sorted by code point) for uppercase AZ equivalent
 include!("hash_uppercase_az.rs");
 
 /// All Uppercase AZ equivalent
-#[derive(Debug, Clone)]
-pub struct UppercaseAZ<'a>(&'a HashMap<u32, Uppercase>);
+#[derive(Debug, Clone, Copy)]
+pub struct UppercaseAZ<'a> {
+    // Table of uppercase AZ equivalent sorted by code point
+    table: &'a [(u32, Uppercase)],
+
+    // Conversion options for chars without uppercase AZ equivalent
+    conversion: Conversion,
+}
 
 impl Default for UppercaseAZ<'_> {
     fn default() -> Self {
-        UppercaseAZ(&UPPERCASE_AZ)
+        UppercaseAZ {
+            table: UPPERCASE_AZ,
+            conversion: Conversion::default(),
+        }
+    }
+}
+
+/// Table snapshot serialized (`serde` feature) as a map of all the chars with an uppercase AZ
+/// equivalent: `{"A":{"letter":"A","property":"Capital","decorations":[]},...}`.
+///
+/// Conversion options are not serialized.
+#[cfg(feature = "serde")]
+impl serde::Serialize for UppercaseAZ<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
     }
 }
 
@@ -31,42 +72,345 @@ impl Index<char> for UppercaseAZ<'_> {
     }
 }
 
-impl UppercaseAZ<'_> {
+impl<'a> UppercaseAZ<'a> {
+    /// Search the table (sorted by code point) for the given char
+    fn find(&self, c: char) -> Option<&Uppercase> {
+        let code_point = c as u32;
+        self.table
+            .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+            .ok()
+            .map(|index| &self.table[index].1)
+    }
+
+    /// Symbol category of a char with an uppercase AZ equivalent that is not a letter (e.g:
+    /// [`Symbol::RegionalIndicator`] for `🇫`, [`Symbol::EnclosedLetter`] for `Ⓐ` or [`Symbol::Abbreviation`]
+    /// for `㎏`)
+    ///
+    /// None if the symbol is not converted according to the [`Conversion::symbols`] option.
+    #[must_use]
+    pub fn symbol_of(&self, c: char) -> Option<Symbol> {
+        symbol_of(c).filter(|&symbol| self.conversion.is_converting(symbol))
+    }
+
+    /// ASCII digit 0-9 equivalent of a Unicode digit (e.g: `０`, `①`, `²`, `₃`, `٣` or `३`)
+    ///
+    /// None without the [`Conversion::fold_digits`] option.
+    #[must_use]
+    pub fn digit_of(&self, c: char) -> Option<char> {
+        digit_of(c).filter(|_| self.conversion.is_folding_digits())
+    }
+
+    /// ASCII equivalent of a Unicode punctuation or space (e.g: `’` -> `'`, `«` -> `"`, `…` -> `...`)
+    ///
+    /// None without the [`Conversion::fold_punctuation`] option.
+    #[must_use]
+    pub fn punctuation_of(&self, c: char) -> Option<&'static str> {
+        punctuation_of(c).filter(|_| self.conversion.is_folding_punctuation())
+    }
+
+    /// Uppercase AZ transliteration of a Cyrillic letter for the standard of the [`Conversion::cyrillic`]
+    /// option (e.g: `Щ` -> `SHCH` with [`Cyrillic::Icao9303`](crate::Cyrillic::Icao9303)), without context
+    /// rules
+    ///
+    /// None without standard.
+    #[must_use]
+    pub fn cyrillic_of(&self, c: char) -> Option<Uppercase> {
+        self.conversion
+            .cyrillic_standard()
+            .and_then(|standard| cyrillic_of(c, standard))
+    }
+
+    /// Revised Romanization of the initial consonant, the vowel and the final consonant of a Hangul syllable
+    /// (e.g: `한` -> `["H", "A", "N"]`, `울` -> `["", "U", "L"]`)
+    ///
+    /// None without the [`Conversion::hangul`] option.
+    #[must_use]
+    pub fn hangul_of(&self, c: char) -> Option<[&'static str; 3]> {
+        hangul_of(c).filter(|_| self.conversion.is_romanizing_hangul())
+    }
+
+    /// Modified Hepburn romanization of a kana, without context rules (e.g: `し` -> `SHI`, small `ゃ` -> `YA`)
+    ///
+    /// None without the [`Conversion::kana`] option. Kana are converted with the context rules (e.g: `しゃ` ->
+    /// `SHA`).
+    #[must_use]
+    pub fn kana_of(&self, c: char) -> Option<&'static str> {
+        kana_of(c)
+            .filter(|_| self.conversion.is_romanizing_kana())
+            .map(|(syllable, _)| syllable)
+    }
+
+    /// Iterator over all the chars with an uppercase AZ equivalent, sorted by code point
+    pub fn iter(&self) -> impl Iterator<Item = (char, Uppercase)> + 'a {
+        self.table.iter().filter_map(|&(code_point, uppercase)| {
+            char::from_u32(code_point).map(|c| (c, uppercase))
+        })
+    }
+
+    /// Iterator over all the chars (and their property) with the given uppercase AZ equivalent
+    ///
+    /// e.g: `Letter::Letter('E')` for `E`, `e`, `É`, `è`, `ẹ`, ...
+    pub fn sources_of(&self, letter: Letter) -> impl Iterator<Item = (char, Property)> + 'a {
+        self.iter()
+            .filter(move |(_, uppercase)| uppercase.letter == letter)
+            .map(|(c, uppercase)| (c, uppercase.property))
+    }
+
+    /// Unicode name of a char with an uppercase AZ equivalent (e.g: `LATIN CAPITAL LETTER D WITH SMALL
+    /// LETTER Z WITH CARON` for `ǅ`)
+    #[cfg(feature = "names")]
+    #[must_use]
+    pub fn name_of(&self, c: char) -> Option<&'static str> {
+        lookup(UPPERCASE_AZ_NAMES, c)
+    }
+
+    /// Same uppercase AZ equivalent with other conversion options for chars without equivalent
+    #[must_use]
+    pub const fn with_conversion(mut self, conversion: Conversion) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Conversion options for chars without uppercase AZ equivalent
+    #[must_use]
+    pub const fn conversion(&self) -> &Conversion {
+        &self.conversion
+    }
+
     #[must_use]
     pub fn get(&self, c: char) -> Option<Uppercase> {
-        let code_point = c as u32;
-        self.0.get(&code_point).copied()
+        self.find(c).copied()
     }
 
     #[must_use]
     pub fn get_ref(&self, c: char) -> &Uppercase {
-        let code_point = c as u32;
-        self.0
-            .get(&code_point)
-            .map_or(&NOT_AN_UPPERCASE, |uppercase| uppercase)
+        self.find(c).unwrap_or(&NOT_AN_UPPERCASE)
+    }
+
+    /// Uppercase AZ equivalent of a char tailored for the locale (e.g: `Ä` -> `AE` with [`Locale::German`]), or
+    /// its default uppercase AZ equivalent
+    #[must_use]
+    pub fn get_localized(&self, c: char, locale: Locale) -> Option<Uppercase> {
+        locale_of(c, locale).or_else(|| self.get(c))
+    }
+
+    /// Lazy iterator over the uppercase AZ equivalent chars of a text
+    ///
+    /// Double letters and sequences are expanded into several chars and chars without equivalent are handled according
+    /// to the conversion options. The iterator ends at the first char that cannot be converted.
+    #[must_use]
+    pub fn chars<'t>(&self, txt: &'t str) -> UppercaseChars<'a, 't> {
+        UppercaseChars::new(*self, txt)
+    }
+
+    /// Lazy iterator over the uppercase AZ equivalent chars of a text, reporting conversion errors
+    #[must_use]
+    pub fn try_chars<'t>(&self, txt: &'t str) -> TryUppercaseChars<'a, 't> {
+        TryUppercaseChars::new(*self, txt)
+    }
+
+    /// Wrapper to display the uppercase AZ equivalent of a text without intermediate allocation
+    #[must_use]
+    pub const fn display<'t>(&self, txt: &'t str) -> UppercaseDisplay<'a, 't> {
+        UppercaseDisplay::new(*self, txt)
+    }
+
+    /// Write the uppercase AZ equivalent of a text into a `fmt::Write` sink
+    ///
+    /// As [`UppercaseAZ::chars`], the conversion stops at the first char that cannot be converted
+    /// ([`Unmapped::Fail`](crate::Unmapped::Fail)): use [`UppercaseAZ::try_to_string`] or
+    /// [`UppercaseAZ::write_to_io`] to get the [`ConversionError`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the sink fails to write
+    pub fn write_to_fmt<W: fmt::Write>(&self, txt: &str, writer: &mut W) -> fmt::Result {
+        for c in self.chars(txt) {
+            writer.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Convert an UTF-8 byte stream chunk by chunk into its uppercase AZ equivalent
+    ///
+    /// UTF-8 sequences split across the reader buffer boundaries are handled, and the end of each chunk
+    /// is kept until the next one as the context of the conversion (e.g: combining marks, Greek diphthongs,
+    /// Hangul liaison, kana yōon): the result is the same as [`UppercaseAZ::try_to_string`].
+    /// Each converted char is written separately: a buffered `writer` (`BufWriter`) is recommended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader or the writer fails, or `io::ErrorKind::InvalidData` if the stream
+    /// is not valid UTF-8 or if a char cannot be converted (the error wraps the [`ConversionError`] with
+    /// its position in the stream)
+    #[cfg(feature = "std")]
+    pub fn write_to_io(
+        &self,
+        mut reader: impl BufRead,
+        mut writer: impl io::Write,
+    ) -> io::Result<()> {
+        fn invalid_utf8() -> io::Error {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            )
+        }
+
+        // Incomplete UTF-8 sequence at the end of the previous chunk
+        let mut pending = Vec::new();
+
+        // Decoded text not converted yet, with the previous char as context
+        let mut txt = String::new();
+
+        // Position in `txt` of the next char to convert
+        let mut start = 0;
+
+        // Position in the stream of the beginning of `txt`
+        let mut position = 0;
+
+        let mut buffer = [0_u8; 4];
+        loop {
+            let chunk = reader.fill_buf()?;
+            let chunk_len = chunk.len();
+            pending.extend_from_slice(chunk);
+            reader.consume(chunk_len);
+
+            // Valid UTF-8 prefix, an incomplete UTF-8 sequence at the end is kept for the next chunk
+            let valid_len = match core::str::from_utf8(&pending) {
+                Ok(valid) => valid.len(),
+                Err(err) if err.error_len().is_none() && chunk_len > 0 => err.valid_up_to(),
+                Err(_) => return Err(invalid_utf8()),
+            };
+            // `valid_len` is the length of the valid UTF-8 prefix: cannot fail
+            txt.push_str(core::str::from_utf8(&pending[..valid_len]).map_err(|_| invalid_utf8())?);
+            pending.drain(..valid_len);
+
+            // The last chars are only converted at the end of the stream, once their context is known
+            let end = if chunk_len == 0 {
+                txt.len()
+            } else {
+                TryUppercaseChars::context_start(&txt)
+            };
+            if start < end {
+                let mut chars = TryUppercaseChars::with_context(*self, &txt, start..end);
+                for c in chars.by_ref() {
+                    let c = c.map_err(|err| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            ConversionError {
+                                position: position + err.position,
+                                ..err
+                            },
+                        )
+                    })?;
+                    writer.write_all(c.encode_utf8(&mut buffer).as_bytes())?;
+                }
+                start = chars.offset();
+            }
+            if chunk_len == 0 {
+                break;
+            }
+
+            // Converted text dropped, but the previous char
+            let previous = TryUppercaseChars::previous_start(&txt, start);
+            txt.drain(..previous);
+            start -= previous;
+            position += previous;
+        }
+
+        writer.flush()
     }
 
+    #[cfg(feature = "alloc")]
     #[must_use]
     pub fn option_string(&self, c: char) -> Option<String> {
-        let code_point = c as u32;
-        self.0
-            .get(&code_point)
-            .map(|uppercase| uppercase.letter.to_string())
+        self.find(c).map(|uppercase| uppercase.letter.to_string())
     }
 
+    /// Uppercase AZ equivalent of a text
+    ///
+    /// The conversion stops at the first char that cannot be converted: use [`UppercaseAZ::try_to_string`]
+    /// to get the error.
+    #[cfg(feature = "alloc")]
     #[must_use]
     pub fn to_string(&self, txt: &str) -> String {
+        self.chars(txt).collect()
+    }
+
+    /// Uppercase AZ equivalent of a text
+    ///
+    /// # Errors
+    ///
+    /// Returns the first char that cannot be converted (see [`crate::Unmapped::Fail`])
+    #[cfg(feature = "alloc")]
+    pub fn try_to_string(&self, txt: &str) -> Result<String, ConversionError> {
+        self.try_chars(txt).collect()
+    }
+
+    /// A-Z and a-z equivalent of a text: the case of the letters is preserved (see
+    /// [`Conversion::preserve_case`])
+    ///
+    /// e.g: `à l'œil` -> `a l'oeil`, `Élodie` -> `Elodie`, `Æ` -> `AE`, `ǅ` -> `Dz`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn fold_preserving_case(&self, txt: &str) -> String {
+        self.with_conversion(self.conversion.preserve_case(true))
+            .to_string(txt)
+    }
+
+    /// Uppercase AZ equivalent of a text with the offsets mapping between the text and its equivalent
+    ///
+    /// The conversion stops at the first char that cannot be converted: use
+    /// [`UppercaseAZ::try_to_string_with_offsets`] to get the error.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_string_with_offsets(&self, txt: &str) -> (String, OffsetMap) {
+        let (result, offsets, _) = self.convert_with_offsets(txt);
+        (result, offsets)
+    }
+
+    /// Uppercase AZ equivalent of a text with the offsets mapping between the text and its equivalent
+    ///
+    /// # Errors
+    ///
+    /// Returns the first char that cannot be converted (see [`crate::Unmapped::Fail`])
+    #[cfg(feature = "alloc")]
+    pub fn try_to_string_with_offsets(
+        &self,
+        txt: &str,
+    ) -> Result<(String, OffsetMap), ConversionError> {
+        match self.convert_with_offsets(txt) {
+            (result, offsets, None) => Ok((result, offsets)),
+            (_, _, Some(err)) => Err(err),
+        }
+    }
+
+    /// Uppercase AZ equivalent of a text (up to the first conversion error) with the offsets mapping
+    #[cfg(feature = "alloc")]
+    fn convert_with_offsets(&self, txt: &str) -> (String, OffsetMap, Option<ConversionError>) {
         let mut result = String::new();
-        for c in txt.chars() {
-            result.push_str(&self.option_string(c).unwrap_or_else(|| c.to_string()));
+        let mut offsets = OffsetMap::default();
+        let mut chars = self.try_chars(txt);
+        while let Some(c) = chars.next() {
+            match c {
+                Ok(c) => {
+                    let start = result.len();
+                    result.push(c);
+                    offsets.push(chars.source(), start..result.len());
+                }
+                Err(err) => return (result, offsets, Some(err)),
+            }
         }
-        result
+        offsets.fill_to(txt.len(), result.len());
+        (result, offsets, None)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cyrillic::Cyrillic;
+    use crate::table::is_sorted_by_code_point;
 
     #[test]
     fn test_uppercase_az_get() {
@@ -75,58 +419,182 @@ mod tests {
             uppercase_az.get('A'),
             Some(Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             })
         );
         assert_eq!(
             uppercase_az.get('a'),
             Some(Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::Small
+                property: Property::Small,
+                decorations: Decorations::empty(),
             })
         );
         assert_eq!(
             uppercase_az.get('Â'),
             Some(Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::CapitalWithDecoration
+                property: Property::CapitalWithDecoration,
+                decorations: Decorations::CIRCUMFLEX,
             })
         );
         assert_eq!(
             uppercase_az.get('à'),
             Some(Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::SmallWithDecoration
+                property: Property::SmallWithDecoration,
+                decorations: Decorations::GRAVE,
             })
         );
         assert_eq!(
             uppercase_az.get('Æ'),
             Some(Uppercase {
                 letter: Letter::Letters('A', 'E'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             })
         );
         assert_eq!(
             uppercase_az.get('Æ'),
             Some(Uppercase {
                 letter: Letter::Letters('A', 'E'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             })
         );
         assert_eq!(
             uppercase_az.get('Œ'),
             Some(Uppercase {
                 letter: Letter::Letters('O', 'E'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             })
         );
         assert_eq!(
             uppercase_az.get('œ'),
             Some(Uppercase {
                 letter: Letter::Letters('O', 'E'),
-                property: Property::Small
+                property: Property::Small,
+                decorations: Decorations::empty(),
+            })
+        );
+        assert_eq!(
+            uppercase_az.get('ℍ'),
+            Some(Uppercase {
+                letter: Letter::Letter('H'),
+                property: Property::CapitalWithDecoration,
+                decorations: Decorations::DOUBLE_STRUCK,
             })
         );
+        assert_eq!(
+            uppercase_az.get('ℓ'),
+            Some(Uppercase {
+                letter: Letter::Letter('L'),
+                property: Property::SmallWithDecoration,
+                decorations: Decorations::SCRIPT,
+            })
+        );
+        assert_eq!(
+            uppercase_az.get('𝐁'),
+            Some(Uppercase {
+                letter: Letter::Letter('B'),
+                property: Property::CapitalWithDecoration,
+                decorations: Decorations::BOLD,
+            })
+        );
+        assert_eq!(
+            uppercase_az.get('𝙤'),
+            Some(Uppercase {
+                letter: Letter::Letter('O'),
+                property: Property::SmallWithDecoration,
+                decorations: Decorations::SANS_SERIF
+                    .union(Decorations::BOLD)
+                    .union(Decorations::ITALIC),
+            })
+        );
+        assert_eq!(uppercase_az.get('𝚨'), None);
+        assert_eq!(
+            uppercase_az.get('\u{212A}'),
+            Some(Uppercase {
+                letter: Letter::Letter('K'),
+                property: Property::Capital,
+                decorations: Decorations::empty(),
+            })
+        );
+        assert_eq!(uppercase_az.get('\u{212B}'), uppercase_az.get('Å'));
+        assert!(uppercase_az.chars("\u{212A}\u{212B}").eq(['K', 'A']));
+        assert_eq!(
+            uppercase_az.get('Ⅻ'),
+            Some(Uppercase {
+                letter: Letter::Sequence("XII"),
+                property: Property::RomanNumeral,
+                decorations: Decorations::empty(),
+            })
+        );
+        assert_eq!(
+            uppercase_az.get('ⅷ'),
+            Some(Uppercase {
+                letter: Letter::Sequence("VIII"),
+                property: Property::SmallRomanNumeral,
+                decorations: Decorations::empty(),
+            })
+        );
+        assert_eq!(uppercase_az.get('ↂ'), None);
+    }
+
+    #[test]
+    fn test_uppercase_az_table_sorted() {
+        assert!(is_sorted_by_code_point(UPPERCASE_AZ));
+    }
+
+    #[test]
+    fn test_uppercase_az_iter() {
+        let uppercase_az = UppercaseAZ::default();
+        assert_eq!(uppercase_az.iter().count(), UPPERCASE_AZ.len());
+        assert_eq!(
+            uppercase_az.iter().next(),
+            Some((
+                'A',
+                Uppercase {
+                    letter: Letter::Letter('A'),
+                    property: Property::Capital,
+                    decorations: Decorations::empty(),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_uppercase_az_sources_of() {
+        let uppercase_az = UppercaseAZ::default();
+        let sources: Vec<(char, Property)> = uppercase_az.sources_of(Letter::Letter('E')).collect();
+        assert!(sources.contains(&('E', Property::Capital)));
+        assert!(sources.contains(&('e', Property::Small)));
+        assert!(sources.contains(&('É', Property::CapitalWithDecoration)));
+        assert!(sources.contains(&('è', Property::SmallWithDecoration)));
+        assert!(sources.contains(&('ẹ', Property::SmallWithDecoration)));
+        assert!(!sources.iter().any(|&(c, _)| c == 'Æ'));
+
+        let sources: Vec<char> = uppercase_az
+            .sources_of(Letter::Letters('O', 'E'))
+            .map(|(c, _)| c)
+            .collect();
+        assert!(sources.contains(&'Œ'));
+        assert!(sources.contains(&'œ'));
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_uppercase_az_name_of() {
+        let uppercase_az = UppercaseAZ::default();
+        assert_eq!(uppercase_az.name_of('A'), Some("LATIN CAPITAL LETTER A"));
+        assert_eq!(
+            uppercase_az.name_of('ǅ'),
+            Some("LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON")
+        );
+        assert_eq!(uppercase_az.name_of('2'), None);
+        assert_eq!(UPPERCASE_AZ_NAMES.len(), UPPERCASE_AZ.len());
     }
 
     #[test]
@@ -135,6 +603,7 @@ mod tests {
         assert_eq!(uppercase_az.get('2'), None);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_uppercase_az_get_string() {
         let uppercase_az = UppercaseAZ::default();
@@ -151,25 +620,29 @@ mod tests {
             uppercase_az['A'],
             Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             }
         );
         assert_eq!(
             uppercase_az['a'],
             Uppercase {
                 letter: Letter::Letter('A'),
-                property: Property::Small
+                property: Property::Small,
+                decorations: Decorations::empty(),
             }
         );
         assert_eq!(
             uppercase_az['Æ'],
             Uppercase {
                 letter: Letter::Letters('A', 'E'),
-                property: Property::Capital
+                property: Property::Capital,
+                decorations: Decorations::empty(),
             }
         );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_uppercase_az_to_string() {
         let uppercase_az = UppercaseAZ::default();
@@ -179,4 +652,184 @@ mod tests {
         assert_eq!(uppercase_az.to_string("2"), "2");
         assert_eq!(uppercase_az.to_string("à l'œil"), "A L'OEIL");
     }
+
+    #[test]
+    fn test_uppercase_az_write_to_fmt() {
+        let uppercase_az = UppercaseAZ::default();
+        let mut result = String::from(">");
+        uppercase_az.write_to_fmt("à l'œil", &mut result).unwrap();
+        assert_eq!(result, ">A L'OEIL");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_uppercase_az_write_to_io() {
+        let uppercase_az = UppercaseAZ::default();
+        let txt = "à l'œil: ǅ 中文 😀";
+        // Small reader buffer to split UTF-8 sequences across chunks
+        for capacity in 1..=8 {
+            let reader = std::io::BufReader::with_capacity(capacity, txt.as_bytes());
+            let mut result = Vec::new();
+            uppercase_az.write_to_io(reader, &mut result).unwrap();
+            assert_eq!(String::from_utf8(result).unwrap(), "A L'OEIL: DZ 中文 😀");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_uppercase_az_write_to_io_context() {
+        let conversion = Conversion::default()
+            .greek(true)
+            .hangul(true)
+            .hangul_separator(Some('-'))
+            .kana(true)
+            .cyrillic(Some(Cyrillic::BgnPcgn))
+            .locale(Some(Locale::German));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        // Context across chunks: combining marks, Greek diphthongs, Hangul liaison and separator, kana
+        // yōon and small tsu, Cyrillic `Е`, locale with combining marks
+        for txt in [
+            "ae\u{301}b",
+            "αυτό αυγό",
+            "한국어",
+            "きょう がっこう まっちゃ",
+            "объект",
+            "Mu\u{308}ller",
+            "e\u{301}\u{302}\u{303}\u{304}\u{306}",
+        ] {
+            for capacity in 1..=8 {
+                let reader = std::io::BufReader::with_capacity(capacity, txt.as_bytes());
+                let mut result = Vec::new();
+                uppercase_az.write_to_io(reader, &mut result).unwrap();
+                assert_eq!(
+                    String::from_utf8(result).unwrap(),
+                    uppercase_az.to_string(txt)
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_uppercase_az_write_to_io_invalid_utf8() {
+        let uppercase_az = UppercaseAZ::default();
+        for bytes in [&b"a\xFFb"[..], &b"a\xC3"[..], &b"a\xE2\x82A"[..]] {
+            let reader = std::io::BufReader::with_capacity(2, bytes);
+            let err = uppercase_az
+                .write_to_io(reader, std::io::sink())
+                .unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_uppercase_az_fold_preserving_case() {
+        let uppercase_az = UppercaseAZ::default();
+
+        assert_eq!(uppercase_az.fold_preserving_case("à l'œil"), "a l'oeil");
+        assert_eq!(uppercase_az.fold_preserving_case("Élodie"), "Elodie");
+        assert_eq!(uppercase_az.fold_preserving_case("Æ æ ǅ"), "AE ae Dz");
+        assert_eq!(uppercase_az.to_string("à l'œil"), "A L'OEIL");
+    }
+
+    #[test]
+    fn test_uppercase_az_of_conversion() {
+        let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::default());
+        assert_eq!(uppercase_az.symbol_of('🇫'), Some(Symbol::RegionalIndicator));
+        assert_eq!(uppercase_az.symbol_of('A'), None);
+        assert_eq!(uppercase_az.digit_of('①'), None);
+        assert_eq!(uppercase_az.punctuation_of('’'), None);
+        assert_eq!(uppercase_az.cyrillic_of('Щ'), None);
+        assert_eq!(uppercase_az.hangul_of('한'), None);
+        assert_eq!(uppercase_az.kana_of('し'), None);
+
+        let conversion = Conversion::default()
+            .symbols(&[Symbol::EnclosedLetter])
+            .fold_digits(true)
+            .fold_punctuation(true)
+            .cyrillic(Some(Cyrillic::Gost779B))
+            .hangul(true)
+            .kana(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        assert_eq!(uppercase_az.symbol_of('🇫'), None);
+        assert_eq!(uppercase_az.symbol_of('Ⓐ'), Some(Symbol::EnclosedLetter));
+        assert_eq!(uppercase_az.digit_of('①'), Some('1'));
+        assert_eq!(uppercase_az.punctuation_of('’'), Some("'"));
+        assert_eq!(
+            uppercase_az
+                .cyrillic_of('Щ')
+                .map(|uppercase| uppercase.letter),
+            Some(Letter::Sequence("SHH"))
+        );
+        assert_eq!(uppercase_az.hangul_of('한'), Some(["H", "A", "N"]));
+        assert_eq!(uppercase_az.kana_of('し'), Some("SHI"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_uppercase_az_conversion() {
+        use crate::conversion::Unmapped;
+
+        let conversion = Conversion::new(Unmapped::Fail)
+            .digit(Unmapped::Drop)
+            .whitespace(Unmapped::Keep)
+            .punctuation(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+
+        assert_eq!(uppercase_az.to_string("à l'œil 2"), "A L?OEIL ");
+        assert_eq!(
+            uppercase_az.try_to_string("à l'œil 2"),
+            Ok("A L?OEIL ".to_string())
+        );
+        assert_eq!(
+            uppercase_az.try_to_string("à 中"),
+            Err(ConversionError {
+                c: '中',
+                position: 3
+            })
+        );
+        assert_eq!(uppercase_az.to_string("à 中"), "A ");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_uppercase_az_write_to_io_conversion_error() {
+        use crate::conversion::Unmapped;
+
+        let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::new(Unmapped::Fail));
+        let reader = std::io::BufReader::with_capacity(3, "éé中".as_bytes());
+        let err = uppercase_az
+            .write_to_io(reader, std::io::sink())
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let err = err
+            .into_inner()
+            .unwrap()
+            .downcast::<ConversionError>()
+            .unwrap();
+        assert_eq!(
+            *err,
+            ConversionError {
+                c: '中',
+                position: 4
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_uppercase_az_serde() {
+        let uppercase_az = UppercaseAZ::default();
+        let json = serde_json::to_string(&uppercase_az).unwrap();
+        assert!(
+            json.starts_with(r#"{"A":{"letter":"A","property":"Capital","decorations":[]},"B":"#)
+        );
+
+        let snapshot: std::collections::BTreeMap<char, Uppercase> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.len(), UPPERCASE_AZ.len());
+        assert_eq!(snapshot[&'ﬃ'], uppercase_az['ﬃ']);
+        assert_eq!(snapshot[&'Å'], uppercase_az['Å']);
+    }
 }
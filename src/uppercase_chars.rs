@@ -0,0 +1,779 @@
+//! Iterators over the uppercase AZ equivalent chars of a text
+use core::ops::Range;
+use core::str::CharIndices;
+
+use crate::combining_marks::combining_mark_of;
+use crate::conversion::Conversion;
+use crate::conversion::{ConversionError, Unmapped};
+use crate::cyrillic::cyrillic_in_context;
+use crate::digits::digit_of;
+use crate::greek::greek_in_context;
+use crate::hangul::{hangul_in_context, hangul_of, MAX_HANGUL_LEN};
+use crate::kana::{kana_in_context, MAX_KANA_LEN};
+use crate::locale::{locale_of, locale_tailorings, Locale};
+use crate::property::Property;
+use crate::punctuation::{punctuation_of, MAX_PUNCTUATION_LEN};
+use crate::symbol::symbol_of;
+use crate::uppercase::Uppercase;
+use crate::uppercase_az::UppercaseAZ;
+
+/// Maximum number of chars of a romanized Hangul syllable followed by a separator (e.g: `GYEONG-` for `경`
+/// followed by a syllable)
+const MAX_HANGUL_FOLDED_LEN: usize = MAX_HANGUL_LEN + 1;
+
+/// Maximum number of chars of a folded equivalent: punctuation, Hangul syllable or kana (the equivalents are
+/// ASCII: one char per byte)
+const FOLDED_CAPACITY: usize = {
+    let mut capacity = MAX_PUNCTUATION_LEN;
+    if MAX_HANGUL_FOLDED_LEN > capacity {
+        capacity = MAX_HANGUL_FOLDED_LEN;
+    }
+    if MAX_KANA_LEN > capacity {
+        capacity = MAX_KANA_LEN;
+    }
+    capacity
+};
+
+// Every folded equivalent fits in a `Folded`
+const _: () = assert!(
+    MAX_PUNCTUATION_LEN <= FOLDED_CAPACITY
+        && MAX_HANGUL_FOLDED_LEN <= FOLDED_CAPACITY
+        && MAX_KANA_LEN <= FOLDED_CAPACITY
+);
+
+/// Maximum number of following chars used as the context of the conversion of a char (e.g: `きゃー` for the
+/// small tsu of `っきゃー`), Greek diphthongs, Hangul and Cyrillic rules use less
+#[cfg(feature = "std")]
+const MAX_LOOKAHEAD: usize = 4;
+
+/// Chars of a folded equivalent being yielded (e.g: `...` for `…`)
+#[derive(Debug, Clone, Copy, Default)]
+struct Folded {
+    // Chars of the folded equivalent
+    chars: [char; FOLDED_CAPACITY],
+
+    // Index of the next char to yield
+    start: usize,
+
+    // Number of chars of the folded equivalent
+    end: usize,
+}
+
+impl Folded {
+    /// Append a char to the folded equivalent
+    fn push(&mut self, c: char) {
+        self.chars[self.end] = c;
+        self.end += 1;
+    }
+
+    /// Append the chars of a string to the folded equivalent
+    fn push_str(&mut self, s: &str) {
+        s.chars().for_each(|c| self.push(c));
+    }
+
+    /// Number of chars remaining to yield
+    const fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            *self = Self::default();
+            return None;
+        }
+        self.start += 1;
+        Some(self.chars[self.start - 1])
+    }
+}
+
+/// Lazy iterator over the uppercase AZ equivalent chars of a text, reporting conversion errors.
+///
+/// Double letters (e.g. `Æ`) and sequences (e.g. `ﬃ`) are expanded into several chars and chars without uppercase AZ equivalent (or
+/// with a property or decorations not allowed) are handled according to the [`crate::Conversion`] options.
+/// The iterator ends after the first [`ConversionError`].
+///
+/// This `struct` is created by [`UppercaseAZ::try_chars`].
+#[derive(Debug, Clone)]
+pub struct TryUppercaseChars<'a, 't> {
+    // Uppercase AZ equivalent table
+    uppercase_az: UppercaseAZ<'a>,
+
+    // Text to convert
+    txt: &'t str,
+
+    // Remaining chars of the text to convert
+    chars: CharIndices<'t>,
+
+    // Position in the text where the conversion stops: the following chars are only a context
+    end: usize,
+
+    // Uppercase AZ equivalent being yielded and index of its next letter
+    pending: Option<(Uppercase, usize)>,
+
+    // Remaining chars of a folded equivalent being yielded (e.g: `...` for `…`)
+    folded: Folded,
+
+    // Byte range in the text of the source of the last yielded char
+    source: Range<usize>,
+
+    // true once a conversion error has been yielded
+    failed: bool,
+}
+
+impl<'a, 't> TryUppercaseChars<'a, 't> {
+    pub(crate) fn new(uppercase_az: UppercaseAZ<'a>, txt: &'t str) -> Self {
+        Self {
+            uppercase_az,
+            txt,
+            chars: txt.char_indices(),
+            end: txt.len(),
+            pending: None,
+            folded: Folded::default(),
+            source: 0..0,
+            failed: false,
+        }
+    }
+
+    /// Iterator over the uppercase AZ equivalent chars of the `range` of a text, the chars before and after
+    /// the range being the context of the conversion
+    #[cfg(feature = "std")]
+    pub(crate) fn with_context(
+        uppercase_az: UppercaseAZ<'a>,
+        txt: &'t str,
+        range: Range<usize>,
+    ) -> Self {
+        let mut chars = Self::new(uppercase_az, txt);
+        while chars.chars.offset() < range.start && chars.chars.next().is_some() {}
+        chars.end = range.end;
+        chars
+    }
+}
+
+impl TryUppercaseChars<'_, '_> {
+    /// Case of the letter at `index` in the uppercase AZ equivalent, according to the property when the
+    /// case-preserving mode is selected: small letters and small roman numerals are lowercase and small and
+    /// capital letters (e.g. `ǅ`) are only capital for the first letter.
+    const fn case(conversion: &Conversion, property: Property, index: usize, c: char) -> char {
+        let is_small = matches!(property, Property::SmallRomanNumeral)
+            || if index == 0 {
+                property.is_small() && !property.is_capital()
+            } else {
+                property.is_small()
+            };
+        if conversion.is_preserving_case() && is_small {
+            c.to_ascii_lowercase()
+        } else {
+            c
+        }
+    }
+
+    /// Char before the current char, ignoring combining marks (e.g: `и` of `и\u{306}е`)
+    fn previous(&self) -> Option<char> {
+        self.txt[..self.source.start]
+            .chars()
+            .rev()
+            .find(|&c| combining_mark_of(c).is_none())
+    }
+
+    /// Uppercase AZ equivalent of a Greek letter according to the following chars, with the number of
+    /// following chars that are part of the equivalent (e.g: `υ` of `αυ`)
+    fn greek(&self, c: char) -> Option<(Uppercase, usize)> {
+        let mut following = self.chars.clone().map(|(_, c)| c);
+        greek_in_context(c, following.next(), following.next())
+    }
+
+    /// Absorbs the combining marks following the `len` chars of an equivalent as decorations (e.g: U+0301
+    /// after `e`), and returns the number of following chars that are part of the equivalent
+    fn combining_marks(&self, mut uppercase: Uppercase, len: usize) -> (Uppercase, usize) {
+        let mut following = self.chars.clone().map(|(_, c)| c).skip(len);
+        let mut nb_marks = 0;
+        while let Some(decorations) = following.next().and_then(combining_mark_of) {
+            uppercase.property = uppercase.property.with_decoration();
+            uppercase.decorations = uppercase.decorations.union(decorations);
+            nb_marks += 1;
+        }
+        (uppercase, len + nb_marks)
+    }
+
+    /// Locale tailoring of a letter that absorbed combining marks, as the precomposed char having the same
+    /// uppercase AZ equivalent (e.g: `u` followed by U+0308 is tailored as `ü`)
+    fn locale_composed(&self, locale: Locale, uppercase: Uppercase) -> Option<Uppercase> {
+        locale_tailorings(locale)
+            .find(|&(c, _)| self.uppercase_az.get(c) == Some(uppercase))
+            .map(|(_, tailored)| tailored)
+    }
+
+    /// Skip the following chars that are part of the equivalent of the current char (the source is extended)
+    fn skip(&mut self, len: usize) {
+        for (position, c) in self.chars.by_ref().take(len) {
+            self.source.end = position + c.len_utf8();
+        }
+    }
+
+    /// Byte range in the text of the source of the last yielded char
+    #[cfg(feature = "alloc")]
+    pub(crate) fn source(&self) -> Range<usize> {
+        self.source.clone()
+    }
+
+    /// Position in the text of the next char to convert
+    #[cfg(feature = "std")]
+    pub(crate) fn offset(&self) -> usize {
+        self.chars.offset()
+    }
+
+    /// Position in a text from which the chars may be the context of the conversion of the previous ones:
+    /// the last [`MAX_LOOKAHEAD`] chars, each with its following combining marks
+    #[cfg(feature = "std")]
+    pub(crate) fn context_start(txt: &str) -> usize {
+        txt.char_indices()
+            .rev()
+            .filter(|&(_, c)| combining_mark_of(c).is_none())
+            .nth(MAX_LOOKAHEAD - 1)
+            .map_or(0, |(position, _)| position)
+    }
+
+    /// Position in a text of the char before `position` that may be the context of the conversion of the
+    /// following ones (see [`TryUppercaseChars::previous`])
+    #[cfg(feature = "std")]
+    pub(crate) fn previous_start(txt: &str, position: usize) -> usize {
+        txt[..position]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| combining_mark_of(c).is_none())
+            .map_or(position, |(position, _)| position)
+    }
+}
+
+impl Iterator for TryUppercaseChars<'_, '_> {
+    type Item = Result<char, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((uppercase, index)) = self.pending.take() {
+                if let Some(c) = uppercase.letter.get(index) {
+                    self.pending = Some((uppercase, index + 1));
+                    let conversion = self.uppercase_az.conversion();
+                    return Some(Ok(Self::case(conversion, uppercase.property, index, c)));
+                }
+            }
+            if let Some(c) = self.folded.next() {
+                return Some(Ok(c));
+            }
+            if self.failed || self.chars.offset() >= self.end {
+                return None;
+            }
+            let (position, c) = self.chars.next()?;
+            self.source = position..position + c.len_utf8();
+            let conversion = *self.uppercase_az.conversion();
+            let is_allowed = |uppercase: &Uppercase| {
+                conversion.is_allowed(uppercase.property)
+                    && conversion.are_allowed(uppercase.decorations)
+            };
+
+            // Letter(s) with an uppercase AZ equivalent, or transliterated (an empty transliteration drops
+            // the letter), absorbing the following combining marks
+            let uppercase = conversion
+                .locale_tailoring()
+                .and_then(|locale| locale_of(c, locale))
+                .or_else(|| {
+                    self.uppercase_az.get(c).filter(|_| {
+                        symbol_of(c).is_none_or(|symbol| conversion.is_converting(symbol))
+                    })
+                })
+                .filter(|uppercase| !uppercase.letter.is_empty())
+                .or_else(|| {
+                    let next = self.chars.clone().next().map(|(_, next)| next);
+                    conversion.cyrillic_standard().and_then(|standard| {
+                        cyrillic_in_context(self.previous(), c, next, standard)
+                    })
+                })
+                .map(|uppercase| (uppercase, 0))
+                .or_else(|| {
+                    if conversion.is_transliterating_greek() {
+                        self.greek(c)
+                    } else {
+                        None
+                    }
+                })
+                .map(|(uppercase, len)| self.combining_marks(uppercase, len))
+                .map(|(uppercase, len)| {
+                    let tailored = conversion
+                        .locale_tailoring()
+                        .filter(|_| uppercase.property.is_decoration())
+                        .and_then(|locale| self.locale_composed(locale, uppercase));
+                    (tailored.unwrap_or(uppercase), len)
+                });
+            match uppercase {
+                Some((uppercase, len)) if is_allowed(&uppercase) => {
+                    self.skip(len);
+                    if !uppercase.letter.is_empty() {
+                        self.pending = Some((uppercase, 0));
+                    }
+                    continue;
+                }
+                // Letter not allowed: handled with its combining marks (e.g: `e` + U+0301 as `é`)
+                Some(_) => {
+                    let nb_marks = self
+                        .chars
+                        .clone()
+                        .take_while(|&(_, c)| combining_mark_of(c).is_some())
+                        .count();
+                    self.skip(nb_marks);
+                }
+                None => {}
+            }
+
+            // Other chars folded according to the conversion options
+            if conversion.is_folding_digits() {
+                if let Some(digit) = digit_of(c) {
+                    return Some(Ok(digit));
+                }
+            }
+            if conversion.is_folding_punctuation() {
+                if let Some(punctuation) = punctuation_of(c) {
+                    self.folded.push_str(punctuation);
+                    continue;
+                }
+            }
+            if conversion.is_romanizing_hangul() {
+                let next = self.chars.clone().next().map(|(_, next)| next);
+                if let Some(jamos) = hangul_in_context(self.previous(), c, next) {
+                    jamos.iter().for_each(|jamo| self.folded.push_str(jamo));
+                    let separator = conversion
+                        .hangul_syllable_separator()
+                        .filter(|_| next.and_then(hangul_of).is_some());
+                    if let Some(separator) = separator {
+                        self.folded.push(separator);
+                    }
+                    continue;
+                }
+            }
+            if conversion.is_romanizing_kana() {
+                let following = self.chars.clone().map(|(_, c)| c);
+                if let Some((parts, len)) = kana_in_context(c, following) {
+                    parts.iter().for_each(|part| self.folded.push_str(part));
+                    self.skip(len);
+                    continue;
+                }
+            }
+
+            // Char without equivalent
+            match conversion.unmapped(c) {
+                Unmapped::Keep => return Some(Ok(c)),
+                Unmapped::Drop => {}
+                Unmapped::Replace(replacement) => return Some(Ok(replacement)),
+                Unmapped::Fail => {
+                    self.failed = true;
+                    return Some(Err(ConversionError { c, position }));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.map_or(0, |(uppercase, index)| {
+            uppercase.letter.len().saturating_sub(index)
+        }) + self.folded.len();
+        let (_, high) = self.chars.size_hint();
+        if self.failed || high == Some(0) {
+            return (pending, Some(pending));
+        }
+        // Chars may be dropped and converted to any number of letters: no bounds
+        (pending, None)
+    }
+}
+
+/// Lazy iterator over the uppercase AZ equivalent chars of a text.
+///
+/// Double letters (e.g. `Æ`) and sequences (e.g. `ﬃ`) are expanded into several chars and chars without uppercase AZ equivalent are
+/// handled according to the [`crate::Conversion`] options (kept unchanged by default).
+/// The iterator ends at the first char that cannot be converted ([`Unmapped::Fail`]): use
+/// [`UppercaseAZ::try_chars`] to get the error.
+///
+/// This `struct` is created by [`UppercaseAZ::chars`].
+#[derive(Debug, Clone)]
+pub struct UppercaseChars<'a, 't>(TryUppercaseChars<'a, 't>);
+
+impl<'a, 't> UppercaseChars<'a, 't> {
+    pub(crate) fn new(uppercase_az: UppercaseAZ<'a>, txt: &'t str) -> Self {
+        Self(TryUppercaseChars::new(uppercase_az, txt))
+    }
+}
+
+impl Iterator for UppercaseChars<'_, '_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()?.ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, high) = self.0.size_hint();
+        (0, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cyrillic::Cyrillic;
+    use crate::decorations::Decorations;
+    use crate::locale::Locale;
+    use crate::symbol::Symbol;
+
+    #[test]
+    fn test_uppercase_chars() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: Vec<char> = uppercase_az.chars("à l'œil").collect();
+        assert_eq!(chars, vec!['A', ' ', 'L', '\'', 'O', 'E', 'I', 'L']);
+    }
+
+    #[test]
+    fn test_uppercase_chars_empty() {
+        let uppercase_az = UppercaseAZ::default();
+        assert_eq!(uppercase_az.chars("").next(), None);
+    }
+
+    #[test]
+    fn test_try_uppercase_chars_fail() {
+        let uppercase_az = UppercaseAZ::default().with_conversion(Conversion::new(Unmapped::Fail));
+        let mut chars = uppercase_az.try_chars("Æ中a");
+        assert_eq!(chars.next(), Some(Ok('A')));
+        assert_eq!(chars.next(), Some(Ok('E')));
+        assert_eq!(
+            chars.next(),
+            Some(Err(ConversionError {
+                c: '中',
+                position: 2
+            }))
+        );
+        assert_eq!(chars.next(), None);
+
+        let chars: Vec<char> = uppercase_az.chars("Æ中a").collect();
+        assert_eq!(chars, vec!['A', 'E']);
+    }
+
+    #[test]
+    fn test_uppercase_chars_properties() {
+        let conversion = Conversion::default().properties(&[Property::Capital, Property::Small]);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: Vec<char> = uppercase_az.chars("Àℍaæ").collect();
+        assert_eq!(chars, vec!['À', 'ℍ', 'A', 'A', 'E']);
+
+        let uppercase_az = uppercase_az.with_conversion(conversion.other(Unmapped::Replace('?')));
+        let chars: Vec<char> = uppercase_az.chars("Àℍaæ").collect();
+        assert_eq!(chars, vec!['?', '?', 'A', 'A', 'E']);
+    }
+
+    #[test]
+    fn test_uppercase_chars_preserve_case() {
+        let uppercase_az =
+            UppercaseAZ::default().with_conversion(Conversion::default().preserve_case(true));
+        let chars: String = uppercase_az.chars("à l'Œil ǅ Ǆ ǆ æ").collect();
+        assert_eq!(chars, "a l'OEil Dz DZ dz ae");
+    }
+
+    #[test]
+    fn test_uppercase_chars_sequence() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars("eﬃcace baﬄe").collect();
+        assert_eq!(chars, "EFFICACE BAFFLE");
+
+        let uppercase_az =
+            UppercaseAZ::default().with_conversion(Conversion::default().preserve_case(true));
+        let chars: String = uppercase_az.chars("eﬃcace").collect();
+        assert_eq!(chars, "efficace");
+    }
+
+    #[test]
+    fn test_uppercase_chars_decorations() {
+        let conversion = Conversion::default()
+            .decorations(Decorations::ACUTE)
+            .other(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("éèøaǝ").collect();
+        assert_eq!(chars, "E??A?");
+    }
+
+    #[test]
+    fn test_uppercase_chars_combining_marks() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars("e\u{301}te\u{301}").collect();
+        assert_eq!(chars, "ETE");
+        assert_eq!(chars, uppercase_az.chars("été").collect::<String>());
+        let chars: String = uppercase_az.chars("c\u{327}a\u{30A}\u{301}").collect();
+        assert_eq!(chars, "CA");
+
+        let conversion = Conversion::default()
+            .decorations(Decorations::ACUTE)
+            .other(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("e\u{301}e\u{300}a").collect();
+        assert_eq!(chars, "E?A");
+        let conversion = conversion.properties(&[Property::Capital, Property::Small]);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        // Same result for decomposed (NFD) and precomposed (NFC) letters
+        let chars: String = uppercase_az.chars("e\u{301}ea").collect();
+        assert_eq!(chars, "?EA");
+        let chars: String = uppercase_az.chars("éea").collect();
+        assert_eq!(chars, "?EA");
+        let chars: String = uppercase_az.chars("\u{301}").collect();
+        assert_eq!(chars, "?");
+    }
+
+    #[test]
+    fn test_uppercase_chars_symbols() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars("🇫🇷 Ⓐ🄰🅰 ㎏㏂™").collect();
+        assert_eq!(chars, "FR AAA KGAMTM");
+
+        let conversion = Conversion::default()
+            .symbols(&[Symbol::RegionalIndicator])
+            .other(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("🇫🇷 Ⓐ ㎏ ℍ").collect();
+        assert_eq!(chars, "FR ? ? H");
+    }
+
+    #[test]
+    fn test_uppercase_chars_roman_numerals() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars("Chapitre Ⅻ, article ⅷ").collect();
+        assert_eq!(chars, "CHAPITRE XII, ARTICLE VIII");
+
+        // Roman numerals are handled as digits if not allowed
+        let conversion = Conversion::new(Unmapped::Replace('?'))
+            .whitespace(Unmapped::Keep)
+            .properties(&[Property::Capital, Property::Small]);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Ⅻ XII").collect();
+        assert_eq!(chars, "? XII");
+
+        // Small roman numerals are not small letters
+        let conversion =
+            conversion.properties(&[Property::Capital, Property::Small, Property::RomanNumeral]);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Ⅻ ⅷ viii").collect();
+        assert_eq!(chars, "XII ? VIII");
+
+        let uppercase_az =
+            UppercaseAZ::default().with_conversion(Conversion::default().preserve_case(true));
+        let chars: String = uppercase_az.chars("Chapitre Ⅻ, article ⅷ").collect();
+        assert_eq!(chars, "Chapitre XII, article viii");
+    }
+
+    #[test]
+    fn test_uppercase_chars_fold_digits() {
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars("①０²₃٣३").collect();
+        assert_eq!(chars, "①０²₃٣३");
+
+        let conversion = Conversion::new(Unmapped::Fail).fold_digits(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("a①０²₃٣३9").collect();
+        assert_eq!(chars, "A1023339");
+    }
+
+    #[test]
+    fn test_uppercase_chars_fold_punctuation() {
+        let txt = "l’œil «\u{A0}Zoé\u{202F}» – c’est…";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "L’OEIL «\u{A0}ZOE\u{202F}» – C’EST…");
+
+        let conversion = Conversion::new(Unmapped::Fail)
+            .whitespace(Unmapped::Keep)
+            .fold_punctuation(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "L'OEIL \" ZOE \" - C'EST...");
+    }
+
+    #[test]
+    fn test_uppercase_chars_cyrillic() {
+        let txt = "Юлия Щербакова, объект, Елена Цыганова";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, txt);
+
+        for (standard, expected) in [
+            (
+                Cyrillic::Icao9303,
+                "IULIIA SHCHERBAKOVA, OBIEEKT, ELENA TSYGANOVA",
+            ),
+            (
+                Cyrillic::Gost779B,
+                "YULIYA SHHERBAKOVA, OBEKT, ELENA CYGANOVA",
+            ),
+            (
+                Cyrillic::BgnPcgn,
+                "YULIYA SHCHERBAKOVA, OBYEKT, YELENA TSYGANOVA",
+            ),
+        ] {
+            let conversion = Conversion::default().cyrillic(Some(standard));
+            let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+            let chars: String = uppercase_az.chars(txt).collect();
+            assert_eq!(chars, expected);
+        }
+
+        let conversion = Conversion::default()
+            .cyrillic(Some(Cyrillic::Icao9303))
+            .preserve_case(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Щука").collect();
+        assert_eq!(chars, "SHCHuka");
+    }
+
+    #[test]
+    fn test_uppercase_chars_cyrillic_signs() {
+        // Signs dropped by the standard, without one call per sign
+        let conversion = Conversion::default().cyrillic(Some(Cyrillic::Icao9303));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let txt = "ь".repeat(100_000);
+        assert_eq!(uppercase_az.chars(&txt).count(), 0);
+        let chars: String = uppercase_az.chars("дьяк").collect();
+        assert_eq!(chars, "DIAK");
+    }
+
+    #[test]
+    fn test_uppercase_chars_greek() {
+        let txt = "Θεσσαλονίκη, Ευάγγελος Αυγερινός, αυτοκίνητο, Ψυχούλα, Ευθύμιος";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, txt);
+
+        let uppercase_az =
+            UppercaseAZ::default().with_conversion(Conversion::default().greek(true));
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(
+            chars,
+            "THESSALONIKI, EVANGELOS AVGERINOS, AFTOKINITO, PSYCHOULA, EFTHYMIOS"
+        );
+
+        let conversion = Conversion::default()
+            .greek(true)
+            .decorations(Decorations::empty())
+            .other(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Αύριο αυτό").collect();
+        assert_eq!(chars, "??RIO AFT?");
+
+        let conversion = Conversion::default().greek(true).preserve_case(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Ευάγγελος Αυγερινός, ΑΥΤΟ").collect();
+        assert_eq!(chars, "Evangelos Avgerinos, AFTO");
+    }
+
+    #[test]
+    fn test_uppercase_chars_hangul() {
+        let txt = "김민준, 서울 대한민국";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, txt);
+
+        let conversion = Conversion::default().hangul(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "GIMMINJUN, SEOUL DAEHANMINGUK");
+
+        let uppercase_az = uppercase_az.with_conversion(conversion.hangul_separator(Some('-')));
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "GIM-MIN-JUN, SEO-UL DAE-HAN-MIN-GUK");
+
+        // Liaison and `ㄹ` rules, also across a separator
+        let chars: String = uppercase_az.chars("한국어 울릉도 신라").collect();
+        assert_eq!(chars, "HAN-GUG-EO UL-LEUNG-DO SIL-LA");
+        let uppercase_az = uppercase_az.with_conversion(conversion);
+        let chars: String = uppercase_az.chars("한국어 울릉도 신라").collect();
+        assert_eq!(chars, "HANGUGEO ULLEUNGDO SILLA");
+    }
+
+    #[test]
+    fn test_uppercase_chars_kana() {
+        let txt = "きょうと、まっちゃ・ラーメン ｶﾞｯｺｳ";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, txt);
+
+        let conversion = Conversion::default().kana(true);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "KYOUTO、MATCHA・RAMEN GAKKOU");
+
+        let chars: String = uppercase_az.chars("しんかんせん ティッシュ").collect();
+        assert_eq!(chars, "SHINKANSEN TISSHU");
+        // Small tsu without consonant to geminate, without one call per kana
+        let txt = "あ".to_string() + &"っ".repeat(100_000);
+        let chars: String = uppercase_az.chars(&txt).collect();
+        assert_eq!(chars, "A");
+    }
+
+    #[test]
+    fn test_uppercase_chars_locale() {
+        let txt = "Jürgen Größe, Søren Åberg, Işıl İnce, Muñoz";
+        let uppercase_az = UppercaseAZ::default();
+        let chars: String = uppercase_az.chars(txt).collect();
+        assert_eq!(chars, "JURGEN GROSE, SOREN ABERG, ISIL INCE, MUNOZ");
+
+        for (locale, expected) in [
+            (
+                Locale::German,
+                "JUERGEN GROESSE, SOREN ABERG, ISIL INCE, MUNOZ",
+            ),
+            (
+                Locale::Danish,
+                "JURGEN GROSE, SOEREN AABERG, ISIL INCE, MUNOZ",
+            ),
+            (
+                Locale::Norwegian,
+                "JURGEN GROSE, SOEREN AABERG, ISIL INCE, MUNOZ",
+            ),
+        ] {
+            let conversion = Conversion::default().locale(Some(locale));
+            let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+            let chars: String = uppercase_az.chars(txt).collect();
+            assert_eq!(chars, expected);
+        }
+
+        // Letters of the alphabet without decoration
+        let conversion = Conversion::default()
+            .decorations(Decorations::empty())
+            .other(Unmapped::Replace('?'));
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        let chars: String = uppercase_az.chars("Işıl İnce Muñoz").collect();
+        assert_eq!(chars, "I??L ?NCE MU?OZ");
+
+        let uppercase_az = uppercase_az.with_conversion(conversion.locale(Some(Locale::Turkish)));
+        let chars: String = uppercase_az.chars("Işıl İnce Muñoz").collect();
+        assert_eq!(chars, "I?IL INCE MU?OZ");
+
+        let uppercase_az = uppercase_az.with_conversion(conversion.locale(Some(Locale::Spanish)));
+        let chars: String = uppercase_az.chars("Işıl İnce Muñoz").collect();
+        assert_eq!(chars, "I??L ?NCE MUNOZ");
+
+        let uppercase_az = uppercase_az.with_conversion(conversion.locale(Some(Locale::Turkish)));
+        let chars: String = uppercase_az.chars("I\u{307}nce").collect();
+        assert_eq!(chars, "INCE");
+
+        // Decomposed letters (NFD) are tailored as the precomposed ones
+        for (locale, txt, expected) in [
+            (
+                Locale::German,
+                "Mu\u{308}ller Mu\u{301}ller",
+                "MUELLER MULLER",
+            ),
+            (Locale::Danish, "A\u{30A}berg", "AABERG"),
+            (Locale::Spanish, "Mun\u{303}oz", "MUNOZ"),
+        ] {
+            let conversion = Conversion::default().locale(Some(locale));
+            let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+            let chars: String = uppercase_az.chars(txt).collect();
+            assert_eq!(chars, expected);
+        }
+    }
+}
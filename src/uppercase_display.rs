@@ -0,0 +1,53 @@
+//! Display wrapper writing the uppercase AZ equivalent of a text without intermediate allocation
+use core::fmt::Display;
+
+use crate::uppercase_az::UppercaseAZ;
+
+/// Display the uppercase AZ equivalent of a text.
+///
+/// The conversion stops at the first char that cannot be converted ([`crate::Unmapped::Fail`]), as
+/// [`UppercaseAZ::chars`].
+///
+/// This `struct` is created by [`UppercaseAZ::display`].
+#[derive(Debug, Clone)]
+pub struct UppercaseDisplay<'a, 't> {
+    // Uppercase AZ equivalent table
+    uppercase_az: UppercaseAZ<'a>,
+
+    // Text to convert
+    txt: &'t str,
+}
+
+impl<'a, 't> UppercaseDisplay<'a, 't> {
+    pub(crate) const fn new(uppercase_az: UppercaseAZ<'a>, txt: &'t str) -> Self {
+        Self { uppercase_az, txt }
+    }
+}
+
+impl Display for UppercaseDisplay<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.uppercase_az.write_to_fmt(self.txt, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion::{Conversion, Unmapped};
+
+    #[test]
+    fn test_uppercase_display() {
+        let uppercase_az = UppercaseAZ::default();
+        assert_eq!(format!("{}", uppercase_az.display("à l'œil")), "A L'OEIL");
+        assert_eq!(format!("<{}>", uppercase_az.display("")), "<>");
+    }
+
+    #[test]
+    fn test_uppercase_display_conversion_error() {
+        let conversion = Conversion::new(Unmapped::Fail);
+        let uppercase_az = UppercaseAZ::default().with_conversion(conversion);
+        assert_eq!(format!("{}", uppercase_az.display("ab中c")), "AB");
+        assert_eq!(uppercase_az.display("中").to_string(), "");
+        assert!(uppercase_az.try_chars("ab中c").any(|c| c.is_err()));
+    }
+}